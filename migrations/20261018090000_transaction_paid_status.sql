@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE koru_transaction ADD COLUMN paid bool default false not null;
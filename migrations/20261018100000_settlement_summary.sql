@@ -0,0 +1,27 @@
+-- Add migration script here
+ALTER TABLE koru_settlement ADD COLUMN label TEXT NULL;
+ALTER TABLE koru_settlement ADD COLUMN total real default 0 not null;
+ALTER TABLE koru_settlement ADD COLUMN expense_count integer default 0 not null;
+
+UPDATE koru_settlement stl
+SET expense_count = (SELECT count(*) FROM koru_settlement_expenses se WHERE se.settlement_id = stl.id),
+    total = COALESCE((SELECT sum(exp.amount)
+                      FROM koru_settlement_expenses se
+                               JOIN koru_expense exp ON exp.id = se.expense_id
+                      WHERE se.settlement_id = stl.id), 0);
+
+CREATE TABLE koru_settlement_member
+(
+    settlement_id          uuid NOT NULL,
+    member_id   uuid        NOT NULL,
+    paid real NOT NULL,
+    share real NOT NULL,
+    net real NOT NULL
+);
+
+ALTER TABLE koru_settlement_member
+    ADD CONSTRAINT fk_stl_member_stl FOREIGN KEY (settlement_id)
+        REFERENCES koru_settlement (id) ON DELETE CASCADE;
+ALTER TABLE koru_settlement_member
+    ADD CONSTRAINT fk_stl_member_user FOREIGN KEY (member_id)
+        REFERENCES koru_user (id) ON DELETE CASCADE;
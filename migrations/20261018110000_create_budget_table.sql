@@ -0,0 +1,13 @@
+-- Add migration script here
+CREATE TABLE koru_budget
+(
+    group_id   uuid        NOT NULL,
+    amount     real        NOT NULL,
+    period     TEXT        NOT NULL,
+    updated_at timestamptz NOT NULL,
+    PRIMARY KEY (group_id)
+);
+
+ALTER TABLE koru_budget
+    ADD CONSTRAINT fk_budget_group FOREIGN KEY (group_id)
+        REFERENCES koru_group (id) ON DELETE CASCADE;
@@ -0,0 +1,13 @@
+-- Add migration script here
+CREATE TABLE koru_user_takeout
+(
+    user_id      uuid        NOT NULL,
+    requested_at timestamptz NOT NULL,
+    generated_at timestamptz NULL DEFAULT NULL,
+    archive      json        NULL DEFAULT NULL,
+    PRIMARY KEY (user_id)
+);
+
+ALTER TABLE koru_user_takeout
+    ADD CONSTRAINT fk_takeout_user FOREIGN KEY (user_id)
+        REFERENCES koru_user (id) ON DELETE CASCADE;
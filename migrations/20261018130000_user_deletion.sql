@@ -0,0 +1,3 @@
+-- Deleted users are anonymized rather than removed, so that the foreign keys on koru_user
+-- do not cascade to the expenses and transactions of the groups they belonged to.
+ALTER TABLE koru_user ADD COLUMN deleted_at timestamptz NULL DEFAULT NULL;
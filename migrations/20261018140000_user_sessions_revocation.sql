@@ -0,0 +1 @@
+ALTER TABLE koru_user ADD COLUMN sessions_revoked_at timestamptz NULL DEFAULT NULL;
@@ -0,0 +1,16 @@
+-- Add migration script here
+CREATE TABLE koru_user_token
+(
+    token_hash TEXT        NOT NULL,
+    user_id    uuid        NOT NULL,
+    kind       TEXT        NOT NULL,
+    created_at timestamptz NOT NULL,
+    expires_at timestamptz NOT NULL,
+    PRIMARY KEY (token_hash)
+);
+
+CREATE INDEX koru_user_token_user_idx ON koru_user_token (user_id, kind);
+
+ALTER TABLE koru_user_token
+    ADD CONSTRAINT fk_user_token_user FOREIGN KEY (user_id)
+        REFERENCES koru_user (id) ON DELETE CASCADE;
@@ -0,0 +1,20 @@
+-- Add migration script here
+CREATE TABLE koru_api_token
+(
+    id           uuid        NOT NULL,
+    user_id      uuid        NOT NULL,
+    name         TEXT        NOT NULL,
+    token_hash   TEXT        NOT NULL,
+    scope        TEXT        NOT NULL,
+    created_at   timestamptz NOT NULL,
+    expires_at   timestamptz NOT NULL,
+    last_used_at timestamptz,
+    PRIMARY KEY (id),
+    UNIQUE (token_hash)
+);
+
+CREATE INDEX koru_api_token_user_idx ON koru_api_token (user_id);
+
+ALTER TABLE koru_api_token
+    ADD CONSTRAINT fk_api_token_user FOREIGN KEY (user_id)
+        REFERENCES koru_user (id) ON DELETE CASCADE;
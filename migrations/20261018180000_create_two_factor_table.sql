@@ -0,0 +1,14 @@
+-- Add migration script here
+CREATE TABLE koru_user_two_factor
+(
+    email            TEXT        NOT NULL,
+    encrypted_secret TEXT        NOT NULL,
+    recovery_codes   TEXT[]      NOT NULL,
+    confirmed_at     timestamptz,
+    last_used_step   BIGINT,
+    PRIMARY KEY (email)
+);
+
+ALTER TABLE koru_user_two_factor
+    ADD CONSTRAINT fk_two_factor_credentials FOREIGN KEY (email)
+        REFERENCES koru_user_credentials (email) ON DELETE CASCADE;
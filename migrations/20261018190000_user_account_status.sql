@@ -0,0 +1 @@
+ALTER TABLE koru_user ADD COLUMN disabled_at TIMESTAMPTZ, ADD COLUMN password_reset_required BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1 @@
+ALTER TABLE koru_group ADD COLUMN archived_at TIMESTAMPTZ;
@@ -0,0 +1,28 @@
+-- Columns extracted from event_data, to filter the audit log
+ALTER TABLE koru_event ADD COLUMN event_kind TEXT NULL;
+ALTER TABLE koru_event ADD COLUMN user_id uuid NULL;
+ALTER TABLE koru_event ADD COLUMN group_id uuid NULL;
+ALTER TABLE koru_event ADD COLUMN actor_id uuid NULL;
+-- Client at the origin of the event, when known
+ALTER TABLE koru_event ADD COLUMN ip TEXT NULL;
+ALTER TABLE koru_event ADD COLUMN user_agent TEXT NULL;
+
+-- event_data holds a single key, the kind of the event
+UPDATE koru_event SET event_kind = (SELECT key FROM json_each(event_data) LIMIT 1);
+UPDATE koru_event
+SET user_id  = CASE WHEN event_kind LIKE 'User%' THEN (event_data -> event_kind ->> 'user_id')::uuid END,
+    -- the group id is stored as `id` in the group creation and deletion events
+    group_id = CASE
+                   WHEN event_kind NOT LIKE 'User%' THEN COALESCE(event_data -> event_kind ->> 'group_id',
+                                                                 event_data -> event_kind ->> 'id')::uuid
+        END,
+    actor_id = COALESCE(event_data -> event_kind ->> 'by',
+                        event_data -> event_kind ->> 'member_id',
+                        event_data -> event_kind ->> 'admin_id',
+                        event_data -> event_kind ->> 'user_id')::uuid;
+
+CREATE INDEX koru_event_date_idx ON koru_event (event_date, id);
+CREATE INDEX koru_event_kind_idx ON koru_event (event_kind, event_date);
+CREATE INDEX koru_event_user_idx ON koru_event (user_id, event_date);
+CREATE INDEX koru_event_group_idx ON koru_event (group_id, event_date);
+CREATE INDEX koru_event_actor_idx ON koru_event (actor_id, event_date);
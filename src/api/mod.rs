@@ -1,15 +1,25 @@
 mod response;
 mod routes;
 
+use crate::api::routes::middleware::rate_limit::RateLimiter;
 use crate::api::routes::{
-    change_color, create_expense, create_group, delete_expense, delete_group, generate_group_token,
-    get_all_groups, get_all_users, get_expenses, get_group, get_groups, get_settlements,
-    health_check, join_group, login, logout, middleware, register, register_device, remove_device,
-    settle, update_expense,
+    archive_group, backup_group, change_color, change_email, change_password, change_user_role,
+    confirm_two_factor, create_api_token, create_expense, create_group, delete_budget,
+    delete_expense, delete_group, delete_user, disable_user, enable_user, enroll_two_factor,
+    export_expenses, export_ledger, export_settlements, export_user_data, force_password_reset,
+    forgot_password, generate_group_token, get_activity, get_all_groups, get_all_users,
+    get_api_tokens, get_audit_log, get_budget, get_debt, get_expenses, get_group, get_groups,
+    get_sessions, get_settlements, get_stats, get_summary, health_check, import_expenses,
+    inspect_group, join_group, login, login_second_factor, logout, middleware, oidc_callback,
+    oidc_login, register, register_device, remove_device, remove_group, remove_member, remove_user,
+    resend_verification, reset_password, reset_two_factor, restore_group, revert_settlement,
+    revoke_api_token, revoke_session, revoke_sessions, set_budget, settle, settle_debt,
+    transfer_group_ownership, transfer_ownership, unarchive_group, update_expense,
+    update_user_name, verify_email, IMPORT_PAYLOAD_LIMIT,
 };
 use crate::application::app::Application;
 use crate::application::store::MultiRepository;
-use crate::configuration::application::{ApiSettings, SessionSettings};
+use crate::configuration::application::{ApiSettings, RateLimitSettings, SessionSettings};
 use crate::infrastructure::session_store::SessionStoreImpl;
 use actix_session::config::PersistentSession;
 use actix_session::SessionMiddleware;
@@ -22,7 +32,7 @@ use secrecy::ExposeSecret;
 use std::net::TcpListener;
 use tracing_actix_web::TracingLogger;
 #[cfg(feature = "openapi")]
-use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
 #[cfg(feature = "openapi")]
 use utoipa::{Modify, OpenApi};
 #[cfg(feature = "openapi")]
@@ -40,7 +50,13 @@ impl RestApi {
     ) -> Result<Self, anyhow::Error> {
         let listener = TcpListener::bind(configuration.address())?;
         let port = listener.local_addr().unwrap().port();
-        let server = run(listener, application, &configuration.session).await?;
+        let server = run(
+            listener,
+            application,
+            &configuration.session,
+            &configuration.rate_limit,
+        )
+        .await?;
 
         Ok(Self { port, server })
     }
@@ -58,15 +74,19 @@ async fn run<Store: MultiRepository + 'static>(
     listener: TcpListener,
     application: Application<Store>,
     session_config: &SessionSettings,
+    rate_limit_config: &RateLimitSettings,
 ) -> Result<Server, anyhow::Error> {
     let app = web::Data::new(application);
+    let rate_limiter = web::Data::new(RateLimiter::build(rate_limit_config).await?);
     let session_store = SessionStoreImpl::build(&session_config.store).await?;
+    let sessions = web::Data::new(session_store.clone());
     let session_key = Key::from(session_config.hmac.expose_secret().as_bytes());
     let session_duration = Duration::days(session_config.duration as i64);
     #[cfg(feature = "openapi")]
     let openapi = ApiDoc::openapi();
     let server = HttpServer::new(move || {
         let api = App::new()
+            .wrap(from_fn(middleware::event_origin::record_origin))
             .wrap(TracingLogger::default())
             .wrap(
                 SessionMiddleware::builder(session_store.clone(), session_key.clone())
@@ -74,18 +94,56 @@ async fn run<Store: MultiRepository + 'static>(
                     .build(),
             )
             .route("/health_check", web::get().to(health_check))
-            .route("/register", web::post().to(register::<Store>))
-            .route("/login", web::post().to(login::<Store>))
+            .service(
+                web::resource("/register")
+                    .wrap(from_fn(middleware::rate_limit::limit_ip))
+                    .route(web::post().to(register::<Store>)),
+            )
+            .service(
+                web::resource("/login")
+                    .wrap(from_fn(middleware::rate_limit::limit_ip))
+                    .route(web::post().to(login::<Store>)),
+            )
+            .service(
+                web::resource("/login/second-factor")
+                    .wrap(from_fn(middleware::rate_limit::limit_ip))
+                    .route(web::post().to(login_second_factor::<Store>)),
+            )
+            .service(
+                web::scope("/oidc")
+                    .route("/login", web::get().to(oidc_login::<Store>))
+                    .route("/callback", web::get().to(oidc_callback::<Store>)),
+            )
+            .service(
+                web::scope("/password")
+                    .wrap(from_fn(middleware::rate_limit::limit_ip))
+                    .route("/forgot", web::post().to(forgot_password::<Store>))
+                    .route("/reset", web::post().to(reset_password::<Store>)),
+            )
+            .service(
+                web::scope("/verification")
+                    .route("", web::post().to(verify_email::<Store>))
+                    .service(
+                        web::resource("/resend")
+                            .wrap(from_fn(middleware::rate_limit::limit_ip))
+                            .route(web::post().to(resend_verification::<Store>)),
+                    ),
+            )
             .service(
                 web::scope("/logout")
-                    .wrap(from_fn(middleware::auth::reject_anonymous_users))
+                    .wrap(from_fn(middleware::auth::reject_anonymous_users::<Store>))
                     .route("", web::post().to(logout::<Store>)),
             )
             .service(
                 web::scope("/groups")
-                    .wrap(from_fn(middleware::auth::reject_anonymous_users))
+                    .wrap(from_fn(middleware::auth::reject_anonymous_users::<Store>))
                     .route("", web::post().to(create_group::<Store>))
                     .route("", web::get().to(get_groups::<Store>))
+                    .service(
+                        web::resource("/restore")
+                            .app_data(web::JsonConfig::default().limit(IMPORT_PAYLOAD_LIMIT))
+                            .route(web::post().to(restore_group::<Store>)),
+                    )
                     .route("/{group_id}", web::get().to(get_group::<Store>))
                     .route("/{group_id}", web::delete().to(delete_group::<Store>))
                     .route(
@@ -97,16 +155,47 @@ async fn run<Store: MultiRepository + 'static>(
                         "/{group_id}/members",
                         web::patch().to(change_color::<Store>),
                     )
+                    .route(
+                        "/{group_id}/admin",
+                        web::put().to(transfer_ownership::<Store>),
+                    )
                     .route("/{group_id}/expenses", web::get().to(get_expenses::<Store>))
+                    .route(
+                        "/{group_id}/expenses.csv",
+                        web::get().to(export_expenses::<Store>),
+                    )
                     .route(
                         "/{group_id}/expenses",
                         web::post().to(create_expense::<Store>),
                     )
+                    .service(
+                        web::resource("/{group_id}/expenses/import")
+                            .app_data(web::JsonConfig::default().limit(IMPORT_PAYLOAD_LIMIT))
+                            .route(web::post().to(import_expenses::<Store>)),
+                    )
+                    .route("/{group_id}/stats", web::get().to(get_stats::<Store>))
+                    .route("/{group_id}/activity", web::get().to(get_activity::<Store>))
+                    .route("/{group_id}/export", web::get().to(export_ledger::<Store>))
+                    .route("/{group_id}/backup", web::get().to(backup_group::<Store>))
+                    .route("/{group_id}/budget", web::get().to(get_budget::<Store>))
+                    .route("/{group_id}/budget", web::put().to(set_budget::<Store>))
+                    .route(
+                        "/{group_id}/budget",
+                        web::delete().to(delete_budget::<Store>),
+                    )
                     .route("/{group_id}/settlements", web::post().to(settle::<Store>))
                     .route(
                         "/{group_id}/settlements",
                         web::get().to(get_settlements::<Store>),
                     )
+                    .route(
+                        "/{group_id}/settlements.csv",
+                        web::get().to(export_settlements::<Store>),
+                    )
+                    .route(
+                        "/{group_id}/settlements/{settlement_id}",
+                        web::delete().to(revert_settlement::<Store>),
+                    )
                     .route(
                         "/{group_id}/expenses/{expense_id}",
                         web::put().to(update_expense::<Store>),
@@ -116,18 +205,115 @@ async fn run<Store: MultiRepository + 'static>(
                         web::delete().to(delete_expense::<Store>),
                     ),
             )
+            .service(
+                web::scope("/me")
+                    .wrap(from_fn(middleware::auth::reject_anonymous_users::<Store>))
+                    .service(
+                        web::resource("")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::delete().to(delete_user::<Store>)),
+                    )
+                    .route("/name", web::put().to(update_user_name::<Store>))
+                    .service(
+                        web::resource("/email")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::put().to(change_email::<Store>)),
+                    )
+                    .service(
+                        web::resource("/password")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::put().to(change_password::<Store>)),
+                    )
+                    .service(
+                        web::resource("/two-factor")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::post().to(enroll_two_factor::<Store>)),
+                    )
+                    .service(
+                        web::resource("/two-factor/confirm")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::post().to(confirm_two_factor::<Store>)),
+                    )
+                    .service(
+                        web::resource("/tokens")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::post().to(create_api_token::<Store>))
+                            .route(web::get().to(get_api_tokens::<Store>)),
+                    )
+                    .service(
+                        web::resource("/tokens/{token_id}")
+                            .wrap(from_fn(middleware::auth::require_session))
+                            .route(web::delete().to(revoke_api_token::<Store>)),
+                    )
+                    .route("/sessions", web::get().to(get_sessions))
+                    .route("/sessions", web::delete().to(revoke_sessions))
+                    .route("/sessions/{session_id}", web::delete().to(revoke_session))
+                    .route("/summary", web::get().to(get_summary::<Store>))
+                    .route("/export", web::get().to(export_user_data::<Store>))
+                    .route("/debts/{user_id}", web::get().to(get_debt::<Store>))
+                    .route(
+                        "/debts/{user_id}/settle",
+                        web::post().to(settle_debt::<Store>),
+                    ),
+            )
             .service(
                 web::scope("/admin")
-                    .wrap(from_fn(middleware::auth::reject_anonymous_users))
+                    .wrap(from_fn(middleware::auth::require_session))
+                    .wrap(from_fn(middleware::auth::reject_anonymous_users::<Store>))
+                    .route("/events", web::get().to(get_audit_log::<Store>))
                     .route("/groups", web::get().to(get_all_groups::<Store>))
-                    .route("/users", web::get().to(get_all_users::<Store>)),
+                    .route("/groups/{group_id}", web::get().to(inspect_group::<Store>))
+                    .route(
+                        "/groups/{group_id}",
+                        web::delete().to(remove_group::<Store>),
+                    )
+                    .route(
+                        "/groups/{group_id}/admin",
+                        web::put().to(transfer_group_ownership::<Store>),
+                    )
+                    .route(
+                        "/groups/{group_id}/archive",
+                        web::post().to(archive_group::<Store>),
+                    )
+                    .route(
+                        "/groups/{group_id}/unarchive",
+                        web::post().to(unarchive_group::<Store>),
+                    )
+                    .route(
+                        "/groups/{group_id}/members/{user_id}",
+                        web::delete().to(remove_member::<Store>),
+                    )
+                    .route("/users", web::get().to(get_all_users::<Store>))
+                    .route("/users/{user_id}", web::delete().to(remove_user::<Store>))
+                    .route(
+                        "/users/{user_id}/role",
+                        web::put().to(change_user_role::<Store>),
+                    )
+                    .route(
+                        "/users/{user_id}/disable",
+                        web::post().to(disable_user::<Store>),
+                    )
+                    .route(
+                        "/users/{user_id}/enable",
+                        web::post().to(enable_user::<Store>),
+                    )
+                    .route(
+                        "/users/{user_id}/password-reset",
+                        web::post().to(force_password_reset::<Store>),
+                    )
+                    .route(
+                        "/users/{user_id}/two-factor",
+                        web::delete().to(reset_two_factor::<Store>),
+                    ),
             )
-            .app_data(app.clone());
+            .app_data(app.clone())
+            .app_data(rate_limiter.clone())
+            .app_data(sessions.clone());
 
         #[cfg(feature = "pushy")]
         let api = api.service(
             web::scope("/devices")
-                .wrap(from_fn(middleware::auth::reject_anonymous_users))
+                .wrap(from_fn(middleware::auth::reject_anonymous_users::<Store>))
                 .route("", web::post().to(register_device::<Store>))
                 .route("", web::delete().to(remove_device::<Store>)),
         );
@@ -149,24 +335,73 @@ async fn run<Store: MultiRepository + 'static>(
 #[openapi(
     paths(
         crate::api::routes::login,
+        crate::api::routes::login_second_factor,
         crate::api::routes::logout,
         crate::api::routes::register,
+        crate::api::routes::oidc_login,
+        crate::api::routes::oidc_callback,
+        crate::api::routes::export_user_data,
+        crate::api::routes::delete_user,
+        crate::api::routes::update_user_name,
+        crate::api::routes::change_email,
+        crate::api::routes::change_password,
+        crate::api::routes::create_api_token,
+        crate::api::routes::get_api_tokens,
+        crate::api::routes::revoke_api_token,
+        crate::api::routes::get_sessions,
+        crate::api::routes::revoke_session,
+        crate::api::routes::revoke_sessions,
+        crate::api::routes::enroll_two_factor,
+        crate::api::routes::confirm_two_factor,
+        crate::api::routes::forgot_password,
+        crate::api::routes::reset_password,
+        crate::api::routes::verify_email,
+        crate::api::routes::resend_verification,
         crate::api::routes::change_color,
         crate::api::routes::create_expense,
         crate::api::routes::create_group,
         crate::api::routes::delete_expense,
         crate::api::routes::delete_group,
+        crate::api::routes::transfer_ownership,
         crate::api::routes::get_expenses,
+        crate::api::routes::export_expenses,
+        crate::api::routes::import_expenses,
         crate::api::routes::get_group,
         crate::api::routes::get_groups,
         crate::api::routes::get_settlements,
+        crate::api::routes::export_settlements,
+        crate::api::routes::get_stats,
+        crate::api::routes::get_activity,
+        crate::api::routes::export_ledger,
+        crate::api::routes::backup_group,
+        crate::api::routes::restore_group,
+        crate::api::routes::get_budget,
+        crate::api::routes::set_budget,
+        crate::api::routes::delete_budget,
+        crate::api::routes::get_summary,
+        crate::api::routes::get_debt,
+        crate::api::routes::settle_debt,
         crate::api::routes::join_group,
         crate::api::routes::register_device,
         crate::api::routes::remove_device,
         crate::api::routes::settle,
+        crate::api::routes::revert_settlement,
         crate::api::routes::update_expense,
         crate::api::routes::get_all_groups,
+        crate::api::routes::inspect_group,
+        crate::api::routes::get_audit_log,
+        crate::api::routes::transfer_group_ownership,
+        crate::api::routes::archive_group,
+        crate::api::routes::unarchive_group,
+        crate::api::routes::remove_group,
+        crate::api::routes::remove_member,
         crate::api::routes::get_all_users,
+        crate::api::routes::reset_two_factor,
+        crate::api::routes::change_user_role,
+        crate::api::routes::disable_user,
+        crate::api::routes::enable_user,
+        crate::api::routes::force_password_reset,
+        crate::api::routes::remove_user,
         crate::api::routes::health_check
     ),
     components(
@@ -180,35 +415,93 @@ async fn run<Store: MultiRepository + 'static>(
             crate::api::response::GetGroupsResponse,
             crate::api::response::GetAllGroupsResponse,
             crate::api::response::GetAllUsersResponse,
+            crate::api::response::InspectGroupResponse,
+            crate::api::response::GetAuditLogResponse,
+            crate::api::response::CreateApiTokenResponse,
+            crate::api::response::GetApiTokensResponse,
+            crate::api::response::EnrollTwoFactorResponse,
+            crate::api::response::GetSessionsResponse,
             crate::api::response::GetSettlementsResponse,
             crate::api::response::SettlementResponse,
+            crate::api::response::GetSummaryResponse,
+            crate::api::response::GetDebtResponse,
+            crate::api::response::GetStatsResponse,
+            crate::api::response::GetActivityResponse,
+            crate::api::response::GetBudgetResponse,
+            crate::api::response::ImportExpensesResponse,
             crate::api::response::MessageData,
             crate::api::response::ErrorData,
             crate::api::response::IdData,
             crate::api::routes::LoginPayload,
+            crate::api::routes::SecondFactorPayload,
             crate::api::routes::RegisterPayload,
+            crate::api::routes::UpdateNamePayload,
+            crate::api::routes::ChangeEmailPayload,
+            crate::api::routes::ChangePasswordPayload,
+            crate::api::routes::CreateApiTokenPayload,
+            crate::api::routes::ApiTokenCreatedResponse,
+            crate::api::routes::ApiTokensResponse,
+            crate::api::routes::TwoFactorEnrollmentResponse,
+            crate::api::routes::SessionsResponse,
+            crate::api::routes::middleware::user_session::SessionInfo,
+            crate::api::routes::ConfirmTwoFactorPayload,
+            crate::api::routes::ChangeRolePayload,
+            crate::api::routes::TransferGroupOwnershipPayload,
+            crate::api::routes::ForgotPasswordPayload,
+            crate::api::routes::ResetPasswordPayload,
+            crate::api::routes::VerifyEmailPayload,
+            crate::api::routes::ResendVerificationPayload,
             crate::api::routes::ChangeColorPayload,
             crate::api::routes::CreateExpensePayload,
             crate::api::routes::CreateGroupPayload,
             crate::api::routes::JoinGroupPayload,
+            crate::api::routes::TransferOwnershipPayload,
             crate::api::routes::DeviceData,
             crate::api::routes::UpdateExpensePayload,
+            crate::api::routes::SettlePayload,
+            crate::api::routes::SetBudgetPayload,
+            crate::api::routes::ImportExpensesPayload,
             crate::api::routes::GroupTokenResponse,
             crate::api::routes::ExpensesResponse,
             crate::api::routes::GroupResponse,
             crate::api::routes::GroupsResponse,
             crate::api::routes::AllGroupsResponse,
             crate::api::routes::AllUsersResponse,
+            crate::api::routes::ModeratedGroupResponse,
+            crate::api::routes::AuditLogResponse,
             crate::api::routes::SettlementsResponse,
+            crate::api::routes::ActivityResponse,
             crate::domain::usecases::dto::dtos::ColorDto,
             crate::domain::usecases::dto::dtos::GroupDto,
             crate::domain::usecases::dto::dtos::DetailedGroupDto,
+            crate::domain::usecases::dto::dtos::ModeratedGroupDto,
+            crate::domain::usecases::dto::dtos::GroupEventDto,
+            crate::domain::usecases::dto::dtos::AuditLogDto,
+            crate::domain::usecases::dto::dtos::AuditEventDto,
             crate::domain::usecases::dto::dtos::ExpenseDto,
             crate::domain::usecases::dto::dtos::MemberDto,
             crate::domain::usecases::dto::dtos::UserDto,
             crate::domain::usecases::dto::dtos::DetailedUserDto,
+            crate::domain::usecases::dto::dtos::ApiTokenDto,
             crate::domain::usecases::dto::dtos::SettlementDto,
             crate::domain::usecases::dto::dtos::TransactionDto,
+            crate::domain::usecases::dto::dtos::MemberBalanceDto,
+            crate::domain::usecases::dto::dtos::SummaryDto,
+            crate::domain::usecases::dto::dtos::GroupSummaryDto,
+            crate::domain::usecases::dto::dtos::DebtDto,
+            crate::domain::usecases::dto::dtos::PaymentDto,
+            crate::domain::usecases::dto::dtos::DebtTransactionDto,
+            crate::domain::usecases::dto::dtos::StatsDto,
+            crate::domain::usecases::dto::dtos::MemberTotalDto,
+            crate::domain::usecases::dto::dtos::MonthTotalDto,
+            crate::domain::usecases::dto::dtos::ActivityFeedDto,
+            crate::domain::usecases::dto::dtos::ActivityDto,
+            crate::domain::usecases::dto::dtos::ActivityMemberDto,
+            crate::domain::usecases::dto::dtos::ActivityKindDto,
+            crate::domain::usecases::dto::dtos::ActivityTransactionDto,
+            crate::domain::usecases::dto::dtos::BudgetDto,
+            crate::domain::usecases::dto::dtos::ImportReportDto,
+            crate::domain::usecases::dto::dtos::ImportErrorDto,
         ),
     ),
     tags(
@@ -234,6 +527,10 @@ impl Modify for SecurityAddon {
         components.add_security_scheme(
             "cookieAuth",
             SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("id"))),
+        );
+        components.add_security_scheme(
+            "bearerAuth",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
         )
     }
 }
@@ -1,8 +1,16 @@
 #[cfg(feature = "openapi")]
+use crate::api::routes::ActivityResponse;
+#[cfg(feature = "openapi")]
 use crate::api::routes::AllGroupsResponse;
 #[cfg(feature = "openapi")]
 use crate::api::routes::AllUsersResponse;
 #[cfg(feature = "openapi")]
+use crate::api::routes::ApiTokenCreatedResponse;
+#[cfg(feature = "openapi")]
+use crate::api::routes::ApiTokensResponse;
+#[cfg(feature = "openapi")]
+use crate::api::routes::AuditLogResponse;
+#[cfg(feature = "openapi")]
 use crate::api::routes::ExpensesResponse;
 #[cfg(feature = "openapi")]
 use crate::api::routes::GroupResponse;
@@ -11,10 +19,26 @@ use crate::api::routes::GroupTokenResponse;
 #[cfg(feature = "openapi")]
 use crate::api::routes::GroupsResponse;
 #[cfg(feature = "openapi")]
+use crate::api::routes::ModeratedGroupResponse;
+#[cfg(feature = "openapi")]
+use crate::api::routes::SessionsResponse;
+#[cfg(feature = "openapi")]
 use crate::api::routes::SettlementsResponse;
 #[cfg(feature = "openapi")]
+use crate::api::routes::TwoFactorEnrollmentResponse;
+#[cfg(feature = "openapi")]
+use crate::domain::usecases::dto::dtos::BudgetDto;
+#[cfg(feature = "openapi")]
+use crate::domain::usecases::dto::dtos::DebtDto;
+#[cfg(feature = "openapi")]
+use crate::domain::usecases::dto::dtos::ImportReportDto;
+#[cfg(feature = "openapi")]
 use crate::domain::usecases::dto::dtos::SettlementDto;
 #[cfg(feature = "openapi")]
+use crate::domain::usecases::dto::dtos::StatsDto;
+#[cfg(feature = "openapi")]
+use crate::domain::usecases::dto::dtos::SummaryDto;
+#[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -30,8 +54,20 @@ use uuid::Uuid;
     GetGroupsResponse = ResponseMessage<GroupsResponse>,
     GetAllGroupsResponse = ResponseMessage<AllGroupsResponse>,
     GetAllUsersResponse = ResponseMessage<AllUsersResponse>,
+    InspectGroupResponse = ResponseMessage<ModeratedGroupResponse>,
+    GetAuditLogResponse = ResponseMessage<AuditLogResponse>,
+    CreateApiTokenResponse = ResponseMessage<ApiTokenCreatedResponse>,
+    GetApiTokensResponse = ResponseMessage<ApiTokensResponse>,
+    EnrollTwoFactorResponse = ResponseMessage<TwoFactorEnrollmentResponse>,
+    GetSessionsResponse = ResponseMessage<SessionsResponse>,
     GetSettlementsResponse = ResponseMessage<SettlementsResponse>,
     SettlementResponse = ResponseMessage<SettlementDto>,
+    GetSummaryResponse = ResponseMessage<SummaryDto>,
+    GetDebtResponse = ResponseMessage<DebtDto>,
+    GetStatsResponse = ResponseMessage<StatsDto>,
+    GetActivityResponse = ResponseMessage<ActivityResponse>,
+    GetBudgetResponse = ResponseMessage<BudgetDto>,
+    ImportExpensesResponse = ResponseMessage<ImportReportDto>,
 ))]
 pub struct ResponseMessage<T> {
     pub success: bool,
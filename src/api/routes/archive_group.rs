@@ -0,0 +1,56 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageGroupError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Archives any group, only available if user is ADMIN
+///
+/// Members can still read an archived group, but neither add, edit or delete expenses, settle,
+/// change the budget nor join it until it is unarchived.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X POST "http://localhost:8000/admin/groups/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41/archive"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/admin/groups/{group_id}/archive",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 204, description = "Group archived"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 409, description = "Group is already archived", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Archive group",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn archive_group<Store: MultiRepository>(
+    group_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageGroupError> {
+    app.admin()
+        .archive_group(&user_id.into_inner(), &group_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,71 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::{BackupGroupRequest, GroupUseCase};
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Exports the whole group as a versioned JSON document: members, expenses, settlements with
+/// their transactions, budget and event history.
+///
+/// Members are referenced by email, the document can be restored with `/groups/restore`
+/// on this or another instance.
+///
+/// This action can only be performed by the group administrator.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID):
+/// ```
+/// curl -i -b cookie -o backup.json "http://localhost:8000/groups/GROUP_ID/backup"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/backup",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 200, description = "Backup document of the group", content_type = "application/json", body = Object),
+        (status = 400, description = "Invalid group id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Backup group",
+    skip(path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn backup_group<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ExportError> {
+    match path_param {
+        Some(group_id) => {
+            let group_id = group_id.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            let data = BackupGroupRequest {
+                group_id,
+                user_id: user_id.into_inner().0,
+            };
+            let backup = app.groups().backup_group(data).await?;
+            Ok(HttpResponse::Ok().json(backup))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
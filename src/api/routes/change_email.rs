@@ -0,0 +1,70 @@
+use crate::api::response::ok_message;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::UpdateUserError;
+use crate::domain::usecases::user::{ChangeEmailRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+use secrecy::Secret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Changes the email of the user making the request, which is also their login.
+///
+/// The current password is required when the credentials are managed by koru.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"email":"new@r1.com","password":"123"}' -b cookie -X PUT "http://localhost:8000/me/email"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/me/email",
+    request_body = ChangeEmailPayload,
+    responses(
+        (status = 200, description = "Email changed successfully", body = MessageResponse),
+        (status = 400, description = "Invalid email", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Invalid password, or request was authenticated by a token", body = ErrorResponse),
+        (status = 409, description = "Email already in use", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Change user email",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        user_email = %payload.email,
+    )
+)]
+pub async fn change_email<Store: MultiRepository>(
+    payload: web::Json<ChangeEmailPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, UpdateUserError> {
+    app.users()
+        .change_email(ChangeEmailRequest {
+            user_id: *user_id.into_inner(),
+            email: payload.0.email,
+            password: payload.0.password,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(ok_message("Email changed.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ChangeEmailPayload {
+    email: String,
+    /// Current password of the user.
+    #[cfg_attr(feature = "openapi", schema(value_type=Option<String>))]
+    password: Option<Secret<String>>,
+}
@@ -0,0 +1,86 @@
+use crate::api::response::ok_message;
+use crate::api::routes::middleware::user_session::{UserId, UserSession};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::UpdateUserError;
+use crate::domain::usecases::user::{ChangePasswordRequest, UserUseCase};
+use crate::utils::date;
+use actix_web::{web, HttpResponse};
+use secrecy::Secret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Changes the password of the user making the request.
+///
+/// All the other sessions of the user are closed, the session of the request is renewed and
+/// a new `Set-Cookie` header is returned.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"current_password":"123","new_password":"456"}' -b cookie -c cookie -X PUT "http://localhost:8000/me/password"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/me/password",
+    request_body = ChangePasswordPayload,
+    responses(
+        (
+            status = 200,
+            description = "Password changed successfully",
+            body = MessageResponse,
+            headers(
+                ("Set-Cookie" = String, description = "Renewed auth cookie")
+            ),
+        ),
+        (status = 400, description = "Invalid password", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Invalid current password, or request was authenticated by a token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Change user password",
+    skip(payload, app, session, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn change_password<Store: MultiRepository>(
+    payload: web::Json<ChangePasswordPayload>,
+    app: web::Data<Application<Store>>,
+    session: UserSession,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, UpdateUserError> {
+    let user_id = *user_id.into_inner();
+    app.users()
+        .change_password(ChangePasswordRequest {
+            user_id,
+            current_password: payload.0.current_password,
+            new_password: payload.0.new_password,
+        })
+        .await?;
+    session.renew();
+    session
+        .insert_user_id(user_id)
+        .map_err(|e| UpdateUserError::Unexpected(e.into()))?;
+    session
+        .insert_logged_in_at(date::now())
+        .map_err(|e| UpdateUserError::Unexpected(e.into()))?;
+    Ok(HttpResponse::Ok().json(ok_message("Password changed.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ChangePasswordPayload {
+    #[cfg_attr(feature = "openapi", schema(value_type=String))]
+    current_password: Secret<String>,
+    #[cfg_attr(feature = "openapi", schema(value_type=String))]
+    new_password: Secret<String>,
+}
@@ -0,0 +1,95 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageUserError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Promotes a user to administrator or demotes them, only available if user is ADMIN
+///
+/// Administrators cannot change their own role.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"role":"Administrator"}' -b cookie -X PUT "http://localhost:8000/admin/users/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41/role"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/admin/users/{user_id}/role",
+    request_body = ChangeRolePayload,
+    params(
+        ("user_id" = Uuid, Path, description = "Id of the user whose role is changed"),
+    ),
+    responses(
+        (status = 204, description = "Role changed"),
+        (status = 400, description = "Invalid role", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Admin targeted their own account", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Change user role",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn change_user_role<Store: MultiRepository>(
+    target_id: web::Path<Uuid>,
+    payload: web::Json<ChangeRolePayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageUserError> {
+    app.admin()
+        .change_role(
+            &user_id.into_inner(),
+            &target_id.into_inner(),
+            &payload.0.role,
+        )
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ChangeRolePayload {
+    /// Either `Administrator` or `User`.
+    role: String,
+}
+
+impl ResponseError for ManageUserError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            ManageUserError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            ManageUserError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            ManageUserError::Conflict(msg) => (StatusCode::CONFLICT, *msg),
+            ManageUserError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            ManageUserError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+            ManageUserError::Unauthorized() => {
+                (StatusCode::FORBIDDEN, "You are not administrator.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
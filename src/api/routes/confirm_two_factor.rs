@@ -0,0 +1,62 @@
+use crate::api::response::ok_message;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TwoFactorError;
+use crate::domain::usecases::user::{ConfirmTwoFactorRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Confirms the pending second factor of the user making the request, given the code shown by
+/// their authenticator application. Their next logins then require a code.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"code":"123456"}' -b cookie "http://localhost:8000/me/two-factor/confirm"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/me/two-factor/confirm",
+    request_body = ConfirmTwoFactorPayload,
+    responses(
+        (status = 200, description = "Second factor enabled", body = MessageResponse),
+        (status = 400, description = "Invalid code", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 404, description = "No enrollment is pending", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Confirm second factor",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn confirm_two_factor<Store: MultiRepository>(
+    payload: web::Json<ConfirmTwoFactorPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, TwoFactorError> {
+    app.users()
+        .confirm_two_factor(ConfirmTwoFactorRequest {
+            user_id: *user_id.into_inner(),
+            code: payload.0.code,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(ok_message("Two-factor authentication enabled.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ConfirmTwoFactorPayload {
+    pub code: String,
+}
@@ -0,0 +1,105 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ApiTokenError;
+use crate::domain::usecases::dto::dtos::ApiTokenDto;
+use crate::domain::usecases::user::{CreateApiTokenRequest, UserUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use secrecy::ExposeSecret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Creates a personal access token, with which scripts and integrations act on behalf of the
+/// user through the `Authorization: Bearer <token>` header instead of the session cookie.
+///
+/// The token is only returned in this response and cannot be retrieved afterwards. A `read`
+/// token is restricted to `GET` requests, a `write` token allows every request.
+///
+/// Requires the auth cookie from `/login` to be attached to the request: tokens cannot be used
+/// to create other tokens.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"name":"backup script","scope":"read","validity_days":90}' -b cookie -X POST "http://localhost:8000/me/tokens"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/me/tokens",
+    request_body = CreateApiTokenPayload,
+    responses(
+        (status = 201, description = "Token created", body = CreateApiTokenResponse),
+        (status = 400, description = "Invalid name, scope or validity", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Request was authenticated by a token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Create API token",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn create_api_token<Store: MultiRepository>(
+    payload: web::Json<CreateApiTokenPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ApiTokenError> {
+    let (api_token, token) = app
+        .users()
+        .create_api_token(CreateApiTokenRequest {
+            user_id: *user_id.into_inner(),
+            name: payload.0.name,
+            scope: payload.0.scope,
+            validity_days: payload.0.validity_days,
+        })
+        .await?;
+    Ok(HttpResponse::Created().json(ok(ApiTokenCreatedResponse {
+        token: token.expose_secret().clone(),
+        api_token: ApiTokenDto::from(api_token),
+    })))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CreateApiTokenPayload {
+    name: String,
+    /// Either `read` or `write`.
+    scope: String,
+    /// Number of days the token is valid, from 1 to 365.
+    validity_days: u16,
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ApiTokenCreatedResponse {
+    /// The token to send in the `Authorization: Bearer` header, only shown this once.
+    token: String,
+    api_token: ApiTokenDto,
+}
+
+impl ResponseError for ApiTokenError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            ApiTokenError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            ApiTokenError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            ApiTokenError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            ApiTokenError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -31,7 +31,8 @@ use utoipa::ToSchema;
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Groups",
 ))]
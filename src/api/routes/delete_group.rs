@@ -35,7 +35,8 @@ use uuid::Uuid;
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Groups",
 ))]
@@ -0,0 +1,77 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::user_session::{UserId, UserSession};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::DeleteUserError;
+use crate::domain::usecases::user::{DeleteUserRequest, UserUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+
+/// Deletes the account of the user making the request.
+///
+/// The user is anonymized in the groups they belonged to: their name and email are replaced
+/// with a tombstone, while their expenses and transactions are kept so that the balances of
+/// the other members stay correct. Credentials and devices are removed and the session is
+/// closed.
+///
+/// The ownership of the groups administered by the user must be transferred beforehand.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/me"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/me",
+    responses(
+        (status = 204, description = "Account deleted"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Request was authenticated by a token", body = ErrorResponse),
+        (status = 409, description = "User still administers groups", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Delete user",
+    skip(session, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn delete_user<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    session: UserSession,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, DeleteUserError> {
+    app.users()
+        .delete(DeleteUserRequest {
+            user_id: *user_id.into_inner(),
+        })
+        .await?;
+    session.purge();
+    Ok(HttpResponse::NoContent().finish())
+}
+
+impl ResponseError for DeleteUserError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            DeleteUserError::Conflict(msg) => (StatusCode::CONFLICT, *msg),
+            DeleteUserError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            DeleteUserError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageUserError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Disables the account of a user, only available if user is ADMIN
+///
+/// The user can no longer log in, and their sessions and personal access tokens are rejected
+/// until the account is enabled again.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X POST "http://localhost:8000/admin/users/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41/disable"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/admin/users/{user_id}/disable",
+    params(
+        ("user_id" = Uuid, Path, description = "Id of the user to disable"),
+    ),
+    responses(
+        (status = 204, description = "Account disabled"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Admin targeted their own account", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Disable user",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn disable_user<Store: MultiRepository>(
+    target_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageUserError> {
+    app.admin()
+        .disable_user(&user_id.into_inner(), &target_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
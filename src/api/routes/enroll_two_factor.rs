@@ -0,0 +1,97 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TwoFactorError;
+use crate::domain::usecases::user::UserUseCase;
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use secrecy::ExposeSecret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Starts the enrollment of a TOTP second factor for the user making the request.
+///
+/// Returns the secret to add to an authenticator application, also as an `otpauth://` URI to
+/// show as a QR code, and the single-use recovery codes, which are only returned in this
+/// response. The second factor is only required at login once confirmed on
+/// `/me/two-factor/confirm`; a new enrollment replaces a pending one.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X POST "http://localhost:8000/me/two-factor"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/me/two-factor",
+    responses(
+        (status = 201, description = "Enrollment started", body = EnrollTwoFactorResponse),
+        (status = 400, description = "Two-factor authentication is not enabled", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Request was authenticated by a token", body = ErrorResponse),
+        (status = 409, description = "Second factor already enrolled", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Enroll second factor",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn enroll_two_factor<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, TwoFactorError> {
+    let enrollment = app.users().enroll_two_factor(&user_id.into_inner()).await?;
+    Ok(
+        HttpResponse::Created().json(ok(TwoFactorEnrollmentResponse {
+            secret: enrollment.secret.expose_secret().clone(),
+            provisioning_uri: enrollment.provisioning_uri,
+            recovery_codes: enrollment
+                .recovery_codes
+                .iter()
+                .map(|code| code.expose_secret().clone())
+                .collect(),
+        })),
+    )
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TwoFactorEnrollmentResponse {
+    /// Base32 secret, for authenticator applications that cannot scan the URI.
+    secret: String,
+    provisioning_uri: String,
+    /// Single-use codes replacing the TOTP code when the authenticator is lost.
+    recovery_codes: Vec<String>,
+}
+
+impl ResponseError for TwoFactorError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            TwoFactorError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            TwoFactorError::InvalidCode() => (StatusCode::BAD_REQUEST, "The code is invalid."),
+            TwoFactorError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            TwoFactorError::Conflict(msg) => (StatusCode::CONFLICT, *msg),
+            TwoFactorError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            TwoFactorError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+            TwoFactorError::Unauthorized() => (StatusCode::FORBIDDEN, "You are not administrator."),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::api::routes::GetExpensesFilter;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::{ExportRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use actix_web::{web, HttpResponse, ResponseError};
+use futures_util::TryStreamExt;
+use uuid::Uuid;
+
+/// Exports expenses of a group as a CSV document (RFC 4180), streamed as it is read.
+/// Filters are the same as the ones of `/groups/{group_id}/expenses`:
+///     - If no filters are provided: un-settled expenses.
+///     - If date filters (`from` and/or `to`) are provided: expenses within the date filters.
+///     - If `settlement_id` filter is provided: expenses of a given settlement, ignoring any provided date filters.
+///
+/// Each row holds the expense id, date, title, amount, member id and name, and settlement id.
+///
+/// This action can only be performed by a group member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples (replace GROUP_ID ith the group UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/expenses.csv"
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/expenses.csv?settlement_id=SETTLEMENT_ID"
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/expenses.csv?from=1676869911768&to=1676869945455"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/expenses.csv",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        GetExpensesFilter,
+    ),
+    responses(
+        (status = 200, description = "CSV document of the expenses matching the filters", content_type = "text/csv", body = String),
+        (status = 400, description = "Invalid group id or filters", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group or settlement not found (if settlement filter provided)", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Expenses",
+))]
+#[tracing::instrument(
+    name = "Export expenses",
+    skip(path_param, req_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+        filters = tracing::field::Empty,
+    )
+)]
+pub async fn export_expenses<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    req_param: Option<web::Query<GetExpensesFilter>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ExportError> {
+    match (path_param, req_param) {
+        (Some(group_id), Some(filters)) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            let filters = filters.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            tracing::Span::current().record("filters", tracing::field::debug(&filters));
+
+            let (from, to, settlement_id) = filters.into_parts();
+
+            let data = ExportRequest {
+                group_id,
+                user_id,
+                settlement_id,
+                from,
+                to,
+            };
+            let csv = app.groups().export_expenses(data).await?;
+
+            Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .streaming(csv.map_ok(Bytes::from)))
+        }
+        _ => Ok(HttpResponse::BadRequest().json(error("Group id or filters are invalid."))),
+    }
+}
+
+impl ResponseError for ExportError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            ExportError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            ExportError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            ExportError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            ExportError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            ExportError::Unauthenticated() => (StatusCode::UNAUTHORIZED, "You are not logged in."),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::{ExportLedgerRequest, GroupUseCase};
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// Exports the books of the user in a group as a beancount or hledger journal.
+///
+/// Each expense is booked with the user share on the expense account, the rest being owed
+/// to or by the group on the group account. Each settlement transaction involving the user
+/// is a transfer between the group account and the cash account, flagged as pending (`!`)
+/// until it is paid.
+///
+/// Account names default to `Expenses:Koru`, `Assets:Koru` and `Assets:Cash`, and the
+/// currency to `EUR`.
+///
+/// This action can only be performed by a group member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples (replace GROUP_ID with the group UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/export?format=beancount"
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/export?format=ledger&expense_account=Expenses:Trip&currency=CHF"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/export",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        ExportLedgerFilter,
+    ),
+    responses(
+        (status = 200, description = "Journal of the user in the group", content_type = "text/plain", body = String),
+        (status = 400, description = "Invalid group id, format, account names or currency", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Export ledger",
+    skip(path_param, req_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+        filters = tracing::field::Empty,
+    )
+)]
+pub async fn export_ledger<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    req_param: Option<web::Query<ExportLedgerFilter>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ExportError> {
+    match (path_param, req_param) {
+        (Some(group_id), Some(filters)) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            let filters = filters.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            tracing::Span::current().record("filters", tracing::field::debug(&filters));
+
+            let data = ExportLedgerRequest {
+                group_id,
+                user_id,
+                format: filters.format,
+                expense_account: filters.expense_account,
+                group_account: filters.group_account,
+                cash_account: filters.cash_account,
+                currency: filters.currency,
+            };
+            let journal = app.groups().export_ledger(data).await?;
+
+            Ok(HttpResponse::Ok()
+                .content_type("text/plain; charset=utf-8")
+                .body(journal))
+        }
+        _ => Ok(HttpResponse::BadRequest().json(error("Group id or format are invalid."))),
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in=Query))]
+pub struct ExportLedgerFilter {
+    /// Either `beancount` or `ledger`.
+    format: String,
+    /// Account of the user share of the expenses.
+    expense_account: Option<String>,
+    /// Account of the balance of the user in the group.
+    group_account: Option<String>,
+    /// Account the user pays from and gets paid to.
+    cash_account: Option<String>,
+    currency: Option<String>,
+}
@@ -0,0 +1,73 @@
+use crate::api::response::{error, ok_message};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TakeoutError;
+use crate::domain::usecases::user::{TakeoutRequest, UserUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+
+/// Exports the personal data of the user making the request as a JSON archive: profile,
+/// credentials metadata, devices, group memberships, expenses they paid, settlements they
+/// took part in and the history of their actions.
+///
+/// The archive of a user belonging to many groups is generated in the background: the
+/// request is then answered with `202 Accepted` and should be repeated until the archive
+/// is returned. A generated archive stays available for `application.takeout.retention` hours.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -o takeout.json "http://localhost:8000/me/export"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/me/export",
+    responses(
+        (status = 200, description = "Archive of the user data", content_type = "application/json", body = Object),
+        (status = 202, description = "The archive is being generated", body = MessageResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Export user data",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn export_user_data<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, TakeoutError> {
+    let user_id = *user_id.into_inner();
+    let archive = app.users().takeout(TakeoutRequest { user_id }).await?;
+    match archive {
+        Some(archive) => Ok(HttpResponse::Ok().json(archive)),
+        None => Ok(HttpResponse::Accepted().json(ok_message(
+            "The archive is being generated, try again later.",
+        ))),
+    }
+}
+
+impl ResponseError for TakeoutError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            TakeoutError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            TakeoutError::Unauthenticated() => (StatusCode::UNAUTHORIZED, "You are not logged in."),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageUserError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Forces a user to reset their password, only available if user is ADMIN
+///
+/// The sessions of the user are revoked and a reset link is mailed to them. They cannot log in
+/// with their password until they have chosen a new one through `/password/reset`.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X POST "http://localhost:8000/admin/users/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41/password-reset"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/admin/users/{user_id}/password-reset",
+    params(
+        ("user_id" = Uuid, Path, description = "Id of the user who must reset their password"),
+    ),
+    responses(
+        (status = 204, description = "Password reset required"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Admin targeted their own account", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Force password reset",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn force_password_reset<Store: MultiRepository>(
+    target_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageUserError> {
+    app.admin()
+        .force_password_reset(&user_id.into_inner(), &target_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,79 @@
+use crate::api::response::{error, ok_message};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::PasswordResetError;
+use crate::domain::usecases::user::{PasswordResetRequest, UserUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Mails a password reset link to the user registered with the given email.
+///
+/// The response is the same whether the email is registered or not, the mail being sent by the
+/// worker.
+///
+/// Too many requests from the same client IP are rejected with `429 Too Many Requests`.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"email":"r@r1.com"}' "http://localhost:8000/password/forgot"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/password/forgot",
+    request_body = ForgotPasswordPayload,
+    responses(
+        (status = 202, description = "Reset link sent if the email is registered", body = MessageResponse),
+        (status = 400, description = "Invalid email", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(name = "Request password reset", skip(payload, app))]
+pub async fn forgot_password<Store: MultiRepository>(
+    payload: web::Json<ForgotPasswordPayload>,
+    app: web::Data<Application<Store>>,
+) -> Result<HttpResponse, PasswordResetError> {
+    app.users()
+        .request_password_reset(PasswordResetRequest {
+            email: payload.0.email,
+        })
+        .await?;
+    Ok(HttpResponse::Accepted().json(ok_message(
+        "If an account is registered with this email, a reset link was sent to it.",
+    )))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ForgotPasswordPayload {
+    email: String,
+}
+
+impl ResponseError for PasswordResetError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            PasswordResetError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            PasswordResetError::InvalidToken() => (
+                StatusCode::BAD_REQUEST,
+                "The reset link is invalid or expired.",
+            ),
+            PasswordResetError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetActivityError;
+use crate::domain::usecases::dto::dtos::ActivityFeedDto;
+use crate::domain::usecases::group::{GetActivityRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Fetches the activity of the group, most recent first: members joining, expenses added,
+/// changed or deleted, settlements, budget changes...
+///
+/// Each activity names the member who did it and tells what happened in a sentence, along with
+/// its typed details. Activities are returned by pages of `limit` activities (20 by default, at
+/// most 100), the `next` cursor of a page being passed as `before` to fetch the following one.
+///
+/// This action can only be performed by a group member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples (replace GROUP_ID ith the group UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/activity"
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/activity?before=ACTIVITY_ID&limit=50"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/activity",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        ActivityPage,
+    ),
+    responses(
+        (status = 200, description = "Page of the activity of the group", body = GetActivityResponse),
+        (status = 400, description = "Invalid group id, limit or cursor", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Get group activity",
+    skip(page, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn get_activity<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    page: web::Query<ActivityPage>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetActivityError> {
+    match path_param {
+        Some(group_id) => {
+            let group_id = group_id.into_inner();
+            let user_id = *user_id.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(&group_id));
+            let page = page.into_inner();
+            let data = GetActivityRequest {
+                group_id,
+                user_id,
+                before: page.before,
+                limit: page.limit,
+            };
+            let activity = app.groups().get_activity(data).await?;
+            Ok(HttpResponse::Ok().json(ok(ActivityResponse { activity })))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ActivityResponse {
+    activity: ActivityFeedDto,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in=Query))]
+pub struct ActivityPage {
+    /// The `next` cursor of the previous page.
+    before: Option<Uuid>,
+    limit: Option<u32>,
+}
+
+impl ResponseError for GetActivityError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetActivityError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            GetActivityError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            GetActivityError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            GetActivityError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetActivityError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -26,7 +26,7 @@ use utoipa::ToSchema;
     responses(
         (status = 200, description = "List of all users", body = GetAllUsersResponse),
         (status = 401, description = "User is not logged in", body = ErrorResponse),
-        (status = 403, description = "User is not Admin", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     security(
@@ -0,0 +1,63 @@
+use crate::api::response::ok;
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ApiTokenError;
+use crate::domain::usecases::dto::dtos::ApiTokenDto;
+use crate::domain::usecases::user::UserUseCase;
+use actix_web::{web, HttpResponse};
+use itertools::Itertools;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Lists the personal access tokens of the user making the request, without the tokens
+/// themselves which are only shown on creation.
+///
+/// Requires the auth cookie from `/login` to be attached to the request: tokens cannot be used
+/// to manage tokens.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie "http://localhost:8000/me/tokens"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/me/tokens",
+    responses(
+        (status = 200, description = "Tokens of the user", body = GetApiTokensResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Request was authenticated by a token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Get API tokens",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn get_api_tokens<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ApiTokenError> {
+    let tokens = app
+        .users()
+        .get_api_tokens(&user_id.into_inner())
+        .await?
+        .into_iter()
+        .map(ApiTokenDto::from)
+        .collect_vec();
+    Ok(HttpResponse::Ok().json(ok(ApiTokensResponse { tokens })))
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ApiTokensResponse {
+    tokens: Vec<ApiTokenDto>,
+}
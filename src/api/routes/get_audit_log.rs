@@ -0,0 +1,130 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetAuditLogError;
+use crate::domain::usecases::admin::{AdminUseCase, AuditLogRequest};
+use crate::domain::usecases::dto::dtos::AuditLogDto;
+use crate::domain::EventFilter;
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use chrono::{LocalResult, TimeZone, Utc};
+#[cfg(feature = "openapi")]
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Fetches the events recorded by the server, newest first, only available if user is ADMIN.
+///     - `user_id`: events of the user account, or performed by the user.
+///     - `group_id`: events of the group.
+///     - `kind`: events of a kind, e.g. `UserLogin` or `ExpenseCreated`.
+///     - `from` and/or `to`: events recorded within the dates, as Unix timestamps in milliseconds.
+///
+/// Events are returned by pages of `limit` events (50 by default, at most 200), the `next` cursor
+/// of a page being passed as `before` to fetch the following one.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples:
+/// ```
+/// curl -i -b cookie "http://localhost:8000/admin/events"
+/// curl -i -b cookie "http://localhost:8000/admin/events?user_id=USER_ID&kind=UserLogin"
+/// curl -i -b cookie "http://localhost:8000/admin/events?group_id=GROUP_ID&before=EVENT_ID&limit=20"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/admin/events",
+    params(AuditLogFilter),
+    responses(
+        (status = 200, description = "Page of the events matching the filters", body = GetAuditLogResponse),
+        (status = 400, description = "Invalid filters, limit or cursor", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Get audit log",
+    skip(filter, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        filter = ?filter.0,
+    )
+)]
+pub async fn get_audit_log<Store: MultiRepository>(
+    filter: web::Query<AuditLogFilter>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetAuditLogError> {
+    let request = filter.into_inner().into_request();
+    let log = app
+        .admin()
+        .get_audit_log(&user_id.into_inner(), request)
+        .await?;
+    Ok(HttpResponse::Ok().json(ok(AuditLogResponse { log })))
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct AuditLogResponse {
+    log: AuditLogDto,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in=Query))]
+pub struct AuditLogFilter {
+    user_id: Option<Uuid>,
+    group_id: Option<Uuid>,
+    kind: Option<String>,
+    from: Option<i64>,
+    to: Option<i64>,
+    /// The `next` cursor of the previous page.
+    before: Option<Uuid>,
+    limit: Option<u32>,
+}
+
+impl AuditLogFilter {
+    /// Builds the request of the audit log, ignoring invalid timestamps.
+    fn into_request(self) -> AuditLogRequest {
+        let date = |timestamp: Option<i64>| match timestamp.map(|t| Utc.timestamp_millis_opt(t)) {
+            Some(LocalResult::Single(date)) => Some(date),
+            _ => None,
+        };
+        AuditLogRequest {
+            filter: EventFilter {
+                user_id: self.user_id,
+                group_id: self.group_id,
+                kind: self.kind,
+                from: date(self.from),
+                to: date(self.to),
+            },
+            before: self.before,
+            limit: self.limit,
+        }
+    }
+}
+
+impl ResponseError for GetAuditLogError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetAuditLogError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            GetAuditLogError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetAuditLogError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+            GetAuditLogError::Unauthorized() => {
+                (StatusCode::FORBIDDEN, "You are not administrator.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetBudgetError;
+use crate::domain::usecases::group::{GetBudgetRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use uuid::Uuid;
+
+/// Fetches the spending budget of a group along with its consumption over the
+/// current period.
+///
+/// This action can only be performed by a group member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/budget"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/budget",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 200, description = "Budget of the group and its consumption", body = GetBudgetResponse),
+        (status = 400, description = "Invalid group id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group or budget not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Get budget",
+    skip(path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn get_budget<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetBudgetError> {
+    match path_param {
+        Some(group_id) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            let data = GetBudgetRequest { group_id, user_id };
+            let budget = app.groups().get_budget(data).await?;
+
+            Ok(HttpResponse::Ok().json(ok(budget)))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
+
+impl ResponseError for GetBudgetError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetBudgetError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetBudgetError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            GetBudgetError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            GetBudgetError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
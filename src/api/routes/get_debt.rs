@@ -0,0 +1,85 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetDebtError;
+use crate::domain::usecases::group::{GetDebtRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use uuid::Uuid;
+
+/// Fetches the outstanding settlement transactions between the user making the request
+/// and another user, across all the groups they share.
+///
+/// The transactions are netted into a single suggested payment. The net amount is
+/// positive when the other user owes money to the user making the request.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace USER_ID with the other user UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/me/debts/USER_ID"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/me/debts/{user_id}",
+    params(
+        ("user_id" = Uuid, Path, description = "Uuid of the other user"),
+    ),
+    responses(
+        (status = 200, description = "Netted debts between the two users", body = GetDebtResponse),
+        (status = 400, description = "Invalid user id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Settlements",
+))]
+#[tracing::instrument(
+    name = "Get debt",
+    skip(path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        other_id = tracing::field::Empty,
+    )
+)]
+pub async fn get_debt<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetDebtError> {
+    match path_param {
+        Some(other_id) => {
+            let other_id = other_id.into_inner();
+            tracing::Span::current().record("other_id", tracing::field::display(other_id));
+            let data = GetDebtRequest {
+                user_id: *user_id.into_inner(),
+                other_id,
+            };
+            let debt = app.groups().get_debt(data).await?;
+            Ok(HttpResponse::Ok().json(ok(debt)))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("User id is invalid."))),
+    }
+}
+
+impl ResponseError for GetDebtError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetDebtError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetDebtError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            GetDebtError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            GetDebtError::Unauthenticated() => (StatusCode::UNAUTHORIZED, "You are not logged in."),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
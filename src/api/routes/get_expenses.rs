@@ -8,7 +8,7 @@ use crate::domain::usecases::group::{GetExpensesRequest, GroupUseCase};
 use actix_web::body::BoxBody;
 use actix_web::http::StatusCode;
 use actix_web::{web, HttpResponse, ResponseError};
-use chrono::{LocalResult, TimeZone, Utc};
+use chrono::{DateTime, LocalResult, TimeZone, Utc};
 #[cfg(feature = "openapi")]
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
@@ -50,7 +50,8 @@ use uuid::Uuid;
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Expenses",
 ))]
@@ -77,23 +78,7 @@ pub async fn get_expenses<Store: MultiRepository>(
             tracing::Span::current().record("group_id", &tracing::field::display(&group_id));
             tracing::Span::current().record("filters", &tracing::field::debug(&filters));
 
-            let from = match filters.from {
-                Some(e) => match Utc.timestamp_millis_opt(e) {
-                    LocalResult::Single(date) => Some(date),
-                    LocalResult::None => None,
-                    LocalResult::Ambiguous(_, _) => None,
-                },
-                None => None,
-            };
-            let to = match filters.to {
-                Some(e) => match Utc.timestamp_millis_opt(e) {
-                    LocalResult::Single(date) => Some(date),
-                    LocalResult::None => None,
-                    LocalResult::Ambiguous(_, _) => None,
-                },
-                None => None,
-            };
-            let settlement_id = filters.settlement_id;
+            let (from, to, settlement_id) = filters.into_parts();
 
             let data = GetExpensesRequest {
                 group_id,
@@ -125,6 +110,17 @@ pub struct GetExpensesFilter {
     settlement_id: Option<Uuid>,
 }
 
+impl GetExpensesFilter {
+    /// Splits the filters into dates and settlement id, ignoring invalid timestamps.
+    pub fn into_parts(self) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>, Option<Uuid>) {
+        let date = |timestamp: Option<i64>| match timestamp.map(|t| Utc.timestamp_millis_opt(t)) {
+            Some(LocalResult::Single(date)) => Some(date),
+            _ => None,
+        };
+        (date(self.from), date(self.to), self.settlement_id)
+    }
+}
+
 impl ResponseError for GetExpensesError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         let (code, msg) = match self {
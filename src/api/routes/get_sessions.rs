@@ -0,0 +1,61 @@
+use crate::api::response::ok;
+use crate::api::routes::middleware::user_session::{
+    SessionError, SessionInfo, UserId, UserSession,
+};
+use crate::infrastructure::session_store::SessionStoreImpl;
+use actix_web::{web, HttpResponse};
+use itertools::Itertools;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Lists the logged in sessions of the user making the request, most recently active first.
+///
+/// Requires the auth cookie from `/login` or a token to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie "http://localhost:8000/me/sessions"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/me/sessions",
+    responses(
+        (status = 200, description = "Sessions of the user", body = GetSessionsResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Get sessions",
+    skip(sessions, session, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn get_sessions(
+    sessions: web::Data<SessionStoreImpl>,
+    session: UserSession,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, SessionError> {
+    let current = session.get_session_id().ok().flatten();
+    let sessions = sessions
+        .user_sessions(&user_id.into_inner())
+        .await?
+        .iter()
+        .filter_map(|(_, state)| SessionInfo::from_state(state, current))
+        .sorted_by(|a, b| b.last_seen_at.cmp(&a.last_seen_at))
+        .collect_vec();
+    Ok(HttpResponse::Ok().json(ok(SessionsResponse { sessions })))
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SessionsResponse {
+    sessions: Vec<SessionInfo>,
+}
@@ -0,0 +1,132 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetStatsError;
+use crate::domain::usecases::group::{GetStatsRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use chrono::{LocalResult, TimeZone, Utc};
+#[cfg(feature = "openapi")]
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// Fetches spending statistics of a group: total spent, totals per member,
+/// totals per month and the largest expenses.
+///
+/// All the expenses of the group are aggregated, settled or not, optionally
+/// restricted to a date range with the `from` and/or `to` filters (Unix timestamps).
+///
+/// This action can only be performed by a group member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples (replace GROUP_ID ith the group UUID):
+/// ```
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/stats"
+/// curl -i -b cookie "http://localhost:8000/groups/GROUP_ID/stats?from=1676869911768&to=1676869945455"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/groups/{group_id}/stats",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        GetStatsFilter,
+    ),
+    responses(
+        (status = 200, description = "Spending statistics of the group", body = GetStatsResponse),
+        (status = 400, description = "Invalid group id or filters", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Expenses",
+))]
+#[tracing::instrument(
+    name = "Get stats",
+    skip(path_param, req_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+        filters = tracing::field::Empty,
+    )
+)]
+pub async fn get_stats<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    req_param: Option<web::Query<GetStatsFilter>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetStatsError> {
+    match (path_param, req_param) {
+        (Some(group_id), Some(filters)) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            let filters = filters.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            tracing::Span::current().record("filters", tracing::field::debug(&filters));
+
+            let from = match filters.from {
+                Some(e) => match Utc.timestamp_millis_opt(e) {
+                    LocalResult::Single(date) => Some(date),
+                    LocalResult::None => None,
+                    LocalResult::Ambiguous(_, _) => None,
+                },
+                None => None,
+            };
+            let to = match filters.to {
+                Some(e) => match Utc.timestamp_millis_opt(e) {
+                    LocalResult::Single(date) => Some(date),
+                    LocalResult::None => None,
+                    LocalResult::Ambiguous(_, _) => None,
+                },
+                None => None,
+            };
+
+            let data = GetStatsRequest {
+                group_id,
+                user_id,
+                from,
+                to,
+            };
+            let stats = app.groups().get_stats(data).await?;
+
+            Ok(HttpResponse::Ok().json(ok(stats)))
+        }
+        _ => Ok(HttpResponse::BadRequest().json(error("Group id or filters are invalid."))),
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in=Query))]
+pub struct GetStatsFilter {
+    from: Option<i64>,
+    to: Option<i64>,
+}
+
+impl ResponseError for GetStatsError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetStatsError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetStatsError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            GetStatsError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            GetStatsError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
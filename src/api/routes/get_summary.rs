@@ -0,0 +1,72 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetSummaryError;
+use crate::domain::usecases::group::{GetSummaryRequest, GroupUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+
+/// Fetches a summary of the groups of the user making the request.
+///
+/// For each group, returns the unsettled balance of the user, the settlement
+/// transactions they still have to pay or receive and the date of the last
+/// activity. The total is the sum of the balances and outstanding transactions
+/// across all groups, positive when the user is owed money.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Examples:
+/// ```
+/// curl -i -b cookie "http://localhost:8000/me/summary"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/me/summary",
+    responses(
+        (status = 200, description = "Summary of the user groups", body = GetSummaryResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Get summary",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn get_summary<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, GetSummaryError> {
+    let user_id = *user_id.into_inner();
+    let summary = app
+        .groups()
+        .get_summary(GetSummaryRequest { user_id })
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ok(summary)))
+}
+
+impl ResponseError for GetSummaryError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            GetSummaryError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            GetSummaryError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,133 @@
+use crate::api::response::{error, ok, ResponseMessage};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ImportExpensesError;
+use crate::domain::usecases::group::{GroupUseCase, ImportExpensesRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use std::collections::HashMap;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Maximum size of an import payload, exports of long-lived groups being large.
+pub const IMPORT_PAYLOAD_LIMIT: usize = 10 * 1024 * 1024;
+
+/// Imports expenses from a Splitwise, Tricount or generic CSV export.
+///
+/// The generic CSV has `date`, `title`, `amount` and `paid_by` columns, dates being
+/// formatted as `YYYY-MM-DD` or RFC 3339.
+/// Every person paying an expense in the file should be mapped to a group member in `members`.
+///
+/// All expenses are imported at once, or none when any line is invalid: the response then
+/// lists the errors of each line. With `dry_run`, the file is only validated.
+///
+/// This action can only be performed by the group administrator.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID and USER_ID with UUIDs, CONTENT with the CSV file content):
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"format": "splitwise", "content": "CONTENT", "members": {"Alice": "USER_ID"}, "dry_run": true}' -b cookie "http://localhost:8000/groups/GROUP_ID/expenses/import"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/groups/{group_id}/expenses/import",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    request_body = ImportExpensesPayload,
+    responses(
+        (status = 200, description = "Expenses imported, or validated on a dry run", body = ImportExpensesResponse),
+        (status = 400, description = "Invalid payload, format, member mapping or file", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 422, description = "Some lines are invalid, nothing was imported", body = ImportExpensesResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Expenses",
+))]
+#[tracing::instrument(
+    name = "Import expenses",
+    skip(payload, path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn import_expenses<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    payload: web::Json<ImportExpensesPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ImportExpensesError> {
+    match path_param {
+        Some(group_id) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            let payload = payload.into_inner();
+            let data = ImportExpensesRequest {
+                group_id,
+                user_id,
+                format: payload.format,
+                content: payload.content,
+                members: payload.members,
+                dry_run: payload.dry_run.unwrap_or(false),
+            };
+            let report = app.groups().import_expenses(data).await?;
+
+            if report.dry_run || report.errors.is_empty() {
+                Ok(HttpResponse::Ok().json(ok(report)))
+            } else {
+                Ok(HttpResponse::UnprocessableEntity().json(ResponseMessage {
+                    success: false,
+                    data: report,
+                }))
+            }
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ImportExpensesPayload {
+    /// Either `splitwise`, `tricount` or `generic`.
+    format: String,
+    /// Content of the CSV file.
+    content: String,
+    /// Group member id of each person named in the file.
+    members: HashMap<String, Uuid>,
+    /// Only validates the file when `true`.
+    dry_run: Option<bool>,
+}
+
+impl ResponseError for ImportExpensesError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            ImportExpensesError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            ImportExpensesError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            ImportExpensesError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            ImportExpensesError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            ImportExpensesError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
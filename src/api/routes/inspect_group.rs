@@ -0,0 +1,87 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageGroupError;
+use crate::domain::usecases::admin::AdminUseCase;
+use crate::domain::usecases::dto::dtos::ModeratedGroupDto;
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Fetches any group with all its expenses, settlements and events, only available if user is
+/// ADMIN
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie "http://localhost:8000/admin/groups/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/admin/groups/{group_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 200, description = "Full detail of the group", body = InspectGroupResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Inspect group",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn inspect_group<Store: MultiRepository>(
+    group_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageGroupError> {
+    let group = app
+        .admin()
+        .get_group(&user_id.into_inner(), &group_id.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(ok(ModeratedGroupResponse { group })))
+}
+
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ModeratedGroupResponse {
+    group: ModeratedGroupDto,
+}
+
+impl ResponseError for ManageGroupError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            ManageGroupError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            ManageGroupError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            ManageGroupError::Conflict(msg) => (StatusCode::CONFLICT, *msg),
+            ManageGroupError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            ManageGroupError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+            ManageGroupError::Unauthorized() => {
+                (StatusCode::FORBIDDEN, "You are not administrator.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
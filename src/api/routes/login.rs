@@ -1,9 +1,10 @@
-use crate::api::response::{error, ok_id};
-use crate::api::routes::middleware::user_session::UserSession;
+use crate::api::response::{error, ok_id, ok_message};
+use crate::api::routes::middleware::rate_limit::RateLimiter;
+use crate::api::routes::middleware::user_session::{ClientInfo, UserSession};
 use crate::application::app::Application;
 use crate::application::store::MultiRepository;
 use crate::domain::errors::LoginError;
-use crate::domain::usecases::user::{LoginRequest, UserUseCase};
+use crate::domain::usecases::user::{LoginOutcome, LoginRequest, UserUseCase};
 use actix_web::body::BoxBody;
 use actix_web::http::StatusCode;
 use actix_web::{web, HttpResponse, ResponseError};
@@ -15,6 +16,12 @@ use utoipa::ToSchema;
 ///
 /// It will return a `Set-Cookie` header, that should be used in next authenticated requests
 ///
+/// When the user enrolled a second factor, the response is `202 Accepted` instead and the login
+/// has to be completed with a code on `/login/second-factor`, sending the returned cookie.
+///
+/// Repeated failed logins lock the account out for a while, during which the response is
+/// `429 Too Many Requests` with a `Retry-After` header, as when the client IP sends too many requests.
+///
 /// Example:
 /// ```
 /// curl -i -H 'Content-Type: application/json' -d '{"password":"123","email":"r@r1.com"}' -c cookie "http://localhost:8000/login"
@@ -32,15 +39,32 @@ use utoipa::ToSchema;
                 ("Set-Cookie" = String, description = "Auth cookie")
             ),
         ),
+        (
+            status = 202,
+            description = "Second factor required",
+            body = MessageResponse,
+            headers(
+                ("Set-Cookie" = String, description = "Cookie to send to /login/second-factor")
+            ),
+        ),
         (status = 400, description = "Validation errors in login request", body = ErrorResponse),
         (status = 401, description = "Invalid credentials", body = ErrorResponse),
+        (status = 403, description = "Email is not verified, account is disabled or a password reset is required", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     tag = "Authentication",
 ))]
 #[tracing::instrument(
     name = "Logging in",
-    skip(payload, app, session),
+    skip(payload, app, rate_limiter, session, client),
     fields(
         user_email = %payload.email,
     )
@@ -48,16 +72,35 @@ use utoipa::ToSchema;
 pub async fn login<Store: MultiRepository>(
     payload: web::Json<LoginPayload>,
     app: web::Data<Application<Store>>,
+    rate_limiter: web::Data<RateLimiter>,
     session: UserSession,
-) -> Result<HttpResponse, LoginError> {
+    client: ClientInfo,
+) -> Result<HttpResponse, actix_web::Error> {
+    rate_limiter.check_account(&payload.email).await?;
     let request = LoginRequest {
-        email: payload.0.email,
+        email: payload.0.email.clone(),
         password: payload.0.password,
     };
-    let user_id = app.users().login(request).await?;
-    session.renew();
+    let outcome = match app.users().login(request).await {
+        Err(LoginError::InvalidCredentials()) => {
+            rate_limiter.record_failure(&payload.0.email).await;
+            return Err(LoginError::InvalidCredentials().into());
+        }
+        outcome => outcome?,
+    };
+    rate_limiter.reset_account(&payload.0.email).await;
+    let user_id = match outcome {
+        LoginOutcome::LoggedIn(user_id) => user_id,
+        LoginOutcome::SecondFactorRequired(user_id) => {
+            session.renew();
+            session
+                .insert_second_factor_user_id(user_id)
+                .map_err(|e| LoginError::Unexpected(e.into()))?;
+            return Ok(HttpResponse::Accepted().json(ok_message("Second factor required.")));
+        }
+    };
     session
-        .insert_user_id(user_id)
+        .log_in(user_id, &client)
         .map_err(|e| LoginError::Unexpected(e.into()))?;
     Ok(HttpResponse::Ok().json(&ok_id(user_id)))
 }
@@ -75,6 +118,11 @@ impl ResponseError for LoginError {
         let (code, msg) = match self {
             LoginError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
             LoginError::InvalidCredentials() => (StatusCode::UNAUTHORIZED, "Invalid credentials."),
+            LoginError::Unverified() => (StatusCode::FORBIDDEN, "Email is not verified."),
+            LoginError::Disabled() => (StatusCode::FORBIDDEN, "Account is disabled."),
+            LoginError::PasswordResetRequired() => {
+                (StatusCode::FORBIDDEN, "A password reset is required.")
+            }
             LoginError::Unexpected(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Unexpected server error.",
@@ -0,0 +1,90 @@
+use crate::api::response::ok_id;
+use crate::api::routes::middleware::rate_limit::RateLimiter;
+use crate::api::routes::middleware::user_session::{ClientInfo, UserSession};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::LoginError;
+use crate::domain::usecases::user::{SecondFactorRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Completes the login of a user who enrolled a second factor, given the code shown by their
+/// authenticator application or one of their recovery codes.
+///
+/// Requires the cookie returned by `/login` to be attached to the request. Each attempt requires
+/// the credentials again, a wrong code closing the pending login. Like `/login`, it will return
+/// a `Set-Cookie` header, that should be used in next authenticated requests.
+///
+/// Repeated wrong codes lock the second factor of the user out for a while, like failed logins.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"code":"123456"}' -b cookie -c cookie "http://localhost:8000/login/second-factor"
+///```
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/login/second-factor",
+    request_body = SecondFactorPayload,
+    responses(
+        (
+            status = 200,
+            description = "Successful login",
+            body = IdResponse,
+            headers(
+                ("Set-Cookie" = String, description = "Auth cookie")
+            ),
+        ),
+        (status = 401, description = "Invalid code or no pending login", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Completing login with second factor",
+    skip(payload, app, rate_limiter, session, client)
+)]
+pub async fn login_second_factor<Store: MultiRepository>(
+    payload: web::Json<SecondFactorPayload>,
+    app: web::Data<Application<Store>>,
+    rate_limiter: web::Data<RateLimiter>,
+    session: UserSession,
+    client: ClientInfo,
+) -> Result<HttpResponse, actix_web::Error> {
+    let user_id = session
+        .remove_second_factor_user_id()
+        .ok_or(LoginError::InvalidCredentials())?;
+    let account = user_id.to_string();
+    rate_limiter.check_account(&account).await?;
+    let request = SecondFactorRequest {
+        user_id,
+        code: payload.0.code,
+    };
+    let user_id = match app.users().complete_second_factor(request).await {
+        Err(LoginError::InvalidCredentials()) => {
+            rate_limiter.record_failure(&account).await;
+            return Err(LoginError::InvalidCredentials().into());
+        }
+        outcome => outcome?,
+    };
+    rate_limiter.reset_account(&account).await;
+    session
+        .log_in(user_id, &client)
+        .map_err(|e| LoginError::Unexpected(e.into()))?;
+    Ok(HttpResponse::Ok().json(ok_id(user_id)))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SecondFactorPayload {
+    /// TOTP code or recovery code.
+    pub code: String,
+}
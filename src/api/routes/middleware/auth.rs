@@ -1,43 +1,114 @@
 use crate::api::response::error;
 use crate::api::routes::middleware::user_session::{UserId, UserSession};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::usecases::user::UserUseCase;
 use crate::error_chain;
 use actix_web::body::{BoxBody, MessageBody};
 use actix_web::dev::{ServiceRequest, ServiceResponse};
-use actix_web::http::StatusCode;
-use actix_web::{FromRequest, HttpMessage, HttpResponse, ResponseError};
+use actix_web::http::{header, Method, StatusCode};
+use actix_web::{web, FromRequest, HttpMessage, HttpResponse, ResponseError};
 use actix_web_lab::middleware::Next;
 use anyhow::anyhow;
-use log::info;
+use log::{info, warn};
+use secrecy::Secret;
 
-pub async fn reject_anonymous_users(
+/// Rejects the requests without a logged in user, or whose session was revoked since, e.g.
+/// by a password change.
+///
+/// Requests bearing a personal access token in their `Authorization` header are authenticated
+/// by the token instead of the session cookie, and read-only tokens are restricted to `GET`.
+/// The [`AuthMethod`] is recorded in the request extensions.
+pub async fn reject_anonymous_users<Store: MultiRepository>(
     mut req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let app = req
+        .app_data::<web::Data<Application<Store>>>()
+        .ok_or_else(|| AuthorizationError::Unexpected(anyhow!("Application is not set.")))?
+        .clone();
+    if let Some(header) = req.headers().get(header::AUTHORIZATION) {
+        let token = header
+            .to_str()
+            .ok()
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .ok_or_else(|| AuthorizationError::Unexpected(anyhow!("Malformed authorization.")))?;
+        let (user_id, scope) = app
+            .users()
+            .authenticate_api_token(&Secret::new(token.trim().to_string()))
+            .await
+            .map_err(AuthorizationError::Unexpected)?
+            .ok_or_else(|| AuthorizationError::Unexpected(anyhow!("Token is not valid.")))?;
+        if !scope.allows_writes() && ![Method::GET, Method::HEAD].contains(req.method()) {
+            return Err(AuthorizationError::Forbidden().into());
+        }
+        info!("Token user is {}", user_id);
+        req.extensions_mut().insert(UserId(user_id));
+        req.extensions_mut().insert(AuthMethod::Token);
+        return next.call(req).await;
+    }
     let session = {
         let (http_request, payload) = req.parts_mut();
         UserSession::from_request(http_request, payload).await
     }?;
-    session
+    let user_id = session
         .get_user_id()
         .map_err(|e| AuthorizationError::Unexpected(anyhow!(e)))
-        .and_then(|id| match id {
-            None => Err(AuthorizationError::Unexpected(anyhow!(
-                "User is not logged in."
-            ))),
-            Some(user_id) => {
-                info!("Session user is {}", user_id);
-                req.extensions_mut().insert(UserId(user_id));
-                Ok(())
-            }
-        })
-        .map_err(actix_web::Error::from)?;
+        .and_then(|id| {
+            id.ok_or_else(|| AuthorizationError::Unexpected(anyhow!("User is not logged in.")))
+        })?;
+    let logged_in_at = session
+        .get_logged_in_at()
+        .map_err(|e| AuthorizationError::Unexpected(anyhow!(e)))?;
+    let valid = app
+        .users()
+        .is_valid_session(&user_id, logged_in_at)
+        .await
+        .map_err(AuthorizationError::Unexpected)?;
+    if !valid {
+        session.purge();
+        return Err(AuthorizationError::Unexpected(anyhow!("Session was revoked.")).into());
+    }
+    if let Err(e) = session.touch() {
+        warn!("Could not record the activity of the session: {:?}", e);
+    }
+    info!("Session user is {}", user_id);
+    req.extensions_mut().insert(UserId(user_id));
+    req.extensions_mut().insert(AuthMethod::Session);
 
     next.call(req).await
 }
 
+/// Rejects the requests authenticated by a personal access token, on the routes managing the
+/// account or the instance, which a leaked token should not give access to.
+///
+/// Has to run after [`reject_anonymous_users`], i.e. be registered before it.
+pub async fn require_session(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    if req.extensions().get::<AuthMethod>() != Some(&AuthMethod::Session) {
+        return Err(AuthorizationError::SessionRequired().into());
+    }
+    next.call(req).await
+}
+
+/// How the user of a request was authenticated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthMethod {
+    /// Session cookie from `/login`.
+    Session,
+    /// Personal access token from `/me/tokens`.
+    Token,
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum AuthorizationError {
+        #[error("The token does not allow this request.")]
+        Forbidden(),
+        #[error("The request is only allowed from a logged in session.")]
+        SessionRequired(),
         #[error(transparent)]
         Unexpected(#[from] anyhow::Error),
     }
@@ -46,6 +117,13 @@ error_chain! {
 impl ResponseError for AuthorizationError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         let (code, msg) = match self {
+            AuthorizationError::Forbidden() => {
+                (StatusCode::FORBIDDEN, "This token is only allowed to read.")
+            }
+            AuthorizationError::SessionRequired() => (
+                StatusCode::FORBIDDEN,
+                "This action is only allowed from a logged in session.",
+            ),
             AuthorizationError::Unexpected(_) => {
                 (StatusCode::UNAUTHORIZED, "You are not logged in.")
             }
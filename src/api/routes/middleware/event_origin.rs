@@ -0,0 +1,18 @@
+use crate::api::routes::middleware::user_session::ClientInfo;
+use crate::domain::EventOrigin;
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web_lab::middleware::Next;
+
+/// Records the client of the request as the origin of the events saved while handling it.
+pub async fn record_origin(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let client = ClientInfo::of(req.request());
+    let origin = EventOrigin {
+        ip: client.ip,
+        user_agent: client.user_agent,
+    };
+    origin.scope(next.call(req)).await
+}
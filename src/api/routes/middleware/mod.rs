@@ -1,2 +1,4 @@
 pub mod auth;
+pub mod event_origin;
+pub mod rate_limit;
 pub mod user_session;
@@ -0,0 +1,165 @@
+use crate::api::response::error;
+use crate::configuration::application::RateLimitSettings;
+use crate::error_chain;
+use crate::infrastructure::rate_limit_store::{RateLimitStore, RateLimitStoreImpl};
+use actix_web::body::{BoxBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::{header, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use actix_web_lab::middleware::Next;
+use log::warn;
+use std::time::Duration;
+
+/// Longest lockout of an account, however many times it was locked.
+const MAX_LOCKOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Throttles the requests of each client IP, and locks accounts out after repeated failed logins,
+/// the lockout doubling each time it is hit again.
+///
+/// Errors of the underlying store are logged and let the requests through, so that an unavailable
+/// store does not lock every user out.
+#[derive(Clone)]
+pub struct RateLimiter {
+    store: RateLimitStoreImpl,
+    ip_attempts: u64,
+    window: Duration,
+    account_failures: u64,
+    lockout: Duration,
+    trust_forwarded_headers: bool,
+}
+
+impl RateLimiter {
+    pub async fn build(configuration: &RateLimitSettings) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            store: RateLimitStoreImpl::build(&configuration.store).await?,
+            ip_attempts: configuration.ip_attempts as u64,
+            window: Duration::from_secs(configuration.window as u64),
+            account_failures: configuration.account_failures.max(1) as u64,
+            lockout: Duration::from_secs(configuration.lockout as u64),
+            trust_forwarded_headers: configuration.trust_forwarded_headers,
+        })
+    }
+
+    /// Counts a request from `ip`, rejecting it once the IP exceeded its attempts in the window.
+    async fn check_ip(&self, ip: &str) -> Result<(), RateLimitError> {
+        let key = format!("rate-limit:ip:{}", ip);
+        let attempts = match self.store.increment(&key, self.window).await {
+            Ok(attempts) => attempts,
+            Err(e) => {
+                warn!("Could not count the attempts of {}: {:?}", ip, e);
+                return Ok(());
+            }
+        };
+        if attempts <= self.ip_attempts {
+            return Ok(());
+        }
+        let retry_after = self
+            .store
+            .expires_in(&key)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(self.window);
+        Err(RateLimitError::TooManyAttempts(retry_after))
+    }
+
+    /// Rejects the login attempts on a locked out account.
+    pub async fn check_account(&self, account: &str) -> Result<(), RateLimitError> {
+        match self.store.expires_in(&Self::lock_key(account)).await {
+            Ok(Some(retry_after)) => Err(RateLimitError::TooManyAttempts(retry_after)),
+            Ok(None) => Ok(()),
+            Err(e) => {
+                warn!("Could not read the lockout of {}: {:?}", account, e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Counts a failed login on `account`, locking it out every `account_failures` failures.
+    pub async fn record_failure(&self, account: &str) {
+        let failures = match self
+            .store
+            .increment(&Self::failures_key(account), MAX_LOCKOUT)
+            .await
+        {
+            Ok(failures) => failures,
+            Err(e) => {
+                warn!("Could not count the failures of {}: {:?}", account, e);
+                return;
+            }
+        };
+        if failures % self.account_failures != 0 {
+            return;
+        }
+        let lockouts = (failures / self.account_failures).min(32) as u32;
+        let lockout = self
+            .lockout
+            .saturating_mul(2u32.saturating_pow(lockouts - 1))
+            .min(MAX_LOCKOUT);
+        if let Err(e) = self.store.set(&Self::lock_key(account), lockout).await {
+            warn!("Could not lock {} out: {:?}", account, e);
+        }
+    }
+
+    /// Forgets the failed logins on `account`, after a successful one.
+    pub async fn reset_account(&self, account: &str) {
+        if let Err(e) = self.store.delete(&Self::failures_key(account)).await {
+            warn!("Could not reset the failures of {}: {:?}", account, e);
+        }
+    }
+
+    fn failures_key(account: &str) -> String {
+        format!("rate-limit:failures:{}", account.trim().to_lowercase())
+    }
+
+    fn lock_key(account: &str) -> String {
+        format!("rate-limit:lock:{}", account.trim().to_lowercase())
+    }
+
+    /// Returns the IP of the client, from the forwarded headers when they are trusted.
+    pub fn client_ip(&self, req: &HttpRequest) -> Option<String> {
+        if self.trust_forwarded_headers {
+            req.connection_info()
+                .realip_remote_addr()
+                .map(|addr| addr.to_string())
+        } else {
+            req.peer_addr().map(|addr| addr.ip().to_string())
+        }
+    }
+}
+
+/// Rejects the requests of a client IP which exceeded its attempts, see [RateLimiter].
+pub async fn limit_ip(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    if let Some(limiter) = req.app_data::<web::Data<RateLimiter>>() {
+        if let Some(ip) = limiter.client_ip(req.request()) {
+            limiter.check_ip(&ip).await?;
+        }
+    }
+    next.call(req).await
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum RateLimitError {
+        #[error("Too many attempts, retry in {0:?}.")]
+        TooManyAttempts(Duration),
+    }
+}
+
+impl ResponseError for RateLimitError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        match self {
+            RateLimitError::TooManyAttempts(retry_after) => {
+                HttpResponse::build(StatusCode::TOO_MANY_REQUESTS)
+                    .insert_header((
+                        header::RETRY_AFTER,
+                        retry_after.as_secs().max(1).to_string(),
+                    ))
+                    .json(error("Too many attempts, retry later."))
+            }
+        }
+    }
+}
@@ -1,17 +1,62 @@
+use crate::api::response::error;
+use crate::api::routes::middleware::rate_limit::RateLimiter;
+use crate::domain::AuthorizationFlow;
+use crate::error_chain;
+use crate::infrastructure::session_store::{SessionState, USER_ID_KEY};
+use crate::utils::date;
 use actix_session::{Session, SessionExt, SessionGetError, SessionInsertError};
+use actix_web::body::BoxBody;
 use actix_web::dev::Payload;
-use actix_web::{FromRequest, HttpRequest};
+use actix_web::http::{header, StatusCode};
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use chrono::{DateTime, Duration, Utc};
+use serde::de::DeserializeOwned;
 use std::future::{ready, Ready};
 use std::ops::Deref;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub struct UserSession(Session);
 
 impl UserSession {
-    const USER_ID_KEY: &'static str = "user_id";
+    const USER_ID_KEY: &'static str = USER_ID_KEY;
+    const LOGGED_IN_AT_KEY: &'static str = "logged_in_at";
+    const AUTHORIZATION_FLOW_KEY: &'static str = "authorization_flow";
+    const SECOND_FACTOR_USER_ID_KEY: &'static str = "second_factor_user_id";
+    const SESSION_ID_KEY: &'static str = "session_id";
+    const CREATED_AT_KEY: &'static str = "created_at";
+    const LAST_SEEN_AT_KEY: &'static str = "last_seen_at";
+    const USER_AGENT_KEY: &'static str = "user_agent";
+    const IP_KEY: &'static str = "ip";
     pub fn renew(&self) {
         self.0.renew();
     }
+    /// Logs the user in a renewed session, identified by a new id and describing the client it
+    /// was opened from.
+    pub fn log_in(&self, user_id: Uuid, client: &ClientInfo) -> Result<(), SessionInsertError> {
+        let now = date::now();
+        self.0.renew();
+        self.0.insert(Self::USER_ID_KEY, user_id)?;
+        self.0.insert(Self::LOGGED_IN_AT_KEY, now)?;
+        self.0.insert(Self::SESSION_ID_KEY, Uuid::new_v4())?;
+        self.0.insert(Self::CREATED_AT_KEY, now)?;
+        self.0.insert(Self::LAST_SEEN_AT_KEY, now)?;
+        self.0.insert(Self::USER_AGENT_KEY, &client.user_agent)?;
+        self.0.insert(Self::IP_KEY, &client.ip)
+    }
+    pub fn get_session_id(&self) -> Result<Option<Uuid>, SessionGetError> {
+        self.0.get(Self::SESSION_ID_KEY)
+    }
+    /// Records the activity of the session, at most once a minute to spare writes to the store.
+    pub fn touch(&self) -> Result<(), SessionInsertError> {
+        let now = date::now();
+        let last_seen_at: Option<DateTime<Utc>> = self.0.get(Self::LAST_SEEN_AT_KEY).ok().flatten();
+        if last_seen_at.map_or(true, |last| now - last >= Duration::minutes(1)) {
+            self.0.insert(Self::LAST_SEEN_AT_KEY, now)?;
+        }
+        Ok(())
+    }
     pub fn purge(&self) {
         self.0.purge();
     }
@@ -21,6 +66,122 @@ impl UserSession {
     pub fn get_user_id(&self) -> Result<Option<Uuid>, SessionGetError> {
         self.0.get(Self::USER_ID_KEY)
     }
+    pub fn insert_logged_in_at(&self, date: DateTime<Utc>) -> Result<(), SessionInsertError> {
+        self.0.insert(Self::LOGGED_IN_AT_KEY, date)
+    }
+    pub fn get_logged_in_at(&self) -> Result<Option<DateTime<Utc>>, SessionGetError> {
+        self.0.get(Self::LOGGED_IN_AT_KEY)
+    }
+    pub fn insert_authorization_flow(
+        &self,
+        flow: AuthorizationFlow,
+    ) -> Result<(), SessionInsertError> {
+        self.0.insert(Self::AUTHORIZATION_FLOW_KEY, flow)
+    }
+    /// Removes the authorization flow from the session, so that it can only be completed once.
+    pub fn remove_authorization_flow(&self) -> Option<AuthorizationFlow> {
+        self.0
+            .remove_as(Self::AUTHORIZATION_FLOW_KEY)
+            .and_then(Result::ok)
+    }
+    /// Keeps the user whose credentials were checked until they provide their second factor.
+    pub fn insert_second_factor_user_id(&self, user_id: Uuid) -> Result<(), SessionInsertError> {
+        self.0.insert(Self::SECOND_FACTOR_USER_ID_KEY, user_id)
+    }
+    /// Removes the user awaiting their second factor from the session, so that each code
+    /// attempt requires the credentials again.
+    pub fn remove_second_factor_user_id(&self) -> Option<Uuid> {
+        self.0
+            .remove_as(Self::SECOND_FACTOR_USER_ID_KEY)
+            .and_then(Result::ok)
+    }
+}
+
+/// Description of a logged in session, read from its state in the session store.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SessionInfo {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    /// Whether it is the session making the request.
+    pub current: bool,
+}
+
+impl SessionInfo {
+    /// Reads the session, unless it was opened before sessions were described.
+    pub fn from_state(state: &SessionState, current: Option<Uuid>) -> Option<Self> {
+        fn get<T: DeserializeOwned>(state: &SessionState, key: &str) -> Option<T> {
+            state
+                .get(key)
+                .and_then(|value| serde_json::from_str(value).ok())
+        }
+        let id = get(state, UserSession::SESSION_ID_KEY)?;
+        let created_at = get(state, UserSession::CREATED_AT_KEY)?;
+        Some(Self {
+            id,
+            created_at,
+            last_seen_at: get(state, UserSession::LAST_SEEN_AT_KEY).unwrap_or(created_at),
+            user_agent: get::<Option<String>>(state, UserSession::USER_AGENT_KEY).flatten(),
+            ip: get::<Option<String>>(state, UserSession::IP_KEY).flatten(),
+            current: current == Some(id),
+        })
+    }
+}
+
+/// Client a session is opened from.
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn of(req: &HttpRequest) -> Self {
+        let user_agent = req
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|agent| agent.to_str().ok())
+            .map(|agent| agent.to_string());
+        let ip = match req.app_data::<web::Data<RateLimiter>>() {
+            Some(limiter) => limiter.client_ip(req),
+            None => req.peer_addr().map(|addr| addr.ip().to_string()),
+        };
+        ClientInfo { user_agent, ip }
+    }
+}
+
+impl FromRequest for ClientInfo {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<ClientInfo, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Ok(ClientInfo::of(req)))
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum SessionError {
+        #[error("Session not found.")]
+        NotFound(),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+impl ResponseError for SessionError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            SessionError::NotFound() => (StatusCode::NOT_FOUND, "Session not found."),
+            SessionError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
 }
 
 impl FromRequest for UserSession {
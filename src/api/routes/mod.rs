@@ -1,46 +1,142 @@
+mod archive_group;
+mod backup_group;
+mod change_email;
 mod change_member_color;
+mod change_password;
+mod change_user_role;
+mod confirm_two_factor;
+mod create_api_token;
 mod create_expense;
 mod create_group;
+mod delete_budget;
 mod delete_expense;
 mod delete_group;
+mod delete_user;
+mod disable_user;
+mod enable_user;
+mod enroll_two_factor;
+mod export_expenses;
+mod export_ledger;
+mod export_settlements;
+mod export_user_data;
+mod force_password_reset;
+mod forgot_password;
 mod generate_group_token;
+mod get_activity;
 mod get_all_groups;
 mod get_all_users;
+mod get_api_tokens;
+mod get_audit_log;
+mod get_budget;
+mod get_debt;
 mod get_expenses;
 mod get_group;
 mod get_groups;
+mod get_sessions;
 mod get_settlements;
+mod get_stats;
+mod get_summary;
 mod health_check;
+mod import_expenses;
+mod inspect_group;
 mod join_group;
 mod login;
+mod login_second_factor;
 mod logout;
 pub mod middleware;
+mod oidc_callback;
+mod oidc_login;
 mod register;
 #[cfg(feature = "pushy")]
 mod register_device;
 #[cfg(feature = "pushy")]
 mod remove_device;
+mod remove_group;
+mod remove_member;
+mod remove_user;
+mod resend_verification;
+mod reset_password;
+mod reset_two_factor;
+mod restore_group;
+mod revert_settlement;
+mod revoke_api_token;
+mod revoke_session;
+mod set_budget;
 mod settle;
+mod settle_debt;
+mod transfer_group_ownership;
+mod transfer_ownership;
+mod unarchive_group;
 mod update_expense;
+mod update_user_name;
+mod verify_email;
 
+pub use archive_group::*;
+pub use backup_group::*;
+pub use change_email::*;
 pub use change_member_color::*;
+pub use change_password::*;
+pub use change_user_role::*;
+pub use confirm_two_factor::*;
+pub use create_api_token::*;
 pub use create_expense::*;
 pub use create_group::*;
+pub use delete_budget::*;
 pub use delete_expense::*;
 pub use delete_group::*;
+pub use delete_user::*;
+pub use disable_user::*;
+pub use enable_user::*;
+pub use enroll_two_factor::*;
+pub use export_expenses::*;
+pub use export_ledger::*;
+pub use export_settlements::*;
+pub use export_user_data::*;
+pub use force_password_reset::*;
+pub use forgot_password::*;
 pub use generate_group_token::*;
+pub use get_activity::*;
 pub use get_all_groups::*;
 pub use get_all_users::*;
+pub use get_api_tokens::*;
+pub use get_audit_log::*;
+pub use get_budget::*;
+pub use get_debt::*;
 pub use get_expenses::*;
 pub use get_group::*;
 pub use get_groups::*;
+pub use get_sessions::*;
 pub use get_settlements::*;
+pub use get_stats::*;
+pub use get_summary::*;
 pub use health_check::*;
+pub use import_expenses::*;
+pub use inspect_group::*;
 pub use join_group::*;
 pub use login::*;
+pub use login_second_factor::*;
 pub use logout::*;
+pub use oidc_callback::*;
+pub use oidc_login::*;
 pub use register::*;
 pub use register_device::*;
 pub use remove_device::*;
+pub use remove_group::*;
+pub use remove_member::*;
+pub use remove_user::*;
+pub use resend_verification::*;
+pub use reset_password::*;
+pub use reset_two_factor::*;
+pub use restore_group::*;
+pub use revert_settlement::*;
+pub use revoke_api_token::*;
+pub use revoke_session::*;
+pub use set_budget::*;
 pub use settle::*;
+pub use settle_debt::*;
+pub use transfer_group_ownership::*;
+pub use transfer_ownership::*;
+pub use unarchive_group::*;
 pub use update_expense::*;
+pub use update_user_name::*;
+pub use verify_email::*;
@@ -0,0 +1,73 @@
+use crate::api::response::ok_id;
+use crate::api::routes::middleware::user_session::{ClientInfo, UserSession};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::LoginError;
+use crate::domain::usecases::user::{IdentityLoginRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::IntoParams;
+
+/// Completes the login the identity provider redirected the user back from.
+///
+/// Like `/login`, it will return a `Set-Cookie` header, that should be used in next
+/// authenticated requests. The user is created on their first login.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -c cookie "http://localhost:8000/oidc/callback?code=abc&state=xyz"
+///```
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/oidc/callback",
+    params(CallbackParams),
+    responses(
+        (
+            status = 200,
+            description = "Successful login",
+            body = IdResponse,
+            headers(
+                ("Set-Cookie" = String, description = "Auth cookie")
+            ),
+        ),
+        (status = 400, description = "No identity provider is configured", body = ErrorResponse),
+        (status = 401, description = "Login refused or not started", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Completing identity provider login",
+    skip(params, app, session, client),
+    fields(
+        provider_error = ?params.error,
+    )
+)]
+pub async fn oidc_callback<Store: MultiRepository>(
+    params: web::Query<CallbackParams>,
+    app: web::Data<Application<Store>>,
+    session: UserSession,
+    client: ClientInfo,
+) -> Result<HttpResponse, LoginError> {
+    let request = IdentityLoginRequest {
+        code: params.0.code,
+        state: params.0.state,
+        flow: session.remove_authorization_flow(),
+    };
+    let user_id = app.users().complete_identity_login(request).await?;
+    session
+        .log_in(user_id, &client)
+        .map_err(|e| LoginError::Unexpected(e.into()))?;
+    Ok(HttpResponse::Ok().json(ok_id(user_id)))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in=Query))]
+pub struct CallbackParams {
+    /// Authorization code, absent when the user denied the login.
+    pub code: Option<String>,
+    pub state: Option<String>,
+    /// Error reported by the provider.
+    pub error: Option<String>,
+}
@@ -0,0 +1,47 @@
+use crate::api::routes::middleware::user_session::UserSession;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::LoginError;
+use crate::domain::usecases::user::UserUseCase;
+use actix_web::http::header::LOCATION;
+use actix_web::{web, HttpResponse};
+
+/// Starts a login through the identity provider, when the `oidc` auth is configured.
+///
+/// It redirects to the provider, which will redirect the user back to `/oidc/callback`. The
+/// returned `Set-Cookie` header has to be kept until then.
+///
+/// Example:
+/// ```
+/// curl -i -c cookie "http://localhost:8000/oidc/login"
+///```
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/oidc/login",
+    responses(
+        (
+            status = 302,
+            description = "Redirection to the identity provider",
+            headers(
+                ("Location" = String, description = "Authorization URL of the provider"),
+                ("Set-Cookie" = String, description = "Session cookie keeping the flow")
+            ),
+        ),
+        (status = 400, description = "No identity provider is configured", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(name = "Starting identity provider login", skip(app, session))]
+pub async fn oidc_login<Store: MultiRepository>(
+    app: web::Data<Application<Store>>,
+    session: UserSession,
+) -> Result<HttpResponse, LoginError> {
+    let (url, flow) = app.users().start_identity_login().await?;
+    session
+        .insert_authorization_flow(flow)
+        .map_err(|e| LoginError::Unexpected(e.into()))?;
+    Ok(HttpResponse::Found()
+        .insert_header((LOCATION, url))
+        .finish())
+}
@@ -13,6 +13,8 @@ use utoipa::ToSchema;
 
 /// Registers a new user.
 ///
+/// Too many requests from the same client IP are rejected with `429 Too Many Requests`.
+///
 /// Example:
 /// ```
 /// curl -i -H 'Content-Type: application/json' -d '{"password":"123","email":"r@r1.com","name":"Bob"}' -c cookie "http://localhost:8000/register"
@@ -24,6 +26,14 @@ use utoipa::ToSchema;
     responses(
         (status = 201, description = "Successful registration", body = MessageResponse),
         (status = 400, description = "Validation errors in registration request", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     tag = "Authentication",
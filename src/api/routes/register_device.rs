@@ -22,7 +22,8 @@ use utoipa::ToSchema;
         (status = 200, description = "Device linked to the user.", body = MessageResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Devices",
 ))]
@@ -19,7 +19,8 @@ use actix_web::{web, HttpResponse};
         (status = 204, description = "Device un-linked from the user.", body = MessageResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Devices",
 ))]
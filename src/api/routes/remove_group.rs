@@ -0,0 +1,52 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageGroupError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Deletes any group along with its expenses and settlements, only available if user is ADMIN
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/admin/groups/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/admin/groups/{group_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 204, description = "Group deleted"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Remove group",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn remove_group<Store: MultiRepository>(
+    group_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageGroupError> {
+    app.admin()
+        .delete_group(&user_id.into_inner(), &group_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,58 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageGroupError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Removes a member from any group, only available if user is ADMIN
+///
+/// Only members without unsettled expenses nor unpaid debts in the group can be removed, and
+/// the ownership of the group must be transferred before removing its admin.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID and USER_ID with the member UUID):
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/admin/groups/GROUP_ID/members/USER_ID"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/admin/groups/{group_id}/members/{user_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        ("user_id" = Uuid, Path, description = "Id of the member to remove"),
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Group or member not found", body = ErrorResponse),
+        (status = 409, description = "Member is the group admin or has unsettled expenses or unpaid debts", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Remove member",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn remove_member<Store: MultiRepository>(
+    path: web::Path<(Uuid, Uuid)>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageGroupError> {
+    let (group_id, member_id) = path.into_inner();
+    app.admin()
+        .remove_member(&user_id.into_inner(), &group_id, &member_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,56 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageUserError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Deletes the account of a user, only available if user is ADMIN
+///
+/// The user is anonymized as if they had deleted their account from `/me`: the ownership of
+/// the groups they administer must be transferred beforehand.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/admin/users/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/admin/users/{user_id}",
+    params(
+        ("user_id" = Uuid, Path, description = "Id of the user to delete"),
+    ),
+    responses(
+        (status = 204, description = "Account deleted"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Admin targeted their own account, or the user still administers groups", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Remove user",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn remove_user<Store: MultiRepository>(
+    target_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageUserError> {
+    app.admin()
+        .delete_user(&user_id.into_inner(), &target_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
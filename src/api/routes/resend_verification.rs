@@ -0,0 +1,60 @@
+use crate::api::response::ok_message;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::EmailVerificationError;
+use crate::domain::usecases::user::{ResendVerificationRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Mails a new verification link to the unverified user registered with the given email, the
+/// previous links being invalidated.
+///
+/// The response is the same whether the email is registered or not.
+///
+/// Too many requests from the same client IP are rejected with `429 Too Many Requests`.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"email":"r@r1.com"}' "http://localhost:8000/verification/resend"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/verification/resend",
+    request_body = ResendVerificationPayload,
+    responses(
+        (status = 202, description = "Verification link sent if the email awaits verification", body = MessageResponse),
+        (status = 400, description = "Invalid email, or verification disabled", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(name = "Resend verification link", skip(payload, app))]
+pub async fn resend_verification<Store: MultiRepository>(
+    payload: web::Json<ResendVerificationPayload>,
+    app: web::Data<Application<Store>>,
+) -> Result<HttpResponse, EmailVerificationError> {
+    app.users()
+        .resend_verification(ResendVerificationRequest {
+            email: payload.0.email,
+        })
+        .await?;
+    Ok(HttpResponse::Accepted().json(ok_message(
+        "If an account awaits verification with this email, a new link was sent to it.",
+    )))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ResendVerificationPayload {
+    email: String,
+}
@@ -0,0 +1,62 @@
+use crate::api::response::ok_message;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::PasswordResetError;
+use crate::domain::usecases::user::{ResetPasswordRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+use secrecy::Secret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Sets a new password with the token of a reset link sent by `/password/forgot`.
+///
+/// The token can only be used once, and all the sessions of the user are closed.
+///
+/// Too many requests from the same client IP are rejected with `429 Too Many Requests`.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"token":"8c1f...","password":"456"}' "http://localhost:8000/password/reset"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/password/reset",
+    request_body = ResetPasswordPayload,
+    responses(
+        (status = 200, description = "Password changed successfully", body = MessageResponse),
+        (status = 400, description = "Invalid password, or invalid or expired token", body = ErrorResponse),
+        (
+            status = 429,
+            description = "Too many attempts",
+            body = ErrorResponse,
+            headers(
+                ("Retry-After" = u64, description = "Seconds to wait before retrying")
+            ),
+        ),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(name = "Reset password", skip(payload, app))]
+pub async fn reset_password<Store: MultiRepository>(
+    payload: web::Json<ResetPasswordPayload>,
+    app: web::Data<Application<Store>>,
+) -> Result<HttpResponse, PasswordResetError> {
+    app.users()
+        .reset_password(ResetPasswordRequest {
+            token: payload.0.token,
+            password: payload.0.password,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(ok_message("Password changed.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ResetPasswordPayload {
+    #[cfg_attr(feature = "openapi", schema(value_type=String))]
+    token: Secret<String>,
+    #[cfg_attr(feature = "openapi", schema(value_type=String))]
+    password: Secret<String>,
+}
@@ -0,0 +1,54 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TwoFactorError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Removes the second factor of a user, only available if user is ADMIN
+///
+/// The user then logs in with their password alone, and can enroll a new second factor.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/admin/users/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41/two-factor"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/admin/users/{user_id}/two-factor",
+    params(
+        ("user_id" = Uuid, Path, description = "Id of the user whose second factor is reset"),
+    ),
+    responses(
+        (status = 204, description = "Second factor removed"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "User not found or without second factor", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Reset second factor",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn reset_two_factor<Store: MultiRepository>(
+    target_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, TwoFactorError> {
+    app.admin()
+        .reset_two_factor(&user_id.into_inner(), &target_id.into_inner())
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,79 @@
+use crate::api::response::{error, ok_id};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::RestoreGroupError;
+use crate::domain::usecases::group::{GroupUseCase, RestoreGroupRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+
+/// Recreates a group from a document exported by `/groups/{group_id}/backup`.
+///
+/// The administrator restoring the group should be its only member, the other members joining
+/// the restored group again through an invitation.
+/// The group, its expenses and settlements get new ids, the history being kept as is
+/// without notifying the members again.
+///
+/// This action can only be performed by the administrator of the backed up group.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d @backup.json -b cookie "http://localhost:8000/groups/restore"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/groups/restore",
+    request_body(content = Object, description = "Backup document", content_type = "application/json"),
+    responses(
+        (status = 201, description = "Group restored. Returns the ID of the group created.", body = IdResponse),
+        (status = 400, description = "Invalid or unsupported document", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed or the backup has other members", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Restore group",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn restore_group<Store: MultiRepository>(
+    payload: web::Json<serde_json::Value>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, RestoreGroupError> {
+    let data = RestoreGroupRequest {
+        user_id: user_id.into_inner().0,
+        document: payload.into_inner(),
+    };
+    let group_id = app.groups().restore_group(data).await?;
+    Ok(HttpResponse::Created().json(ok_id(group_id)))
+}
+
+impl ResponseError for RestoreGroupError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            RestoreGroupError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            RestoreGroupError::Unauthorized(msg) => (StatusCode::FORBIDDEN, *msg),
+            RestoreGroupError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            RestoreGroupError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::api::response::{error, ok_message};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::RevertSettlementError;
+use crate::domain::usecases::group::{GroupUseCase, RevertSettlementRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use uuid::Uuid;
+
+/// Reverts the most recent settlement of a group.
+///
+/// The settled expenses become unsettled again and the settlement is deleted.
+/// This action can only be performed by the group administrator, within the configured
+/// revert window, and as long as none of the settlement transactions was paid.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID ith the group UUID and SETTLEMENT_ID with the settlement Uuid):
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/groups/GROUP_ID/settlements/SETTLEMENT_ID"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/groups/{group_id}/settlements/{settlement_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+        ("settlement_id" = Uuid, Path, description = "Settlement Uuid"),
+    ),
+    responses(
+        (status = 204, description = "Settlement reverted successfully.", body = MessageResponse),
+        (status = 400, description = "Invalid settlement or group id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group or settlement not found", body = ErrorResponse),
+        (status = 409, description = "Settlement cannot be reverted", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Settlements",
+))]
+#[tracing::instrument(
+    name = "Revert settlement",
+    skip(path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+        settlement_id = tracing::field::Empty,
+    )
+)]
+pub async fn revert_settlement<Store: MultiRepository>(
+    path_param: Option<web::Path<(Uuid, Uuid)>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, RevertSettlementError> {
+    match path_param {
+        Some(path_param) => {
+            let (group_id, settlement_id) = path_param.into_inner();
+            let user_id = *user_id.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(&group_id));
+            tracing::Span::current()
+                .record("settlement_id", tracing::field::display(&settlement_id));
+            let data = RevertSettlementRequest {
+                group_id,
+                settlement_id,
+                user_id,
+            };
+            app.groups().revert_settlement(data).await?;
+            Ok(HttpResponse::NoContent().json(ok_message("Settlement reverted.")))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group or Settlement id are invalid."))),
+    }
+}
+
+impl ResponseError for RevertSettlementError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            RevertSettlementError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            RevertSettlementError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            RevertSettlementError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            RevertSettlementError::Conflict(msg) => (StatusCode::CONFLICT, *msg),
+            RevertSettlementError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ApiTokenError;
+use crate::domain::usecases::user::{RevokeApiTokenRequest, UserUseCase};
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Revokes a personal access token of the user making the request, which is rejected from
+/// then on.
+///
+/// Requires the auth cookie from `/login` to be attached to the request: tokens cannot be used
+/// to manage tokens.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/me/tokens/ba5a6e9c-3e4b-4c45-9f6b-0d7b4e8b2a41"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/me/tokens/{token_id}",
+    params(
+        ("token_id" = Uuid, Path, description = "Id of the token to revoke"),
+    ),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Token not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Revoke API token",
+    skip(app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn revoke_api_token<Store: MultiRepository>(
+    token_id: web::Path<Uuid>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ApiTokenError> {
+    app.users()
+        .revoke_api_token(RevokeApiTokenRequest {
+            user_id: *user_id.into_inner(),
+            token_id: token_id.into_inner(),
+        })
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,108 @@
+use crate::api::routes::middleware::user_session::{
+    SessionError, SessionInfo, UserId, UserSession,
+};
+use crate::infrastructure::session_store::SessionStoreImpl;
+use actix_session::storage::SessionStore;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Logs out a session of the user making the request, e.g. on a lost device.
+///
+/// Requires the auth cookie from `/login` or a token to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/me/sessions/6f1b1f5e-3c1a-4b8e-9d0c-2a7c5e4f8b13"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/me/sessions/{session_id}",
+    params(
+        ("session_id" = Uuid, Path, description = "Id of the session to revoke"),
+    ),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Token is only allowed to read", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Revoke session",
+    skip(sessions, session, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn revoke_session(
+    session_id: web::Path<Uuid>,
+    sessions: web::Data<SessionStoreImpl>,
+    session: UserSession,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, SessionError> {
+    let session_id = session_id.into_inner();
+    let (key, _) = sessions
+        .user_sessions(&user_id.into_inner())
+        .await?
+        .into_iter()
+        .find(|(_, state)| {
+            SessionInfo::from_state(state, None).map_or(false, |info| info.id == session_id)
+        })
+        .ok_or(SessionError::NotFound())?;
+    sessions.delete(&key).await?;
+    if session.get_session_id().ok().flatten() == Some(session_id) {
+        session.purge();
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Logs out every session of the user making the request, the current one included.
+///
+/// Requires the auth cookie from `/login` or a token to be attached to the request. Personal
+/// access tokens are not revoked.
+///
+/// Example:
+/// ```
+/// curl -i -b cookie -X DELETE "http://localhost:8000/me/sessions"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    delete,
+    path = "/me/sessions",
+    responses(
+        (status = 204, description = "Sessions revoked"),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "Token is only allowed to read", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Revoke all sessions",
+    skip(sessions, session, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn revoke_sessions(
+    sessions: web::Data<SessionStoreImpl>,
+    session: UserSession,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, SessionError> {
+    for (key, _) in sessions.user_sessions(&user_id.into_inner()).await? {
+        sessions.delete(&key).await?;
+    }
+    session.purge();
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,110 @@
+use crate::api::response::{error, ok};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::SetBudgetError;
+use crate::domain::usecases::group::{GroupUseCase, SetBudgetRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Sets the spending budget of a group, replacing the previous one if any.
+///
+/// The budget either covers a calendar month (`monthly`) or the expenses since
+/// the last settlement (`settlement`). Members are notified when the spending of
+/// the period reaches 80% and 100% of the budget.
+///
+/// This action can only be performed by the group administrator.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID):
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"amount": 500, "period": "monthly"}' -b cookie -X PUT "http://localhost:8000/groups/GROUP_ID/budget"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/groups/{group_id}/budget",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    request_body = SetBudgetPayload,
+    responses(
+        (status = 200, description = "Budget set, with its current consumption", body = GetBudgetResponse),
+        (status = 400, description = "Invalid payload or group id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Set budget",
+    skip(payload, path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn set_budget<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    payload: web::Json<SetBudgetPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, SetBudgetError> {
+    match path_param {
+        Some(group_id) => {
+            let group_id = group_id.into_inner();
+            let user_id = user_id.into_inner().0;
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            let data = SetBudgetRequest {
+                group_id,
+                user_id,
+                amount: payload.0.amount,
+                period: payload.0.period,
+            };
+            let budget = app.groups().set_budget(data).await?;
+
+            Ok(HttpResponse::Ok().json(ok(budget)))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SetBudgetPayload {
+    amount: f32,
+    /// Either `monthly` or `settlement`.
+    period: String,
+}
+
+impl ResponseError for SetBudgetError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            SetBudgetError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            SetBudgetError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            SetBudgetError::Unauthorized(_) => (
+                StatusCode::FORBIDDEN,
+                "You are not authorized to perform this action.",
+            ),
+            SetBudgetError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            SetBudgetError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -8,17 +8,20 @@ use crate::domain::usecases::group::SettleRequest;
 use actix_web::body::BoxBody;
 use actix_web::http::StatusCode;
 use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Settles the current expenses and returns the settlement summary.
 ///
+/// An optional label (e.g. "Rome trip") can be given to the settlement.
 /// This action can only be performed by the group administrator.
 ///
 /// Requires the auth cookie from `/login` to be attached to the request.
 ///
 /// Example (replace GROUP_ID ith the group UUID):
 /// ```
-/// curl -i -H 'Content-Type: application/json' -b cookie -X POST "http://localhost:8000/groups/GROUP_ID/settlements"
+/// curl -i -H 'Content-Type: application/json' -d '{"label":"Rome trip"}' -b cookie -X POST "http://localhost:8000/groups/GROUP_ID/settlements"
 /// ```
 ///
 #[cfg_attr(feature = "openapi", utoipa::path(
@@ -27,28 +30,31 @@ use uuid::Uuid;
     params(
         ("group_id" = Uuid, Path, description = "Group Uuid"),
     ),
+    request_body(content = Option<SettlePayload>),
     responses(
         (status = 201, description = "Settlement computed", body = SettlementResponse),
-        (status = 400, description = "Invalid group id", body = ErrorResponse),
+        (status = 400, description = "Invalid payload, label or group id", body = ErrorResponse),
         (status = 401, description = "User is not logged in", body = ErrorResponse),
         (status = 403, description = "User is not allowed", body = ErrorResponse),
         (status = 404, description = "Group not found", body = ErrorResponse),
         (status = 500, description = "Unexpected server error", body = ErrorResponse),
     ),
     security(
-        ("cookieAuth" = [])
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
     ),
     tag = "Settlements",
 ))]
 #[tracing::instrument(
     name = "Settle",
-    skip(path_param, app, user_id),
+    skip(body, path_param, app, user_id),
     fields(
         user_id = %user_id.0,
         group_id = tracing::field::Empty,
     )
 )]
 pub async fn settle<Store: MultiRepository>(
+    body: web::Bytes,
     path_param: Option<web::Path<Uuid>>,
     app: web::Data<Application<Store>>,
     user_id: web::ReqData<UserId>,
@@ -58,7 +64,22 @@ pub async fn settle<Store: MultiRepository>(
             let group_id = group_id.into_inner();
             let user_id = *user_id.into_inner();
             tracing::Span::current().record("group_id", &tracing::field::display(&group_id));
-            let data = SettleRequest { group_id, user_id };
+            // The payload is optional, but a body that is sent must be a valid one.
+            let payload = if body.iter().all(u8::is_ascii_whitespace) {
+                None
+            } else {
+                match serde_json::from_slice::<SettlePayload>(&body) {
+                    Ok(payload) => Some(payload),
+                    Err(_) => {
+                        return Ok(HttpResponse::BadRequest().json(error("Payload is invalid.")))
+                    }
+                }
+            };
+            let data = SettleRequest {
+                group_id,
+                user_id,
+                label: payload.and_then(|p| p.label),
+            };
             let settlement = app.groups().settle(data).await?;
             Ok(HttpResponse::Created().json(&ok(settlement)))
         }
@@ -66,6 +87,12 @@ pub async fn settle<Store: MultiRepository>(
     }
 }
 
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SettlePayload {
+    label: Option<String>,
+}
+
 impl ResponseError for SettlementError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         let (code, msg) = match self {
@@ -73,6 +100,7 @@ impl ResponseError for SettlementError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Unexpected server error.",
             ),
+            SettlementError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
             SettlementError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
             SettlementError::Unauthorized(_) => (
                 StatusCode::FORBIDDEN,
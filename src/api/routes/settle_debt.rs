@@ -0,0 +1,86 @@
+use crate::api::response::{error, ok_message};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::SettleDebtError;
+use crate::domain::usecases::group::{GroupUseCase, SettleDebtRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use uuid::Uuid;
+
+/// Settles the debts between the user making the request and another user.
+///
+/// All the outstanding settlement transactions between the two users, across all the
+/// groups they share, are marked as paid at once.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace USER_ID with the other user UUID):
+/// ```
+/// curl -i -b cookie -X POST "http://localhost:8000/me/debts/USER_ID/settle"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/me/debts/{user_id}/settle",
+    params(
+        ("user_id" = Uuid, Path, description = "Uuid of the other user"),
+    ),
+    responses(
+        (status = 200, description = "Debts settled successfully.", body = MessageResponse),
+        (status = 400, description = "Invalid user id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 404, description = "User not found or nothing to settle", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Settlements",
+))]
+#[tracing::instrument(
+    name = "Settle debt",
+    skip(path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        other_id = tracing::field::Empty,
+    )
+)]
+pub async fn settle_debt<Store: MultiRepository>(
+    path_param: Option<web::Path<Uuid>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, SettleDebtError> {
+    match path_param {
+        Some(other_id) => {
+            let other_id = other_id.into_inner();
+            tracing::Span::current().record("other_id", tracing::field::display(other_id));
+            let data = SettleDebtRequest {
+                user_id: *user_id.into_inner(),
+                other_id,
+            };
+            app.groups().settle_debt(data).await?;
+            Ok(HttpResponse::Ok().json(ok_message("Debts settled.")))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("User id is invalid."))),
+    }
+}
+
+impl ResponseError for SettleDebtError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            SettleDebtError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            SettleDebtError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            SettleDebtError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            SettleDebtError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
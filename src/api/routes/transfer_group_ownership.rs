@@ -0,0 +1,70 @@
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ManageGroupError;
+use crate::domain::usecases::admin::AdminUseCase;
+use actix_web::{web, HttpResponse};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Makes another member the administrator of any group, only available if user is ADMIN
+///
+/// The current administrator of the group stays a member.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID and USER_ID with the new admin UUID):
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"user_id":"USER_ID"}' -b cookie -X PUT "http://localhost:8000/admin/groups/GROUP_ID/admin"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/admin/groups/{group_id}/admin",
+    request_body = TransferGroupOwnershipPayload,
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    responses(
+        (status = 204, description = "Ownership transferred"),
+        (status = 400, description = "Member is already the admin", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not Admin, or request was authenticated by a token", body = ErrorResponse),
+        (status = 404, description = "Group or member not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = [])
+    ),
+    tag = "Admin",
+))]
+#[tracing::instrument(
+    name = "Transfer group ownership as admin",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn transfer_group_ownership<Store: MultiRepository>(
+    group_id: web::Path<Uuid>,
+    payload: web::Json<TransferGroupOwnershipPayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, ManageGroupError> {
+    app.admin()
+        .transfer_group_ownership(
+            &user_id.into_inner(),
+            &group_id.into_inner(),
+            &payload.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TransferGroupOwnershipPayload {
+    /// Id of the member to make administrator.
+    user_id: Uuid,
+}
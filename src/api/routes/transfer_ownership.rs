@@ -0,0 +1,100 @@
+use crate::api::response::{error, ok_message};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TransferOwnershipError;
+use crate::domain::usecases::group::{GroupUseCase, TransferOwnershipRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Makes another member the administrator of the group, the current administrator staying
+/// a member.
+///
+/// This action can only be performed by the group administrator.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example (replace GROUP_ID with the group UUID and USER_ID with the new admin UUID):
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"user_id":"USER_ID"}' -b cookie -X PUT "http://localhost:8000/groups/GROUP_ID/admin"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/groups/{group_id}/admin",
+    params(
+        ("group_id" = Uuid, Path, description = "Group Uuid"),
+    ),
+    request_body = TransferOwnershipPayload,
+    responses(
+        (status = 200, description = "Ownership transferred successfully", body = MessageResponse),
+        (status = 400, description = "Invalid payload or group id", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 403, description = "User is not allowed", body = ErrorResponse),
+        (status = 404, description = "Group or member not found", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Groups",
+))]
+#[tracing::instrument(
+    name = "Transfer group ownership",
+    skip(payload, path_param, app, user_id),
+    fields(
+        user_id = %user_id.0,
+        group_id = tracing::field::Empty,
+    )
+)]
+pub async fn transfer_ownership<Store: MultiRepository>(
+    payload: web::Json<TransferOwnershipPayload>,
+    path_param: Option<web::Path<Uuid>>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, TransferOwnershipError> {
+    match path_param {
+        Some(path_param) => {
+            let group_id = path_param.into_inner();
+            tracing::Span::current().record("group_id", tracing::field::display(group_id));
+            let data = TransferOwnershipRequest {
+                group_id,
+                user_id: *user_id.into_inner(),
+                new_admin_id: payload.user_id,
+            };
+            app.groups().transfer_ownership(data).await?;
+            Ok(HttpResponse::Ok().json(ok_message("Ownership transferred.")))
+        }
+        None => Ok(HttpResponse::BadRequest().json(error("Group id is invalid."))),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TransferOwnershipPayload {
+    /// Id of the member to make administrator.
+    user_id: Uuid,
+}
+
+impl ResponseError for TransferOwnershipError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            TransferOwnershipError::NotFound(msg) => (StatusCode::NOT_FOUND, *msg),
+            TransferOwnershipError::Unauthorized(msg) => (StatusCode::FORBIDDEN, *msg),
+            TransferOwnershipError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            TransferOwnershipError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            TransferOwnershipError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
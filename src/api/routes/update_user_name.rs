@@ -0,0 +1,84 @@
+use crate::api::response::{error, ok_message};
+use crate::api::routes::middleware::user_session::UserId;
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::UpdateUserError;
+use crate::domain::usecases::user::{UpdateNameRequest, UserUseCase};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Changes the name of the user making the request, as shown to the other members of their
+/// groups.
+///
+/// Requires the auth cookie from `/login` to be attached to the request.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"name":"Bob"}' -b cookie -X PUT "http://localhost:8000/me/name"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/me/name",
+    request_body = UpdateNamePayload,
+    responses(
+        (status = 200, description = "Name changed successfully", body = MessageResponse),
+        (status = 400, description = "Invalid name", body = ErrorResponse),
+        (status = 401, description = "User is not logged in", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    security(
+        ("cookieAuth" = []),
+        ("bearerAuth" = [])
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(
+    name = "Update user name",
+    skip(payload, app, user_id),
+    fields(
+        user_id = %user_id.0,
+    )
+)]
+pub async fn update_user_name<Store: MultiRepository>(
+    payload: web::Json<UpdateNamePayload>,
+    app: web::Data<Application<Store>>,
+    user_id: web::ReqData<UserId>,
+) -> Result<HttpResponse, UpdateUserError> {
+    app.users()
+        .update_name(UpdateNameRequest {
+            user_id: *user_id.into_inner(),
+            name: payload.0.name,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(ok_message("Name changed.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct UpdateNamePayload {
+    name: String,
+}
+
+impl ResponseError for UpdateUserError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            UpdateUserError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            UpdateUserError::InvalidCredentials() => {
+                (StatusCode::FORBIDDEN, "Current password is invalid.")
+            }
+            UpdateUserError::Conflict() => (StatusCode::CONFLICT, "Email already in use."),
+            UpdateUserError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+            UpdateUserError::Unauthenticated() => {
+                (StatusCode::UNAUTHORIZED, "You are not logged in.")
+            }
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::api::response::{error, ok_message};
+use crate::application::app::Application;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::EmailVerificationError;
+use crate::domain::usecases::user::{UserUseCase, VerifyEmailRequest};
+use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpResponse, ResponseError};
+use secrecy::Secret;
+#[cfg(feature = "openapi")]
+use utoipa::ToSchema;
+
+/// Verifies the email of a new user with the token of the link mailed on registration, after
+/// which the user can log in.
+///
+/// Example:
+/// ```
+/// curl -i -H 'Content-Type: application/json' -d '{"token":"8c1f..."}' "http://localhost:8000/verification"
+/// ```
+///
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/verification",
+    request_body = VerifyEmailPayload,
+    responses(
+        (status = 200, description = "Email verified successfully", body = MessageResponse),
+        (status = 400, description = "Invalid or expired token, or verification disabled", body = ErrorResponse),
+        (status = 500, description = "Unexpected server error", body = ErrorResponse),
+    ),
+    tag = "Authentication",
+))]
+#[tracing::instrument(name = "Verify email", skip(payload, app))]
+pub async fn verify_email<Store: MultiRepository>(
+    payload: web::Json<VerifyEmailPayload>,
+    app: web::Data<Application<Store>>,
+) -> Result<HttpResponse, EmailVerificationError> {
+    app.users()
+        .verify_email(VerifyEmailRequest {
+            token: payload.0.token,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(ok_message("Email verified.")))
+}
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct VerifyEmailPayload {
+    #[cfg_attr(feature = "openapi", schema(value_type=String))]
+    token: Secret<String>,
+}
+
+impl ResponseError for EmailVerificationError {
+    fn error_response(&self) -> HttpResponse<BoxBody> {
+        let (code, msg) = match self {
+            EmailVerificationError::Validation(msg) => (StatusCode::BAD_REQUEST, *msg),
+            EmailVerificationError::InvalidToken() => (
+                StatusCode::BAD_REQUEST,
+                "The verification link is invalid or expired.",
+            ),
+            EmailVerificationError::Unexpected(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unexpected server error.",
+            ),
+        };
+        HttpResponse::build(code).json(error(msg))
+    }
+}
@@ -1,22 +1,50 @@
 use crate::application::event_bus::EventBus;
+use crate::application::group::GroupUsecase;
 use crate::application::store::MultiRepository;
-use crate::domain::errors::{GetAllGroupsError, GetUsersError};
-use crate::domain::usecases::admin::AdminUseCase;
-use crate::domain::usecases::dto::dtos::{DetailedUserDto, GroupDto};
-use crate::domain::User;
-use anyhow::Context;
+use crate::application::user::UserUsecase;
+use crate::domain::errors::{
+    CreateUserError, DeleteGroupError, DeleteUserError, ExportError, GetAllGroupsError,
+    GetAuditLogError, GetUsersError, ManageGroupError, ManageUserError, PasswordResetError,
+    RestoreGroupError, TransferOwnershipError, TwoFactorError,
+};
+use crate::domain::usecases::admin::{AdminUseCase, AuditLogRequest};
+use crate::domain::usecases::dto::dtos::{
+    AuditEventDto, AuditLogDto, DetailedGroupDto, DetailedUserDto, GroupDto, GroupEventDto,
+    ModeratedGroupDto, SettlementDto, StoredEventDto,
+};
+use crate::domain::usecases::group::{BackupGroupRequest, GroupUseCase, RestoreGroupRequest};
+use crate::domain::usecases::user::{RegistrationRequest, UserUseCase};
+use crate::domain::{
+    Email, Event, Group, GroupActor, GroupBackup, User, UserEvent, UserEventKind, UserRole,
+};
+use anyhow::{anyhow, Context};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use log::warn;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct AdminUsecase<Store: MultiRepository> {
     store: Arc<Store>,
-    _event_bus: Arc<dyn EventBus>,
+    event_bus: Arc<dyn EventBus>,
+    user_uc: Arc<UserUsecase<Store>>,
+    group_uc: Arc<GroupUsecase<Store>>,
 }
 
 impl<Store: MultiRepository> AdminUsecase<Store> {
-    pub fn new(store: Arc<Store>, _event_bus: Arc<dyn EventBus>) -> Self {
-        Self { store, _event_bus }
+    pub fn new(
+        store: Arc<Store>,
+        event_bus: Arc<dyn EventBus>,
+        user_uc: Arc<UserUsecase<Store>>,
+        group_uc: Arc<GroupUsecase<Store>>,
+    ) -> Self {
+        Self {
+            store,
+            event_bus,
+            user_uc,
+            group_uc,
+        }
     }
 
     async fn is_admin(&self, requester: &Uuid) -> Result<User, GetUsersError> {
@@ -33,6 +61,237 @@ impl<Store: MultiRepository> AdminUsecase<Store> {
             Err(GetUsersError::Unauthorized())
         }
     }
+
+    /// Returns the requesting administrator and the user they manage, who cannot be themselves.
+    async fn managed_user(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(User, User), ManageUserError> {
+        let admin = self.is_admin(requester).await.map_err(|e| match e {
+            GetUsersError::Unauthenticated() => ManageUserError::Unauthenticated(),
+            GetUsersError::Unauthorized() => ManageUserError::Unauthorized(),
+            GetUsersError::Unexpected(a) => ManageUserError::Unexpected(a),
+        })?;
+        if admin.id == *user_id {
+            return Err(ManageUserError::Conflict(
+                "Administrators cannot manage their own account.",
+            ));
+        }
+        let user = self
+            .store
+            .users()
+            .find(user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(ManageUserError::NotFound("User not found."))?;
+        Ok((admin, user))
+    }
+
+    /// Returns the requesting administrator, acting on the group, and the group they moderate.
+    async fn moderated_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(GroupActor, Group), ManageGroupError> {
+        let admin = self.is_admin(requester).await.map_err(|e| match e {
+            GetUsersError::Unauthenticated() => ManageGroupError::Unauthenticated(),
+            GetUsersError::Unauthorized() => ManageGroupError::Unauthorized(),
+            GetUsersError::Unexpected(a) => ManageGroupError::Unexpected(a),
+        })?;
+        let group = self
+            .store
+            .groups()
+            .find(group_id)
+            .await
+            .context("Failed to fetch group")?
+            .ok_or(ManageGroupError::NotFound("Group not found."))?;
+        Ok((GroupActor::Administrator(admin.id), group))
+    }
+
+    /// Saves the user along with the events, if any.
+    async fn save(
+        &self,
+        user: &User,
+        events: impl IntoIterator<Item = UserEventKind>,
+    ) -> Result<(), anyhow::Error> {
+        let events = events
+            .into_iter()
+            .map(|e| Event::User(UserEvent::new(user.id, e)))
+            .collect_vec();
+        if events.is_empty() {
+            return Ok(());
+        }
+        let mut tx = self.store.tx().await?;
+        self.store
+            .users()
+            .save(&mut tx, user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.event_bus
+            .publish(&events.iter().map(|e| e.id()).collect_vec())
+            .await
+            .context("Failed to notify event bus.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+        Ok(())
+    }
+}
+
+/// Recorded as the author of the changes made by the operators through `koru-admin`.
+pub const OPERATOR_ID: Uuid = Uuid::nil();
+
+const AUDIT_PAGE_SIZE: u32 = 50;
+const MAX_AUDIT_PAGE_SIZE: u32 = 200;
+
+/// Actions of the operators running `koru-admin`, who are trusted without being administrators
+/// of the application.
+impl<Store: MultiRepository> AdminUsecase<Store> {
+    /// Registers an administrator, whose email is considered verified.
+    pub async fn create_administrator(
+        &self,
+        request: RegistrationRequest,
+    ) -> Result<Uuid, CreateUserError> {
+        let user_id = self.user_uc.register(request).await?;
+        let mut user = self
+            .store
+            .users()
+            .find(&user_id)
+            .await
+            .context("Failed to fetch user")?
+            .ok_or_else(|| anyhow!("Registered user not found."))?;
+        let events = [
+            user.verify_email(),
+            user.change_role(UserRole::Administrator, OPERATOR_ID),
+        ];
+        self.save(&user, events.into_iter().flatten()).await?;
+        Ok(user_id)
+    }
+
+    /// Makes the user with this email an administrator.
+    pub async fn promote(&self, email: &str) -> Result<Uuid, ManageUserError> {
+        let email = Email::try_from(email.to_string()).map_err(ManageUserError::Validation)?;
+        let mut user = self
+            .store
+            .users()
+            .find_by_email(&email)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(ManageUserError::NotFound("User not found."))?;
+        let event = user.change_role(UserRole::Administrator, OPERATOR_ID);
+        self.save(&user, event).await?;
+        Ok(user.id)
+    }
+
+    /// Lists the most recent events, newest first.
+    pub async fn list_events(
+        &self,
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<StoredEventDto>, anyhow::Error> {
+        let events = self
+            .store
+            .events()
+            .get_events(unprocessed_only, limit)
+            .await
+            .context("Failed to fetch events")?;
+        Ok(events
+            .iter()
+            .map(|e| StoredEventDto::from(&e.event, e.processed_at))
+            .collect())
+    }
+
+    /// Deletes the events processed before the date, returning how many were deleted.
+    pub async fn purge_events(
+        &self,
+        processed_before: &DateTime<Utc>,
+    ) -> Result<u64, anyhow::Error> {
+        self.store
+            .events()
+            .purge(processed_before)
+            .await
+            .context("Failed to purge events")
+    }
+
+    /// Publishes again the events the worker has not processed, oldest first, for instance after
+    /// the event bus was unavailable.
+    pub async fn republish_events(&self) -> Result<Vec<Uuid>, anyhow::Error> {
+        let ids = self
+            .store
+            .events()
+            .get_events(true, None)
+            .await
+            .context("Failed to fetch events")?
+            .iter()
+            .rev()
+            .map(|e| e.event.id())
+            .collect_vec();
+        if !ids.is_empty() {
+            self.event_bus
+                .publish(&ids)
+                .await
+                .context("Failed to notify event bus.")?;
+        }
+        Ok(ids)
+    }
+
+    /// Backs up any group, as its administrator would.
+    pub async fn export_group(&self, group_id: &Uuid) -> Result<GroupBackup, ExportError> {
+        let group = self
+            .store
+            .groups()
+            .find(group_id)
+            .await
+            .context("Failed to fetch group")?
+            .ok_or(ExportError::NotFound("Group not found."))?;
+        self.group_uc
+            .backup_group(BackupGroupRequest {
+                group_id: group.id,
+                user_id: group.admin_id,
+            })
+            .await
+    }
+
+    /// Restores a group backup, as the administrator of the backed up group would.
+    pub async fn import_group(
+        &self,
+        document: serde_json::Value,
+    ) -> Result<Uuid, RestoreGroupError> {
+        let backup = GroupBackup::read(document.clone()).map_err(RestoreGroupError::Validation)?;
+        let admin =
+            backup
+                .members
+                .iter()
+                .find(|m| m.is_admin)
+                .ok_or(RestoreGroupError::Validation(
+                    "The backup has no administrator.",
+                ))?;
+        let email = Email::try_from(admin.email.clone()).map_err(RestoreGroupError::Validation)?;
+        let admin = self
+            .store
+            .users()
+            .find_by_email(&email)
+            .await
+            .context("Failed to fetch user")?
+            .ok_or(RestoreGroupError::Validation(
+                "The administrator of the group has no account.",
+            ))?;
+        self.group_uc
+            .restore_group(RestoreGroupRequest {
+                user_id: admin.id,
+                document,
+            })
+            .await
+    }
 }
 
 #[async_trait(?Send)]
@@ -62,14 +321,313 @@ impl<Store: MultiRepository> AdminUseCase for AdminUsecase<Store> {
             .context("Failed to fetch groups")?;
         Ok(groups.into_iter().map(GroupDto::from).collect())
     }
+
+    async fn reset_two_factor(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(), TwoFactorError> {
+        let admin = self.is_admin(requester).await.map_err(|e| match e {
+            GetUsersError::Unauthenticated() => TwoFactorError::Unauthenticated(),
+            GetUsersError::Unauthorized() => TwoFactorError::Unauthorized(),
+            GetUsersError::Unexpected(a) => TwoFactorError::Unexpected(a),
+        })?;
+        let user = self
+            .store
+            .users()
+            .find(user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(TwoFactorError::NotFound("User not found."))?;
+        self.store
+            .two_factors()
+            .find(&user.email)
+            .await
+            .context("Failed to fetch second factor")?
+            .ok_or(TwoFactorError::NotFound(
+                "Two-factor authentication is not enabled for the user.",
+            ))?;
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::TwoFactorReset { by: admin.id },
+        ))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .two_factors()
+            .delete(&mut tx, &user.email)
+            .await
+            .context("Failed to delete second factor")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.event_bus
+            .publish(&events.iter().map(|e| e.id()).collect_vec())
+            .await
+            .context("Failed to notify event bus.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+        Ok(())
+    }
+
+    async fn change_role(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+        role: &str,
+    ) -> Result<(), ManageUserError> {
+        let role = UserRole::try_from(role).map_err(ManageUserError::Validation)?;
+        let (admin, mut user) = self.managed_user(requester, user_id).await?;
+        let event = user.change_role(role, admin.id);
+        self.save(&user, event).await?;
+        Ok(())
+    }
+
+    async fn disable_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError> {
+        let (admin, mut user) = self.managed_user(requester, user_id).await?;
+        let event = user.disable(admin.id);
+        self.save(&user, event).await?;
+        Ok(())
+    }
+
+    async fn enable_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError> {
+        let (admin, mut user) = self.managed_user(requester, user_id).await?;
+        let event = user.enable(admin.id);
+        self.save(&user, event).await?;
+        Ok(())
+    }
+
+    async fn force_password_reset(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(), ManageUserError> {
+        let (admin, user) = self.managed_user(requester, user_id).await?;
+        self.user_uc
+            .require_password_reset(user, admin.id)
+            .await
+            .map_err(|e| match e {
+                PasswordResetError::Validation(msg) => ManageUserError::Validation(msg),
+                e => ManageUserError::Unexpected(anyhow!(e)),
+            })
+    }
+
+    async fn delete_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError> {
+        let (admin, user) = self.managed_user(requester, user_id).await?;
+        self.user_uc
+            .remove(user, UserEventKind::DeletedByAdmin { by: admin.id })
+            .await
+            .map_err(|e| match e {
+                DeleteUserError::Conflict(msg) => ManageUserError::Conflict(msg),
+                e => ManageUserError::Unexpected(anyhow!(e)),
+            })
+    }
+
+    async fn get_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<ModeratedGroupDto, ManageGroupError> {
+        let (_, group) = self.moderated_group(requester, group_id).await?;
+        let expenses = self
+            .store
+            .expenses()
+            .get_expenses(group_id, None, None)
+            .await
+            .context("Failed to fetch expenses")?;
+        let settlements = self
+            .store
+            .settlements()
+            .get_settlements(group_id)
+            .await
+            .context("Failed to fetch settlements")?
+            .into_iter()
+            .map(|s| SettlementDto::from(s, &group.members))
+            .sorted_by(|a, b| b.end_date.cmp(&a.end_date))
+            .collect();
+        let events = self
+            .store
+            .events()
+            .get_group_events(group_id)
+            .await
+            .context("Failed to fetch events")?
+            .into_iter()
+            .map(GroupEventDto::from)
+            .collect();
+        Ok(ModeratedGroupDto {
+            group: DetailedGroupDto::from(group, expenses),
+            settlements,
+            events,
+        })
+    }
+
+    async fn transfer_group_ownership(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+        new_admin_id: &Uuid,
+    ) -> Result<(), ManageGroupError> {
+        let (actor, mut group) = self.moderated_group(requester, group_id).await?;
+        group
+            .transfer_ownership(actor, *new_admin_id)
+            .map_err(|e| match e {
+                TransferOwnershipError::NotFound(msg) => ManageGroupError::NotFound(msg),
+                TransferOwnershipError::Validation(msg) => ManageGroupError::Validation(msg),
+                e => ManageGroupError::Unexpected(anyhow!(e)),
+            })?;
+        self.group_uc.finalize(&group).await?;
+        Ok(())
+    }
+
+    async fn archive_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(), ManageGroupError> {
+        let (actor, mut group) = self.moderated_group(requester, group_id).await?;
+        group.archive(actor)?;
+        self.group_uc.finalize(&group).await?;
+        Ok(())
+    }
+
+    async fn unarchive_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(), ManageGroupError> {
+        let (actor, mut group) = self.moderated_group(requester, group_id).await?;
+        group.unarchive(actor)?;
+        self.group_uc.finalize(&group).await?;
+        Ok(())
+    }
+
+    async fn delete_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(), ManageGroupError> {
+        let (actor, mut group) = self.moderated_group(requester, group_id).await?;
+        group.delete(actor).map_err(|e| match e {
+            DeleteGroupError::Unexpected(a) => ManageGroupError::Unexpected(a),
+            e => ManageGroupError::Unexpected(anyhow!(e)),
+        })?;
+        self.group_uc.finalize(&group).await?;
+        Ok(())
+    }
+
+    async fn remove_member(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), ManageGroupError> {
+        let (actor, mut group) = self.moderated_group(requester, group_id).await?;
+        let expenses = self
+            .store
+            .expenses()
+            .get_expenses(group_id, None, None)
+            .await
+            .context("Failed to fetch expenses")?;
+        let settlements = self
+            .store
+            .settlements()
+            .get_settlements(group_id)
+            .await
+            .context("Failed to fetch settlements")?;
+        group.remove_member(actor, *member_id, &expenses, &settlements)?;
+        self.group_uc.finalize(&group).await?;
+        Ok(())
+    }
+
+    async fn get_audit_log(
+        &self,
+        requester: &Uuid,
+        request: AuditLogRequest,
+    ) -> Result<AuditLogDto, GetAuditLogError> {
+        self.is_admin(requester).await.map_err(|e| match e {
+            GetUsersError::Unauthenticated() => GetAuditLogError::Unauthenticated(),
+            GetUsersError::Unauthorized() => GetAuditLogError::Unauthorized(),
+            GetUsersError::Unexpected(a) => GetAuditLogError::Unexpected(a),
+        })?;
+        let limit = match request.limit {
+            None => AUDIT_PAGE_SIZE,
+            Some(limit) if (1..=MAX_AUDIT_PAGE_SIZE).contains(&limit) => limit,
+            Some(_) => {
+                return Err(GetAuditLogError::Validation(
+                    "The limit should be between 1 and 200.",
+                ))
+            }
+        };
+        let before = match request.before {
+            None => None,
+            Some(id) => {
+                let event = self
+                    .store
+                    .events()
+                    .find(&id)
+                    .await
+                    .context("Failed to fetch event")?
+                    .ok_or(GetAuditLogError::Validation("Unknown cursor."))?;
+                Some((event.date(), id))
+            }
+        };
+        // one more event than the page holds tells whether there is a next page
+        let mut events = self
+            .store
+            .events()
+            .get_event_page(&request.filter, before, limit + 1)
+            .await
+            .context("Failed to fetch events")?;
+        let next = if events.len() > limit as usize {
+            events.truncate(limit as usize);
+            events.last().map(|e| e.event.id())
+        } else {
+            None
+        };
+        Ok(AuditLogDto {
+            events: events
+                .into_iter()
+                .map(|e| AuditEventDto::from(e.event, e.origin))
+                .collect(),
+            next,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::application::admin::OPERATOR_ID;
+    use crate::application::auth::current_code;
+    use crate::application::store::MultiRepository;
     use crate::application::tests::TestContext;
-    use crate::domain::errors::{GetAllGroupsError, GetUsersError};
-    use crate::domain::usecases::admin::AdminUseCase;
-    use claim::{assert_err, assert_matches, assert_ok, assert_some};
+    use crate::domain::errors::{
+        CreateExpenseError, ExportError, GetAllGroupsError, GetAuditLogError, GetUsersError,
+        LoginError, ManageGroupError, ManageUserError, TwoFactorError,
+    };
+    use crate::domain::usecases::admin::{AdminUseCase, AuditLogRequest};
+    use crate::domain::usecases::group::{CreateExpenseRequest, GroupUseCase};
+    use crate::domain::usecases::user::{
+        ConfirmTwoFactorRequest, LoginRequest, RegistrationRequest, UserUseCase,
+    };
+    use crate::domain::{EventFilter, EventOrigin, User, UserRole};
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use chrono::{Duration, Utc};
+    use claim::{assert_err, assert_matches, assert_none, assert_ok, assert_some};
+    use secrecy::Secret;
+    use uuid::Uuid;
+
+    fn login_request(user: &User) -> LoginRequest {
+        LoginRequest {
+            email: String::from(user.email.clone()),
+            password: Some(Secret::new(
+                String::from(user.email.clone()).replace("@", "p_"),
+            )),
+        }
+    }
 
     #[tokio::test]
     async fn get_users_should_return_all_users_when_requested_by_admin() -> Result<(), anyhow::Error>
@@ -136,4 +694,778 @@ mod tests {
         assert_matches!(err, GetAllGroupsError::Unauthorized());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn reset_two_factor_should_remove_the_second_factor_when_requested_by_admin() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+        let enrollment = assert_ok!(ctx.user_with_two_factor().enroll_two_factor(&user.id).await);
+        assert_ok!(
+            ctx.user_with_two_factor()
+                .confirm_two_factor(ConfirmTwoFactorRequest {
+                    user_id: user.id,
+                    code: assert_some!(current_code(&enrollment.secret)),
+                })
+                .await
+        );
+
+        let res = ctx.admin().reset_two_factor(&admin.id, &user.id).await;
+
+        assert_ok!(res);
+        assert_none!(assert_ok!(
+            ctx.store().two_factors().find(&user.email).await
+        ));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserTwoFactorReset { by, .. } if by == admin.id);
+        let err = assert_err!(ctx.admin().reset_two_factor(&admin.id, &user.id).await);
+        assert_matches!(err, TwoFactorError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn reset_two_factor_should_return_unauthorized_when_requested_by_a_non_admin() {
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let other = ctx.with_user().await;
+
+        let res = ctx.admin().reset_two_factor(&user.id, &other.id).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, TwoFactorError::Unauthorized());
+    }
+
+    #[tokio::test]
+    async fn change_role_should_promote_the_user_when_requested_by_admin() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+
+        let res = ctx
+            .admin()
+            .change_role(&admin.id, &user.id, "Administrator")
+            .await;
+
+        assert_ok!(res);
+        assert_eq!(ctx.get_user(&user.id).await.role, UserRole::Administrator);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserRoleChanged { role, by, .. } if role == "Administrator" && by == admin.id
+        );
+    }
+
+    #[tokio::test]
+    async fn change_role_should_return_validation_error_given_an_unknown_role() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+
+        let res = ctx.admin().change_role(&admin.id, &user.id, "Owner").await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageUserError::Validation(_));
+    }
+
+    #[tokio::test]
+    async fn change_role_should_return_conflict_when_admin_targets_themselves() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+
+        let res = ctx.admin().change_role(&admin.id, &admin.id, "User").await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageUserError::Conflict(_));
+        assert_eq!(ctx.get_user(&admin.id).await.role, UserRole::Administrator);
+    }
+
+    #[tokio::test]
+    async fn disable_user_should_block_the_login_until_the_user_is_enabled() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+
+        let res = ctx.admin().disable_user(&admin.id, &user.id).await;
+
+        assert_ok!(res);
+        let disabled = ctx.get_user(&user.id).await;
+        assert_some!(disabled.disabled_at);
+        assert_some!(disabled.sessions_revoked_at);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserDisabled { by, .. } if by == admin.id);
+        let err = assert_err!(ctx.user().login(login_request(&user)).await);
+        assert_matches!(err, LoginError::Disabled());
+
+        assert_ok!(ctx.admin().enable_user(&admin.id, &user.id).await);
+
+        assert_none!(ctx.get_user(&user.id).await.disabled_at);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserEnabled { by, .. } if by == admin.id);
+        assert_ok!(ctx.user().login(login_request(&user)).await);
+    }
+
+    #[tokio::test]
+    async fn disable_user_should_return_unauthorized_when_requested_by_a_non_admin() {
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let other = ctx.with_user().await;
+
+        let res = ctx.admin().disable_user(&user.id, &other.id).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageUserError::Unauthorized());
+        assert_none!(ctx.get_user(&other.id).await.disabled_at);
+    }
+
+    #[tokio::test]
+    async fn force_password_reset_should_block_the_login_and_mail_a_reset_link() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+
+        let res = ctx.admin().force_password_reset(&admin.id, &user.id).await;
+
+        assert_ok!(res);
+        let flagged = ctx.get_user(&user.id).await;
+        assert!(flagged.password_reset_required);
+        assert_some!(flagged.sessions_revoked_at);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserPasswordResetForced { by, .. } if by == admin.id);
+        let mails = ctx.sent_mails();
+        assert_eq!(mails.len(), 1);
+        assert_eq!(
+            String::from(mails[0].to.clone()),
+            String::from(user.email.clone())
+        );
+        let err = assert_err!(ctx.user().login(login_request(&user)).await);
+        assert_matches!(err, LoginError::PasswordResetRequired());
+    }
+
+    #[tokio::test]
+    async fn delete_user_should_anonymize_the_user_when_requested_by_admin() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+
+        let res = ctx.admin().delete_user(&admin.id, &user.id).await;
+
+        assert_ok!(res);
+        let deleted = ctx.get_user(&user.id).await;
+        assert!(deleted.is_deleted());
+        assert_none!(ctx.find_credentials(&user.id).await);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserDeletedByAdmin { by, .. } if by == admin.id);
+        let err = assert_err!(ctx.admin().delete_user(&admin.id, &user.id).await);
+        assert_matches!(err, ManageUserError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn get_group_should_return_the_expenses_settlements_and_events_of_any_group() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        let mut expenses = vec![ctx.with_expense(&mut group, group_admin).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_expense(&mut group, group_admin).await;
+
+        let res = ctx.admin().get_group(&admin.id, &group.id).await;
+
+        let moderated = assert_ok!(res);
+        assert_eq!(moderated.group.id, group.id);
+        assert_eq!(moderated.group.expenses.len(), 2);
+        assert_eq!(moderated.settlements.len(), 1);
+        assert_eq!(
+            moderated
+                .events
+                .iter()
+                .map(|e| e.kind.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "GroupCreated",
+                "ExpenseCreated",
+                "Settled",
+                "ExpenseCreated"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_group_should_return_unauthorized_when_requested_by_a_non_admin() {
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+
+        let res = ctx.admin().get_group(&group.admin_id, &group.id).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::Unauthorized());
+    }
+
+    #[tokio::test]
+    async fn get_group_should_return_not_found_for_an_unknown_group() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+
+        let res = ctx.admin().get_group(&admin.id, &Uuid::new_v4()).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn transfer_group_ownership_should_make_the_member_admin() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let previous_admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+
+        let res = ctx
+            .admin()
+            .transfer_group_ownership(&admin.id, &group.id, &member.id)
+            .await;
+
+        assert_ok!(res);
+        let group = ctx.get_group(&group.id).await;
+        assert!(group.is_admin(&member.id));
+        assert!(group.is_member(&previous_admin));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::OwnershipTransferred { member_id, new_admin_id, .. }
+                if member_id == admin.id && new_admin_id == member.id
+        );
+    }
+
+    #[tokio::test]
+    async fn transfer_group_ownership_should_return_not_found_for_a_non_member() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let group = ctx.with_group().await;
+
+        let res = ctx
+            .admin()
+            .transfer_group_ownership(&admin.id, &group.id, &admin.id)
+            .await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn archive_group_should_make_the_group_read_only_until_unarchived() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let group = ctx.with_group().await;
+        let expense = CreateExpenseRequest {
+            group_id: group.id,
+            member_id: group.admin_id,
+            title: "my expense".to_string(),
+            amount: 12.0,
+        };
+
+        assert_ok!(ctx.admin().archive_group(&admin.id, &group.id).await);
+
+        assert!(ctx.get_group(&group.id).await.is_archived());
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::GroupArchived { member_id, .. } if member_id == admin.id
+        );
+        let err = assert_err!(ctx.group().create_expense(expense.clone()).await);
+        assert_matches!(err, CreateExpenseError::Validation(_));
+        let err = assert_err!(ctx.admin().archive_group(&admin.id, &group.id).await);
+        assert_matches!(err, ManageGroupError::Conflict(_));
+
+        assert_ok!(ctx.admin().unarchive_group(&admin.id, &group.id).await);
+
+        assert!(!ctx.get_group(&group.id).await.is_archived());
+        assert_ok!(ctx.group().create_expense(expense).await);
+        let err = assert_err!(ctx.admin().unarchive_group(&admin.id, &group.id).await);
+        assert_matches!(err, ManageGroupError::Conflict(_));
+    }
+
+    #[tokio::test]
+    async fn delete_group_should_delete_any_group() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        ctx.with_expense(&mut group, group_admin).await;
+
+        let res = ctx.admin().delete_group(&admin.id, &group.id).await;
+
+        assert_ok!(res);
+        assert_none!(ctx.find_group(&group.id).await);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::GroupDeleted { admin_id, .. } if admin_id == admin.id
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_group_should_return_unauthorized_when_requested_by_a_non_admin() {
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+
+        let res = ctx.admin().delete_group(&group.admin_id, &group.id).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::Unauthorized());
+        assert_some!(ctx.find_group(&group.id).await);
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_remove_a_member_without_expenses() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &member.id)
+            .await;
+
+        assert_ok!(res);
+        assert!(!ctx.get_group(&group.id).await.is_member(&member.id));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::MemberRemoved { member_id, removed_member_id, .. }
+                if member_id == admin.id && removed_member_id == member.id
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_return_conflict_for_a_member_with_expenses() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        ctx.with_expense(&mut group, member.id).await;
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &member.id)
+            .await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::Conflict(_));
+        assert!(ctx.get_group(&group.id).await.is_member(&member.id));
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_remove_a_member_whose_settlements_are_paid() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, member.id).await];
+        let mut settlement = ctx.settle(&mut group, &mut expenses).await;
+        settlement
+            .transactions
+            .iter_mut()
+            .for_each(|t| t.paid = true);
+        let mut tx = ctx.store().tx().await.unwrap();
+        assert_ok!(ctx.store().settlements().save(&mut tx, &settlement).await);
+        assert_ok!(ctx.store().commit(tx.into_inner()).await);
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &member.id)
+            .await;
+
+        assert_ok!(res);
+        assert!(!ctx.get_group(&group.id).await.is_member(&member.id));
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_return_conflict_for_a_member_with_unpaid_debts() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, member.id).await];
+        ctx.settle(&mut group, &mut expenses).await;
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &member.id)
+            .await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::Conflict(_));
+        assert!(ctx.get_group(&group.id).await.is_member(&member.id));
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_return_conflict_for_the_group_admin() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let group = ctx.with_group().await;
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &group.admin_id)
+            .await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::Conflict(_));
+    }
+
+    #[tokio::test]
+    async fn remove_member_should_return_not_found_for_a_non_member() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let group = ctx.with_group().await;
+
+        let res = ctx
+            .admin()
+            .remove_member(&admin.id, &group.id, &admin.id)
+            .await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageGroupError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn create_administrator_should_register_a_verified_administrator() {
+        let ctx = TestContext::new();
+        let request = RegistrationRequest {
+            name: "Jean".to_string(),
+            email: "jean@koru.com".to_string(),
+            password: Some(Secret::new("password".to_string())),
+        };
+
+        let res = ctx.admin().create_administrator(request).await;
+
+        let id = assert_ok!(res);
+        let user = ctx.get_user(&id).await;
+        assert_eq!(user.role, UserRole::Administrator);
+        assert!(user.email_verified);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserRoleChanged { by, .. } if by == OPERATOR_ID
+        );
+        assert_ok!(
+            ctx.user()
+                .login(LoginRequest {
+                    email: "jean@koru.com".to_string(),
+                    password: Some(Secret::new("password".to_string())),
+                })
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn promote_should_make_the_user_administrator() {
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+
+        let res = ctx.admin().promote(&String::from(user.email.clone())).await;
+
+        assert_eq!(assert_ok!(res), user.id);
+        assert_eq!(ctx.get_user(&user.id).await.role, UserRole::Administrator);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserRoleChanged { by, .. } if by == OPERATOR_ID
+        );
+    }
+
+    #[tokio::test]
+    async fn promote_should_return_not_found_for_an_unknown_email() {
+        let ctx = TestContext::new();
+
+        let res = ctx.admin().promote("nobody@koru.com").await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ManageUserError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn list_events_should_return_the_most_recent_events_first() {
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        ctx.with_expense(&mut group, group_admin).await;
+        let created = ctx
+            .store()
+            .events()
+            .get_group_events(&group.id)
+            .await
+            .unwrap()[0]
+            .id;
+        assert_ok!(ctx.store().events().mark_processed(&created).await);
+
+        let all = assert_ok!(ctx.admin().list_events(false, None).await);
+        let unprocessed = assert_ok!(ctx.admin().list_events(true, None).await);
+        let last = assert_ok!(ctx.admin().list_events(false, Some(1)).await);
+
+        assert_eq!(
+            all.iter().map(|e| e.kind.as_str()).collect::<Vec<_>>(),
+            vec!["ExpenseCreated", "GroupCreated"]
+        );
+        assert_some!(all[1].processed_at);
+        assert_eq!(all[1].group_id, Some(group.id));
+        assert_eq!(all[1].member_id, Some(group_admin));
+        assert_eq!(unprocessed.len(), 1);
+        assert_eq!(unprocessed[0].kind, "ExpenseCreated");
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].id, all[0].id);
+    }
+
+    #[tokio::test]
+    async fn purge_events_should_delete_the_processed_events_only() {
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        ctx.with_expense(&mut group, group_admin).await;
+        let created = ctx
+            .store()
+            .events()
+            .get_group_events(&group.id)
+            .await
+            .unwrap()[0]
+            .id;
+        assert_ok!(ctx.store().events().mark_processed(&created).await);
+
+        let res = ctx
+            .admin()
+            .purge_events(&(Utc::now() + Duration::minutes(1)))
+            .await;
+
+        assert_eq!(assert_ok!(res), 1);
+        let events = assert_ok!(ctx.admin().list_events(false, None).await);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, "ExpenseCreated");
+    }
+
+    #[tokio::test]
+    async fn republish_events_should_publish_the_unprocessed_events_oldest_first() {
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        let expense = ctx.with_expense(&mut group, group_admin).await;
+        let events = ctx
+            .store()
+            .events()
+            .get_group_events(&group.id)
+            .await
+            .unwrap();
+        assert_ok!(ctx.store().events().mark_processed(&events[1].id).await);
+
+        let res = ctx.admin().republish_events().await;
+
+        assert_eq!(assert_ok!(res), vec![events[0].id]);
+        assert_eq!(ctx.last_published_event(), Some(events[0].id));
+        assert_some!(ctx.find_expense(&expense.id).await);
+    }
+
+    #[tokio::test]
+    async fn export_group_should_be_imported_back_as_a_new_group() {
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        ctx.with_expense(&mut group, group_admin).await;
+
+        let backup = assert_ok!(ctx.admin().export_group(&group.id).await);
+        let res = ctx
+            .admin()
+            .import_group(assert_ok!(serde_json::to_value(&backup)))
+            .await;
+
+        let group_id = assert_ok!(res);
+        assert_ne!(group_id, group.id);
+        let restored = ctx.get_group(&group_id).await;
+        assert!(restored.is_admin(&group_admin));
+        assert_eq!(restored.members.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn export_group_should_return_not_found_for_an_unknown_group() {
+        let ctx = TestContext::new();
+
+        let res = ctx.admin().export_group(&Uuid::new_v4()).await;
+
+        let err = assert_err!(res);
+        assert_matches!(err, ExportError::NotFound(_));
+    }
+
+    #[tokio::test]
+    async fn get_audit_log_should_return_the_events_matching_the_filters() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        ctx.with_expense(&mut group, group_admin).await;
+        let origin = EventOrigin {
+            ip: Some("10.0.0.1".to_string()),
+            user_agent: Some("koru-app".to_string()),
+        };
+        assert_ok!(
+            origin
+                .clone()
+                .scope(ctx.user().login(login_request(&user)))
+                .await
+        );
+
+        let logins = assert_ok!(
+            ctx.admin()
+                .get_audit_log(
+                    &admin.id,
+                    audit_request(EventFilter {
+                        user_id: Some(user.id),
+                        ..EventFilter::default()
+                    })
+                )
+                .await
+        );
+        let expenses = assert_ok!(
+            ctx.admin()
+                .get_audit_log(
+                    &admin.id,
+                    audit_request(EventFilter {
+                        group_id: Some(group.id),
+                        kind: Some("ExpenseCreated".to_string()),
+                        ..EventFilter::default()
+                    })
+                )
+                .await
+        );
+        let none = assert_ok!(
+            ctx.admin()
+                .get_audit_log(
+                    &admin.id,
+                    audit_request(EventFilter {
+                        from: Some(Utc::now() + Duration::days(1)),
+                        ..EventFilter::default()
+                    })
+                )
+                .await
+        );
+
+        assert_eq!(logins.events.len(), 1);
+        assert_eq!(logins.events[0].kind, "UserLogin");
+        assert_eq!(logins.events[0].actor_id, user.id);
+        assert_eq!(logins.events[0].ip, origin.ip);
+        assert_eq!(logins.events[0].user_agent, origin.user_agent);
+        assert_eq!(expenses.events.len(), 1);
+        assert_eq!(expenses.events[0].actor_id, group_admin);
+        assert_some!(expenses.events[0].subject_id);
+        assert_none!(&expenses.events[0].ip);
+        assert!(none.events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_audit_log_should_include_the_events_performed_by_the_user() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let user = ctx.with_user().await;
+        assert_ok!(ctx.admin().disable_user(&admin.id, &user.id).await);
+
+        let res = ctx
+            .admin()
+            .get_audit_log(
+                &admin.id,
+                audit_request(EventFilter {
+                    user_id: Some(admin.id),
+                    ..EventFilter::default()
+                }),
+            )
+            .await;
+
+        let log = assert_ok!(res);
+        assert_eq!(log.events.len(), 1);
+        assert_eq!(log.events[0].kind, "UserDisabled");
+        assert_eq!(log.events[0].user_id, Some(user.id));
+        assert_eq!(log.events[0].actor_id, admin.id);
+    }
+
+    #[tokio::test]
+    async fn get_audit_log_should_return_the_events_page_by_page() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+        let mut group = ctx.with_group().await;
+        let group_admin = group.admin_id;
+        for _ in 0..3 {
+            ctx.with_expense(&mut group, group_admin).await;
+        }
+        let request = |before| AuditLogRequest {
+            filter: EventFilter::default(),
+            before,
+            limit: Some(2),
+        };
+
+        let first = assert_ok!(ctx.admin().get_audit_log(&admin.id, request(None)).await);
+        let second = assert_ok!(
+            ctx.admin()
+                .get_audit_log(&admin.id, request(first.next))
+                .await
+        );
+
+        assert_eq!(first.events.len(), 2);
+        assert_eq!(first.next, Some(first.events[1].id));
+        assert_eq!(second.events.len(), 2);
+        assert_none!(second.next);
+        assert!(first.events[1].date >= second.events[0].date);
+        assert!(second
+            .events
+            .iter()
+            .all(|e| first.events.iter().all(|f| f.id != e.id)));
+    }
+
+    #[tokio::test]
+    async fn get_audit_log_should_reject_invalid_limits_and_cursors() {
+        let ctx = TestContext::new();
+        let admin = ctx.with_admin_user().await;
+
+        let limit = ctx
+            .admin()
+            .get_audit_log(
+                &admin.id,
+                AuditLogRequest {
+                    limit: Some(0),
+                    ..audit_request(EventFilter::default())
+                },
+            )
+            .await;
+        let cursor = ctx
+            .admin()
+            .get_audit_log(
+                &admin.id,
+                AuditLogRequest {
+                    before: Some(Uuid::new_v4()),
+                    ..audit_request(EventFilter::default())
+                },
+            )
+            .await;
+
+        assert_matches!(assert_err!(limit), GetAuditLogError::Validation(_));
+        assert_matches!(assert_err!(cursor), GetAuditLogError::Validation(_));
+    }
+
+    #[tokio::test]
+    async fn get_audit_log_should_return_unauthorized_for_non_admin_users() {
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+
+        let res = ctx
+            .admin()
+            .get_audit_log(&user.id, audit_request(EventFilter::default()))
+            .await;
+
+        assert_matches!(assert_err!(res), GetAuditLogError::Unauthorized());
+    }
+
+    fn audit_request(filter: EventFilter) -> AuditLogRequest {
+        AuditLogRequest {
+            filter,
+            before: None,
+            limit: None,
+        }
+    }
 }
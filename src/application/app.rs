@@ -3,10 +3,13 @@ use crate::application::auth::AuthService;
 use crate::application::group::GroupUsecase;
 use crate::application::notification::DeviceService;
 use crate::application::store::MultiRepository;
-use crate::application::user::UserUsecase;
+use crate::application::user::{AccountMailer, UserUsecase};
 use crate::configuration::application::{ApplicationSettings, AuthSettings};
+use crate::domain::IdentityProvider;
 use crate::infrastructure::event_bus::EventBusImpl;
 use crate::infrastructure::services::credentials_hasher::ArgonCredentialsHasher;
+use anyhow::bail;
+use chrono::Duration;
 use std::sync::Arc;
 
 pub struct Application<Store: MultiRepository> {
@@ -26,29 +29,64 @@ impl<Store: MultiRepository> Application<Store> {
     ) -> Result<Self, anyhow::Error> {
         let event_bus = Arc::new(event_bus);
         let token_generator = configuration.token.setup_token_generator()?;
-        let auth_service = match configuration.auth {
-            AuthSettings::None => None,
-            AuthSettings::Internal => Some(AuthService::new(
-                store.clone(),
-                ArgonCredentialsHasher::new(argon_memory),
-            )),
-        };
+        let (auth_service, identity_provider): (_, Option<Arc<dyn IdentityProvider>>) =
+            match &configuration.auth {
+                AuthSettings::None => (None, None),
+                AuthSettings::Internal => {
+                    let auth_service =
+                        AuthService::new(store.clone(), ArgonCredentialsHasher::new(argon_memory));
+                    let auth_service = match &configuration.two_factor {
+                        None => auth_service,
+                        Some(conf) => {
+                            auth_service.with_two_factor(conf.issuer.clone(), &conf.encryption_key)
+                        }
+                    };
+                    (Some(auth_service), None)
+                }
+                #[cfg(feature = "oidc")]
+                AuthSettings::Oidc(conf) => (None, Some(Arc::new(conf.setup_identity_provider()))),
+            };
+        if auth_service.is_none() && configuration.email_verification.is_some() {
+            bail!("Email verification requires the `internal` auth.");
+        }
+        if auth_service.is_none() && configuration.two_factor.is_some() {
+            bail!("Two-factor authentication requires the `internal` auth.");
+        }
         #[cfg(feature = "pushy")]
         let device_service = Arc::new(DeviceService::new(store.clone()));
+        let account_mailer = AccountMailer::new(
+            configuration.mail.setup_mailer()?,
+            configuration.mail.public_url.clone(),
+        );
         let user_uc = Arc::new(UserUsecase::new(
             store.clone(),
             event_bus.clone(),
             auth_service,
+            identity_provider,
             #[cfg(feature = "pushy")]
             device_service.clone(),
+            Duration::hours(configuration.takeout.retention.into()),
+            configuration.takeout.sync_group_limit.into(),
+            account_mailer,
+            Duration::minutes(configuration.password_reset.validity.into()),
+            configuration
+                .email_verification
+                .as_ref()
+                .map(|conf| Duration::hours(conf.validity.into())),
         ));
         let group_uc = Arc::new(GroupUsecase::new(
             store.clone(),
             event_bus.clone(),
             Arc::new(token_generator),
             user_uc.clone(),
+            Duration::hours(configuration.settlement.revert_window.into()),
+        ));
+        let admin_uc = Arc::new(AdminUsecase::new(
+            store,
+            event_bus,
+            user_uc.clone(),
+            group_uc.clone(),
         ));
-        let admin_uc = Arc::new(AdminUsecase::new(store, event_bus));
 
         Ok(Self {
             group_uc,
@@ -1,16 +1,39 @@
-use crate::application::auth::UserCredentials;
-use crate::application::auth::{CredentialsHasher, Password};
+use crate::application::auth::{CredentialsHasher, Password, SecretCipher};
+use crate::application::auth::{TwoFactor, TwoFactorSecrets};
+use crate::application::auth::{UserCredentials, UserToken, UserTokenKind};
 use crate::application::store::MultiRepository;
-use crate::domain::errors::{CreateUserError, CredentialServiceError, LoginError};
+use crate::domain::errors::{
+    CreateUserError, CredentialServiceError, LoginError, TwoFactorError, UpdateUserError,
+};
 use crate::domain::Email;
+use crate::utils::date;
 use crate::utils::telemetry::spawn_blocking_with_tracing;
 use anyhow::{anyhow, Context};
+use chrono::Duration;
 use secrecy::Secret;
+use std::cell::RefCell;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct AuthService<Store: MultiRepository> {
     store: Arc<Store>,
     hasher: Arc<dyn CredentialsHasher>,
+    /// Users can enroll a TOTP second factor when set.
+    two_factor: Option<TwoFactorConfig>,
+}
+
+struct TwoFactorConfig {
+    /// Name under which authenticator applications list the accounts.
+    issuer: String,
+    cipher: SecretCipher,
+}
+
+/// Outcome of valid credentials.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoginStatus {
+    Authenticated,
+    /// The user enrolled a second factor, whose code has to be verified as well.
+    SecondFactorRequired,
 }
 
 impl<Store: MultiRepository> AuthService<Store> {
@@ -18,9 +41,19 @@ impl<Store: MultiRepository> AuthService<Store> {
         Self {
             store,
             hasher: Arc::new(hasher),
+            two_factor: None,
         }
     }
 
+    /// Allows users to enroll a TOTP second factor, whose secrets are encrypted with `key`.
+    pub fn with_two_factor(mut self, issuer: String, key: &Secret<String>) -> Self {
+        self.two_factor = Some(TwoFactorConfig {
+            issuer,
+            cipher: SecretCipher::new(key),
+        });
+        self
+    }
+
     pub async fn register(&self, request: RegisterRequest) -> Result<(), CreateUserError> {
         let email = request.email.clone();
         let creds = self.hasher.clone();
@@ -41,13 +74,127 @@ impl<Store: MultiRepository> AuthService<Store> {
         Ok(())
     }
 
-    pub async fn login(&self, request: LoginRequest) -> Result<(), LoginError> {
+    pub async fn login(&self, request: LoginRequest) -> Result<LoginStatus, LoginError> {
         let email = Email::try_from(request.email).map_err(LoginError::Validation)?;
-        let _ = Password::try_from(request.password.clone()).map_err(LoginError::Validation)?;
+        self.verify(&email, request.password).await?;
+        if self.two_factor.is_none() {
+            return Ok(LoginStatus::Authenticated);
+        }
+        let two_factor = self
+            .store
+            .two_factors()
+            .find(&email)
+            .await
+            .context("Failed to fetch second factor")?;
+        match two_factor {
+            Some(two_factor) if two_factor.is_confirmed() => Ok(LoginStatus::SecondFactorRequired),
+            _ => Ok(LoginStatus::Authenticated),
+        }
+    }
+
+    /// Checks the TOTP or recovery code of the second factor registered under `email`, within
+    /// `tx` as neither can be used twice.
+    pub async fn verify_second_factor(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        email: &Email,
+        code: &str,
+    ) -> Result<(), LoginError> {
+        let config = self
+            .two_factor
+            .as_ref()
+            .ok_or(LoginError::InvalidCredentials())?;
+        // locked until the login commits, so that a code can only be used once
+        let mut two_factor = self
+            .store
+            .two_factors()
+            .find_for_update(tx, email)
+            .await
+            .context("Failed to fetch second factor")?
+            .filter(TwoFactor::is_confirmed)
+            .ok_or(LoginError::InvalidCredentials())?;
+        if !two_factor.verify_code(code, &config.cipher)? && !two_factor.use_recovery_code(code) {
+            return Err(LoginError::InvalidCredentials());
+        }
+        self.store
+            .two_factors()
+            .save(tx, &two_factor)
+            .await
+            .context("Failed to save second factor")?;
+        Ok(())
+    }
+
+    /// Starts the enrollment of a second factor for the credentials registered under `email`,
+    /// replacing a pending one. Returns the provisioning URI along with the secrets in clear.
+    pub async fn enroll_two_factor(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        email: &Email,
+    ) -> Result<(String, TwoFactorSecrets), TwoFactorError> {
+        let config = self.two_factor.as_ref().ok_or(TwoFactorError::Validation(
+            "Two-factor authentication is not enabled.",
+        ))?;
+        let existing = self
+            .store
+            .two_factors()
+            .find(email)
+            .await
+            .context("Failed to fetch second factor")?;
+        if existing.map_or(false, |t| t.is_confirmed()) {
+            return Err(TwoFactorError::Conflict(
+                "Two-factor authentication is already enabled.",
+            ));
+        }
+        let (secrets, two_factor) = TwoFactor::generate(email.clone(), &config.cipher)?;
+        self.store
+            .two_factors()
+            .save(tx, &two_factor)
+            .await
+            .context("Failed to save second factor")?;
+        let uri = TwoFactor::provisioning_uri(&config.issuer, email, &secrets.secret);
+        Ok((uri, secrets))
+    }
+
+    /// Confirms the pending enrollment of the credentials registered under `email` given a
+    /// valid code, after which the second factor is required at login.
+    pub async fn confirm_two_factor(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        email: &Email,
+        code: &str,
+    ) -> Result<(), TwoFactorError> {
+        let config = self.two_factor.as_ref().ok_or(TwoFactorError::Validation(
+            "Two-factor authentication is not enabled.",
+        ))?;
+        let mut two_factor = self
+            .store
+            .two_factors()
+            .find(email)
+            .await
+            .context("Failed to fetch second factor")?
+            .filter(|t| !t.is_confirmed())
+            .ok_or(TwoFactorError::NotFound(
+                "No two-factor enrollment is pending.",
+            ))?;
+        if !two_factor.verify_code(code, &config.cipher)? {
+            return Err(TwoFactorError::InvalidCode());
+        }
+        two_factor.confirmed_at = Some(date::now());
+        self.store
+            .two_factors()
+            .save(tx, &two_factor)
+            .await
+            .context("Failed to save second factor")?;
+        Ok(())
+    }
+
+    /// Checks `password` against the credentials registered under `email`.
+    pub async fn verify(&self, email: &Email, password: Secret<String>) -> Result<(), LoginError> {
+        let _ = Password::try_from(password.clone()).map_err(LoginError::Validation)?;
         let credentials = self
             .store
             .credentials()
-            .fetch_by_email(&email)
+            .fetch_by_email(email)
             .await
             .context("Failed to fetch user")
             .map_err(LoginError::Unexpected)?;
@@ -59,7 +206,6 @@ impl<Store: MultiRepository> AuthService<Store> {
                     .get()
                     .map_err(|_| LoginError::Unexpected(anyhow!("Corrupted password")))?;
                 let creds = self.hasher.clone();
-                let password = request.password.clone();
                 spawn_blocking_with_tracing(move || {
                     creds
                         .verify(password, expected_password)
@@ -76,6 +222,131 @@ impl<Store: MultiRepository> AuthService<Store> {
             }
         }
     }
+
+    /// Replaces the password of the credentials registered under `email`, within `tx`.
+    pub async fn change_password(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        email: &Email,
+        password: Secret<String>,
+    ) -> Result<(), UpdateUserError> {
+        let email = String::from(email.clone());
+        let creds = self.hasher.clone();
+        let user_credentials =
+            spawn_blocking_with_tracing(move || UserCredentials::create(email, password, creds))
+                .await
+                .context("Failed to build user credentials")?
+                .map_err(|e| match e {
+                    CreateUserError::Validation(e) => UpdateUserError::Validation(e),
+                    e => UpdateUserError::Unexpected(anyhow!(e)),
+                })?;
+        self.store
+            .credentials()
+            .save(tx, &user_credentials)
+            .await
+            .context("Failed to save credentials")?;
+        Ok(())
+    }
+
+    /// Moves the credentials registered under `previous` to `email`, within `tx`.
+    ///
+    /// The second factor references the credentials, so it is moved to the new ones before the
+    /// previous credentials are deleted.
+    pub async fn change_email(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        previous: &Email,
+        email: &Email,
+    ) -> Result<(), UpdateUserError> {
+        let credentials = self
+            .store
+            .credentials()
+            .fetch_by_email(previous)
+            .await
+            .context("Failed to fetch credentials")?
+            .ok_or_else(|| anyhow!("No credentials registered for the user."))?;
+        let two_factor = self
+            .store
+            .two_factors()
+            .find_for_update(tx, previous)
+            .await
+            .context("Failed to fetch second factor")?;
+        self.store
+            .credentials()
+            .save(
+                tx,
+                &UserCredentials {
+                    email: email.clone(),
+                    password: credentials.password,
+                },
+            )
+            .await
+            .context("Failed to save credentials")?;
+        if let Some(two_factor) = two_factor {
+            self.store
+                .two_factors()
+                .save(
+                    tx,
+                    &TwoFactor {
+                        email: email.clone(),
+                        ..two_factor
+                    },
+                )
+                .await
+                .context("Failed to save second factor")?;
+            self.store
+                .two_factors()
+                .delete(tx, previous)
+                .await
+                .context("Failed to delete second factor")?;
+        }
+        self.store
+            .credentials()
+            .delete(tx, previous)
+            .await
+            .context("Failed to delete credentials")?;
+        Ok(())
+    }
+
+    /// Issues a token of the given kind to the user within `tx`, replacing the previous ones.
+    pub async fn issue_token(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        user_id: Uuid,
+        kind: UserTokenKind,
+        validity: Duration,
+    ) -> Result<Secret<String>, anyhow::Error> {
+        let (token, user_token) = UserToken::generate(user_id, kind, validity);
+        self.store
+            .user_tokens()
+            .delete_all(tx, &user_id, kind)
+            .await
+            .context("Failed to delete previous tokens")?;
+        self.store
+            .user_tokens()
+            .save(tx, &user_token)
+            .await
+            .context("Failed to save token")?;
+        Ok(token)
+    }
+
+    /// Returns the user the token was issued to, removing the token within `tx` so that it can
+    /// only be used once. `None` when the token is unknown or expired.
+    pub async fn consume_token(
+        &self,
+        tx: &mut RefCell<Store::KTransaction>,
+        token: &Secret<String>,
+        kind: UserTokenKind,
+    ) -> Result<Option<Uuid>, anyhow::Error> {
+        Ok(self
+            .store
+            .user_tokens()
+            .take(tx, &UserToken::hash(token), kind)
+            .await
+            .context("Failed to take token")?
+            .filter(|t| !t.is_expired())
+            .map(|t| t.user_id))
+    }
 }
 
 pub struct RegisterRequest {
@@ -12,6 +12,8 @@ error_chain! {
         Insert(#[source] anyhow::Error),
         #[error("Failed to fetch credential.")]
         Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete credential.")]
+        Delete(#[source] anyhow::Error),
         #[error("Corrupted data in DB: {0}")]
         CorruptedData(&'static str),
     }
@@ -31,4 +33,10 @@ pub trait CredentialRepository: Send + Sync {
         &self,
         email: &Email,
     ) -> Result<Option<UserCredentials>, CredentialRepositoryError>;
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), CredentialRepositoryError>;
 }
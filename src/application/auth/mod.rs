@@ -1,11 +1,19 @@
 mod auth_service;
 mod credential_repository;
 mod credentials_hasher;
+mod two_factor;
+mod two_factor_repository;
 mod user_credentials;
 mod user_password;
+mod user_token;
+mod user_token_repository;
 
 pub use auth_service::*;
 pub use credential_repository::*;
 pub use credentials_hasher::CredentialsHasher;
+pub use two_factor::{current_code, SecretCipher, TwoFactor, TwoFactorSecrets};
+pub use two_factor_repository::*;
 pub use user_credentials::UserCredentials;
 pub use user_password::Password;
+pub use user_token::{UserToken, UserTokenKind};
+pub use user_token_repository::*;
@@ -0,0 +1,278 @@
+use crate::domain::Email;
+use crate::utils::date;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, Secret};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+const SECRET_LENGTH: usize = 20;
+const STEP_SECONDS: i64 = 30;
+const DIGITS: u32 = 6;
+/// Codes of the previous and next steps are accepted as well, to allow for clock drift.
+const ALLOWED_DRIFT: i64 = 1;
+const RECOVERY_CODES: usize = 10;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// TOTP second factor (RFC 6238) of the credentials registered under `email`.
+///
+/// The shared secret is stored encrypted, as it has to be read back to check the codes, and the
+/// single-use recovery codes are stored hashed.
+#[derive(Debug, Clone)]
+pub struct TwoFactor {
+    pub email: Email,
+    pub encrypted_secret: String,
+    pub recovery_codes: Vec<String>,
+    /// Codes are only required at login once the enrollment is confirmed with a valid code.
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Time step of the last accepted code, which cannot be replayed.
+    pub last_used_step: Option<i64>,
+}
+
+/// Shared secret and recovery codes of a new enrollment, in clear.
+pub struct TwoFactorSecrets {
+    pub secret: Secret<String>,
+    pub recovery_codes: Vec<Secret<String>>,
+}
+
+impl TwoFactor {
+    /// Generates an unconfirmed second factor, returned in clear along with what is stored.
+    pub fn generate(
+        email: Email,
+        cipher: &SecretCipher,
+    ) -> Result<(TwoFactorSecrets, Self), anyhow::Error> {
+        let secret = rand::random::<[u8; SECRET_LENGTH]>();
+        let recovery_codes = (0..RECOVERY_CODES)
+            .map(|_| {
+                let code = hex::encode(rand::random::<[u8; 5]>());
+                Secret::new(format!("{}-{}", &code[..5], &code[5..]))
+            })
+            .collect::<Vec<_>>();
+        let two_factor = Self {
+            email,
+            encrypted_secret: cipher.encrypt(&secret)?,
+            recovery_codes: recovery_codes.iter().map(Self::hash).collect(),
+            confirmed_at: None,
+            last_used_step: None,
+        };
+        let secrets = TwoFactorSecrets {
+            secret: Secret::new(base32(&secret)),
+            recovery_codes,
+        };
+        Ok((secrets, two_factor))
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+
+    /// Checks a TOTP code, recording its time step so that it cannot be used twice.
+    pub fn verify_code(
+        &mut self,
+        code: &str,
+        cipher: &SecretCipher,
+    ) -> Result<bool, anyhow::Error> {
+        let secret = cipher.decrypt(&self.encrypted_secret)?;
+        let current = date::now().timestamp().div_euclid(STEP_SECONDS);
+        let step = (current - ALLOWED_DRIFT..=current + ALLOWED_DRIFT)
+            .filter(|step| self.last_used_step.map_or(true, |last| *step > last))
+            .find(|step| totp(&secret, *step) == code.trim());
+        match step {
+            Some(step) => {
+                self.last_used_step = Some(step);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Checks a recovery code, removing it so that it can only be used once.
+    pub fn use_recovery_code(&mut self, code: &str) -> bool {
+        let hash = Self::hash(&Secret::new(code.trim().to_lowercase()));
+        let count = self.recovery_codes.len();
+        self.recovery_codes.retain(|c| *c != hash);
+        self.recovery_codes.len() < count
+    }
+
+    /// URI of the secret to show as a QR code to authenticator applications.
+    pub fn provisioning_uri(issuer: &str, email: &Email, secret: &Secret<String>) -> String {
+        let email = String::from(email.clone());
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            percent_encode(issuer),
+            percent_encode(&email),
+            secret.expose_secret(),
+            percent_encode(issuer),
+            DIGITS,
+            STEP_SECONDS
+        )
+    }
+
+    fn hash(code: &Secret<String>) -> String {
+        hex::encode(Sha256::digest(code.expose_secret().as_bytes()))
+    }
+}
+
+/// Encrypts the TOTP secrets at rest with AES-256-GCM, the key being derived from the
+/// configured encryption key.
+pub struct SecretCipher {
+    cipher: Aes256Gcm,
+}
+
+impl SecretCipher {
+    pub fn new(key: &Secret<String>) -> Self {
+        let key = Sha256::digest(key.expose_secret().as_bytes());
+        Self {
+            cipher: Aes256Gcm::new(&key),
+        }
+    }
+
+    /// Returns the hex encoded nonce followed by the ciphertext.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<String, anyhow::Error> {
+        let nonce = rand::random::<[u8; 12]>();
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| anyhow!("Failed to encrypt secret."))?;
+        Ok(hex::encode([nonce.as_slice(), &ciphertext].concat()))
+    }
+
+    pub fn decrypt(&self, encrypted: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let bytes = hex::decode(encrypted)?;
+        if bytes.len() < 12 {
+            return Err(anyhow!("Encrypted secret is corrupted."));
+        }
+        let (nonce, ciphertext) = bytes.split_at(12);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Failed to decrypt secret."))
+    }
+}
+
+/// Code of the given time step, as computed by authenticator applications (RFC 4226).
+fn totp(secret: &[u8], step: i64) -> String {
+    let mut mac =
+        <Hmac<Sha1> as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        code % 10u32.pow(DIGITS),
+        width = DIGITS as usize
+    )
+}
+
+/// Base32 without padding (RFC 4648), the encoding authenticator applications expect.
+fn base32(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    encoded
+}
+
+/// Code an authenticator application shows for the base32 secret at the current time, `None`
+/// when the secret is not valid base32.
+pub fn current_code(secret: &Secret<String>) -> Option<String> {
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in secret.expose_secret().bytes() {
+        let value = BASE32_ALPHABET.iter().position(|a| *a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(totp(
+        &bytes,
+        date::now().timestamp().div_euclid(STEP_SECONDS),
+    ))
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::auth::two_factor::{base32, totp, SecretCipher, TwoFactor};
+    use crate::domain::Email;
+    use claim::{assert_err, assert_ok};
+    use secrecy::{ExposeSecret, Secret};
+
+    #[test]
+    fn it_should_compute_the_codes_of_the_rfc_test_vectors() {
+        // given
+        let secret = b"12345678901234567890";
+        // then
+        assert_eq!(totp(secret, 59 / 30), "287082");
+        assert_eq!(totp(secret, 1111111109 / 30), "081804");
+        assert_eq!(totp(secret, 2000000000 / 30), "279037");
+    }
+
+    #[test]
+    fn it_should_encode_in_base32() {
+        assert_eq!(base32(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(
+            base32(b"12345678901234567890"),
+            "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ"
+        );
+    }
+
+    #[test]
+    fn it_should_only_decrypt_with_the_same_key() {
+        // given
+        let cipher = SecretCipher::new(&Secret::new("key".to_string()));
+        let other = SecretCipher::new(&Secret::new("other".to_string()));
+        // when
+        let encrypted = assert_ok!(cipher.encrypt(b"secret"));
+        // then
+        assert_eq!(assert_ok!(cipher.decrypt(&encrypted)), b"secret");
+        assert_err!(other.decrypt(&encrypted));
+        assert_ne!(assert_ok!(cipher.encrypt(b"secret")), encrypted);
+    }
+
+    #[test]
+    fn it_should_use_recovery_codes_only_once() {
+        // given
+        let cipher = SecretCipher::new(&Secret::new("key".to_string()));
+        let email = Email::try_from("r@r.com".to_string()).unwrap();
+        let (secrets, mut two_factor) = assert_ok!(TwoFactor::generate(email, &cipher));
+        let code = secrets.recovery_codes[3].expose_secret().clone();
+        // then
+        assert!(two_factor.use_recovery_code(&code.to_uppercase()));
+        assert!(!two_factor.use_recovery_code(&code));
+        assert_eq!(two_factor.recovery_codes.len(), 9);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::application::auth::TwoFactor;
+use crate::application::store::Tx;
+use crate::domain::Email;
+use crate::error_chain;
+use async_trait::async_trait;
+use std::cell::RefCell;
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum TwoFactorRepositoryError {
+        #[error("Failed to insert second factor.")]
+        Insert(#[source] anyhow::Error),
+        #[error("Failed to fetch second factor.")]
+        Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete second factor.")]
+        Delete(#[source] anyhow::Error),
+        #[error("Corrupted data in DB: {0}")]
+        CorruptedData(&'static str),
+    }
+}
+
+#[async_trait]
+pub trait TwoFactorRepository: Send + Sync {
+    type Tr: Tx;
+
+    /// Inserts the second factor of the credentials or replaces it.
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        two_factor: &TwoFactor,
+    ) -> Result<(), TwoFactorRepositoryError>;
+
+    async fn find(&self, email: &Email) -> Result<Option<TwoFactor>, TwoFactorRepositoryError>;
+
+    /// Fetches the second factor within `tx`, locking it until the transaction ends so that
+    /// concurrent logins cannot use the same code twice.
+    async fn find_for_update(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<Option<TwoFactor>, TwoFactorRepositoryError>;
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), TwoFactorRepositoryError>;
+}
@@ -0,0 +1,73 @@
+use crate::utils::date;
+use chrono::{DateTime, Duration, Utc};
+use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Single-use token emailed to a user to prove they own their email address.
+///
+/// Only the SHA-256 hash of the token is stored, the token itself only exists in the email.
+/// A plain hash is enough as the token is random and long, unlike a password.
+#[derive(Debug, Clone)]
+pub struct UserToken {
+    pub hash: String,
+    pub user_id: Uuid,
+    pub kind: UserTokenKind,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UserTokenKind {
+    PasswordReset,
+    EmailVerification,
+}
+
+impl UserToken {
+    /// Generates a token valid for `validity`, returned in clear along with what is stored.
+    pub fn generate(
+        user_id: Uuid,
+        kind: UserTokenKind,
+        validity: Duration,
+    ) -> (Secret<String>, Self) {
+        let token = hex::encode(rand::random::<[u8; 32]>());
+        let created_at = date::now();
+        let user_token = Self {
+            hash: Self::hash(&Secret::new(token.clone())),
+            user_id,
+            kind,
+            created_at,
+            expires_at: created_at + validity,
+        };
+        (Secret::new(token), user_token)
+    }
+
+    pub fn hash(token: &Secret<String>) -> String {
+        hex::encode(Sha256::digest(token.expose_secret().as_bytes()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at < date::now()
+    }
+}
+
+impl UserTokenKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserTokenKind::PasswordReset => "password_reset",
+            UserTokenKind::EmailVerification => "email_verification",
+        }
+    }
+}
+
+impl TryFrom<&str> for UserTokenKind {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "password_reset" => Ok(UserTokenKind::PasswordReset),
+            "email_verification" => Ok(UserTokenKind::EmailVerification),
+            _ => Err("Unknown user token kind"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::application::auth::{UserToken, UserTokenKind};
+use crate::application::store::Tx;
+use crate::error_chain;
+use async_trait::async_trait;
+use std::cell::RefCell;
+use uuid::Uuid;
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum UserTokenRepositoryError {
+        #[error("Failed to insert user token.")]
+        Insert(#[source] anyhow::Error),
+        #[error("Failed to fetch user token.")]
+        Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete user token.")]
+        Delete(#[source] anyhow::Error),
+        #[error("Corrupted data in DB: {0}")]
+        CorruptedData(&'static str),
+    }
+}
+
+#[async_trait]
+pub trait UserTokenRepository: Send + Sync {
+    type Tr: Tx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &UserToken,
+    ) -> Result<(), UserTokenRepositoryError>;
+
+    async fn find(&self, hash: &str) -> Result<Option<UserToken>, UserTokenRepositoryError>;
+
+    /// Removes the token of the given kind within `tx`, returning it when it existed.
+    async fn take(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        hash: &str,
+        kind: UserTokenKind,
+    ) -> Result<Option<UserToken>, UserTokenRepositoryError>;
+
+    /// Removes the tokens of the given kind issued to the user, used or not.
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+        kind: UserTokenKind,
+    ) -> Result<(), UserTokenRepositoryError>;
+
+    /// Removes every token issued to the user, whatever their kind.
+    async fn delete_user_tokens(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), UserTokenRepositoryError>;
+}
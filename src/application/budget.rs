@@ -0,0 +1,59 @@
+use crate::application::store::MultiRepository;
+use crate::domain::{Budget, BudgetConsumption, BudgetPeriod};
+use anyhow::Context;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// Computes the group spending of the budget period containing `date`, up to `date` included.
+pub async fn consumption(
+    budget: &Budget,
+    date: &DateTime<Utc>,
+    store: Arc<impl MultiRepository>,
+) -> Result<BudgetConsumption, anyhow::Error> {
+    period_consumption(budget, date, date, store).await
+}
+
+/// Computes the group spending of the budget period containing `date`, before `date`.
+pub async fn consumption_before(
+    budget: &Budget,
+    date: &DateTime<Utc>,
+    store: Arc<impl MultiRepository>,
+) -> Result<BudgetConsumption, anyhow::Error> {
+    period_consumption(budget, date, &(*date - Duration::microseconds(1)), store).await
+}
+
+/// Computes the group spending of the budget period containing `date`, up to `until` included.
+async fn period_consumption(
+    budget: &Budget,
+    date: &DateTime<Utc>,
+    until: &DateTime<Utc>,
+    store: Arc<impl MultiRepository>,
+) -> Result<BudgetConsumption, anyhow::Error> {
+    let last_settlement = match budget.period {
+        BudgetPeriod::Monthly => None,
+        BudgetPeriod::Settlement => store
+            .settlements()
+            .get_settlements(&budget.group_id)
+            .await
+            .context("Failed to fetch settlements.")?
+            .into_iter()
+            .map(|s| s.end_date)
+            .filter(|end_date| end_date < date)
+            .max(),
+    };
+    let start = budget.period_start(date, last_settlement);
+    // Expense stats exclude their lower bound, which is only right after a settlement.
+    let lower_bound = match budget.period {
+        BudgetPeriod::Monthly => start.map(|s| s - Duration::microseconds(1)),
+        BudgetPeriod::Settlement => start,
+    };
+    let stats = store
+        .expenses()
+        .get_stats(&budget.group_id, lower_bound.as_ref(), Some(until), 0)
+        .await
+        .context("Failed to fetch expense stats.")?;
+    Ok(BudgetConsumption {
+        start,
+        spent: stats.total,
+    })
+}
@@ -0,0 +1,127 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::BackupGroupRequest;
+use crate::domain::GroupBackup;
+use anyhow::{anyhow, Context};
+use itertools::Itertools;
+use std::sync::Arc;
+
+pub async fn backup(
+    data: BackupGroupRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<GroupBackup, ExportError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(ExportError::Unexpected)?;
+    let group = match group {
+        Some(group) => {
+            if !group.is_admin(&data.user_id) {
+                return Err(ExportError::Unauthorized("User is not admin."));
+            }
+            group
+        }
+        None => return Err(ExportError::NotFound("Group not found.")),
+    };
+    let expenses = store
+        .expenses()
+        .get_expenses(&group.id, None, None)
+        .await
+        .context("Failed to fetch expenses.")
+        .map_err(ExportError::Unexpected)?
+        .into_iter()
+        .sorted_by_key(|e| e.created_at)
+        .collect_vec();
+    let settlements = store
+        .settlements()
+        .get_settlements(&group.id)
+        .await
+        .context("Failed to fetch settlements.")
+        .map_err(ExportError::Unexpected)?;
+    let budget = store
+        .budgets()
+        .find(&group.id)
+        .await
+        .context("Failed to fetch budget.")
+        .map_err(ExportError::Unexpected)?;
+    let events = store
+        .events()
+        .get_group_events(&group.id)
+        .await
+        .context("Failed to fetch events.")
+        .map_err(ExportError::Unexpected)?;
+    GroupBackup::create(&group, &expenses, &settlements, budget.as_ref(), &events)
+        .map_err(|e| ExportError::Unexpected(anyhow!(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok};
+
+    #[tokio::test]
+    async fn it_should_backup_the_whole_group() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, member.id).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_expense_of(&mut group, 12.0, admin).await;
+        let req = BackupGroupRequest {
+            group_id: group.id,
+            user_id: admin,
+        };
+        // when
+        let resp = ctx.group().backup_group(req).await;
+        // then
+        let backup = assert_ok!(resp);
+        assert_eq!(backup.version, 1);
+        assert_eq!(backup.members.len(), 2);
+        assert_eq!(backup.expenses.len(), 2);
+        assert_eq!(backup.expenses.iter().filter(|e| e.settled).count(), 1);
+        assert_eq!(backup.settlements.len(), 1);
+        let transactions = &backup.settlements[0].transactions;
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].to, String::from(member.email));
+        let events = assert_ok!(serde_json::to_value(&backup.events));
+        assert_eq!(events[0]["event"]["type"], "group_created");
+        assert_eq!(
+            events[0]["member"],
+            String::from(group.admin().email.clone())
+        );
+        assert!(events
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|e| e["event"]["type"] == "settled"));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = BackupGroupRequest {
+            group_id: group.id,
+            user_id: member.id,
+        };
+        // when
+        let resp = ctx.group().backup_group(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            ExportError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
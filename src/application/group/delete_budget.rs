@@ -0,0 +1,104 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::DeleteBudgetError;
+use crate::domain::usecases::group::DeleteBudgetRequest;
+use crate::domain::Group;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn delete(
+    data: DeleteBudgetRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<Group, DeleteBudgetError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(DeleteBudgetError::Unexpected)?;
+    match group {
+        Some(mut group) => {
+            let budget = store
+                .budgets()
+                .find(&group.id)
+                .await
+                .context("Failed to fetch budget.")
+                .map_err(DeleteBudgetError::Unexpected)?;
+            group.remove_budget(data.user_id, budget)?;
+            Ok(group)
+        }
+        None => Err(DeleteBudgetError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_none, assert_ok, assert_some};
+
+    #[tokio::test]
+    async fn it_should_remove_the_group_budget_when_user_is_admin() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        ctx.with_budget(&group, 100.0, "monthly").await;
+        let req = DeleteBudgetRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+        };
+        // when
+        let resp = ctx.group().delete_budget(req).await;
+        // then
+        assert_ok!(resp);
+        assert_none!(ctx.find_budget(&group.id).await);
+        assert_some!(ctx.last_published_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        ctx.with_budget(&group, 100.0, "monthly").await;
+        let req = DeleteBudgetRequest {
+            group_id: group.id,
+            user_id: member.id,
+        };
+        // when
+        let resp = ctx.group().delete_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            DeleteBudgetError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+        assert_some!(ctx.find_budget(&group.id).await);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_group_has_no_budget() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = DeleteBudgetRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+        };
+        // when
+        let resp = ctx.group().delete_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            DeleteBudgetError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::application::store::MultiRepository;
 use crate::domain::errors::DeleteGroupError;
 use crate::domain::usecases::group::DeleteGroupRequest;
-use crate::domain::Group;
+use crate::domain::{Group, GroupActor};
 use anyhow::Context;
 use std::sync::Arc;
 
@@ -18,7 +18,7 @@ pub async fn delete(
 
     match opt_group {
         Some(mut group) => {
-            group.delete(&data.user_id)?;
+            group.delete(GroupActor::Member(data.user_id))?;
             Ok(group)
         }
         None => Err(DeleteGroupError::NotFound()),
@@ -0,0 +1,238 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::{CsvStream, ExportRequest};
+use crate::domain::{Expense, ExpenseFilter};
+use crate::utils::csv;
+use anyhow::Context;
+use futures_util::{future, stream, StreamExt};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Number of expenses fetched from the store for each chunk of the export.
+const PAGE_SIZE: u32 = 500;
+
+const HEADER: [&str; 7] = [
+    "expense_id",
+    "date",
+    "title",
+    "amount",
+    "member_id",
+    "member",
+    "settlement_id",
+];
+
+pub async fn export(
+    data: ExportRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<CsvStream, ExportError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(ExportError::Unexpected)?;
+    // check user is a member of the group
+    let group = match group {
+        Some(group) => {
+            if !group.is_member(&data.user_id) {
+                return Err(ExportError::Unauthorized("User is not a member."));
+            }
+            group
+        }
+        None => return Err(ExportError::NotFound("Group not found.")),
+    };
+    // if using settlement filter, check it exists
+    if let Some(stl_id) = &data.settlement_id {
+        let exists = store
+            .settlements()
+            .exists(stl_id)
+            .await
+            .context("Failed to find settlement.")
+            .map_err(ExportError::Unexpected)?;
+        if !exists {
+            return Err(ExportError::NotFound("Settlement not found."));
+        }
+    }
+
+    let members = Arc::new(
+        group
+            .members
+            .into_iter()
+            .map(|m| (m.id, String::from(m.name)))
+            .collect::<HashMap<Uuid, String>>(),
+    );
+    let filter = ExpenseFilter::new(data.from, data.to, data.settlement_id);
+    let group_id = data.group_id;
+    // expenses are fetched page by page, the state being the cursor of the next page
+    // (`None` once the last page has been read)
+    let rows = stream::try_unfold(Some(None), move |cursor| {
+        let store = store.clone();
+        let members = members.clone();
+        async move {
+            let after = match cursor {
+                Some(after) => after,
+                None => return Ok(None),
+            };
+            let page = store
+                .expenses()
+                .get_expense_page(&group_id, &filter, after, PAGE_SIZE)
+                .await
+                .context("Failed to fetch expenses.")?;
+            let next = match page.last() {
+                Some((last, _)) if page.len() == PAGE_SIZE as usize => {
+                    Some(Some((last.created_at, last.id)))
+                }
+                _ => None,
+            };
+            let chunk = page
+                .iter()
+                .map(|(expense, settlement_id)| row(expense, settlement_id, &members))
+                .collect::<String>();
+            Ok(Some((chunk, next)))
+        }
+    });
+    Ok(stream::once(future::ready(Ok(csv::record(&HEADER))))
+        .chain(rows)
+        .boxed_local())
+}
+
+fn row(expense: &Expense, settlement_id: &Option<Uuid>, members: &HashMap<Uuid, String>) -> String {
+    csv::record(&[
+        expense.id.to_string(),
+        expense.created_at.to_rfc3339(),
+        String::from(expense.title.clone()),
+        format!("{:.2}", f32::from(expense.amount)),
+        expense.member_id.to_string(),
+        members.get(&expense.member_id).cloned().unwrap_or_default(),
+        settlement_id.map(|id| id.to_string()).unwrap_or_default(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok, assert_some};
+    use futures_util::TryStreamExt;
+
+    async fn collect(stream: CsvStream) -> Vec<String> {
+        let csv = assert_ok!(stream.try_collect::<String>().await);
+        csv.split_terminator("\r\n").map(String::from).collect()
+    }
+
+    #[tokio::test]
+    async fn it_should_export_the_unsettled_expenses_as_csv() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let mut settled = vec![ctx.with_expense_of(&mut group, 10.0, admin).await];
+        ctx.settle(&mut group, &mut settled).await;
+        let first = ctx.with_expense_of(&mut group, 12.5, member.id).await;
+        let second = ctx.with_expense_of(&mut group, 7.0, admin).await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: member.id,
+            settlement_id: None,
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_expenses(req).await;
+        // then
+        let lines = collect(assert_ok!(resp)).await;
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "expense_id,date,title,amount,member_id,member,settlement_id"
+        );
+        let first = assert_some!(lines.iter().find(|l| l.starts_with(&first.id.to_string())));
+        assert!(first.contains(",12.50,"));
+        assert!(first.contains(&String::from(member.name.clone())));
+        let second = assert_some!(lines.iter().find(|l| l.starts_with(&second.id.to_string())));
+        assert!(second.contains(",7.00,"));
+        assert!(second.ends_with(','));
+    }
+
+    #[tokio::test]
+    async fn it_should_export_the_expenses_of_a_settlement_across_pages() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let mut expenses = Vec::new();
+        for _ in 0..PAGE_SIZE + 2 {
+            expenses.push(ctx.with_expense_of(&mut group, 1.0, admin).await);
+        }
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: admin,
+            settlement_id: Some(settlement.id),
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_expenses(req).await;
+        // then
+        let lines = collect(assert_ok!(resp)).await;
+        assert_eq!(lines.len(), PAGE_SIZE as usize + 3);
+        assert!(lines[1..]
+            .iter()
+            .all(|line| line.ends_with(&settlement.id.to_string())));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_settlement_does_not_exist() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+            settlement_id: Some(Uuid::new_v4()),
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_expenses(req).await;
+        // then
+        let err = assert_err!(resp.map(|_| ()));
+        match err {
+            ExportError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: user.id,
+            settlement_id: None,
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_expenses(req).await;
+        // then
+        let err = assert_err!(resp.map(|_| ()));
+        match err {
+            ExportError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::ExportLedgerRequest;
+use crate::domain::{LedgerAccounts, LedgerEntry, LedgerFormat};
+use anyhow::Context;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Renders the books of the requesting member for the whole group history.
+pub async fn export(
+    data: ExportLedgerRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<String, ExportError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(ExportError::Unexpected)?;
+    let group = match group {
+        Some(group) => {
+            if !group.is_member(&data.user_id) {
+                return Err(ExportError::Unauthorized("User is not a member."));
+            }
+            group
+        }
+        None => return Err(ExportError::NotFound("Group not found.")),
+    };
+    let format = LedgerFormat::try_from(data.format).map_err(ExportError::Validation)?;
+    let accounts = LedgerAccounts::new(
+        format,
+        data.expense_account,
+        data.group_account,
+        data.cash_account,
+        data.currency,
+    )
+    .map_err(ExportError::Validation)?;
+
+    let expenses = store
+        .expenses()
+        .get_expenses(&group.id, None, None)
+        .await
+        .context("Failed to fetch expenses.")
+        .map_err(ExportError::Unexpected)?;
+    let settlements = store
+        .settlements()
+        .get_settlements(&group.id)
+        .await
+        .context("Failed to get settlements.")
+        .map_err(ExportError::Unexpected)?;
+
+    let names = group
+        .members
+        .iter()
+        .map(|m| (m.id, String::from(m.name.clone())))
+        .collect::<HashMap<Uuid, String>>();
+    let name = |id: &Uuid| {
+        names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| "Former member".to_string())
+    };
+    // settled expenses are split between the members at the time of the settlement
+    let participants = settlements
+        .iter()
+        .flat_map(|s| {
+            let count = s.balances.iter().filter(|b| b.share > 0.0).count();
+            s.expense_ids.iter().map(move |id| (*id, count))
+        })
+        .collect::<HashMap<Uuid, usize>>();
+
+    let mut entries = expenses
+        .iter()
+        .map(|expense| {
+            LedgerEntry::from_expense(
+                expense,
+                &data.user_id,
+                participants
+                    .get(&expense.id)
+                    .copied()
+                    .unwrap_or(group.members.len()),
+                name(&expense.member_id),
+                &accounts,
+            )
+        })
+        .collect::<Vec<_>>();
+    for settlement in settlements.iter() {
+        for transaction in settlement.transactions.iter() {
+            let other = match transaction.from == data.user_id {
+                true => name(&transaction.to),
+                false => name(&transaction.from),
+            };
+            entries.extend(LedgerEntry::from_transaction(
+                settlement,
+                transaction,
+                &data.user_id,
+                other,
+                &accounts,
+            ));
+        }
+    }
+    Ok(format.render(&mut entries, &accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok};
+
+    fn request(group_id: Uuid, user_id: Uuid, format: &str) -> ExportLedgerRequest {
+        ExportLedgerRequest {
+            group_id,
+            user_id,
+            format: format.to_string(),
+            expense_account: None,
+            group_account: Some("Assets:Friends".to_string()),
+            cash_account: None,
+            currency: Some("CHF".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_render_the_member_view_of_the_group() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, admin).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_member(&mut group).await;
+        ctx.with_expense_of(&mut group, 9.0, admin).await;
+        // when
+        let resp = ctx
+            .group()
+            .export_ledger(request(group.id, member.id, "ledger"))
+            .await;
+        // then
+        let journal = assert_ok!(resp);
+        // settled expense split in 2, then expense split in 3
+        assert!(journal.contains("    Expenses:Koru  15.00 CHF\n    Assets:Friends  -15.00 CHF\n"));
+        assert!(journal.contains("    Expenses:Koru  3.00 CHF\n    Assets:Friends  -3.00 CHF\n"));
+        assert!(journal.contains("    Assets:Friends  15.00 CHF\n    Assets:Cash  -15.00 CHF\n"));
+        assert!(journal.contains("Settlement payment to "));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_for_an_unknown_format() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        // when
+        let resp = ctx
+            .group()
+            .export_ledger(request(group.id, group.admin_id, "gnucash"))
+            .await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            ExportError::Validation(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Validation, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        // when
+        let resp = ctx
+            .group()
+            .export_ledger(request(group.id, user.id, "beancount"))
+            .await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            ExportError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
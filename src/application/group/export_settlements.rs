@@ -0,0 +1,218 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ExportError;
+use crate::domain::usecases::group::{CsvStream, ExportRequest};
+use crate::domain::Settlement;
+use crate::utils::csv;
+use anyhow::Context;
+use futures_util::{stream, StreamExt};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const HEADER: [&str; 10] = [
+    "settlement_id",
+    "label",
+    "start_date",
+    "end_date",
+    "from_id",
+    "from",
+    "to_id",
+    "to",
+    "amount",
+    "paid",
+];
+
+/// Exports the settlements of a group, one CSV row per transaction.
+///
+/// The `settlement_id` filter selects a single settlement, otherwise the date filters
+/// select the settlements ended within the period.
+pub async fn export(
+    data: ExportRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<CsvStream, ExportError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(ExportError::Unexpected)?;
+    // check user is a member of the group
+    let group = match group {
+        Some(group) => {
+            if !group.is_member(&data.user_id) {
+                return Err(ExportError::Unauthorized("User is not a member."));
+            }
+            group
+        }
+        None => return Err(ExportError::NotFound("Group not found.")),
+    };
+    let settlements = match &data.settlement_id {
+        Some(stl_id) => {
+            let settlement = store
+                .settlements()
+                .find(stl_id)
+                .await
+                .context("Failed to find settlement.")
+                .map_err(ExportError::Unexpected)?;
+            match settlement {
+                Some(settlement) if settlement.group_id == group.id => vec![settlement],
+                _ => return Err(ExportError::NotFound("Settlement not found.")),
+            }
+        }
+        None => store
+            .settlements()
+            .get_settlements(&data.group_id)
+            .await
+            .context("Failed to get settlements.")
+            .map_err(ExportError::Unexpected)?
+            .into_iter()
+            .filter(|s| data.from.map_or(true, |from| s.end_date > from))
+            .filter(|s| data.to.map_or(true, |to| s.end_date <= to))
+            .sorted_by_key(|s| s.end_date)
+            .collect(),
+    };
+
+    let members = group
+        .members
+        .into_iter()
+        .map(|m| (m.id, String::from(m.name)))
+        .collect::<HashMap<Uuid, String>>();
+    let rows = stream::iter(settlements).map(move |settlement| Ok(rows(settlement, &members)));
+    Ok(stream::once(async { Ok(csv::record(&HEADER)) })
+        .chain(rows)
+        .boxed_local())
+}
+
+fn rows(settlement: Settlement, members: &HashMap<Uuid, String>) -> String {
+    let settlement_fields = [
+        settlement.id.to_string(),
+        settlement.label.map(String::from).unwrap_or_default(),
+        settlement
+            .start_date
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_default(),
+        settlement.end_date.to_rfc3339(),
+    ];
+    if settlement.transactions.is_empty() {
+        return csv::record(&[settlement_fields.to_vec(), vec![String::new(); 6]].concat());
+    }
+    settlement
+        .transactions
+        .iter()
+        .map(|transaction| {
+            let name = |id: &Uuid| members.get(id).cloned().unwrap_or_default();
+            let transaction_fields = vec![
+                transaction.from.to_string(),
+                name(&transaction.from),
+                transaction.to.to_string(),
+                name(&transaction.to),
+                format!("{:.2}", f32::from(transaction.amount)),
+                transaction.paid.to_string(),
+            ];
+            csv::record(&[settlement_fields.to_vec(), transaction_fields].concat())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok};
+    use futures_util::TryStreamExt;
+
+    async fn collect(stream: CsvStream) -> Vec<String> {
+        let csv = assert_ok!(stream.try_collect::<String>().await);
+        csv.split_terminator("\r\n").map(String::from).collect()
+    }
+
+    #[tokio::test]
+    async fn it_should_export_one_row_per_settlement_transaction() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, admin).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, admin).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: member.id,
+            settlement_id: Some(settlement.id),
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_settlements(req).await;
+        // then
+        let lines = collect(assert_ok!(resp)).await;
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "settlement_id,label,start_date,end_date,from_id,from,to_id,to,amount,paid"
+        );
+        assert!(lines[1].starts_with(&settlement.id.to_string()));
+        assert!(lines[1].contains(&String::from(member.name.clone())));
+        assert!(lines[1].ends_with(",15.00,false"));
+    }
+
+    #[tokio::test]
+    async fn it_should_export_all_settlements_without_filters() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_member(&mut group).await;
+        for amount in [30.0, 10.0] {
+            let mut expenses = vec![ctx.with_expense_of(&mut group, amount, admin).await];
+            ctx.settle(&mut group, &mut expenses).await;
+        }
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: admin,
+            settlement_id: None,
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_settlements(req).await;
+        // then
+        let lines = collect(assert_ok!(resp)).await;
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].ends_with(",15.00,false"));
+        assert!(lines[2].ends_with(",5.00,false"));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_settlement_is_not_in_the_group() {
+        // given
+        let ctx = TestContext::new();
+        let mut other = ctx.with_group().await;
+        let other_admin = other.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut other, 30.0, other_admin).await];
+        let settlement = ctx.settle(&mut other, &mut expenses).await;
+        let group = ctx.with_group().await;
+        let req = ExportRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+            settlement_id: Some(settlement.id),
+            from: None,
+            to: None,
+        };
+        // when
+        let resp = ctx.group().export_settlements(req).await;
+        // then
+        let err = assert_err!(resp.map(|_| ()));
+        match err {
+            ExportError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetActivityError;
+use crate::domain::usecases::dto::dtos::{ActivityDto, ActivityFeedDto};
+use crate::domain::usecases::group::GetActivityRequest;
+use crate::domain::{Event, GroupEvent, GroupEventKind};
+use anyhow::Context;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const ACTIVITY_PAGE_SIZE: u32 = 20;
+const MAX_ACTIVITY_PAGE_SIZE: u32 = 100;
+
+pub async fn get(
+    data: GetActivityRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<ActivityFeedDto, GetActivityError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")?
+        .ok_or(GetActivityError::NotFound("Group not found."))?;
+    if !group.is_member(&data.user_id) {
+        return Err(GetActivityError::Unauthorized("User is not a member."));
+    }
+    let limit = match data.limit {
+        None => ACTIVITY_PAGE_SIZE,
+        Some(limit) if (1..=MAX_ACTIVITY_PAGE_SIZE).contains(&limit) => limit,
+        Some(_) => {
+            return Err(GetActivityError::Validation(
+                "The limit should be between 1 and 100.",
+            ))
+        }
+    };
+    let before = match data.before {
+        None => None,
+        Some(id) => match store
+            .events()
+            .find(&id)
+            .await
+            .context("Failed to fetch event.")?
+        {
+            Some(Event::Group(event)) if event.group_id == group.id => Some((event.event_date, id)),
+            _ => return Err(GetActivityError::Validation("Unknown cursor.")),
+        },
+    };
+    // one more event than the page holds tells whether there is a next page
+    let mut events = store
+        .events()
+        .get_group_event_page(&group.id, before, limit + 1)
+        .await
+        .context("Failed to fetch group events.")?;
+    let next = if events.len() > limit as usize {
+        events.truncate(limit as usize);
+        events.last().map(|e| e.id)
+    } else {
+        None
+    };
+
+    // former members are named after their account, when it still exists
+    let mut names: HashMap<Uuid, String> = group
+        .members
+        .into_iter()
+        .map(|m| (m.id, String::from(m.name)))
+        .collect();
+    for id in events.iter().flat_map(mentioned_ids) {
+        if names.contains_key(&id) {
+            continue;
+        }
+        if let Some(user) = store
+            .users()
+            .find(&id)
+            .await
+            .context("Failed to fetch user.")?
+        {
+            names.insert(id, String::from(user.name));
+        }
+    }
+
+    Ok(ActivityFeedDto {
+        activities: events
+            .into_iter()
+            .map(|e| ActivityDto::from(e, &names))
+            .collect(),
+        next,
+    })
+}
+
+/// The members the event is about, starting with the one who did it.
+fn mentioned_ids(event: &GroupEvent) -> Vec<Uuid> {
+    let mut ids = vec![event.member_id];
+    match &event.event {
+        GroupEventKind::Settled { transactions, .. } => {
+            ids.extend(transactions.iter().flat_map(|t| [t.from, t.to]))
+        }
+        GroupEventKind::OwnershipTransferred { new_admin_id } => ids.push(*new_admin_id),
+        GroupEventKind::MemberRemoved { member_id } => ids.push(*member_id),
+        _ => {}
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::dto::dtos::ActivityKindDto;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_none, assert_ok, assert_some};
+
+    fn activity_request(group_id: Uuid, user_id: Uuid) -> GetActivityRequest {
+        GetActivityRequest {
+            group_id,
+            user_id,
+            before: None,
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_tell_the_group_activity_with_member_names() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let expense = ctx.with_expense_of(&mut group, 42.0, member.id).await;
+
+        // when
+        let resp = ctx
+            .group()
+            .get_activity(activity_request(group.id, member.id))
+            .await;
+
+        // then
+        let feed = assert_ok!(resp);
+        assert_eq!(feed.activities.len(), 3);
+        assert_none!(feed.next);
+        let admin = ctx.get_user(&group.admin_id).await;
+        let created = assert_some!(feed
+            .activities
+            .iter()
+            .find(|a| matches!(a.activity, ActivityKindDto::GroupCreated { .. })));
+        assert_eq!(created.member.id, admin.id);
+        assert_eq!(created.member.name, String::from(admin.name.clone()));
+        let added = assert_some!(feed
+            .activities
+            .iter()
+            .find(|a| matches!(a.activity, ActivityKindDto::ExpenseCreated { .. })));
+        assert_eq!(added.member.name, String::from(member.name.clone()));
+        assert_eq!(
+            added.summary,
+            format!(
+                "{} added \"{}\" (42.00)",
+                String::from(member.name),
+                String::from(expense.title)
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_page_the_group_activity() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        for _ in 0..4 {
+            ctx.with_expense(&mut group, admin).await;
+        }
+        let mut req = activity_request(group.id, admin);
+        req.limit = Some(3);
+
+        // when
+        let first = assert_ok!(ctx.group().get_activity(req.clone()).await);
+        req.before = first.next;
+        let second = assert_ok!(ctx.group().get_activity(req).await);
+
+        // then
+        assert_eq!(first.activities.len(), 3);
+        assert_eq!(first.next, Some(first.activities[2].id));
+        assert_eq!(second.activities.len(), 2);
+        assert_none!(second.next);
+        assert!(second
+            .activities
+            .iter()
+            .all(|a| a.date <= first.activities[2].date
+                && first.activities.iter().all(|f| f.id != a.id)));
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_users_who_are_not_members() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+
+        // when
+        let resp = ctx
+            .group()
+            .get_activity(activity_request(group.id, user.id))
+            .await;
+
+        // then
+        let err = assert_err!(resp);
+        assert!(matches!(err, GetActivityError::Unauthorized(_)));
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_cursors_of_other_groups_and_invalid_limits() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let mut other = ctx.with_group().await;
+        let other_admin = other.admin_id;
+        ctx.with_expense(&mut other, other_admin).await;
+        let other_event = assert_some!(ctx.last_stored_event());
+        let mut req = activity_request(group.id, group.admin_id);
+        req.before = Some(other_event.id);
+
+        // when
+        let cursor = ctx.group().get_activity(req.clone()).await;
+        req.before = None;
+        req.limit = Some(0);
+        let limit = ctx.group().get_activity(req).await;
+
+        // then
+        assert!(matches!(
+            assert_err!(cursor),
+            GetActivityError::Validation("Unknown cursor.")
+        ));
+        assert!(matches!(
+            assert_err!(limit),
+            GetActivityError::Validation(_)
+        ));
+    }
+}
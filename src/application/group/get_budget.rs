@@ -0,0 +1,144 @@
+use crate::application::budget::consumption;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetBudgetError;
+use crate::domain::usecases::dto::dtos::BudgetDto;
+use crate::domain::usecases::group::GetBudgetRequest;
+use crate::utils::date;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn get(
+    data: GetBudgetRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<BudgetDto, GetBudgetError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(GetBudgetError::Unexpected)?;
+    match group {
+        Some(group) => {
+            if !group.is_member(&data.user_id) {
+                return Err(GetBudgetError::Unauthorized("User is not a member."));
+            }
+            let budget = store
+                .budgets()
+                .find(&group.id)
+                .await
+                .context("Failed to fetch budget.")
+                .map_err(GetBudgetError::Unexpected)?
+                .map_or_else(|| Err(GetBudgetError::NotFound("Budget not found.")), Ok)?;
+            let consumption = consumption(&budget, &date::now(), store)
+                .await
+                .map_err(GetBudgetError::Unexpected)?;
+            Ok(BudgetDto::from(&budget, consumption))
+        }
+        None => Err(GetBudgetError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_none, assert_ok, assert_some};
+
+    #[tokio::test]
+    async fn it_should_report_the_spending_since_the_last_settlement() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 70.0, member.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_expense_of(&mut group, 15.0, member.id).await;
+        ctx.with_expense_of(&mut group, 35.0, admin).await;
+        ctx.with_budget(&group, 200.0, "settlement").await;
+        let req = GetBudgetRequest {
+            group_id: group.id,
+            user_id: member.id,
+        };
+        // when
+        let resp = ctx.group().get_budget(req).await;
+        // then
+        let budget = assert_ok!(resp);
+        assert_eq!(budget.period, "settlement");
+        assert_eq!(assert_some!(budget.start), settlement.end_date);
+        assert_eq!(budget.spent, 50.0);
+        assert_eq!(budget.remaining, 150.0);
+        assert_eq!(budget.percentage, 25.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_report_the_spending_of_the_current_month() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_expense_of(&mut group, 60.0, admin).await;
+        ctx.with_budget(&group, 50.0, "monthly").await;
+        let req = GetBudgetRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+        };
+        // when
+        let resp = ctx.group().get_budget(req).await;
+        // then
+        let budget = assert_ok!(resp);
+        assert_eq!(budget.period, "monthly");
+        assert_some!(budget.start);
+        assert_eq!(budget.spent, 60.0);
+        assert_eq!(budget.remaining, -10.0);
+        assert_eq!(budget.percentage, 120.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_group_has_no_budget() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        assert_none!(ctx.find_budget(&group.id).await);
+        let req = GetBudgetRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+        };
+        // when
+        let resp = ctx.group().get_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            GetBudgetError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        ctx.with_budget(&group, 50.0, "monthly").await;
+        let req = GetBudgetRequest {
+            group_id: group.id,
+            user_id: user.id,
+        };
+        // when
+        let resp = ctx.group().get_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            GetBudgetError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetDebtError;
+use crate::domain::usecases::dto::dtos::DebtDto;
+use crate::domain::usecases::group::GetDebtRequest;
+use crate::domain::Debt;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn get(
+    request: GetDebtRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<DebtDto, GetDebtError> {
+    if request.user_id == request.other_id {
+        return Err(GetDebtError::Validation(
+            "Cannot compute debts with yourself.",
+        ));
+    }
+    let user = store
+        .users()
+        .find(&request.user_id)
+        .await
+        .context("Failed to fetch user.")
+        .map_err(GetDebtError::Unexpected)?
+        .ok_or(GetDebtError::Unauthenticated())?;
+    let other = store
+        .users()
+        .find(&request.other_id)
+        .await
+        .context("Failed to fetch user.")
+        .map_err(GetDebtError::Unexpected)?
+        .ok_or(GetDebtError::NotFound("User not found."))?;
+    let settlements = store
+        .settlements()
+        .get_unpaid_settlements(&user.id, &other.id)
+        .await
+        .context("Failed to fetch settlements.")
+        .map_err(GetDebtError::Unexpected)?;
+    let debt = Debt::new(user.id, other.id, settlements);
+    Ok(DebtDto::from(&debt, user, other))
+}
@@ -1,6 +1,6 @@
 use crate::application::store::MultiRepository;
 use crate::domain::errors::GetSettlementsError;
-use crate::domain::usecases::dto::dtos::{SettlementDto, TransactionDto};
+use crate::domain::usecases::dto::dtos::SettlementDto;
 use crate::domain::usecases::group::GetSettlementsRequest;
 use anyhow::Context;
 use itertools::Itertools;
@@ -36,12 +36,7 @@ pub async fn get(
 
     Ok(settlements
         .into_iter()
-        .map(|settlement| SettlementDto {
-            id: settlement.id,
-            start_date: settlement.start_date,
-            end_date: settlement.end_date,
-            transactions: TransactionDto::from_vec(settlement.transactions, &members),
-        })
+        .map(|settlement| SettlementDto::from(settlement, &members))
         .sorted_by(|a, b| {
             b.end_date
                 .partial_cmp(&a.end_date)
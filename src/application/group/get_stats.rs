@@ -0,0 +1,139 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetStatsError;
+use crate::domain::usecases::dto::dtos::StatsDto;
+use crate::domain::usecases::group::GetStatsRequest;
+use anyhow::Context;
+use std::sync::Arc;
+
+/// Number of largest expenses returned with the stats.
+const LARGEST_EXPENSES: u32 = 5;
+
+pub async fn get(
+    data: GetStatsRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<StatsDto, GetStatsError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(GetStatsError::Unexpected)?;
+    match group {
+        Some(group) => {
+            if !group.is_member(&data.user_id) {
+                return Err(GetStatsError::Unauthorized("User is not a member."));
+            }
+            let stats = store
+                .expenses()
+                .get_stats(
+                    &data.group_id,
+                    data.from.as_ref(),
+                    data.to.as_ref(),
+                    LARGEST_EXPENSES,
+                )
+                .await
+                .context("Failed to fetch expense stats.")
+                .map_err(GetStatsError::Unexpected)?;
+            Ok(StatsDto::from(stats, &group.members, data.from, data.to))
+        }
+        None => Err(GetStatsError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_aggregate_the_group_expenses() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let admin = group.admin_id;
+        for amount in [5.0, 40.0, 12.0] {
+            ctx.with_expense_of(&mut group, amount, admin).await;
+        }
+        for amount in [1.0, 2.0, 3.0, 60.0] {
+            ctx.with_expense_of(&mut group, amount, member.id).await;
+        }
+        let req = GetStatsRequest {
+            group_id: group.id,
+            user_id: member.id,
+            from: None,
+            to: None,
+        };
+
+        // when
+        let resp = ctx.group().get_stats(req).await;
+
+        // then
+        let stats = assert_ok!(resp);
+        assert_eq!(stats.total, 123.0);
+        assert_eq!(stats.count, 7);
+        assert_eq!(stats.members.len(), 2);
+        assert_eq!(stats.members[0].member.id, member.id);
+        assert_eq!(stats.members[0].total, 66.0);
+        assert_eq!(stats.members[0].count, 4);
+        assert_eq!(stats.members[1].member.id, admin);
+        assert_eq!(stats.members[1].total, 57.0);
+        assert_eq!(stats.months.len(), 1);
+        assert_eq!(stats.months[0].total, 123.0);
+        assert_eq!(stats.months[0].count, 7);
+        assert_eq!(
+            stats.largest.iter().map(|e| e.amount).collect::<Vec<_>>(),
+            vec![60.0, 40.0, 12.0, 5.0, 3.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_a_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        let req = GetStatsRequest {
+            group_id: group.id,
+            user_id: user.id,
+            from: None,
+            to: None,
+        };
+
+        // when
+        let resp = ctx.group().get_stats(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            GetStatsError::Unauthorized(_) => {}
+            e => unreachable!("{}", format!("Expected Unauthorized error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_group_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = GetStatsRequest {
+            group_id: Uuid::new_v4(),
+            user_id: user.id,
+            from: None,
+            to: None,
+        };
+
+        // when
+        let resp = ctx.group().get_stats(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            GetStatsError::NotFound(_) => {}
+            e => unreachable!("{}", format!("Expected NotFound error, got {:?}", e)),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::GetSummaryError;
+use crate::domain::usecases::dto::dtos::{GroupSummaryDto, SummaryDto};
+use crate::domain::usecases::group::GetSummaryRequest;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn get(
+    request: GetSummaryRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<SummaryDto, GetSummaryError> {
+    let summaries = store
+        .groups()
+        .get_user_summaries(&request.user_id)
+        .await
+        .context("Failed to get user group summaries.")
+        .map_err(GetSummaryError::Unexpected)?;
+
+    let res = summaries
+        .into_iter()
+        .map(|summary| GroupSummaryDto::from(summary, &request.user_id))
+        .collect();
+    Ok(SummaryDto::from(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use claim::{assert_err, assert_ok};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_return_the_unsettled_balance_of_each_group() {
+        // given
+        let ctx = TestContext::new();
+        let mut group_1 = ctx.with_group().await;
+        let member = ctx.with_member(&mut group_1).await;
+        let admin = group_1.admin_id;
+        ctx.with_expense_of(&mut group_1, 30.0, admin).await;
+        ctx.with_expense_of(&mut group_1, 10.0, member.id).await;
+        let group_2 = ctx.with_group().await;
+
+        // when
+        let resp = ctx
+            .group()
+            .get_summary(GetSummaryRequest { user_id: admin })
+            .await;
+
+        // then
+        let resp = assert_ok!(resp);
+        assert_eq!(resp.groups.len(), 1);
+        let grp = &resp.groups[0];
+        assert_eq!(grp.id, group_1.id);
+        assert_eq!(grp.balance, 10.0);
+        assert_eq!(grp.outstanding, 0.0);
+        assert_eq!(grp.transactions.len(), 0);
+        assert_eq!(resp.total, 10.0);
+        let resp = ctx
+            .group()
+            .get_summary(GetSummaryRequest {
+                user_id: group_2.admin_id,
+            })
+            .await;
+        let resp = assert_ok!(resp);
+        assert_eq!(resp.groups.len(), 1);
+        assert_eq!(resp.groups[0].balance, 0.0);
+        assert_eq!(resp.total, 0.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_the_outstanding_transactions_of_the_user() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let other = ctx.with_member(&mut group).await;
+        let admin = group.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, admin).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_expense_of(&mut group, 12.0, other.id).await;
+
+        // when
+        let resp = ctx
+            .group()
+            .get_summary(GetSummaryRequest { user_id: member.id })
+            .await;
+
+        // then
+        let resp = assert_ok!(resp);
+        assert_eq!(resp.groups.len(), 1);
+        let grp = &resp.groups[0];
+        assert_eq!(grp.balance, -4.0);
+        assert_eq!(grp.outstanding, -10.0);
+        assert_eq!(grp.transactions.len(), 1);
+        assert_eq!(grp.name, "My group");
+        assert_eq!(grp.transactions[0].from.id, member.id);
+        assert_eq!(grp.transactions[0].from.name, String::from(member.name));
+        assert_eq!(grp.transactions[0].to.id, admin);
+        assert_eq!(
+            grp.transactions[0].to.name,
+            String::from(ctx.get_user(&admin).await.name)
+        );
+        assert_eq!(resp.total, -14.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_paid_transactions() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let admin = group.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, admin).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        ctx.pay_transactions(&settlement.id);
+
+        // when
+        let resp = ctx
+            .group()
+            .get_summary(GetSummaryRequest { user_id: member.id })
+            .await;
+
+        // then
+        let resp = assert_ok!(resp);
+        assert_eq!(resp.groups[0].transactions.len(), 0);
+        assert_eq!(resp.groups[0].outstanding, 0.0);
+        assert_eq!(resp.total, 0.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthenticated_when_user_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let _ = ctx.with_group().await;
+
+        // when
+        let resp = ctx
+            .group()
+            .get_summary(GetSummaryRequest {
+                user_id: Uuid::new_v4(),
+            })
+            .await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            GetSummaryError::Unauthenticated() => {}
+            e => unreachable!("{}", format!("Expected Unauthenticated error, got {:?}", e)),
+        }
+    }
+}
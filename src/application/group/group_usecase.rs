@@ -1,38 +1,64 @@
 use crate::application::event_bus::EventBus;
+use crate::application::group::backup_group::backup;
 use crate::application::group::change_member_color::change_color;
 use crate::application::group::create_expense::create as create_expense;
 use crate::application::group::create_group::create;
+use crate::application::group::delete_budget::delete as delete_budget;
 use crate::application::group::delete_expense::delete as delete_expense;
 use crate::application::group::delete_group::delete;
+use crate::application::group::export_expenses::export as export_expenses;
+use crate::application::group::export_ledger::export as export_ledger;
+use crate::application::group::export_settlements::export as export_settlements;
 use crate::application::group::generate_token::generate;
+use crate::application::group::get_activity::get as get_activity;
+use crate::application::group::get_budget::get as get_budget;
+use crate::application::group::get_debt::get as get_debt;
 use crate::application::group::get_expenses::get as get_expenses;
 use crate::application::group::get_group::get as get_group;
 use crate::application::group::get_groups::get as get_groups;
 use crate::application::group::get_settlements::get as get_settlements;
+use crate::application::group::get_stats::get as get_stats;
+use crate::application::group::get_summary::get as get_summary;
+use crate::application::group::import_expenses::import as import_expenses;
 use crate::application::group::join_group::join;
+use crate::application::group::restore_group::restore;
+use crate::application::group::revert_settlement::revert;
+use crate::application::group::set_budget::set as set_budget;
 use crate::application::group::settle::execute;
+use crate::application::group::settle_debt::settle as settle_debt;
+use crate::application::group::transfer_ownership::transfer;
 use crate::application::group::update_expense::update;
 use crate::application::store::MultiRepository;
 use crate::application::user::UserUsecase;
 use crate::domain::errors::{
-    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteExpenseError,
-    DeleteGroupError, GenerateGroupTokenError, GetExpensesError, GetGroupError, GetGroupsError,
-    GetSettlementsError, JoinGroupError, SettlementError, UpdateExpenseError,
+    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteBudgetError,
+    DeleteExpenseError, DeleteGroupError, ExportError, GenerateGroupTokenError, GetActivityError,
+    GetBudgetError, GetDebtError, GetExpensesError, GetGroupError, GetGroupsError,
+    GetSettlementsError, GetStatsError, GetSummaryError, ImportExpensesError, JoinGroupError,
+    RestoreGroupError, RevertSettlementError, SetBudgetError, SettleDebtError, SettlementError,
+    TransferOwnershipError, UpdateExpenseError,
 };
 use crate::domain::usecases::dto::dtos::{
-    DetailedGroupDto, ExpenseDto, GroupDto, SettlementDto, TransactionDto,
+    ActivityFeedDto, BudgetDto, DebtDto, DetailedGroupDto, ExpenseDto, GroupDto, ImportReportDto,
+    SettlementDto, StatsDto, SummaryDto,
 };
 use crate::domain::usecases::group::{
-    ChangeMemberColorRequest, CreateExpenseRequest, CreateGroupRequest, DeleteExpenseRequest,
-    DeleteGroupRequest, GenerateGroupTokenRequest, GetExpensesRequest, GetGroupRequest,
-    GetGroupsRequest, GetSettlementsRequest, GroupUseCase, JoinGroupRequest, SettleRequest,
-    UpdateExpenseRequest,
+    BackupGroupRequest, ChangeMemberColorRequest, CreateExpenseRequest, CreateGroupRequest,
+    CsvStream, DeleteBudgetRequest, DeleteExpenseRequest, DeleteGroupRequest, ExportLedgerRequest,
+    ExportRequest, GenerateGroupTokenRequest, GetActivityRequest, GetBudgetRequest, GetDebtRequest,
+    GetExpensesRequest, GetGroupRequest, GetGroupsRequest, GetSettlementsRequest, GetStatsRequest,
+    GetSummaryRequest, GroupUseCase, ImportExpensesRequest, JoinGroupRequest, RestoreGroupRequest,
+    RevertSettlementRequest, SetBudgetRequest, SettleDebtRequest, SettleRequest,
+    TransferOwnershipRequest, UpdateExpenseRequest,
 };
 use crate::domain::usecases::user::UserUseCase;
-use crate::domain::GroupEventKind::{ExpenseDeleted, GroupDeleted};
-use crate::domain::{Event, Expense, Group, Settlement, TokenGenerator};
+use crate::domain::GroupEventKind::{ExpenseDeleted, GroupDeleted, MemberRemoved};
+use crate::domain::{
+    Budget, Debt, Event, Expense, Group, GroupBackup, RestoredGroup, Settlement, TokenGenerator,
+};
 use anyhow::Context;
 use async_trait::async_trait;
+use chrono::Duration;
 use itertools::Itertools;
 use log::warn;
 use std::sync::Arc;
@@ -43,6 +69,7 @@ pub struct GroupUsecase<Store: MultiRepository> {
     event_bus: Arc<dyn EventBus>,
     token_generator: Arc<dyn TokenGenerator>,
     users: Arc<UserUsecase<Store>>,
+    revert_window: Duration,
 }
 
 impl<Store: MultiRepository> GroupUsecase<Store> {
@@ -51,12 +78,14 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
         event_bus: Arc<dyn EventBus>,
         token_svc: Arc<dyn TokenGenerator>,
         users: Arc<UserUsecase<Store>>,
+        revert_window: Duration,
     ) -> Self {
         Self {
             store,
             event_bus,
             token_generator: token_svc,
             users,
+            revert_window,
         }
     }
 
@@ -71,6 +100,17 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
         Ok(())
     }
 
+    async fn finalize_revert_settlement(
+        &self,
+        group: &Group,
+        settlement: &Settlement,
+        expenses: &[Expense],
+    ) -> Result<(), anyhow::Error> {
+        self.delete_settlement(settlement, group, expenses).await?;
+        self.publish(group).await;
+        Ok(())
+    }
+
     async fn finalize_expense(
         &self,
         group: &Group,
@@ -81,7 +121,27 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
         Ok(())
     }
 
-    async fn finalize(&self, group: &Group) -> Result<(), anyhow::Error> {
+    async fn finalize_import(
+        &self,
+        group: &Group,
+        expenses: &[Expense],
+    ) -> Result<(), anyhow::Error> {
+        self.save_import(group, expenses).await?;
+        self.publish(group).await;
+        Ok(())
+    }
+
+    async fn finalize_budget(
+        &self,
+        group: &Group,
+        budget: Option<&Budget>,
+    ) -> Result<(), anyhow::Error> {
+        self.save_budget(group, budget).await?;
+        self.publish(group).await;
+        Ok(())
+    }
+
+    pub(crate) async fn finalize(&self, group: &Group) -> Result<(), anyhow::Error> {
         self.save(group).await?;
         self.publish(group).await;
         Ok(())
@@ -133,6 +193,32 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
         Ok(())
     }
 
+    async fn save_import(&self, group: &Group, expenses: &[Expense]) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        self.store
+            .groups()
+            .save(&mut tx, group)
+            .await
+            .context("Failed to insert group")?;
+        for expense in expenses {
+            self.store
+                .expenses()
+                .save(&mut tx, expense)
+                .await
+                .context("Failed to insert expense")?;
+        }
+        self.store
+            .events()
+            .save(
+                &mut tx,
+                &group.events.iter().cloned().map(Event::Group).collect_vec(),
+            )
+            .await
+            .context("Failed to insert event")?;
+        self.store.commit(tx.into_inner()).await?;
+        Ok(())
+    }
+
     async fn save_settlement(
         &self,
         settlement: &Settlement,
@@ -169,6 +255,137 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
         Ok(())
     }
 
+    async fn delete_settlement(
+        &self,
+        settlement: &Settlement,
+        group: &Group,
+        expenses: &[Expense],
+    ) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        self.store
+            .settlements()
+            .delete(&mut tx, &settlement.id)
+            .await
+            .context("Failed to delete settlement")?;
+        self.store
+            .groups()
+            .save(&mut tx, group)
+            .await
+            .context("Failed to insert group")?;
+        for expense in expenses {
+            self.store
+                .expenses()
+                .save(&mut tx, expense)
+                .await
+                .context("Failed to insert expense")?;
+        }
+        self.store
+            .events()
+            .save(
+                &mut tx,
+                &group.events.iter().cloned().map(Event::Group).collect_vec(),
+            )
+            .await
+            .context("Failed to insert event")?;
+        self.store.commit(tx.into_inner()).await?;
+        Ok(())
+    }
+
+    async fn save_debt(&self, debt: &Debt) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        for settlement in &debt.settlements {
+            self.store
+                .settlements()
+                .update_transactions(&mut tx, settlement)
+                .await
+                .context("Failed to update settlement transactions")?;
+        }
+        self.store.commit(tx.into_inner()).await?;
+        Ok(())
+    }
+
+    async fn save_budget(
+        &self,
+        group: &Group,
+        budget: Option<&Budget>,
+    ) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        match budget {
+            Some(budget) => self
+                .store
+                .budgets()
+                .save(&mut tx, budget)
+                .await
+                .context("Failed to insert budget")?,
+            None => self
+                .store
+                .budgets()
+                .delete(&mut tx, &group.id)
+                .await
+                .context("Failed to delete budget")?,
+        }
+        self.store
+            .events()
+            .save(
+                &mut tx,
+                &group.events.iter().cloned().map(Event::Group).collect_vec(),
+            )
+            .await
+            .context("Failed to insert event")?;
+        self.store.commit(tx.into_inner()).await?;
+        Ok(())
+    }
+
+    /// Saves a restored group with its history, which is not published: members were
+    /// already notified of it on the original group.
+    async fn save_restore(&self, restored: &RestoredGroup) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        let group = &restored.group;
+        self.store
+            .groups()
+            .save(&mut tx, group)
+            .await
+            .context("Failed to insert group")?;
+        for member in group.members.iter() {
+            self.store
+                .members()
+                .save(&mut tx, member)
+                .await
+                .context("Failed to insert member")?;
+        }
+        for expense in restored.expenses.iter() {
+            self.store
+                .expenses()
+                .save(&mut tx, expense)
+                .await
+                .context("Failed to insert expense")?;
+        }
+        for settlement in restored.settlements.iter() {
+            self.store
+                .settlements()
+                .save(&mut tx, settlement)
+                .await
+                .context("Failed to insert settlement")?;
+        }
+        if let Some(budget) = &restored.budget {
+            self.store
+                .budgets()
+                .save(&mut tx, budget)
+                .await
+                .context("Failed to insert budget")?;
+        }
+        self.store
+            .events()
+            .save(
+                &mut tx,
+                &group.events.iter().cloned().map(Event::Group).collect_vec(),
+            )
+            .await
+            .context("Failed to insert event")?;
+        self.store.commit(tx.into_inner()).await?;
+        Ok(())
+    }
+
     async fn save(&self, group: &Group) -> Result<(), anyhow::Error> {
         let mut tx = self.store.tx().await?;
         if group.events.iter().any(|e| matches!(e.event, GroupDeleted)) {
@@ -190,6 +407,15 @@ impl<Store: MultiRepository> GroupUsecase<Store> {
                     .await
                     .context("Failed to insert member")?;
             }
+            for event in group.events.iter() {
+                if let MemberRemoved { member_id } = event.event {
+                    self.store
+                        .members()
+                        .delete(&mut tx, &group.id, &member_id)
+                        .await
+                        .context("Failed to delete member")?;
+                }
+            }
         }
         self.store
             .events()
@@ -258,6 +484,19 @@ impl<Store: MultiRepository> GroupUseCase for GroupUsecase<Store> {
             .map_err(DeleteGroupError::Unexpected)?;
         Ok(())
     }
+    async fn transfer_ownership(
+        &self,
+        request: TransferOwnershipRequest,
+    ) -> Result<(), TransferOwnershipError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(TransferOwnershipError::Unauthenticated());
+        }
+        let group = transfer(request, self.store.clone()).await?;
+        self.finalize(&group)
+            .await
+            .map_err(TransferOwnershipError::Unexpected)?;
+        Ok(())
+    }
     async fn get_group(&self, request: GetGroupRequest) -> Result<DetailedGroupDto, GetGroupError> {
         if !self.users.is_valid_user(&request.user_id).await? {
             return Err(GetGroupError::Unauthenticated());
@@ -270,6 +509,12 @@ impl<Store: MultiRepository> GroupUseCase for GroupUsecase<Store> {
         }
         get_groups(request, self.store.clone()).await
     }
+    async fn get_summary(&self, request: GetSummaryRequest) -> Result<SummaryDto, GetSummaryError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(GetSummaryError::Unauthenticated());
+        }
+        get_summary(request, self.store.clone()).await
+    }
     async fn get_expenses(
         &self,
         request: GetExpensesRequest,
@@ -288,6 +533,21 @@ impl<Store: MultiRepository> GroupUseCase for GroupUsecase<Store> {
         }
         get_settlements(request, self.store.clone()).await
     }
+    async fn get_activity(
+        &self,
+        request: GetActivityRequest,
+    ) -> Result<ActivityFeedDto, GetActivityError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(GetActivityError::Unauthenticated());
+        }
+        get_activity(request, self.store.clone()).await
+    }
+    async fn get_stats(&self, request: GetStatsRequest) -> Result<StatsDto, GetStatsError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(GetStatsError::Unauthenticated());
+        }
+        get_stats(request, self.store.clone()).await
+    }
     async fn create_expense(
         &self,
         request: CreateExpenseRequest,
@@ -335,11 +595,111 @@ impl<Store: MultiRepository> GroupUseCase for GroupUsecase<Store> {
         self.finalize_settlement(&group, &settlement, &expenses)
             .await
             .map_err(SettlementError::Unexpected)?;
-        Ok(SettlementDto {
-            id: settlement.id,
-            start_date: settlement.start_date,
-            end_date: settlement.end_date,
-            transactions: TransactionDto::from_vec(settlement.transactions, &group.members),
-        })
+        Ok(SettlementDto::from(settlement, &group.members))
+    }
+    async fn revert_settlement(
+        &self,
+        request: RevertSettlementRequest,
+    ) -> Result<(), RevertSettlementError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(RevertSettlementError::Unauthenticated());
+        }
+        let (group, settlement, expenses) =
+            revert(request, self.store.clone(), self.revert_window).await?;
+        self.finalize_revert_settlement(&group, &settlement, &expenses)
+            .await
+            .map_err(RevertSettlementError::Unexpected)?;
+        Ok(())
+    }
+    async fn get_debt(&self, request: GetDebtRequest) -> Result<DebtDto, GetDebtError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(GetDebtError::Unauthenticated());
+        }
+        get_debt(request, self.store.clone()).await
+    }
+    async fn settle_debt(&self, request: SettleDebtRequest) -> Result<(), SettleDebtError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(SettleDebtError::Unauthenticated());
+        }
+        let debt = settle_debt(request, self.store.clone()).await?;
+        self.save_debt(&debt)
+            .await
+            .map_err(SettleDebtError::Unexpected)?;
+        Ok(())
+    }
+    async fn set_budget(&self, request: SetBudgetRequest) -> Result<BudgetDto, SetBudgetError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(SetBudgetError::Unauthenticated());
+        }
+        let (group, budget, dto) = set_budget(request, self.store.clone()).await?;
+        self.finalize_budget(&group, Some(&budget))
+            .await
+            .map_err(SetBudgetError::Unexpected)?;
+        Ok(dto)
+    }
+    async fn delete_budget(&self, request: DeleteBudgetRequest) -> Result<(), DeleteBudgetError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(DeleteBudgetError::Unauthenticated());
+        }
+        let group = delete_budget(request, self.store.clone()).await?;
+        self.finalize_budget(&group, None)
+            .await
+            .map_err(DeleteBudgetError::Unexpected)?;
+        Ok(())
+    }
+    async fn get_budget(&self, request: GetBudgetRequest) -> Result<BudgetDto, GetBudgetError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(GetBudgetError::Unauthenticated());
+        }
+        get_budget(request, self.store.clone()).await
+    }
+    async fn export_expenses(&self, request: ExportRequest) -> Result<CsvStream, ExportError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(ExportError::Unauthenticated());
+        }
+        export_expenses(request, self.store.clone()).await
+    }
+    async fn export_settlements(&self, request: ExportRequest) -> Result<CsvStream, ExportError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(ExportError::Unauthenticated());
+        }
+        export_settlements(request, self.store.clone()).await
+    }
+    async fn export_ledger(&self, request: ExportLedgerRequest) -> Result<String, ExportError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(ExportError::Unauthenticated());
+        }
+        export_ledger(request, self.store.clone()).await
+    }
+    async fn import_expenses(
+        &self,
+        request: ImportExpensesRequest,
+    ) -> Result<ImportReportDto, ImportExpensesError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(ImportExpensesError::Unauthenticated());
+        }
+        let (group, expenses, report) = import_expenses(request, self.store.clone()).await?;
+        if !expenses.is_empty() {
+            self.finalize_import(&group, &expenses)
+                .await
+                .map_err(ImportExpensesError::Unexpected)?;
+        }
+        Ok(report)
+    }
+    async fn backup_group(&self, request: BackupGroupRequest) -> Result<GroupBackup, ExportError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(ExportError::Unauthenticated());
+        }
+        backup(request, self.store.clone()).await
+    }
+    async fn restore_group(&self, request: RestoreGroupRequest) -> Result<Uuid, RestoreGroupError> {
+        if !self.users.is_valid_user(&request.user_id).await? {
+            return Err(RestoreGroupError::Unauthenticated());
+        }
+        let restored = restore(request, self.store.clone()).await?;
+        self.save_restore(&restored)
+            .await
+            .map_err(RestoreGroupError::Unexpected)?;
+        Ok(restored.group.id)
     }
 }
@@ -0,0 +1,241 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::ImportExpensesError;
+use crate::domain::usecases::dto::dtos::ImportReportDto;
+use crate::domain::usecases::group::ImportExpensesRequest;
+use crate::domain::{Expense, Group, ImportFormat, ImportLineError};
+use anyhow::Context;
+use std::sync::Arc;
+
+/// Reads and validates the expenses of an export, returning the group with its
+/// `ExpenseCreated` events and the expenses to save.
+///
+/// Nothing is returned to save when running dry or when any line is invalid.
+pub async fn import(
+    data: ImportExpensesRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<(Group, Vec<Expense>, ImportReportDto), ImportExpensesError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(ImportExpensesError::Unexpected)?;
+    match group {
+        Some(mut group) => {
+            if !group.is_admin(&data.user_id) {
+                return Err(ImportExpensesError::Unauthorized("User is not admin."));
+            }
+            let format =
+                ImportFormat::try_from(data.format).map_err(ImportExpensesError::Validation)?;
+            if data.members.values().any(|id| !group.is_member(id)) {
+                return Err(ImportExpensesError::Validation(
+                    "Mapped users should be members of the group.",
+                ));
+            }
+            let lines = format
+                .read(&data.content)
+                .map_err(ImportExpensesError::Validation)?;
+
+            let mut expenses = Vec::new();
+            let mut errors = Vec::new();
+            for line in lines {
+                let expense = line.and_then(|e| {
+                    let member = data.members.get(&e.paid_by).ok_or_else(|| {
+                        ImportLineError::new(
+                            e.line,
+                            format!("No group member is mapped to {}.", e.paid_by),
+                        )
+                    })?;
+                    group
+                        .add_expense_at(e.title, e.amount, *member, e.date)
+                        .map_err(|err| ImportLineError::new(e.line, err.to_string()))
+                });
+                match expense {
+                    Ok(expense) => expenses.push(expense),
+                    Err(error) => errors.push(error),
+                }
+            }
+            let report = ImportReportDto::from(data.dry_run, expenses.len(), &errors);
+            if data.dry_run || !errors.is_empty() {
+                group.events.clear();
+                expenses.clear();
+            }
+            Ok((group, expenses, report))
+        }
+        None => Err(ImportExpensesError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use chrono::{TimeZone, Utc};
+    use claim::{assert_err, assert_ok, assert_some};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    const CONTENT: &str = "date,title,amount,paid_by\n\
+        2023-01-02,Groceries,30,Alice\n\
+        2023-01-03,Dinner,42.5,Bob\n";
+
+    fn request(group: &Group, members: &[(&str, Uuid)], content: &str) -> ImportExpensesRequest {
+        ImportExpensesRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+            format: "generic".to_string(),
+            content: content.to_string(),
+            members: members
+                .iter()
+                .map(|(name, id)| (name.to_string(), *id))
+                .collect::<HashMap<_, _>>(),
+            dry_run: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_import_every_expense_with_its_date() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = request(
+            &group,
+            &[("Alice", group.admin_id), ("Bob", member.id)],
+            CONTENT,
+        );
+        // when
+        let resp = ctx.group().import_expenses(req).await;
+        // then
+        let report = assert_ok!(resp);
+        assert_eq!(report.imported, 2);
+        assert!(report.errors.is_empty());
+        let expenses = ctx
+            .store()
+            .expenses()
+            .get_unsettled_expenses(&group.id)
+            .await
+            .unwrap();
+        assert_eq!(expenses.len(), 2);
+        let dinner = assert_some!(expenses.iter().find(|e| e.member_id == member.id));
+        assert_eq!(f32::from(dinner.amount), 42.5);
+        assert_eq!(
+            dinner.created_at,
+            Utc.with_ymd_and_hms(2023, 1, 3, 12, 0, 0).unwrap()
+        );
+        let event = assert_some!(ctx.last_stored_event());
+        match event.event {
+            InnerEventKind::ExpenseCreated { .. } => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect event expected ExpenseCreated, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_report_errors_per_line_without_importing_anything() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let content = format!(
+            "{}2023-01-04,,10,Alice\n2023-01-05,Taxi,-3,Alice\n",
+            CONTENT
+        );
+        let req = request(&group, &[("Alice", group.admin_id)], &content);
+        // when
+        let resp = ctx.group().import_expenses(req).await;
+        // then
+        let report = assert_ok!(resp);
+        assert_eq!(report.imported, 0);
+        let lines = report.errors.iter().map(|e| e.line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![3, 4, 5]);
+        assert_eq!(report.errors[0].error, "No group member is mapped to Bob.");
+        assert!(ctx
+            .store()
+            .expenses()
+            .get_unsettled_expenses(&group.id)
+            .await
+            .unwrap()
+            .is_empty());
+        let event = assert_some!(ctx.last_stored_event());
+        assert!(!matches!(
+            event.event,
+            InnerEventKind::ExpenseCreated { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_import_anything_on_a_dry_run() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut req = request(
+            &group,
+            &[("Alice", group.admin_id), ("Bob", member.id)],
+            CONTENT,
+        );
+        req.dry_run = true;
+        // when
+        let resp = ctx.group().import_expenses(req).await;
+        // then
+        let report = assert_ok!(resp);
+        assert!(report.dry_run);
+        assert_eq!(report.imported, 2);
+        assert!(ctx
+            .store()
+            .expenses()
+            .get_unsettled_expenses(&group.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_when_mapping_a_non_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        let req = request(
+            &group,
+            &[("Alice", group.admin_id), ("Bob", user.id)],
+            CONTENT,
+        );
+        // when
+        let resp = ctx.group().import_expenses(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            ImportExpensesError::Validation(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Validation, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut req = request(&group, &[("Alice", group.admin_id)], CONTENT);
+        req.user_id = member.id;
+        // when
+        let resp = ctx.group().import_expenses(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            ImportExpensesError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
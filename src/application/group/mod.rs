@@ -1,16 +1,32 @@
+mod backup_group;
 mod change_member_color;
 mod create_expense;
 mod create_group;
+mod delete_budget;
 mod delete_expense;
 mod delete_group;
+mod export_expenses;
+mod export_ledger;
+mod export_settlements;
 mod generate_token;
+mod get_activity;
+mod get_budget;
+mod get_debt;
 mod get_expenses;
 mod get_group;
 mod get_groups;
 mod get_settlements;
+mod get_stats;
+mod get_summary;
 mod group_usecase;
+mod import_expenses;
 mod join_group;
+mod restore_group;
+mod revert_settlement;
+mod set_budget;
 mod settle;
+mod settle_debt;
+mod transfer_ownership;
 mod update_expense;
 
 pub use group_usecase::GroupUsecase;
@@ -0,0 +1,207 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::RestoreGroupError;
+use crate::domain::usecases::group::RestoreGroupRequest;
+use crate::domain::{GroupBackup, RestoredGroup};
+use anyhow::Context;
+use std::sync::Arc;
+
+/// Rebuilds a group from a backup document, its only member being the user restoring it.
+///
+/// Members are matched by email, so a document listing any other member is refused: it could
+/// add registered users to a group without their consent. They join the restored group again
+/// through the usual invitation.
+pub async fn restore(
+    data: RestoreGroupRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<RestoredGroup, RestoreGroupError> {
+    let backup = GroupBackup::read(data.document).map_err(RestoreGroupError::Validation)?;
+    let user = store
+        .users()
+        .find(&data.user_id)
+        .await
+        .context("Failed to fetch user.")
+        .map_err(RestoreGroupError::Unexpected)?
+        .ok_or(RestoreGroupError::Unauthenticated())?;
+    let email = String::from(user.email.clone()).to_lowercase();
+    if backup
+        .members
+        .iter()
+        .any(|m| m.email.to_lowercase() != email)
+    {
+        return Err(RestoreGroupError::Unauthorized(
+            "Only a group whose administrator is the only member can be restored.",
+        ));
+    }
+    let restored = backup
+        .restore(&[user])
+        .map_err(RestoreGroupError::Validation)?;
+    if !restored.group.is_admin(&data.user_id) {
+        return Err(RestoreGroupError::Unauthorized(
+            "Only the group administrator can restore it.",
+        ));
+    }
+    Ok(restored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::{BackupGroupRequest, GroupUseCase};
+    use claim::{assert_err, assert_ok, assert_some};
+
+    #[tokio::test]
+    async fn it_should_restore_a_backup_with_new_ids() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 30.0, admin).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let expense = ctx.with_expense_of(&mut group, 12.0, admin).await;
+        let backup = assert_ok!(
+            ctx.group()
+                .backup_group(BackupGroupRequest {
+                    group_id: group.id,
+                    user_id: admin,
+                })
+                .await
+        );
+        let published = ctx.last_published_event();
+        let req = RestoreGroupRequest {
+            user_id: admin,
+            document: assert_ok!(serde_json::to_value(&backup)),
+        };
+        // when
+        let resp = ctx.group().restore_group(req).await;
+        // then
+        let group_id = assert_ok!(resp);
+        // the history is not notified again
+        assert_eq!(ctx.last_published_event(), published);
+        assert_ne!(group_id, group.id);
+        let restored = assert_some!(ctx.find_group(&group_id).await);
+        assert_eq!(restored.admin_id, admin);
+        assert_eq!(restored.members.len(), 1);
+        let expenses = ctx
+            .store()
+            .expenses()
+            .get_expenses(&group_id, None, None)
+            .await
+            .unwrap();
+        assert_eq!(expenses.len(), 2);
+        assert!(expenses.iter().all(|e| e.id != expense.id));
+        let settlements = ctx
+            .store()
+            .settlements()
+            .get_settlements(&group_id)
+            .await
+            .unwrap();
+        assert_eq!(settlements.len(), 1);
+        assert_ne!(settlements[0].id, settlement.id);
+        let settled = expenses.iter().find(|e| e.settled).unwrap();
+        assert_eq!(settlements[0].expense_ids, vec![settled.id]);
+        let events = ctx
+            .store()
+            .events()
+            .get_group_events(&group_id)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), backup.events.len());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_the_backup_lists_another_user() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let other = ctx.with_user().await;
+        let backup = assert_ok!(
+            ctx.group()
+                .backup_group(BackupGroupRequest {
+                    group_id: group.id,
+                    user_id: admin,
+                })
+                .await
+        );
+        let mut document = assert_ok!(serde_json::to_value(&backup));
+        let mut forged = document["members"][0].clone();
+        forged["email"] = String::from(other.email.clone()).into();
+        forged["is_admin"] = false.into();
+        document["members"].as_array_mut().unwrap().push(forged);
+        let groups = ctx.store().groups().fetch_all_groups().await.unwrap();
+        let req = RestoreGroupRequest {
+            user_id: admin,
+            document,
+        };
+        // when
+        let resp = ctx.group().restore_group(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            RestoreGroupError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+        assert_eq!(
+            ctx.store().groups().fetch_all_groups().await.unwrap().len(),
+            groups.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_for_an_unknown_version() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = RestoreGroupRequest {
+            user_id: user.id,
+            document: serde_json::json!({ "version": 99 }),
+        };
+        // when
+        let resp = ctx.group().restore_group(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            RestoreGroupError::Validation(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Validation, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_the_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let backup = assert_ok!(
+            ctx.group()
+                .backup_group(BackupGroupRequest {
+                    group_id: group.id,
+                    user_id: admin,
+                })
+                .await
+        );
+        let req = RestoreGroupRequest {
+            user_id: member.id,
+            document: assert_ok!(serde_json::to_value(&backup)),
+        };
+        // when
+        let resp = ctx.group().restore_group(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            RestoreGroupError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+    }
+}
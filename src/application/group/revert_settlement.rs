@@ -0,0 +1,259 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::RevertSettlementError;
+use crate::domain::usecases::group::RevertSettlementRequest;
+use crate::domain::{Expense, Group, Settlement};
+use anyhow::Context;
+use chrono::Duration;
+use std::sync::Arc;
+
+pub async fn revert(
+    data: RevertSettlementRequest,
+    store: Arc<impl MultiRepository>,
+    revert_window: Duration,
+) -> Result<(Group, Settlement, Vec<Expense>), RevertSettlementError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(RevertSettlementError::Unexpected)?;
+    match group {
+        Some(mut group) => {
+            let settlements = store
+                .settlements()
+                .get_settlements(&group.id)
+                .await
+                .context("Failed to fetch settlements.")
+                .map_err(RevertSettlementError::Unexpected)?;
+            let expense_ids = settlements
+                .iter()
+                .find(|s| s.id == data.settlement_id)
+                .map_or(Vec::new(), |s| s.expense_ids.clone());
+            let mut expenses = store
+                .expenses()
+                .get_expenses_by_id(&expense_ids)
+                .await
+                .context("Failed to fetch expenses.")
+                .map_err(RevertSettlementError::Unexpected)?;
+            let settlement = group.revert_settlement(
+                data.settlement_id,
+                settlements,
+                &mut expenses,
+                data.user_id,
+                revert_window,
+            )?;
+            Ok((group, settlement, expenses))
+        }
+        None => Err(RevertSettlementError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use claim::{assert_err, assert_none, assert_ok, assert_some};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_revert_the_last_settlement_when_user_is_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let admin = group.admin_id;
+        let mut expenses = vec![
+            ctx.with_expense_of(&mut group, 10.0, member.id).await,
+            ctx.with_expense_of(&mut group, 30.0, admin).await,
+        ];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: settlement.id,
+            user_id: admin,
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        assert_ok!(resp);
+        assert_none!(ctx.find_settlement(&settlement.id).await);
+        let grp = ctx.get_group(&group.id).await;
+        assert_eq!(grp.settlement_ids.len(), 0);
+        assert_eq!(grp.expense_ids.len(), 2);
+        for exp in &expenses {
+            assert!(!ctx.get_expense(&exp.id).await.settled);
+        }
+        let event = assert_some!(ctx.last_stored_event());
+        match event.event {
+            InnerEventKind::SettlementReverted { id, .. } => assert_eq!(id, settlement.id),
+            e => unreachable!(
+                "{}",
+                format!(
+                    "Got incorrect event expected SettlementReverted, got: {:?}",
+                    e
+                )
+            ),
+        }
+        let event_id = assert_some!(ctx.last_published_event());
+        assert_eq!(event.id, event_id);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_conflict_when_settlement_is_not_the_last_one() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, member.id).await];
+        let first = ctx.settle(&mut group, &mut expenses).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 20.0, member.id).await];
+        let _second = ctx.settle(&mut group, &mut expenses).await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: first.id,
+            user_id: group.admin_id,
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::Conflict(_) => {}
+            e => unreachable!("{}", format!("Expected Conflict error, got {:?}", e)),
+        }
+        assert_some!(ctx.find_settlement(&first.id).await);
+        assert_eq!(ctx.get_group(&group.id).await.settlement_ids.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_conflict_when_revert_window_is_over() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, member.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: settlement.id,
+            user_id: group.admin_id,
+        };
+
+        // when
+        let resp = revert(req, ctx.store(), Duration::zero()).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::Conflict(_) => {}
+            e => unreachable!("{}", format!("Expected Conflict error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_conflict_when_a_transaction_is_paid() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, member.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        ctx.pay_transactions(&settlement.id);
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: settlement.id,
+            user_id: group.admin_id,
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::Conflict(_) => {}
+            e => unreachable!("{}", format!("Expected Conflict error, got {:?}", e)),
+        }
+        assert_some!(ctx.find_settlement(&settlement.id).await);
+        assert!(ctx.get_expense(&expenses[0].id).await.settled);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, member.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: settlement.id,
+            user_id: member.id,
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::Unauthorized(_) => {}
+            e => unreachable!("{}", format!("Expected Unauthorized error, got {:?}", e)),
+        }
+        assert_some!(ctx.find_settlement(&settlement.id).await);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_settlement_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: Uuid::new_v4(),
+            user_id: group.admin_id,
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::NotFound(_) => {}
+            e => unreachable!("{}", format!("Expected NotFound error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthenticated_when_user_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, member.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+        let req = RevertSettlementRequest {
+            group_id: group.id,
+            settlement_id: settlement.id,
+            user_id: Uuid::new_v4(),
+        };
+
+        // when
+        let resp = ctx.group().revert_settlement(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            RevertSettlementError::Unauthenticated() => {}
+            e => unreachable!("{}", format!("Expected Unauthenticated error, got {:?}", e)),
+        }
+    }
+}
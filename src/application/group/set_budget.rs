@@ -0,0 +1,157 @@
+use crate::application::budget::consumption;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::SetBudgetError;
+use crate::domain::usecases::dto::dtos::BudgetDto;
+use crate::domain::usecases::group::SetBudgetRequest;
+use crate::domain::{Budget, Group};
+use crate::utils::date;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn set(
+    data: SetBudgetRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<(Group, Budget, BudgetDto), SetBudgetError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(SetBudgetError::Unexpected)?;
+    match group {
+        Some(mut group) => {
+            let budget = group.set_budget(data.user_id, data.amount, data.period)?;
+            let consumption = consumption(&budget, &date::now(), store)
+                .await
+                .map_err(SetBudgetError::Unexpected)?;
+            let dto = BudgetDto::from(&budget, consumption);
+            Ok((group, budget, dto))
+        }
+        None => Err(SetBudgetError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use crate::domain::BudgetPeriod;
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use claim::{assert_err, assert_ok, assert_some};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_set_the_group_budget_when_user_is_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_expense_of(&mut group, 30.0, admin).await;
+        let req = SetBudgetRequest {
+            group_id: group.id,
+            user_id: admin,
+            amount: 120.0,
+            period: "monthly".to_string(),
+        };
+        // when
+        let resp = ctx.group().set_budget(req).await;
+        // then
+        let budget = assert_ok!(resp);
+        assert_eq!(budget.amount, 120.0);
+        assert_eq!(budget.spent, 30.0);
+        assert_eq!(budget.remaining, 90.0);
+        assert_eq!(budget.percentage, 25.0);
+        let stored = assert_some!(ctx.find_budget(&group.id).await);
+        assert_eq!(f32::from(stored.amount), 120.0);
+        assert_eq!(stored.period, BudgetPeriod::Monthly);
+        let event = assert_some!(ctx.last_stored_event());
+        match event.event {
+            InnerEventKind::BudgetChanged {
+                amount: Some(_),
+                period: Some(_),
+                ..
+            } => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect event expected BudgetChanged, got: {:?}", e)
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = SetBudgetRequest {
+            group_id: group.id,
+            user_id: member.id,
+            amount: 120.0,
+            period: "monthly".to_string(),
+        };
+        // when
+        let resp = ctx.group().set_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            SetBudgetError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+        assert!(ctx.find_budget(&group.id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_for_an_invalid_budget() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        for (amount, period) in [(0.0, "monthly"), (-5.0, "settlement"), (10.0, "weekly")] {
+            let req = SetBudgetRequest {
+                group_id: group.id,
+                user_id: group.admin_id,
+                amount,
+                period: period.to_string(),
+            };
+            // when
+            let resp = ctx.group().set_budget(req).await;
+            // then
+            let err = assert_err!(resp);
+            match err {
+                SetBudgetError::Validation(_) => {}
+                e => unreachable!(
+                    "{}",
+                    format!("Got incorrect error expected Validation, got: {:?}", e)
+                ),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_group_does_not_exist() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = SetBudgetRequest {
+            group_id: Uuid::new_v4(),
+            user_id: group.admin_id,
+            amount: 120.0,
+            period: "monthly".to_string(),
+        };
+        // when
+        let resp = ctx.group().set_budget(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            SetBudgetError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -32,7 +32,8 @@ pub async fn execute(
                     .map_err(SettlementError::Unexpected)?,
                 None => None,
             };
-            let settlement = group.settle(&mut expenses, last_settlement, data.user_id)?;
+            let settlement =
+                group.settle(&mut expenses, last_settlement, data.user_id, data.label)?;
             Ok((group, settlement, expenses))
         }
         None => Err(SettlementError::NotFound("Group not found.")),
@@ -83,6 +84,7 @@ mod tests {
         let req = SettleRequest {
             group_id: group.id,
             user_id: admin,
+            label: None,
         };
 
         // when
@@ -106,6 +108,11 @@ mod tests {
         let mut exps = stl.expense_ids.clone();
         exps.sort();
         assert_eq!(exps, expenses);
+        assert_eq!(stl.expense_count, 10);
+        assert_eq!(stl.total, 250.0);
+        assert_eq!(stl.balances.len(), 4);
+        assert_eq!(stl_dto.expense_count, 10);
+        assert_eq!(stl_dto.total, 250.0);
         assert_eq!(stl.transactions.len(), 3);
         assert_eq!(stl.transactions.get(1).unwrap().from, member_1.id);
         assert_eq!(stl.transactions.get(1).unwrap().to, admin);
@@ -193,6 +200,7 @@ mod tests {
         let req = SettleRequest {
             group_id: group.id,
             user_id: member_1.id,
+            label: None,
         };
 
         // when
@@ -257,6 +265,7 @@ mod tests {
         let req = SettleRequest {
             group_id: group.id,
             user_id: Uuid::new_v4(),
+            label: None,
         };
 
         // when
@@ -322,6 +331,7 @@ mod tests {
         let req = SettleRequest {
             group_id: group.id,
             user_id: user.id,
+            label: None,
         };
 
         // when
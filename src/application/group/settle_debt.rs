@@ -0,0 +1,195 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::SettleDebtError;
+use crate::domain::usecases::group::SettleDebtRequest;
+use crate::domain::Debt;
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn settle(
+    request: SettleDebtRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<Debt, SettleDebtError> {
+    if request.user_id == request.other_id {
+        return Err(SettleDebtError::Validation(
+            "Cannot settle debts with yourself.",
+        ));
+    }
+    let other = store
+        .users()
+        .find(&request.other_id)
+        .await
+        .context("Failed to fetch user.")
+        .map_err(SettleDebtError::Unexpected)?;
+    if other.is_none() {
+        return Err(SettleDebtError::NotFound("User not found."));
+    }
+    let settlements = store
+        .settlements()
+        .get_unpaid_settlements(&request.user_id, &request.other_id)
+        .await
+        .context("Failed to fetch settlements.")
+        .map_err(SettleDebtError::Unexpected)?;
+    let mut debt = Debt::new(request.user_id, request.other_id, settlements);
+    debt.settle()?;
+    Ok(debt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::{GetDebtRequest, GroupUseCase};
+    use claim::{assert_err, assert_ok, assert_some};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_mark_the_transactions_of_all_shared_groups_paid() {
+        // given
+        let ctx = TestContext::new();
+        let mut group_1 = ctx.with_group().await;
+        let member = ctx.with_member(&mut group_1).await;
+        let admin = group_1.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut group_1, 30.0, admin).await];
+        let first = ctx.settle(&mut group_1, &mut expenses).await;
+        // the member is owed money in another group both users belong to
+        let mut group_2 = ctx.with_group().await;
+        let other = group_2.admin_id;
+        ctx.join(&mut group_2, &ctx.get_user(&admin).await).await;
+        ctx.join(&mut group_2, &ctx.get_user(&member.id).await)
+            .await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group_2, 30.0, member.id).await];
+        let second = ctx.settle(&mut group_2, &mut expenses).await;
+        let debt = ctx
+            .group()
+            .get_debt(GetDebtRequest {
+                user_id: admin,
+                other_id: member.id,
+            })
+            .await;
+        let debt = assert_ok!(debt);
+        assert_eq!(debt.net, 5.0);
+        assert_eq!(debt.transactions.len(), 2);
+        let payment = assert_some!(debt.payment);
+        assert_eq!(payment.from.id, member.id);
+        assert_eq!(payment.to.id, admin);
+        assert_eq!(payment.amount, 5.0);
+        let req = SettleDebtRequest {
+            user_id: member.id,
+            other_id: admin,
+        };
+
+        // when
+        let resp = ctx.group().settle_debt(req).await;
+
+        // then
+        assert_ok!(resp);
+        let first = assert_some!(ctx.find_settlement(&first.id).await);
+        assert_eq!(first.transactions.len(), 1);
+        assert!(first.transactions[0].paid);
+        assert_eq!(f32::from(first.transactions[0].amount), 15.0);
+        let second = assert_some!(ctx.find_settlement(&second.id).await);
+        assert_eq!(second.transactions.len(), 2);
+        for tr in &second.transactions {
+            assert_eq!(tr.to, member.id);
+            assert_eq!(f32::from(tr.amount), 10.0);
+            // only the transaction between the two users is paid
+            assert_eq!(tr.paid, tr.from == admin);
+        }
+        assert!(second.transactions.iter().any(|tr| tr.from == other));
+        let debt = ctx
+            .group()
+            .get_debt(GetDebtRequest {
+                user_id: admin,
+                other_id: member.id,
+            })
+            .await;
+        let debt = assert_ok!(debt);
+        assert!(debt.transactions.is_empty());
+        assert_eq!(debt.net, 0.0);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_there_is_nothing_to_settle() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = SettleDebtRequest {
+            user_id: group.admin_id,
+            other_id: member.id,
+        };
+
+        // when
+        let resp = ctx.group().settle_debt(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            SettleDebtError::NotFound(_) => {}
+            e => unreachable!("{}", format!("Expected NotFound error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_other_user_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = SettleDebtRequest {
+            user_id: group.admin_id,
+            other_id: Uuid::new_v4(),
+        };
+
+        // when
+        let resp = ctx.group().settle_debt(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            SettleDebtError::NotFound(_) => {}
+            e => unreachable!("{}", format!("Expected NotFound error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_when_settling_with_oneself() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = SettleDebtRequest {
+            user_id: group.admin_id,
+            other_id: group.admin_id,
+        };
+
+        // when
+        let resp = ctx.group().settle_debt(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            SettleDebtError::Validation(_) => {}
+            e => unreachable!("{}", format!("Expected Validation error, got {:?}", e)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthenticated_when_user_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let req = SettleDebtRequest {
+            user_id: Uuid::new_v4(),
+            other_id: group.admin_id,
+        };
+
+        // when
+        let resp = ctx.group().settle_debt(req).await;
+
+        // then
+        let resp = assert_err!(resp);
+        match resp {
+            SettleDebtError::Unauthenticated() => {}
+            e => unreachable!("{}", format!("Expected Unauthenticated error, got {:?}", e)),
+        }
+    }
+}
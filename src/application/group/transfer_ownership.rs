@@ -0,0 +1,120 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TransferOwnershipError;
+use crate::domain::usecases::group::TransferOwnershipRequest;
+use crate::domain::{Group, GroupActor};
+use anyhow::Context;
+use std::sync::Arc;
+
+pub async fn transfer(
+    data: TransferOwnershipRequest,
+    store: Arc<impl MultiRepository>,
+) -> Result<Group, TransferOwnershipError> {
+    let group = store
+        .groups()
+        .find(&data.group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(TransferOwnershipError::Unexpected)?;
+    match group {
+        Some(mut group) => {
+            group.transfer_ownership(GroupActor::Member(data.user_id), data.new_admin_id)?;
+            Ok(group)
+        }
+        None => Err(TransferOwnershipError::NotFound("Group not found.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::GroupUseCase;
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use claim::{assert_err, assert_ok, assert_some};
+
+    #[tokio::test]
+    async fn it_should_make_the_member_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let req = TransferOwnershipRequest {
+            group_id: group.id,
+            user_id: admin,
+            new_admin_id: member.id,
+        };
+        // when
+        let resp = ctx.group().transfer_ownership(req).await;
+        // then
+        assert_ok!(resp);
+        let group = ctx.get_group(&group.id).await;
+        assert!(group.is_admin(&member.id));
+        assert_eq!(group.admin().id, member.id);
+        assert!(group.is_member(&admin));
+        let event = assert_some!(ctx.last_stored_event());
+        match event.event {
+            InnerEventKind::OwnershipTransferred { new_admin_id, .. } => {
+                assert_eq!(new_admin_id, member.id)
+            }
+            e => unreachable!(
+                "{}",
+                format!(
+                    "Got incorrect event expected OwnershipTransferred, got: {:?}",
+                    e
+                )
+            ),
+        }
+        let event_id = assert_some!(ctx.last_published_event());
+        assert_eq!(event.id, event_id);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthorized_when_user_is_not_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = TransferOwnershipRequest {
+            group_id: group.id,
+            user_id: member.id,
+            new_admin_id: member.id,
+        };
+        // when
+        let resp = ctx.group().transfer_ownership(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            TransferOwnershipError::Unauthorized(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected Unauthorized, got: {:?}", e)
+            ),
+        }
+        assert!(ctx.get_group(&group.id).await.is_admin(&group.admin_id));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_not_found_when_new_admin_is_not_member() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let user = ctx.with_user().await;
+        let req = TransferOwnershipRequest {
+            group_id: group.id,
+            user_id: group.admin_id,
+            new_admin_id: user.id,
+        };
+        // when
+        let resp = ctx.group().transfer_ownership(req).await;
+        // then
+        let err = assert_err!(resp);
+        match err {
+            TransferOwnershipError::NotFound(_) => {}
+            e => unreachable!(
+                "{}",
+                format!("Got incorrect error expected NotFound, got: {:?}", e)
+            ),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod app;
 pub mod auth;
+pub mod budget;
 pub mod event_bus;
 pub mod group;
 #[cfg(feature = "notification")]
@@ -16,22 +17,26 @@ mod tests {
     use crate::application::group::GroupUsecase;
     use crate::application::notification::DeviceService;
     use crate::application::store::MultiRepository;
-    use crate::application::user::UserUsecase;
+    use crate::application::user::{AccountMailer, UserUsecase};
+    use crate::domain::mail::Mail;
     #[cfg(feature = "notification")]
     use crate::domain::notification::NotificationService;
-    use crate::domain::usecases::admin::AdminUseCase;
     use crate::domain::usecases::group::GroupUseCase;
     use crate::domain::usecases::user::UserUseCase;
     use crate::domain::UserRole::Administrator;
-    use crate::domain::{Event, Expense, Group, GroupMember, MemberColor, Settlement, User};
+    use crate::domain::{
+        Budget, Event, Expense, Group, GroupMember, Identity, MemberColor, Settlement, User,
+    };
     use crate::infrastructure::event_bus::direct_event_bus::DirectEventBus;
+    use crate::infrastructure::identity_provider::FakeIdentityProvider;
+    use crate::infrastructure::mailer::FakeMailer;
     #[cfg(feature = "notification")]
     use crate::infrastructure::notification_service::{FakeNotificationService, InnerNotification};
     use crate::infrastructure::services::credentials_hasher::FakeCredentialsHasher;
     use crate::infrastructure::store::mem::mem_store::InnerEvent;
     use crate::infrastructure::store::InMemoryStore;
     use crate::infrastructure::token_generator::FakeTokenGenerator;
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use itertools::Itertools;
     use rand::random;
     use secrecy::Secret;
@@ -47,7 +52,12 @@ mod tests {
         admin_uc: Arc<AdminUsecase<InMemoryStore>>,
         user_uc: Arc<UserUsecase<InMemoryStore>>,
         user_uc_no_auth: Arc<UserUsecase<InMemoryStore>>,
+        user_uc_verification: Arc<UserUsecase<InMemoryStore>>,
+        user_uc_identity: Arc<UserUsecase<InMemoryStore>>,
+        user_uc_two_factor: Arc<UserUsecase<InMemoryStore>>,
+        identity_provider: Arc<FakeIdentityProvider>,
         token_svc: Arc<FakeTokenGenerator>,
+        mailer: Arc<FakeMailer>,
     }
 
     impl TestContext {
@@ -56,29 +66,94 @@ mod tests {
             let event_bus = Arc::new(DirectEventBus::new());
             let auth_service = AuthService::new(store.clone(), FakeCredentialsHasher::new());
             let token_svc = Arc::new(FakeTokenGenerator::new());
+            let mailer = Arc::new(FakeMailer::new());
             #[cfg(feature = "pushy")]
             let device_service = Arc::new(DeviceService::new(store.clone()));
             let user_uc = Arc::new(UserUsecase::new(
                 store.clone(),
                 event_bus.clone(),
                 Some(auth_service),
+                None,
+                #[cfg(feature = "pushy")]
+                device_service.clone(),
+                Duration::hours(24),
+                1,
+                AccountMailer::new(mailer.clone(), "http://test".to_string()),
+                Duration::minutes(60),
+                None,
+            ));
+            let user_uc_verification = Arc::new(UserUsecase::new(
+                store.clone(),
+                event_bus.clone(),
+                Some(AuthService::new(
+                    store.clone(),
+                    FakeCredentialsHasher::new(),
+                )),
+                None,
                 #[cfg(feature = "pushy")]
                 device_service.clone(),
+                Duration::hours(24),
+                1,
+                AccountMailer::new(mailer.clone(), "http://test".to_string()),
+                Duration::minutes(60),
+                Some(Duration::hours(24)),
+            ));
+            let user_uc_two_factor = Arc::new(UserUsecase::new(
+                store.clone(),
+                event_bus.clone(),
+                Some(
+                    AuthService::new(store.clone(), FakeCredentialsHasher::new())
+                        .with_two_factor("koru".to_string(), &Secret::new("key".to_string())),
+                ),
+                None,
+                #[cfg(feature = "pushy")]
+                device_service.clone(),
+                Duration::hours(24),
+                1,
+                AccountMailer::new(mailer.clone(), "http://test".to_string()),
+                Duration::minutes(60),
+                None,
             ));
             let user_uc_no_auth = Arc::new(UserUsecase::new(
                 store.clone(),
                 event_bus.clone(),
                 None,
+                None,
+                #[cfg(feature = "pushy")]
+                device_service.clone(),
+                Duration::hours(24),
+                1,
+                AccountMailer::new(mailer.clone(), "http://test".to_string()),
+                Duration::minutes(60),
+                None,
+            ));
+            let identity_provider = Arc::new(FakeIdentityProvider::new());
+            let user_uc_identity = Arc::new(UserUsecase::new(
+                store.clone(),
+                event_bus.clone(),
+                None,
+                Some(identity_provider.clone()),
                 #[cfg(feature = "pushy")]
                 device_service,
+                Duration::hours(24),
+                1,
+                AccountMailer::new(mailer.clone(), "http://test".to_string()),
+                Duration::minutes(60),
+                None,
             ));
             let group_uc = Arc::new(GroupUsecase::new(
                 store.clone(),
                 event_bus.clone(),
                 token_svc.clone(),
                 user_uc.clone(),
+                Duration::hours(24),
+            ));
+            let admin_uc = Arc::new(AdminUsecase::new(
+                store.clone(),
+                event_bus.clone(),
+                user_uc.clone(),
+                group_uc.clone(),
             ));
-            let admin_uc = Arc::new(AdminUsecase::new(store.clone(), event_bus.clone()));
             Self {
                 store,
                 event_bus,
@@ -88,7 +163,12 @@ mod tests {
                 admin_uc,
                 user_uc,
                 user_uc_no_auth,
+                user_uc_verification,
+                user_uc_identity,
+                user_uc_two_factor,
+                identity_provider,
                 token_svc,
+                mailer,
             }
         }
 
@@ -100,7 +180,7 @@ mod tests {
             self.group_uc.clone()
         }
 
-        pub fn admin(&self) -> Arc<impl AdminUseCase> {
+        pub fn admin(&self) -> Arc<AdminUsecase<InMemoryStore>> {
             self.admin_uc.clone()
         }
 
@@ -112,6 +192,35 @@ mod tests {
             self.user_uc_no_auth.clone()
         }
 
+        /// Users registered through this one have to verify their email.
+        pub fn user_with_verification(&self) -> Arc<impl UserUseCase> {
+            self.user_uc_verification.clone()
+        }
+
+        /// Users log in through the fake identity provider with this one.
+        pub fn user_with_identity_provider(&self) -> Arc<impl UserUseCase> {
+            self.user_uc_identity.clone()
+        }
+
+        /// Users can enroll a second factor with this one.
+        pub fn user_with_two_factor(&self) -> Arc<impl UserUseCase> {
+            self.user_uc_two_factor.clone()
+        }
+
+        /// Sets the identity the fake provider asserts, rejecting the logins when `None`.
+        pub fn assert_identity(&self, identity: Option<Identity>) {
+            *self.identity_provider.identity.lock().unwrap() = identity;
+        }
+
+        pub fn sent_mails(&self) -> Vec<Mail> {
+            self.mailer.mails.lock().unwrap().clone()
+        }
+
+        /// Sends its mails through the fake mailer of the context.
+        pub fn account_mailer(&self) -> AccountMailer {
+            AccountMailer::new(self.mailer.clone(), "http://test".to_string())
+        }
+
         #[cfg(feature = "notification")]
         pub fn notification_svc(&self) -> Arc<dyn NotificationService> {
             self.notification_svc.clone()
@@ -204,8 +313,12 @@ mod tests {
         }
 
         pub async fn with_member(&self, group: &mut Group) -> GroupMember {
-            let mut tx = self.store.tx().await.unwrap();
             let user = self.with_user().await;
+            self.join(group, &user).await
+        }
+
+        pub async fn join(&self, group: &mut Group, user: &User) -> GroupMember {
+            let mut tx = self.store.tx().await.unwrap();
             let member = group
                 .add_member(
                     user.id,
@@ -287,7 +400,7 @@ mod tests {
 
         pub async fn settle(&self, group: &mut Group, expenses: &mut [Expense]) -> Settlement {
             let mut tx = self.store.tx().await.unwrap();
-            let settlement = group.settle(expenses, None, group.admin_id).unwrap();
+            let settlement = group.settle(expenses, None, group.admin_id, None).unwrap();
             self.store
                 .settlements()
                 .save(&mut tx, &settlement)
@@ -314,6 +427,14 @@ mod tests {
             settlement
         }
 
+        pub async fn with_budget(&self, group: &Group, amount: f32, period: &str) -> Budget {
+            let mut tx = self.store.tx().await.unwrap();
+            let budget = Budget::create(group.id, amount, period.to_string()).unwrap();
+            self.store.budgets().save(&mut tx, &budget).await.unwrap();
+            self.store.commit(tx.into_inner()).await.unwrap();
+            budget
+        }
+
         pub async fn get_user(&self, user_id: &Uuid) -> User {
             self.find_user(user_id).await.unwrap()
         }
@@ -343,10 +464,23 @@ mod tests {
             self.store.groups().find(group_id).await.unwrap()
         }
 
+        pub async fn find_budget(&self, group_id: &Uuid) -> Option<Budget> {
+            self.store.budgets().find(group_id).await.unwrap()
+        }
+
         pub async fn find_settlement(&self, settlement_id: &Uuid) -> Option<Settlement> {
             self.store.settlements().find(settlement_id).await.unwrap()
         }
 
+        pub fn pay_transactions(&self, settlement_id: &Uuid) {
+            let mut settlements = self.store.settlements.lock().unwrap();
+            if let Some(settlement) = settlements.get_mut(settlement_id) {
+                for tr in settlement.transactions.iter_mut() {
+                    tr.paid = true;
+                }
+            }
+        }
+
         pub async fn find_expense(&self, expense_id: &Uuid) -> Option<Expense> {
             self.store.expenses().find(expense_id).await.unwrap()
         }
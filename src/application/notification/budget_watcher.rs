@@ -0,0 +1,32 @@
+use crate::application::notification::check_budget::check_budget;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::EventHandlerError;
+use crate::domain::notification::NotificationService;
+use crate::domain::usecases::event_processor::EventProcessor;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct BudgetWatcher<Store: MultiRepository> {
+    store: Arc<Store>,
+    notification_svc: Arc<dyn NotificationService>,
+}
+
+impl<Store: MultiRepository> BudgetWatcher<Store> {
+    pub fn new(store: Arc<Store>, notification_svc: Arc<dyn NotificationService>) -> Self {
+        Self {
+            store,
+            notification_svc,
+        }
+    }
+}
+
+#[async_trait]
+impl<Store: MultiRepository> EventProcessor for BudgetWatcher<Store> {
+    async fn handle(&self, event_id: &Uuid) -> Result<(), EventHandlerError> {
+        check_budget(event_id, self.store.clone(), self.notification_svc.clone())
+            .await
+            .map_err(|e| EventHandlerError::Unexpected(anyhow!(e)))
+    }
+}
@@ -0,0 +1,250 @@
+use crate::application::budget::consumption_before;
+use crate::application::store::MultiRepository;
+use crate::domain::errors::NotifyError;
+use crate::domain::notification::NotificationService;
+use crate::domain::{Budget, Event, GroupEventKind};
+use anyhow::Context;
+use log::warn;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Alerts every group member when an expense makes the group spending cross a budget threshold.
+pub async fn check_budget(
+    event_id: &Uuid,
+    store: Arc<impl MultiRepository>,
+    notification_svc: Arc<dyn NotificationService>,
+) -> Result<(), NotifyError> {
+    let event = store
+        .events()
+        .find(event_id)
+        .await
+        .context("Failed to fetch event.")
+        .map_err(NotifyError::Unexpected)?
+        .map_or_else(|| Err(NotifyError::NotFound("Event not found")), Ok)?;
+    let (group_id, amount, date) = match event {
+        Event::Group(e) => match e.event {
+            GroupEventKind::ExpenseCreated { amount, date, .. } => (e.group_id, amount, date),
+            _ => return Ok(()),
+        },
+        Event::User(_) => return Ok(()),
+    };
+    let budget = store
+        .budgets()
+        .find(&group_id)
+        .await
+        .context("Failed to fetch budget.")
+        .map_err(NotifyError::Unexpected)?;
+    let budget = match budget {
+        Some(budget) => budget,
+        None => return Ok(()),
+    };
+    // the spending as of the expense, whatever was added since or however late the worker runs
+    let previous = consumption_before(&budget, &date, store.clone())
+        .await
+        .map_err(NotifyError::Unexpected)?
+        .spent;
+    let spent = previous + amount;
+    let threshold = match budget.crossed_threshold(previous, spent) {
+        Some(threshold) => threshold,
+        None => return Ok(()),
+    };
+    let group = store
+        .groups()
+        .find(&group_id)
+        .await
+        .context("Failed to fetch group.")
+        .map_err(NotifyError::Unexpected)?
+        .map_or_else(|| Err(NotifyError::NotFound("Group not found")), Ok)?;
+    let title = format!("Budget alert in {}", String::from(group.name.clone()));
+    let text = alert_text(&budget, threshold, spent);
+    for member in group.members.iter() {
+        notification_svc
+            .send(&member.id, title.clone(), text.clone())
+            .await
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+    }
+    Ok(())
+}
+
+fn alert_text(budget: &Budget, threshold: u8, spent: f32) -> String {
+    let period = String::from(budget.period);
+    let amount = f32::from(budget.amount);
+    if threshold >= 100 {
+        format!(
+            "The {} budget of {:.2} is exceeded: {:.2} spent.",
+            period, amount, spent
+        )
+    } else {
+        format!(
+            "{}% of the {} budget is used: {:.2} of {:.2} spent.",
+            threshold, period, spent, amount
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use claim::{assert_err, assert_some};
+
+    #[tokio::test]
+    async fn it_should_alert_all_members_when_the_budget_reaches_80_percent(
+    ) -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        ctx.with_budget(&group, 100.0, "monthly").await;
+        ctx.with_expense_of(&mut group, 50.0, member.id).await;
+        // when
+        ctx.with_expense_of(&mut group, 35.0, admin).await;
+        let event = ctx.last_published_event().unwrap();
+        check_budget(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        let notifications = ctx.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert_some!(notifications.iter().find(|n| n.user == member.id));
+        assert_some!(notifications.iter().find(|n| n.user == group.admin_id));
+        for notif in notifications {
+            assert_eq!(
+                notif.title,
+                format!("Budget alert in {}", String::from(group.name.clone()))
+            );
+            assert_eq!(
+                notif.text,
+                "80% of the monthly budget is used: 85.00 of 100.00 spent."
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_alert_when_the_budget_is_exceeded() -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_budget(&group, 100.0, "settlement").await;
+        ctx.with_expense_of(&mut group, 90.0, admin).await;
+        // when
+        ctx.with_expense_of(&mut group, 20.0, admin).await;
+        let event = ctx.last_published_event().unwrap();
+        check_budget(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        let notifications = ctx.notifications();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].text,
+            "The settlement budget of 100.00 is exceeded: 110.00 spent."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_not_alert_twice_for_the_same_threshold() -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_budget(&group, 100.0, "monthly").await;
+        ctx.with_expense_of(&mut group, 85.0, admin).await;
+        // when
+        ctx.with_expense_of(&mut group, 5.0, admin).await;
+        let event = ctx.last_published_event().unwrap();
+        check_budget(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        assert!(ctx.notifications().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_alert_the_threshold_crossed_by_the_expense_when_processed_late(
+    ) -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        ctx.with_budget(&group, 100.0, "monthly").await;
+        ctx.with_expense_of(&mut group, 70.0, admin).await;
+        ctx.with_expense_of(&mut group, 15.0, admin).await;
+        let first = ctx.last_published_event().unwrap();
+        ctx.with_expense_of(&mut group, 20.0, admin).await;
+        let second = ctx.last_published_event().unwrap();
+        // when
+        check_budget(&first, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        check_budget(&second, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        let texts = ctx
+            .notifications()
+            .into_iter()
+            .map(|n| n.text)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            vec![
+                "80% of the monthly budget is used: 85.00 of 100.00 spent.",
+                "The monthly budget of 100.00 is exceeded: 105.00 spent.",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_expenses_settled_in_a_previous_period() -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 90.0, admin).await];
+        ctx.settle(&mut group, &mut expenses).await;
+        ctx.with_budget(&group, 100.0, "settlement").await;
+        // when
+        ctx.with_expense_of(&mut group, 10.0, admin).await;
+        let event = ctx.last_published_event().unwrap();
+        check_budget(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        assert!(ctx.notifications().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_groups_without_budget() -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        // when
+        ctx.with_expense_of(&mut group, 500.0, admin).await;
+        let event = ctx.last_published_event().unwrap();
+        check_budget(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        assert!(ctx.notifications().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_return_an_error_if_event_is_not_found() {
+        // given
+        let ctx = TestContext::new();
+        // when
+        let resp = check_budget(
+            &Uuid::new_v4(),
+            ctx.store().clone(),
+            ctx.notification_svc().clone(),
+        )
+        .await;
+        // then
+        assert_err!(resp);
+        assert!(ctx.notifications().is_empty());
+    }
+}
@@ -1,8 +1,11 @@
+mod budget_watcher;
+mod check_budget;
 #[cfg(feature = "pushy")]
 mod device_service;
 mod notifier;
 mod notify;
 
+pub use budget_watcher::BudgetWatcher;
 #[cfg(feature = "pushy")]
 pub use device_service::DeviceService;
 pub use notifier::Notifier;
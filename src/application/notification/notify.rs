@@ -109,7 +109,12 @@ async fn to_notification(
         }
         GroupEventKind::ExpenseModified { .. } => None,
         GroupEventKind::ExpenseDeleted { .. } => None,
+        GroupEventKind::BudgetChanged { .. } => None,
+        GroupEventKind::OwnershipTransferred { .. } => None,
         GroupEventKind::GroupDeleted { .. } => None,
+        GroupEventKind::GroupArchived => None,
+        GroupEventKind::GroupUnarchived => None,
+        GroupEventKind::MemberRemoved { .. } => None,
         GroupEventKind::Settled { transactions, .. } => {
             let notification_title =
                 format!("Group {} was settled", String::from(group.name.clone()));
@@ -139,6 +144,20 @@ async fn to_notification(
                 text: notification,
             })
         }
+        GroupEventKind::SettlementReverted { .. } => {
+            let notification_title = format!(
+                "Settlement reverted in {}",
+                String::from(group.name.clone())
+            );
+            let notification = format!(
+                "{} reverted the last settlement, its expenses are open again.",
+                String::from(member.name.clone())
+            );
+            Some(Notification {
+                title: notification_title,
+                text: notification,
+            })
+        }
     }
 }
 
@@ -146,6 +165,7 @@ async fn to_notification(
 mod tests {
     use super::*;
     use crate::application::tests::TestContext;
+    use crate::domain::usecases::group::{GroupUseCase, RevertSettlementRequest};
     use claim::{assert_err, assert_some};
     use uuid::Uuid;
 
@@ -258,6 +278,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn it_should_send_a_notification_to_other_members_on_settlement_revert(
+    ) -> Result<(), anyhow::Error> {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let user_1 = ctx.with_member(&mut group).await;
+        let user_2 = ctx.with_member(&mut group).await;
+        let mut expenses = vec![ctx.with_expense_of(&mut group, 10.0, user_2.id).await];
+        let settlement = ctx.settle(&mut group, &mut expenses).await;
+
+        // when
+        ctx.group()
+            .revert_settlement(RevertSettlementRequest {
+                group_id: group.id,
+                settlement_id: settlement.id,
+                user_id: group.admin_id,
+            })
+            .await?;
+        let event = ctx.last_published_event().unwrap();
+        notify(&event, ctx.store().clone(), ctx.notification_svc().clone()).await?;
+        // then
+        let notifications = ctx.notifications();
+        assert_eq!(notifications.len(), 2);
+        assert_some!(notifications.iter().find(|n| n.user == user_1.id));
+        assert_some!(notifications.iter().find(|n| n.user == user_2.id));
+        let expected_title = format!(
+            "Settlement reverted in {}",
+            String::from(group.name.clone())
+        );
+        let expected_text = format!(
+            "{} reverted the last settlement, its expenses are open again.",
+            String::from(group.admin().name.clone())
+        );
+        for notif in notifications {
+            assert_eq!(notif.title, expected_title);
+            assert_eq!(notif.text, expected_text);
+        }
+        assert_some!(ctx.get_event_process_date(&event).await);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn it_should_return_an_error_if_event_is_not_found() -> Result<(), anyhow::Error> {
         // given
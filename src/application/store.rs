@@ -1,6 +1,8 @@
-use crate::application::auth::CredentialRepository;
+use crate::application::auth::{CredentialRepository, TwoFactorRepository, UserTokenRepository};
 use crate::domain::{
-    Email, Event, Expense, Group, GroupMember, Settlement, SettlementDescription, User,
+    ApiToken, Budget, Email, Event, EventFilter, EventOrigin, Expense, ExpenseFilter, ExpenseStats,
+    Group, GroupEvent, GroupMember, GroupSummary, Settlement, SettlementDescription, Takeout, User,
+    UserEvent,
 };
 use crate::error_chain;
 use async_trait::async_trait;
@@ -8,14 +10,15 @@ use chrono::{DateTime, Utc};
 use std::cell::RefCell;
 use uuid::Uuid;
 
-pub trait Tx {}
+pub trait Tx: Send {}
 
 #[async_trait]
-pub trait MultiRepository: Send + Sync {
+pub trait MultiRepository: Send + Sync + 'static {
     type KTransaction: Tx;
     async fn tx(&self) -> Result<RefCell<Self::KTransaction>, anyhow::Error>;
     async fn commit(&self, mut tx: Self::KTransaction) -> Result<(), anyhow::Error>;
     fn users(&self) -> &dyn UserRepository<Tr = Self::KTransaction>;
+    fn budgets(&self) -> &dyn BudgetRepository<Tr = Self::KTransaction>;
     fn credentials(&self) -> &dyn CredentialRepository<Tr = Self::KTransaction>;
     #[cfg(feature = "pushy")]
     fn device(&self) -> &dyn DeviceRepository<Tr = Self::KTransaction>;
@@ -24,6 +27,43 @@ pub trait MultiRepository: Send + Sync {
     fn expenses(&self) -> &dyn ExpenseRepository<Tr = Self::KTransaction>;
     fn settlements(&self) -> &dyn SettlementRepository<Tr = Self::KTransaction>;
     fn events(&self) -> &dyn EventRepository<Tr = Self::KTransaction>;
+    fn takeouts(&self) -> &dyn TakeoutRepository<Tr = Self::KTransaction>;
+    fn user_tokens(&self) -> &dyn UserTokenRepository<Tr = Self::KTransaction>;
+    fn api_tokens(&self) -> &dyn ApiTokenRepository<Tr = Self::KTransaction>;
+    fn two_factors(&self) -> &dyn TwoFactorRepository<Tr = Self::KTransaction>;
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum BudgetRepositoryError {
+        #[error("Failed to insert budget.")]
+        Insert(#[source] anyhow::Error),
+        #[error("Failed to fetch budget.")]
+        Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete budget.")]
+        Delete(#[source] anyhow::Error),
+        #[error("Corrupted data in DB: {0}")]
+        CorruptedData(&'static str),
+    }
+}
+
+#[async_trait]
+pub trait BudgetRepository: Send + Sync {
+    type Tr: Tx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        budget: &Budget,
+    ) -> Result<(), BudgetRepositoryError>;
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+    ) -> Result<(), BudgetRepositoryError>;
+
+    async fn find(&self, group_id: &Uuid) -> Result<Option<Budget>, BudgetRepositoryError>;
 }
 
 error_chain! {
@@ -35,11 +75,21 @@ error_chain! {
         Fetch(#[source] anyhow::Error),
         #[error("Failed to update events.")]
         Update(#[source] anyhow::Error),
+        #[error("Failed to delete events.")]
+        Delete(#[source] anyhow::Error),
         #[error("Corrupted data in DB: {0}")]
         CorruptedData(&'static str),
     }
 }
 
+/// An event along with the client it came from and the date the worker processed it, if it did.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    pub event: Event,
+    pub origin: EventOrigin,
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
 #[async_trait]
 pub trait EventRepository: Send + Sync {
     type Tr: Tx;
@@ -52,7 +102,45 @@ pub trait EventRepository: Send + Sync {
 
     async fn find(&self, id: &Uuid) -> Result<Option<Event>, EventRepositoryError>;
 
+    /// Fetches the events of a group, oldest first.
+    async fn get_group_events(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError>;
+
+    /// Fetches the events of a user account, oldest first.
+    async fn get_user_events(&self, user_id: &Uuid)
+        -> Result<Vec<UserEvent>, EventRepositoryError>;
+
     async fn mark_processed(&self, id: &Uuid) -> Result<(), EventRepositoryError>;
+
+    /// Fetches the most recent events, newest first, up to `limit` if any.
+    async fn get_events(
+        &self,
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError>;
+
+    /// Deletes the events processed before the date, returning how many were deleted.
+    async fn purge(&self, processed_before: &DateTime<Utc>) -> Result<u64, EventRepositoryError>;
+
+    /// Fetches a page of the events matching `filter`, newest first and starting strictly before
+    /// the `before` cursor.
+    async fn get_event_page(
+        &self,
+        filter: &EventFilter,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError>;
+
+    /// Fetches a page of the events of a group, newest first and starting strictly before the
+    /// `before` cursor.
+    async fn get_group_event_page(
+        &self,
+        group_id: &Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError>;
 }
 
 error_chain! {
@@ -105,6 +193,24 @@ pub trait ExpenseRepository: Send + Sync {
         &self,
         group_id: &Uuid,
     ) -> Result<Vec<Expense>, ExpenseRepositoryError>;
+
+    async fn get_stats(
+        &self,
+        group_id: &Uuid,
+        start_date: Option<&DateTime<Utc>>,
+        end_date: Option<&DateTime<Utc>>,
+        largest: u32,
+    ) -> Result<ExpenseStats, ExpenseRepositoryError>;
+
+    /// Fetches a page of the group expenses matching `filter`, with their settlement id,
+    /// ordered by creation date and starting strictly after the `after` cursor.
+    async fn get_expense_page(
+        &self,
+        group_id: &Uuid,
+        filter: &ExpenseFilter,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<(Expense, Option<Uuid>)>, ExpenseRepositoryError>;
 }
 
 error_chain! {
@@ -141,6 +247,11 @@ pub trait GroupRepository: Send + Sync {
 
     async fn get_user_groups(&self, user_id: &Uuid) -> Result<Vec<Group>, GroupRepositoryError>;
 
+    async fn get_user_summaries(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<GroupSummary>, GroupRepositoryError>;
+
     async fn fetch_all_groups(&self) -> Result<Vec<Group>, GroupRepositoryError>;
 }
 
@@ -151,6 +262,8 @@ error_chain! {
         Insert(#[source] anyhow::Error),
         #[error("Failed to fetch member.")]
         Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete member.")]
+        Delete(#[source] anyhow::Error),
         #[error("Corrupted data in DB: {0}")]
         CorruptedData(&'static str),
     }
@@ -166,6 +279,13 @@ pub trait MemberRepository: Send + Sync {
         member: &GroupMember,
     ) -> Result<(), MemberRepositoryError>;
 
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), MemberRepositoryError>;
+
     async fn fetch_members(
         &self,
         group_id: &Uuid,
@@ -210,6 +330,8 @@ error_chain! {
         Fetch(#[source] anyhow::Error),
         #[error("Failed to delete settlement.")]
         Delete(#[source] anyhow::Error),
+        #[error("Failed to update settlement.")]
+        Update(#[source] anyhow::Error),
         #[error("Corrupted data in DB: {0}")]
         CorruptedData(&'static str),
     }
@@ -225,6 +347,18 @@ pub trait SettlementRepository: Send + Sync {
         settlement: &Settlement,
     ) -> Result<(), SettlementRepositoryError>;
 
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement_id: &Uuid,
+    ) -> Result<(), SettlementRepositoryError>;
+
+    async fn update_transactions(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement: &Settlement,
+    ) -> Result<(), SettlementRepositoryError>;
+
     async fn get_settlements(
         &self,
         group_id: &Uuid,
@@ -235,6 +369,12 @@ pub trait SettlementRepository: Send + Sync {
         settlement_id: &Uuid,
     ) -> Result<Option<SettlementDescription>, SettlementRepositoryError>;
 
+    async fn get_unpaid_settlements(
+        &self,
+        user_id: &Uuid,
+        other_id: &Uuid,
+    ) -> Result<Vec<Settlement>, SettlementRepositoryError>;
+
     async fn find(
         &self,
         settlement_id: &Uuid,
@@ -248,6 +388,86 @@ pub trait SettlementRepository: Send + Sync {
     ) -> Result<Vec<Uuid>, SettlementRepositoryError>;
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum TakeoutRepositoryError {
+        #[error("Failed to insert takeout.")]
+        Insert(#[source] anyhow::Error),
+        #[error("Failed to fetch takeout.")]
+        Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete takeout.")]
+        Delete(#[source] anyhow::Error),
+        #[error("Corrupted data in DB: {0}")]
+        CorruptedData(&'static str),
+    }
+}
+
+#[async_trait]
+pub trait TakeoutRepository: Send + Sync {
+    type Tr: Tx;
+
+    /// Inserts the takeout of the user or replaces the previous one.
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        takeout: &Takeout,
+    ) -> Result<(), TakeoutRepositoryError>;
+
+    async fn find(&self, user_id: &Uuid) -> Result<Option<Takeout>, TakeoutRepositoryError>;
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), TakeoutRepositoryError>;
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ApiTokenRepositoryError {
+        #[error("Failed to insert API token.")]
+        Insert(#[source] anyhow::Error),
+        #[error("Failed to fetch API token.")]
+        Fetch(#[source] anyhow::Error),
+        #[error("Failed to delete API token.")]
+        Delete(#[source] anyhow::Error),
+        #[error("Corrupted data in DB: {0}")]
+        CorruptedData(&'static str),
+    }
+}
+
+#[async_trait]
+pub trait ApiTokenRepository: Send + Sync {
+    type Tr: Tx;
+
+    /// Inserts the token or updates the date it was last used.
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &ApiToken,
+    ) -> Result<(), ApiTokenRepositoryError>;
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiToken>, ApiTokenRepositoryError>;
+
+    async fn get_user_tokens(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError>;
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError>;
+
+    /// Removes every token of the user.
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError>;
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum UserRepositoryError {
@@ -0,0 +1,69 @@
+use crate::domain::mail::{Mail, Mailer, MailerError};
+use crate::domain::User;
+use chrono::Duration;
+use secrecy::{ExposeSecret, Secret};
+use std::sync::Arc;
+
+/// Writes the mails sent to the users about their account, whose links lead to the client
+/// application at `public_url`.
+pub struct AccountMailer {
+    mailer: Arc<dyn Mailer>,
+    public_url: String,
+}
+
+impl AccountMailer {
+    pub fn new(mailer: Arc<dyn Mailer>, public_url: String) -> Self {
+        Self {
+            mailer,
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub async fn send_password_reset(
+        &self,
+        user: &User,
+        token: &Secret<String>,
+        validity: Duration,
+    ) -> Result<(), MailerError> {
+        self.mailer
+            .send(Mail {
+                to: user.email.clone(),
+                subject: "Reset your Koru password".to_string(),
+                body: format!(
+                    "Hello {},\n\n\
+                    A password reset was requested for your account. Follow this link within {} minutes to choose a new password:\n\
+                    {}/reset-password?token={}\n\n\
+                    If you did not request it, you can ignore this mail.",
+                    String::from(user.name.clone()),
+                    validity.num_minutes(),
+                    self.public_url,
+                    token.expose_secret()
+                ),
+            })
+            .await
+    }
+
+    pub async fn send_email_verification(
+        &self,
+        user: &User,
+        token: &Secret<String>,
+        validity: Duration,
+    ) -> Result<(), MailerError> {
+        self.mailer
+            .send(Mail {
+                to: user.email.clone(),
+                subject: "Verify your Koru email".to_string(),
+                body: format!(
+                    "Hello {},\n\n\
+                    Welcome to Koru! Follow this link within {} hours to verify your email and activate your account:\n\
+                    {}/verify-email?token={}\n\n\
+                    If you did not register, you can ignore this mail.",
+                    String::from(user.name.clone()),
+                    validity.num_hours(),
+                    self.public_url,
+                    token.expose_secret()
+                ),
+            })
+            .await
+    }
+}
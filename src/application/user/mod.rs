@@ -1,3 +1,10 @@
+mod account_mailer;
+mod password_reset_mailer;
+mod takeout;
+mod takeout_generator;
 mod user_usecase;
 
+pub use account_mailer::AccountMailer;
+pub use password_reset_mailer::PasswordResetMailer;
+pub use takeout_generator::TakeoutGenerator;
 pub use user_usecase::*;
@@ -0,0 +1,167 @@
+use crate::application::auth::{UserToken, UserTokenKind};
+use crate::application::store::MultiRepository;
+use crate::application::user::AccountMailer;
+use crate::domain::errors::EventHandlerError;
+use crate::domain::usecases::event_processor::EventProcessor;
+use crate::domain::{Event, UserEventKind};
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use chrono::Duration;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Mails the reset links requested by the users who forgot their password, away from the request
+/// so that its duration does not tell whether the email is registered.
+pub struct PasswordResetMailer<Store: MultiRepository> {
+    store: Arc<Store>,
+    account_mailer: AccountMailer,
+    validity: Duration,
+}
+
+impl<Store: MultiRepository> PasswordResetMailer<Store> {
+    pub fn new(store: Arc<Store>, account_mailer: AccountMailer, validity: Duration) -> Self {
+        Self {
+            store,
+            account_mailer,
+            validity,
+        }
+    }
+
+    async fn send(&self, event_id: &Uuid) -> Result<(), anyhow::Error> {
+        let event = self
+            .store
+            .events()
+            .find(event_id)
+            .await
+            .context("Failed to fetch event.")?
+            .ok_or_else(|| anyhow!("Event not found."))?;
+        let user_id = match event {
+            Event::User(e) => match e.event {
+                UserEventKind::PasswordResetRequested => e.user_id,
+                _ => return Ok(()),
+            },
+            Event::Group(_) => return Ok(()),
+        };
+        let user = self
+            .store
+            .users()
+            .find(&user_id)
+            .await
+            .context("Failed to fetch user.")?
+            .filter(|u| !u.is_deleted());
+        if let Some(user) = user {
+            let (token, user_token) =
+                UserToken::generate(user.id, UserTokenKind::PasswordReset, self.validity);
+            let mut tx = self.store.tx().await?;
+            self.store
+                .user_tokens()
+                .delete_all(&mut tx, &user.id, UserTokenKind::PasswordReset)
+                .await
+                .context("Failed to delete previous tokens.")?;
+            self.store
+                .user_tokens()
+                .save(&mut tx, &user_token)
+                .await
+                .context("Failed to save token.")?;
+            self.store.commit(tx.into_inner()).await?;
+            self.account_mailer
+                .send_password_reset(&user, &token, self.validity)
+                .await
+                .context("Failed to send password reset mail.")?;
+        }
+        self.store
+            .events()
+            .mark_processed(event_id)
+            .await
+            .context("Failed to mark as processed")?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Store: MultiRepository> EventProcessor for PasswordResetMailer<Store> {
+    async fn handle(&self, event_id: &Uuid) -> Result<(), EventHandlerError> {
+        self.send(event_id)
+            .await
+            .map_err(EventHandlerError::Unexpected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::user::{DeleteUserRequest, PasswordResetRequest, UserUseCase};
+    use claim::{assert_none, assert_ok, assert_some};
+    use secrecy::{ExposeSecret, Secret};
+
+    fn mailer(ctx: &TestContext) -> PasswordResetMailer<impl MultiRepository> {
+        PasswordResetMailer::new(ctx.store(), ctx.account_mailer(), Duration::minutes(60))
+    }
+
+    #[tokio::test]
+    async fn it_should_mail_a_reset_link_and_only_store_the_token_hash() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = PasswordResetRequest {
+            email: String::from(user.email.clone()),
+        };
+        assert_ok!(ctx.user().request_password_reset(req).await);
+        assert!(ctx.sent_mails().is_empty());
+        let event = assert_some!(ctx.last_published_event());
+        // when
+        let resp = mailer(&ctx).handle(&event).await;
+        // then
+        assert_ok!(resp);
+        assert_some!(ctx.get_event_process_date(&event).await);
+        let mails = ctx.sent_mails();
+        assert_eq!(mails.len(), 1);
+        assert_eq!(String::from(mails[0].to.clone()), String::from(user.email));
+        let (_, token) = mails[0].body.split_once("token=").unwrap();
+        let token = Secret::new(token.split_whitespace().next().unwrap().to_string());
+        let store = ctx.store();
+        let tokens = store.user_tokens();
+        assert_none!(assert_ok!(tokens.find(token.expose_secret()).await));
+        let stored = assert_some!(assert_ok!(tokens.find(&UserToken::hash(&token)).await));
+        assert_eq!(stored.user_id, user.id);
+        assert_eq!(stored.kind, UserTokenKind::PasswordReset);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_mail_a_user_deleted_since_the_request() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = PasswordResetRequest {
+            email: String::from(user.email.clone()),
+        };
+        assert_ok!(ctx.user().request_password_reset(req).await);
+        let event = assert_some!(ctx.last_published_event());
+        assert_ok!(
+            ctx.user()
+                .delete(DeleteUserRequest { user_id: user.id })
+                .await
+        );
+        // when
+        let resp = mailer(&ctx).handle(&event).await;
+        // then
+        assert_ok!(resp);
+        assert!(ctx.sent_mails().is_empty());
+        assert_some!(ctx.get_event_process_date(&event).await);
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_other_events() {
+        // given
+        let ctx = TestContext::new();
+        ctx.with_group().await;
+        let event = assert_some!(ctx.last_published_event());
+        // when
+        let resp = mailer(&ctx).handle(&event).await;
+        // then
+        assert_ok!(resp);
+        assert!(ctx.sent_mails().is_empty());
+        assert_none!(ctx.get_event_process_date(&event).await);
+    }
+}
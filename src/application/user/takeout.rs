@@ -0,0 +1,173 @@
+use crate::application::store::MultiRepository;
+use crate::domain::errors::TakeoutError;
+use crate::domain::{Event, Takeout, UserEventKind, UserTakeout};
+use anyhow::{anyhow, Context};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Gathers the archive of the personal data of the user.
+pub async fn collect(
+    user_id: &Uuid,
+    store: Arc<impl MultiRepository>,
+) -> Result<UserTakeout, TakeoutError> {
+    let user = store
+        .users()
+        .find(user_id)
+        .await
+        .context("Failed to fetch user.")?
+        .ok_or(TakeoutError::Unauthenticated())?;
+    let credentials = store
+        .credentials()
+        .fetch_by_email(&user.email)
+        .await
+        .context("Failed to fetch credentials.")?;
+    #[cfg(feature = "pushy")]
+    let devices = store
+        .device()
+        .fetch_device(user_id)
+        .await
+        .context("Failed to fetch device.")?
+        .into_iter()
+        .collect();
+    #[cfg(not(feature = "pushy"))]
+    let devices = Vec::new();
+    let groups = store
+        .groups()
+        .get_user_groups(user_id)
+        .await
+        .context("Failed to fetch groups.")?;
+    let mut expenses = Vec::new();
+    let mut settlements = Vec::new();
+    let mut group_events = Vec::new();
+    for group in &groups {
+        expenses.extend(
+            store
+                .expenses()
+                .get_expenses(&group.id, None, None)
+                .await
+                .context("Failed to fetch expenses.")?,
+        );
+        settlements.extend(
+            store
+                .settlements()
+                .get_settlements(&group.id)
+                .await
+                .context("Failed to fetch settlements.")?,
+        );
+        group_events.extend(
+            store
+                .events()
+                .get_group_events(&group.id)
+                .await
+                .context("Failed to fetch group events.")?,
+        );
+    }
+    let user_events = store
+        .events()
+        .get_user_events(user_id)
+        .await
+        .context("Failed to fetch user events.")?;
+    UserTakeout::create(
+        &user,
+        credentials.as_ref().map(|c| &c.email),
+        devices,
+        &groups,
+        &expenses,
+        &settlements,
+        &user_events,
+        &group_events,
+    )
+    .map_err(|e| TakeoutError::Unexpected(anyhow!(e)))
+}
+
+/// Generates the archive requested by a `TakeoutRequested` event and keeps it for download.
+pub async fn generate(
+    event_id: &Uuid,
+    store: Arc<impl MultiRepository>,
+) -> Result<(), TakeoutError> {
+    let event = store
+        .events()
+        .find(event_id)
+        .await
+        .context("Failed to fetch event.")?
+        .ok_or_else(|| anyhow!("Event not found."))?;
+    let user_id = match event {
+        Event::User(e) => match e.event {
+            UserEventKind::TakeoutRequested => e.user_id,
+            _ => return Ok(()),
+        },
+        Event::Group(_) => return Ok(()),
+    };
+    let mut takeout = store
+        .takeouts()
+        .find(&user_id)
+        .await
+        .context("Failed to fetch takeout.")?
+        .unwrap_or_else(|| Takeout::request(user_id));
+    let archive = collect(&user_id, store.clone()).await?;
+    takeout.complete(serde_json::to_value(archive).context("Failed to serialize takeout.")?);
+    let mut tx = store.tx().await?;
+    store
+        .takeouts()
+        .save(&mut tx, &takeout)
+        .await
+        .context("Failed to save takeout.")?;
+    store.commit(tx.into_inner()).await?;
+    store
+        .events()
+        .mark_processed(event_id)
+        .await
+        .context("Failed to mark as processed")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::tests::TestContext;
+    use crate::domain::usecases::user::{TakeoutRequest, UserUseCase};
+    use claim::{assert_none, assert_ok, assert_some};
+
+    #[tokio::test]
+    async fn it_should_generate_the_requested_takeout() {
+        // given
+        let ctx = TestContext::new();
+        let mut first = ctx.with_group().await;
+        let mut second = ctx.with_group().await;
+        let admin = second.admin_id;
+        let member = ctx.with_member(&mut first).await;
+        let user = ctx.get_user(&member.id).await;
+        ctx.join(&mut second, &user).await;
+        ctx.with_expense_of(&mut first, 10.0, member.id).await;
+        ctx.with_expense_of(&mut second, 20.0, admin).await;
+        let req = TakeoutRequest { user_id: user.id };
+        // the user belongs to too many groups for the archive to be generated on request
+        assert_none!(assert_ok!(ctx.user().takeout(req.clone()).await));
+        let event = assert_some!(ctx.last_published_event());
+        // when
+        let resp = generate(&event, ctx.store()).await;
+        // then
+        assert_ok!(resp);
+        assert_some!(ctx.get_event_process_date(&event).await);
+        let archive = assert_some!(assert_ok!(ctx.user().takeout(req).await));
+        assert_eq!(archive["profile"]["id"], user.id.to_string());
+        assert_eq!(archive["memberships"].as_array().unwrap().len(), 2);
+        assert_eq!(archive["expenses"].as_array().unwrap().len(), 1);
+        assert_eq!(archive["credentials"]["login"], String::from(user.email));
+        assert!(archive["credentials"].get("hash").is_none());
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_other_events() {
+        // given
+        let ctx = TestContext::new();
+        let group = ctx.with_group().await;
+        let event = assert_some!(ctx.last_published_event());
+        // when
+        let resp = generate(&event, ctx.store()).await;
+        // then
+        assert_ok!(resp);
+        assert_none!(ctx.store().takeouts().find(&group.admin_id).await.unwrap());
+        assert_none!(ctx.get_event_process_date(&event).await);
+    }
+}
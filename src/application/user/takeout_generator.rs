@@ -0,0 +1,28 @@
+use crate::application::store::MultiRepository;
+use crate::application::user::takeout::generate;
+use crate::domain::errors::EventHandlerError;
+use crate::domain::usecases::event_processor::EventProcessor;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Generates the archives requested by the users too large to be generated on request.
+pub struct TakeoutGenerator<Store: MultiRepository> {
+    store: Arc<Store>,
+}
+
+impl<Store: MultiRepository> TakeoutGenerator<Store> {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl<Store: MultiRepository> EventProcessor for TakeoutGenerator<Store> {
+    async fn handle(&self, event_id: &Uuid) -> Result<(), EventHandlerError> {
+        generate(event_id, self.store.clone())
+            .await
+            .map_err(|e| EventHandlerError::Unexpected(anyhow!(e)))
+    }
+}
@@ -1,18 +1,31 @@
 use crate::application::auth;
-use crate::application::auth::{AuthService, RegisterRequest};
+use crate::application::auth::{AuthService, LoginStatus, RegisterRequest, UserTokenKind};
 use crate::application::event_bus::EventBus;
 use crate::application::notification::DeviceService;
 use crate::application::store::MultiRepository;
-use crate::domain::errors::{CreateUserError, LoginError, LogoutError};
+use crate::application::user::{takeout, AccountMailer};
+use crate::domain::errors::{
+    ApiTokenError, CreateUserError, DeleteUserError, EmailVerificationError, LoginError,
+    LogoutError, PasswordResetError, TakeoutError, TwoFactorError, UpdateUserError,
+};
 use crate::domain::usecases::user::{
-    LoginRequest, LogoutRequest, RegistrationRequest, UserUseCase,
+    ChangeEmailRequest, ChangePasswordRequest, ConfirmTwoFactorRequest, CreateApiTokenRequest,
+    DeleteUserRequest, IdentityLoginRequest, LoginOutcome, LoginRequest, LogoutRequest,
+    PasswordResetRequest, RegistrationRequest, ResendVerificationRequest, ResetPasswordRequest,
+    RevokeApiTokenRequest, SecondFactorRequest, TakeoutRequest, TwoFactorEnrollment,
+    UpdateNameRequest, UserUseCase, VerifyEmailRequest,
+};
+use crate::domain::{
+    ApiToken, ApiTokenScope, AuthorizationFlow, Email, Event, IdentityProvider,
+    IdentityProviderError, Takeout, User, UserEvent, UserEventKind,
 };
-use crate::domain::{Email, Event, User, UserEvent, UserEventKind};
 use crate::utils::date;
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use itertools::Itertools;
-use log::warn;
+use log::{info, warn};
+use secrecy::Secret;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -20,23 +33,44 @@ pub struct UserUsecase<Store: MultiRepository> {
     store: Arc<Store>,
     event_bus: Arc<dyn EventBus>,
     auth_service: Option<AuthService<Store>>,
+    /// Users log in through this provider instead of the application when set.
+    identity_provider: Option<Arc<dyn IdentityProvider>>,
     #[cfg(feature = "pushy")]
     device_service: Arc<DeviceService<Store>>,
+    takeout_retention: Duration,
+    takeout_sync_groups: usize,
+    account_mailer: AccountMailer,
+    password_reset_validity: Duration,
+    /// New users have to verify their email within this duration when set.
+    email_verification_validity: Option<Duration>,
 }
 
 impl<Store: MultiRepository> UserUsecase<Store> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         store: Arc<Store>,
         event_bus: Arc<dyn EventBus>,
         auth_service: Option<AuthService<Store>>,
+        identity_provider: Option<Arc<dyn IdentityProvider>>,
         #[cfg(feature = "pushy")] device_service: Arc<DeviceService<Store>>,
+        takeout_retention: Duration,
+        takeout_sync_groups: usize,
+        account_mailer: AccountMailer,
+        password_reset_validity: Duration,
+        email_verification_validity: Option<Duration>,
     ) -> Self {
         Self {
             store,
             event_bus,
             auth_service,
+            identity_provider,
             #[cfg(feature = "pushy")]
             device_service,
+            takeout_retention,
+            takeout_sync_groups,
+            account_mailer,
+            password_reset_validity,
+            email_verification_validity,
         }
     }
 }
@@ -44,7 +78,12 @@ impl<Store: MultiRepository> UserUsecase<Store> {
 #[async_trait(?Send)]
 impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
     async fn register(&self, request: RegistrationRequest) -> Result<Uuid, CreateUserError> {
-        let user = User::create(request.name, request.email.clone())?;
+        if self.identity_provider.is_some() {
+            return Err(CreateUserError::Validation(
+                "Users are created through the identity provider.",
+            ));
+        }
+        let mut user = User::create(request.name, request.email.clone())?;
         let exists = self
             .store
             .users()
@@ -68,7 +107,7 @@ impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
                 auth_service.register(req).await?;
             }
         }
-        let events = [Event::User(UserEvent {
+        let created = Event::User(UserEvent {
             id: Uuid::new_v4(),
             event_date: date::now(),
             user_id: user.id,
@@ -76,17 +115,28 @@ impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
                 name: String::from(user.name.clone()),
                 email: String::from(user.email.clone()),
             },
-        })];
+        });
 
-        self.finalize(Some(&user), &events)
-            .await
-            .map_err(CreateUserError::Unexpected)?;
+        match self.email_verification() {
+            None => self.finalize(Some(&user), &[created]).await,
+            Some((auth_service, validity)) => {
+                user.require_email_verification();
+                self.send_verification(auth_service, &user, vec![created], validity)
+                    .await
+            }
+        }
+        .map_err(CreateUserError::Unexpected)?;
         Ok(user.id)
     }
 
-    async fn login(&self, request: LoginRequest) -> Result<Uuid, LoginError> {
-        match &self.auth_service {
-            None => {}
+    async fn login(&self, request: LoginRequest) -> Result<LoginOutcome, LoginError> {
+        if self.identity_provider.is_some() {
+            return Err(LoginError::Validation(
+                "Log in through the identity provider.",
+            ));
+        }
+        let status = match &self.auth_service {
+            None => LoginStatus::Authenticated,
             Some(auth_service) => {
                 let req = auth::LoginRequest {
                     email: request.email.clone(),
@@ -95,9 +145,9 @@ impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
                         Some(password) => password,
                     },
                 };
-                auth_service.login(req).await?;
+                auth_service.login(req).await?
             }
-        }
+        };
         let email = Email::try_from(request.email).map_err(LoginError::Validation)?;
         let user = self
             .store
@@ -105,26 +155,125 @@ impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
             .find_by_email(&email)
             .await
             .context("Failed to fetch user")
-            .map(|u| u.map(|u| u.id))
             .map_err(LoginError::Unexpected)?;
 
         match user {
             None => Err(LoginError::InvalidCredentials()),
+            Some(user) if !user.email_verified => Err(LoginError::Unverified()),
+            Some(user) if user.is_disabled() => Err(LoginError::Disabled()),
+            Some(user) if user.password_reset_required => Err(LoginError::PasswordResetRequired()),
+            Some(user) if status == LoginStatus::SecondFactorRequired => {
+                Ok(LoginOutcome::SecondFactorRequired(user.id))
+            }
             Some(user) => {
                 let events = [Event::User(UserEvent {
                     id: Uuid::new_v4(),
                     event_date: date::now(),
-                    user_id: user,
+                    user_id: user.id,
                     event: UserEventKind::Login,
                 })];
                 self.finalize(None, &events)
                     .await
                     .map_err(LoginError::Unexpected)?;
-                Ok(user)
+                Ok(LoginOutcome::LoggedIn(user.id))
             }
         }
     }
 
+    async fn complete_second_factor(
+        &self,
+        request: SecondFactorRequest,
+    ) -> Result<Uuid, LoginError> {
+        let auth_service = self
+            .auth_service
+            .as_ref()
+            .ok_or(LoginError::InvalidCredentials())?;
+        let user = self
+            .store
+            .users()
+            .find(&request.user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted() && !u.is_disabled() && u.email_verified)
+            .ok_or(LoginError::InvalidCredentials())?;
+        let events = [Event::User(UserEvent::new(user.id, UserEventKind::Login))];
+        let mut tx = self.store.tx().await?;
+        auth_service
+            .verify_second_factor(&mut tx, &user.email, &request.code)
+            .await?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(user.id)
+    }
+
+    async fn start_identity_login(&self) -> Result<(String, AuthorizationFlow), LoginError> {
+        self.identity_provider()?
+            .authorize()
+            .await
+            .map_err(identity_error)
+    }
+
+    async fn complete_identity_login(
+        &self,
+        request: IdentityLoginRequest,
+    ) -> Result<Uuid, LoginError> {
+        let identity_provider = self.identity_provider()?;
+        let flow = request.flow.ok_or(LoginError::InvalidCredentials())?;
+        if request.state.as_deref() != Some(flow.state.as_str()) {
+            return Err(LoginError::InvalidCredentials());
+        }
+        let code = request.code.ok_or(LoginError::InvalidCredentials())?;
+        let identity = identity_provider
+            .authenticate(&code, &flow)
+            .await
+            .map_err(identity_error)?;
+        let email = Email::try_from(identity.email).map_err(LoginError::Validation)?;
+        let user = self
+            .store
+            .users()
+            .find_by_email(&email)
+            .await
+            .context("Failed to fetch user")?;
+        let (user, mut events) = match user {
+            Some(user) if user.is_deleted() => return Err(LoginError::InvalidCredentials()),
+            Some(user) if user.is_disabled() => return Err(LoginError::Disabled()),
+            Some(mut user) => {
+                // the provider vouches for the email of the user
+                let events = user
+                    .verify_email()
+                    .map(|event| Event::User(UserEvent::new(user.id, event)))
+                    .into_iter()
+                    .collect_vec();
+                (user, events)
+            }
+            None => {
+                let email = String::from(email);
+                let name = identity
+                    .name
+                    .filter(|name| !name.trim().is_empty())
+                    .unwrap_or_else(|| email.split('@').next().unwrap_or_default().to_string());
+                let user = User::create(name, email).map_err(|e| match e {
+                    CreateUserError::Validation(msg) => LoginError::Validation(msg),
+                    e => LoginError::Unexpected(anyhow!(e)),
+                })?;
+                let created = UserEventKind::Created {
+                    name: String::from(user.name.clone()),
+                    email: String::from(user.email.clone()),
+                };
+                let events = vec![Event::User(UserEvent::new(user.id, created))];
+                (user, events)
+            }
+        };
+        events.push(Event::User(UserEvent::new(user.id, UserEventKind::Login)));
+        self.finalize(Some(&user), &events).await?;
+        Ok(user.id)
+    }
+
     async fn logout(&self, request: LogoutRequest) -> Result<(), LogoutError> {
         self.is_valid_user(&request.user_id)
             .await
@@ -150,23 +299,323 @@ impl<Store: MultiRepository> UserUseCase for UserUsecase<Store> {
             .find(user_id)
             .await
             .context("Failed to fetch user")?
-            .is_some())
+            .map_or(false, |u| {
+                !u.is_deleted() && !u.is_disabled() && u.email_verified
+            }))
     }
-}
 
-impl<Store: MultiRepository> UserUsecase<Store> {
-    async fn finalize(&self, user: Option<&User>, events: &[Event]) -> Result<(), anyhow::Error> {
-        let mut tx = self.store.tx().await?;
-        if let Some(user) = user {
-            self.store
-                .users()
-                .save(&mut tx, user)
+    async fn is_valid_session(
+        &self,
+        user_id: &Uuid,
+        logged_in_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, anyhow::Error> {
+        Ok(self
+            .store
+            .users()
+            .find(user_id)
+            .await
+            .context("Failed to fetch user")?
+            .map_or(false, |u| {
+                !u.is_deleted() && !u.is_disabled() && u.accepts_session(logged_in_at)
+            }))
+    }
+
+    async fn update_name(&self, request: UpdateNameRequest) -> Result<(), UpdateUserError> {
+        let mut user = self.find_active_user(&request.user_id).await?;
+        let event = user
+            .rename(request.name)
+            .map_err(UpdateUserError::Validation)?;
+        let events = [Event::User(UserEvent::new(user.id, event))];
+        self.finalize(Some(&user), &events).await?;
+        Ok(())
+    }
+
+    async fn change_email(&self, request: ChangeEmailRequest) -> Result<(), UpdateUserError> {
+        let mut user = self.find_active_user(&request.user_id).await?;
+        let email = Email::try_from(request.email).map_err(UpdateUserError::Validation)?;
+        if String::from(email.clone()) == String::from(user.email.clone()) {
+            return Ok(());
+        }
+        if self.identity_provider.is_some() {
+            return Err(UpdateUserError::Validation(
+                "Emails are managed by the identity provider.",
+            ));
+        }
+        let exists = self
+            .store
+            .users()
+            .exists_by_email(&email)
+            .await
+            .context("Failed to fetch user by email")?;
+        if exists {
+            return Err(UpdateUserError::Conflict());
+        }
+        if let Some(auth_service) = &self.auth_service {
+            let password = request
+                .password
+                .ok_or(UpdateUserError::Validation("No password provided"))?;
+            auth_service
+                .verify(&user.email, password)
                 .await
-                .context("Failed to insert user")?;
+                .map_err(verification_error)?;
         }
+        let previous = user.email.clone();
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            user.change_email(email.clone()),
+        ))];
+        let mut tx = self.store.tx().await?;
+        if let Some(auth_service) = &self.auth_service {
+            auth_service
+                .change_email(&mut tx, &previous, &email)
+                .await?;
+        }
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
         self.store
             .events()
-            .save(&mut tx, events)
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+
+    async fn change_password(&self, request: ChangePasswordRequest) -> Result<(), UpdateUserError> {
+        let mut user = self.find_active_user(&request.user_id).await?;
+        let auth_service = self
+            .auth_service
+            .as_ref()
+            .ok_or(UpdateUserError::Validation(
+                "Passwords are not managed by this application.",
+            ))?;
+        auth_service
+            .verify(&user.email, request.current_password)
+            .await
+            .map_err(verification_error)?;
+        user.revoke_sessions();
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::PasswordChanged,
+        ))];
+        let mut tx = self.store.tx().await?;
+        auth_service
+            .change_password(&mut tx, &user.email, request.new_password)
+            .await?;
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+
+    async fn request_password_reset(
+        &self,
+        request: PasswordResetRequest,
+    ) -> Result<(), PasswordResetError> {
+        self.password_auth_service()?;
+        let email = Email::try_from(request.email).map_err(PasswordResetError::Validation)?;
+        let user = self
+            .store
+            .users()
+            .find_by_email(&email)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted());
+        let user = match user {
+            Some(user) => user,
+            None => {
+                info!("Password reset requested for an unknown email.");
+                return Ok(());
+            }
+        };
+        // the link is mailed by the worker, to answer as fast as for an unknown email
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::PasswordResetRequested,
+        ))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+
+    async fn reset_password(
+        &self,
+        request: ResetPasswordRequest,
+    ) -> Result<(), PasswordResetError> {
+        let auth_service = self.password_auth_service()?;
+        let mut tx = self.store.tx().await?;
+        let user_id = auth_service
+            .consume_token(&mut tx, &request.token, UserTokenKind::PasswordReset)
+            .await?
+            .ok_or(PasswordResetError::InvalidToken())?;
+        let mut user = self
+            .store
+            .users()
+            .find(&user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(PasswordResetError::InvalidToken())?;
+        auth_service
+            .change_password(&mut tx, &user.email, request.password)
+            .await
+            .map_err(|e| match e {
+                UpdateUserError::Validation(msg) => PasswordResetError::Validation(msg),
+                e => PasswordResetError::Unexpected(anyhow!(e)),
+            })?;
+        user.revoke_sessions();
+        user.password_reset_required = false;
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::PasswordChanged,
+        ))];
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+
+    async fn verify_email(
+        &self,
+        request: VerifyEmailRequest,
+    ) -> Result<(), EmailVerificationError> {
+        let (auth_service, _) =
+            self.email_verification()
+                .ok_or(EmailVerificationError::Validation(
+                    "Email verification is not enabled.",
+                ))?;
+        let mut tx = self.store.tx().await?;
+        let user_id = auth_service
+            .consume_token(&mut tx, &request.token, UserTokenKind::EmailVerification)
+            .await?
+            .ok_or(EmailVerificationError::InvalidToken())?;
+        let mut user = self
+            .store
+            .users()
+            .find(&user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(EmailVerificationError::InvalidToken())?;
+        let events = user
+            .verify_email()
+            .map(|kind| Event::User(UserEvent::new(user.id, kind)))
+            .into_iter()
+            .collect_vec();
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> Result<(), EmailVerificationError> {
+        let (auth_service, validity) =
+            self.email_verification()
+                .ok_or(EmailVerificationError::Validation(
+                    "Email verification is not enabled.",
+                ))?;
+        let email = Email::try_from(request.email).map_err(EmailVerificationError::Validation)?;
+        let user = self
+            .store
+            .users()
+            .find_by_email(&email)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted() && !u.email_verified);
+        match user {
+            Some(user) => {
+                self.send_verification(auth_service, &user, Vec::new(), validity)
+                    .await?
+            }
+            None => info!("Verification requested for an unknown or verified email."),
+        }
+        Ok(())
+    }
+
+    async fn takeout(
+        &self,
+        request: TakeoutRequest,
+    ) -> Result<Option<serde_json::Value>, TakeoutError> {
+        if !self.is_valid_user(&request.user_id).await? {
+            return Err(TakeoutError::Unauthenticated());
+        }
+        let takeout = self
+            .store
+            .takeouts()
+            .find(&request.user_id)
+            .await
+            .context("Failed to fetch takeout.")?;
+        if let Some(takeout) = takeout {
+            if !takeout.is_expired(self.takeout_retention) {
+                return Ok(takeout.archive);
+            }
+        }
+        let groups = self
+            .store
+            .groups()
+            .get_user_groups(&request.user_id)
+            .await
+            .context("Failed to fetch groups.")?;
+        if groups.len() <= self.takeout_sync_groups {
+            let archive = takeout::collect(&request.user_id, self.store.clone()).await?;
+            return Ok(Some(
+                serde_json::to_value(archive).context("Failed to serialize takeout.")?,
+            ));
+        }
+        let takeout = Takeout::request(request.user_id);
+        let events = [Event::User(UserEvent::new(
+            request.user_id,
+            UserEventKind::TakeoutRequested,
+        ))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .takeouts()
+            .save(&mut tx, &takeout)
+            .await
+            .context("Failed to save takeout.")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
             .await
             .context("Failed to save event")?;
         self.store.commit(tx.into_inner()).await?;
@@ -177,64 +626,494 @@ impl<Store: MultiRepository> UserUsecase<Store> {
             .unwrap_or_else(|failure| {
                 warn!("{:?}", failure);
             });
-        Ok(())
+        Ok(None)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::application::tests::TestContext;
-    use crate::domain::errors::{CreateUserError, LoginError};
-    use crate::domain::usecases::user::{LoginRequest, RegistrationRequest};
-    use crate::domain::usecases::user::{LogoutRequest, UserUseCase};
-    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
-    use claim::{assert_err, assert_matches, assert_none, assert_ok, assert_some};
-    use secrecy::{ExposeSecret, Secret};
 
-    #[tokio::test]
-    async fn it_should_create_a_user_given_valid_credentials() -> Result<(), anyhow::Error> {
-        let ctx = TestContext::new();
+    async fn delete(&self, request: DeleteUserRequest) -> Result<(), DeleteUserError> {
+        let user = self
+            .store
+            .users()
+            .find(&request.user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(DeleteUserError::Unauthenticated())?;
+        self.remove(user, UserEventKind::Deleted).await
+    }
 
-        let req = RegistrationRequest {
-            name: "bob".to_string(),
-            email: "r@r.com".to_string(),
-            password: Some(Secret::new("password".to_string())),
-        };
+    async fn create_api_token(
+        &self,
+        request: CreateApiTokenRequest,
+    ) -> Result<(ApiToken, Secret<String>), ApiTokenError> {
+        if !self.is_valid_user(&request.user_id).await? {
+            return Err(ApiTokenError::Unauthenticated());
+        }
+        let scope =
+            ApiTokenScope::try_from(request.scope.as_str()).map_err(ApiTokenError::Validation)?;
+        let (secret, token) =
+            ApiToken::generate(request.user_id, request.name, scope, request.validity_days)
+                .map_err(ApiTokenError::Validation)?;
+        let events = [Event::User(UserEvent::new(
+            token.user_id,
+            UserEventKind::ApiTokenCreated {
+                token_id: token.id,
+                name: token.name.clone(),
+            },
+        ))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .api_tokens()
+            .save(&mut tx, &token)
+            .await
+            .context("Failed to save API token.")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok((token, secret))
+    }
 
-        let res = ctx.user().register(req.clone()).await;
+    async fn get_api_tokens(&self, user_id: &Uuid) -> Result<Vec<ApiToken>, ApiTokenError> {
+        if !self.is_valid_user(user_id).await? {
+            return Err(ApiTokenError::Unauthenticated());
+        }
+        Ok(self
+            .store
+            .api_tokens()
+            .get_user_tokens(user_id)
+            .await
+            .context("Failed to fetch API tokens.")?)
+    }
 
-        let user_id = assert_ok!(res);
-        let user = ctx.get_user(&user_id).await;
-        assert_eq!(String::from(user.name.clone()), req.name);
-        assert_eq!(String::from(user.email.clone()), req.email);
-        let creds = ctx.get_credentials(&user_id).await;
-        assert_eq!(String::from(creds.email.clone()), req.email);
-        assert_ne!(
-            creds.password.get()?.expose_secret(),
-            &"password".to_string()
-        );
-        let event = assert_some!(ctx.last_stored_event());
-        assert_matches!(
-            event.event,
-            InnerEventKind::UserCreated { .. },
-            "Got incorrect event expected UserCreated"
-        );
-        let event_id = assert_some!(ctx.last_published_event());
-        assert_eq!(event.id, event_id);
+    async fn revoke_api_token(&self, request: RevokeApiTokenRequest) -> Result<(), ApiTokenError> {
+        let token = self
+            .get_api_tokens(&request.user_id)
+            .await?
+            .into_iter()
+            .find(|t| t.id == request.token_id)
+            .ok_or(ApiTokenError::NotFound("Token not found."))?;
+        let events = [Event::User(UserEvent::new(
+            token.user_id,
+            UserEventKind::ApiTokenRevoked {
+                token_id: token.id,
+                name: token.name,
+            },
+        ))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .api_tokens()
+            .delete(&mut tx, &token.id)
+            .await
+            .context("Failed to delete API token.")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
         Ok(())
     }
 
-    #[tokio::test]
-    async fn it_should_return_conflict_if_email_is_already_in_use() -> Result<(), anyhow::Error> {
-        let ctx = TestContext::new();
-        let user = ctx.with_user().await;
-
-        let req = RegistrationRequest {
-            name: "bob".to_string(),
-            email: String::from(user.email.clone()),
-            password: Some(Secret::new("password".to_string())),
+    async fn authenticate_api_token(
+        &self,
+        token: &Secret<String>,
+    ) -> Result<Option<(Uuid, ApiTokenScope)>, anyhow::Error> {
+        let token = self
+            .store
+            .api_tokens()
+            .find_by_hash(&ApiToken::hash(token))
+            .await
+            .context("Failed to fetch API token.")?;
+        let mut token = match token {
+            Some(token) if !token.is_expired() => token,
+            _ => return Ok(None),
         };
-
+        if !self.is_valid_user(&token.user_id).await? {
+            return Ok(None);
+        }
+        if token.mark_used() {
+            let mut tx = self.store.tx().await?;
+            self.store
+                .api_tokens()
+                .save(&mut tx, &token)
+                .await
+                .context("Failed to save API token.")?;
+            self.store.commit(tx.into_inner()).await?;
+        }
+        Ok(Some((token.user_id, token.scope)))
+    }
+
+    async fn enroll_two_factor(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<TwoFactorEnrollment, TwoFactorError> {
+        let auth_service = self.two_factor_auth_service()?;
+        let user = self.find_active_user(user_id).await.map_err(|e| match e {
+            UpdateUserError::Unexpected(e) => TwoFactorError::Unexpected(e),
+            _ => TwoFactorError::Unauthenticated(),
+        })?;
+        let mut tx = self.store.tx().await?;
+        let (provisioning_uri, secrets) =
+            auth_service.enroll_two_factor(&mut tx, &user.email).await?;
+        self.store.commit(tx.into_inner()).await?;
+        Ok(TwoFactorEnrollment {
+            secret: secrets.secret,
+            provisioning_uri,
+            recovery_codes: secrets.recovery_codes,
+        })
+    }
+
+    async fn confirm_two_factor(
+        &self,
+        request: ConfirmTwoFactorRequest,
+    ) -> Result<(), TwoFactorError> {
+        let auth_service = self.two_factor_auth_service()?;
+        let user = self
+            .find_active_user(&request.user_id)
+            .await
+            .map_err(|e| match e {
+                UpdateUserError::Unexpected(e) => TwoFactorError::Unexpected(e),
+                _ => TwoFactorError::Unauthenticated(),
+            })?;
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::TwoFactorEnabled,
+        ))];
+        let mut tx = self.store.tx().await?;
+        auth_service
+            .confirm_two_factor(&mut tx, &user.email, &request.code)
+            .await?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        Ok(())
+    }
+}
+
+impl<Store: MultiRepository> UserUsecase<Store> {
+    fn password_auth_service(&self) -> Result<&AuthService<Store>, PasswordResetError> {
+        self.auth_service
+            .as_ref()
+            .ok_or(PasswordResetError::Validation(
+                "Passwords are not managed by this application.",
+            ))
+    }
+
+    fn two_factor_auth_service(&self) -> Result<&AuthService<Store>, TwoFactorError> {
+        self.auth_service.as_ref().ok_or(TwoFactorError::Validation(
+            "Passwords are not managed by this application.",
+        ))
+    }
+
+    fn identity_provider(&self) -> Result<&dyn IdentityProvider, LoginError> {
+        self.identity_provider
+            .as_deref()
+            .ok_or(LoginError::Validation(
+                "No identity provider is configured.",
+            ))
+    }
+
+    fn email_verification(&self) -> Option<(&AuthService<Store>, Duration)> {
+        self.auth_service
+            .as_ref()
+            .zip(self.email_verification_validity)
+    }
+
+    /// Saves the user along with a new verification token and mails them the link to verify
+    /// their email.
+    async fn send_verification(
+        &self,
+        auth_service: &AuthService<Store>,
+        user: &User,
+        mut events: Vec<Event>,
+        validity: Duration,
+    ) -> Result<(), anyhow::Error> {
+        events.push(Event::User(UserEvent::new(
+            user.id,
+            UserEventKind::EmailVerificationRequested,
+        )));
+        let mut tx = self.store.tx().await?;
+        self.store
+            .users()
+            .save(&mut tx, user)
+            .await
+            .context("Failed to save user")?;
+        let token = auth_service
+            .issue_token(&mut tx, user.id, UserTokenKind::EmailVerification, validity)
+            .await?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        self.account_mailer
+            .send_email_verification(user, &token, validity)
+            .await
+            .context("Failed to send verification mail.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+        Ok(())
+    }
+
+    /// Anonymizes the user and removes their credentials, recording the deletion as `event`.
+    pub(crate) async fn remove(
+        &self,
+        mut user: User,
+        event: UserEventKind,
+    ) -> Result<(), DeleteUserError> {
+        let groups = self
+            .store
+            .groups()
+            .get_user_groups(&user.id)
+            .await
+            .context("Failed to fetch groups.")?;
+        if groups.iter().any(|g| g.is_admin(&user.id)) {
+            return Err(DeleteUserError::Conflict(
+                "The ownership of the groups administered by the user must be transferred first.",
+            ));
+        }
+        let login = user.email.clone();
+        user.anonymize();
+        let events = [Event::User(UserEvent::new(user.id, event))];
+        let mut tx = self.store.tx().await?;
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .credentials()
+            .delete(&mut tx, &login)
+            .await
+            .context("Failed to delete credentials")?;
+        self.store
+            .two_factors()
+            .delete(&mut tx, &login)
+            .await
+            .context("Failed to delete second factor")?;
+        self.store
+            .takeouts()
+            .delete(&mut tx, &user.id)
+            .await
+            .context("Failed to delete takeout.")?;
+        self.store
+            .api_tokens()
+            .delete_all(&mut tx, &user.id)
+            .await
+            .context("Failed to delete API tokens.")?;
+        self.store
+            .user_tokens()
+            .delete_user_tokens(&mut tx, &user.id)
+            .await
+            .context("Failed to delete user tokens.")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        #[cfg(feature = "pushy")]
+        self.device_service.delete(&user.id).await;
+        self.event_bus
+            .publish(&events.iter().map(|e| e.id()).collect_vec())
+            .await
+            .context("Failed to notify event bus.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+        Ok(())
+    }
+
+    /// Rejects the sessions of the user and mails them a password reset link, without which
+    /// they can no longer log in.
+    pub(crate) async fn require_password_reset(
+        &self,
+        mut user: User,
+        by: Uuid,
+    ) -> Result<(), PasswordResetError> {
+        let auth_service = self.password_auth_service()?;
+        let events = [Event::User(UserEvent::new(
+            user.id,
+            user.require_password_reset(by),
+        ))];
+        let mut tx = self.store.tx().await?;
+        let token = auth_service
+            .issue_token(
+                &mut tx,
+                user.id,
+                UserTokenKind::PasswordReset,
+                self.password_reset_validity,
+            )
+            .await?;
+        self.store
+            .users()
+            .save(&mut tx, &user)
+            .await
+            .context("Failed to save user")?;
+        self.store
+            .events()
+            .save(&mut tx, &events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(&events).await;
+        self.account_mailer
+            .send_password_reset(&user, &token, self.password_reset_validity)
+            .await
+            .context("Failed to send password reset mail.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+        Ok(())
+    }
+
+    async fn find_active_user(&self, user_id: &Uuid) -> Result<User, UpdateUserError> {
+        self.store
+            .users()
+            .find(user_id)
+            .await
+            .context("Failed to fetch user")?
+            .filter(|u| !u.is_deleted())
+            .ok_or(UpdateUserError::Unauthenticated())
+    }
+
+    async fn publish(&self, events: &[Event]) {
+        self.event_bus
+            .publish(&events.iter().map(|e| e.id()).collect_vec())
+            .await
+            .context("Failed to notify event bus.")
+            .unwrap_or_else(|failure| {
+                warn!("{:?}", failure);
+            });
+    }
+
+    async fn finalize(&self, user: Option<&User>, events: &[Event]) -> Result<(), anyhow::Error> {
+        let mut tx = self.store.tx().await?;
+        if let Some(user) = user {
+            self.store
+                .users()
+                .save(&mut tx, user)
+                .await
+                .context("Failed to insert user")?;
+        }
+        self.store
+            .events()
+            .save(&mut tx, events)
+            .await
+            .context("Failed to save event")?;
+        self.store.commit(tx.into_inner()).await?;
+        self.publish(events).await;
+        Ok(())
+    }
+}
+
+fn verification_error(e: LoginError) -> UpdateUserError {
+    match e {
+        LoginError::Validation(msg) => UpdateUserError::Validation(msg),
+        LoginError::InvalidCredentials() => UpdateUserError::InvalidCredentials(),
+        LoginError::Unverified() | LoginError::Disabled() | LoginError::PasswordResetRequired() => {
+            UpdateUserError::Unauthenticated()
+        }
+        LoginError::Unexpected(e) => UpdateUserError::Unexpected(e),
+    }
+}
+
+fn identity_error(e: IdentityProviderError) -> LoginError {
+    match e {
+        IdentityProviderError::Rejected(reason) => {
+            warn!("Identity rejected: {}", reason);
+            LoginError::InvalidCredentials()
+        }
+        IdentityProviderError::Unexpected(e) => LoginError::Unexpected(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::auth::{current_code, UserToken, UserTokenKind};
+    use crate::application::store::MultiRepository;
+    use crate::application::tests::TestContext;
+    use crate::application::user::PasswordResetMailer;
+    use crate::domain::errors::{
+        ApiTokenError, CreateUserError, DeleteUserError, EmailVerificationError, LoginError,
+        PasswordResetError, TwoFactorError, UpdateUserError,
+    };
+    use crate::domain::mail::Mail;
+    use crate::domain::usecases::event_processor::EventProcessor;
+    use crate::domain::usecases::group::{GroupUseCase, TransferOwnershipRequest};
+    use crate::domain::usecases::user::{
+        ChangeEmailRequest, ChangePasswordRequest, CreateApiTokenRequest, PasswordResetRequest,
+        ResendVerificationRequest, ResetPasswordRequest, RevokeApiTokenRequest, UpdateNameRequest,
+        VerifyEmailRequest,
+    };
+    use crate::domain::usecases::user::{
+        ConfirmTwoFactorRequest, LoginOutcome, SecondFactorRequest,
+    };
+    use crate::domain::usecases::user::{DeleteUserRequest, LoginRequest, RegistrationRequest};
+    use crate::domain::usecases::user::{IdentityLoginRequest, LogoutRequest, UserUseCase};
+    use crate::domain::{ApiToken, ApiTokenScope, Identity, User, UserEventKind};
+    use crate::infrastructure::store::mem::mem_store::InnerEventKind;
+    use crate::utils::date;
+    use chrono::Duration;
+    use claim::{assert_err, assert_matches, assert_none, assert_ok, assert_some};
+    use secrecy::{ExposeSecret, Secret};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn it_should_create_a_user_given_valid_credentials() -> Result<(), anyhow::Error> {
+        let ctx = TestContext::new();
+
+        let req = RegistrationRequest {
+            name: "bob".to_string(),
+            email: "r@r.com".to_string(),
+            password: Some(Secret::new("password".to_string())),
+        };
+
+        let res = ctx.user().register(req.clone()).await;
+
+        let user_id = assert_ok!(res);
+        let user = ctx.get_user(&user_id).await;
+        assert_eq!(String::from(user.name.clone()), req.name);
+        assert_eq!(String::from(user.email.clone()), req.email);
+        let creds = ctx.get_credentials(&user_id).await;
+        assert_eq!(String::from(creds.email.clone()), req.email);
+        assert_ne!(
+            creds.password.get()?.expose_secret(),
+            &"password".to_string()
+        );
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserCreated { .. },
+            "Got incorrect event expected UserCreated"
+        );
+        let event_id = assert_some!(ctx.last_published_event());
+        assert_eq!(event.id, event_id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_should_return_conflict_if_email_is_already_in_use() -> Result<(), anyhow::Error> {
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+
+        let req = RegistrationRequest {
+            name: "bob".to_string(),
+            email: String::from(user.email.clone()),
+            password: Some(Secret::new("password".to_string())),
+        };
+
         let res = ctx.user().register(req.clone()).await;
 
         let err = assert_err!(res);
@@ -423,8 +1302,8 @@ mod tests {
 
         let res = ctx.user().login(req.clone()).await;
 
-        let user_id = assert_ok!(res);
-        assert_eq!(user.id, user_id);
+        let outcome = assert_ok!(res);
+        assert_eq!(LoginOutcome::LoggedIn(user.id), outcome);
         let event = assert_some!(ctx.last_stored_event());
         assert_matches!(
             event.event,
@@ -441,8 +1320,8 @@ mod tests {
 
         let res = ctx.user_no_auth().login(req.clone()).await;
 
-        let user_id = assert_ok!(res);
-        assert_eq!(user.id, user_id);
+        let outcome = assert_ok!(res);
+        assert_eq!(LoginOutcome::LoggedIn(user.id), outcome);
         let event = assert_some!(ctx.last_stored_event());
         assert_matches!(
             event.event,
@@ -599,4 +1478,997 @@ mod tests {
         }
         Ok(())
     }
+
+    #[tokio::test]
+    async fn it_should_anonymize_a_deleted_user_and_keep_their_expenses() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let expense = ctx.with_expense_of(&mut group, 10.0, member.id).await;
+        let email = member.email.clone();
+        // when
+        let res = ctx
+            .user()
+            .delete(DeleteUserRequest { user_id: member.id })
+            .await;
+        // then
+        assert_ok!(res);
+        let user = ctx.get_user(&member.id).await;
+        assert!(user.is_deleted());
+        assert_ne!(
+            String::from(user.email.clone()),
+            String::from(email.clone())
+        );
+        assert_none!(ctx
+            .store()
+            .credentials()
+            .fetch_by_email(&email)
+            .await
+            .unwrap());
+        assert!(!assert_ok!(ctx.user().is_valid_user(&member.id).await));
+        let group = ctx.get_group(&group.id).await;
+        let anonymized = group.members.iter().find(|m| m.id == member.id).unwrap();
+        assert_eq!(String::from(anonymized.name.clone()), "Deleted user");
+        assert_some!(ctx.find_expense(&expense.id).await);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserDeleted { .. },
+            "Got incorrect event expected UserDeleted"
+        );
+        let event_id = assert_some!(ctx.last_published_event());
+        assert_eq!(event.id, event_id);
+        let res = ctx
+            .user()
+            .login(LoginRequest {
+                email: String::from(email),
+                password: None,
+            })
+            .await;
+        assert_err!(res);
+    }
+
+    #[tokio::test]
+    async fn it_should_require_an_ownership_transfer_before_deleting_an_admin() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let admin = group.admin_id;
+        let member = ctx.with_member(&mut group).await;
+        let req = DeleteUserRequest { user_id: admin };
+        // when
+        let res = ctx.user().delete(req.clone()).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, DeleteUserError::Conflict(_));
+        assert!(!ctx.get_user(&admin).await.is_deleted());
+        assert_ok!(
+            ctx.group()
+                .transfer_ownership(TransferOwnershipRequest {
+                    group_id: group.id,
+                    user_id: admin,
+                    new_admin_id: member.id,
+                })
+                .await
+        );
+        assert_ok!(ctx.user().delete(req).await);
+        assert!(ctx.get_user(&admin).await.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unauthenticated_when_user_is_already_deleted() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = DeleteUserRequest { user_id: user.id };
+        assert_ok!(ctx.user().delete(req.clone()).await);
+        // when
+        let res = ctx.user().delete(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, DeleteUserError::Unauthenticated());
+    }
+
+    #[tokio::test]
+    async fn it_should_revoke_the_password_reset_token_of_a_deleted_user() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = PasswordResetRequest {
+            email: String::from(user.email.clone()),
+        };
+        assert_ok!(ctx.user().request_password_reset(req).await);
+        let token = mail_reset_link(&ctx).await;
+        assert_ok!(
+            ctx.user()
+                .delete(DeleteUserRequest { user_id: user.id })
+                .await
+        );
+        // when
+        let res = ctx
+            .user()
+            .reset_password(ResetPasswordRequest {
+                token: token.clone(),
+                password: Secret::new("new password".to_string()),
+            })
+            .await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, PasswordResetError::InvalidToken());
+        assert_none!(assert_ok!(
+            ctx.store()
+                .user_tokens()
+                .find(&UserToken::hash(&token))
+                .await
+        ));
+    }
+
+    /// Password given to the users created by the test context.
+    fn password_of(user: &User) -> Secret<String> {
+        let name = String::from(user.name.clone());
+        Secret::new(format!("p_{}", &name[2..]))
+    }
+
+    #[tokio::test]
+    async fn it_should_rename_the_user() {
+        // given
+        let ctx = TestContext::new();
+        let mut group = ctx.with_group().await;
+        let member = ctx.with_member(&mut group).await;
+        let req = UpdateNameRequest {
+            user_id: member.id,
+            name: "Bobby".to_string(),
+        };
+        // when
+        let res = ctx.user().update_name(req).await;
+        // then
+        assert_ok!(res);
+        let user = ctx.get_user(&member.id).await;
+        assert_eq!(String::from(user.name), "Bobby");
+        let group = ctx.get_group(&group.id).await;
+        let renamed = group.members.iter().find(|m| m.id == member.id).unwrap();
+        assert_eq!(String::from(renamed.name.clone()), "Bobby");
+        let event = assert_some!(ctx.last_stored_event());
+        match event.event {
+            InnerEventKind::UserNameChanged {
+                previous_name,
+                new_name,
+                ..
+            } => {
+                assert_eq!(previous_name, String::from(member.name));
+                assert_eq!(new_name, "Bobby");
+            }
+            e => unreachable!("Got incorrect event expected UserNameChanged, got: {:?}", e),
+        }
+        assert_eq!(Some(event.id), ctx.last_published_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_when_the_name_is_empty() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = UpdateNameRequest {
+            user_id: user.id,
+            name: "".to_string(),
+        };
+        // when
+        let res = ctx.user().update_name(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, UpdateUserError::Validation(_));
+        assert_none!(ctx.last_stored_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_change_the_email_and_move_the_credentials() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = ChangeEmailRequest {
+            user_id: user.id,
+            email: "new@test.com".to_string(),
+            password: Some(password_of(&user)),
+        };
+        // when
+        let res = ctx.user().change_email(req).await;
+        // then
+        assert_ok!(res);
+        assert_eq!(
+            String::from(ctx.get_user(&user.id).await.email),
+            "new@test.com"
+        );
+        assert_none!(ctx
+            .store()
+            .credentials()
+            .fetch_by_email(&user.email)
+            .await
+            .unwrap());
+        assert_some!(ctx.find_credentials(&user.id).await);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserEmailChanged { .. },
+            "Got incorrect event expected UserEmailChanged"
+        );
+        let res = ctx
+            .user()
+            .login(LoginRequest {
+                email: "new@test.com".to_string(),
+                password: Some(password_of(&user)),
+            })
+            .await;
+        assert_eq!(assert_ok!(res), LoginOutcome::LoggedIn(user.id));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_change_the_email_given_a_wrong_password() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = ChangeEmailRequest {
+            user_id: user.id,
+            email: "new@test.com".to_string(),
+            password: Some(Secret::new("wrong".to_string())),
+        };
+        // when
+        let res = ctx.user().change_email(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, UpdateUserError::InvalidCredentials());
+        assert_eq!(
+            String::from(ctx.get_user(&user.id).await.email),
+            String::from(user.email)
+        );
+        assert_some!(ctx.find_credentials(&user.id).await);
+        assert_none!(ctx.last_stored_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_conflict_when_the_email_is_in_use() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let other = ctx.with_user().await;
+        let req = ChangeEmailRequest {
+            user_id: user.id,
+            email: String::from(other.email),
+            password: Some(password_of(&user)),
+        };
+        // when
+        let res = ctx.user().change_email(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, UpdateUserError::Conflict());
+    }
+
+    #[tokio::test]
+    async fn it_should_change_the_password_and_revoke_the_sessions() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let logged_in_at = date::now();
+        assert!(assert_ok!(
+            ctx.user()
+                .is_valid_session(&user.id, Some(logged_in_at))
+                .await
+        ));
+        let req = ChangePasswordRequest {
+            user_id: user.id,
+            current_password: password_of(&user),
+            new_password: Secret::new("new password".to_string()),
+        };
+        // when
+        let res = ctx.user().change_password(req).await;
+        // then
+        assert_ok!(res);
+        assert!(!assert_ok!(
+            ctx.user()
+                .is_valid_session(&user.id, Some(logged_in_at))
+                .await
+        ));
+        assert!(!assert_ok!(
+            ctx.user().is_valid_session(&user.id, None).await
+        ));
+        assert!(assert_ok!(
+            ctx.user()
+                .is_valid_session(&user.id, Some(date::now()))
+                .await
+        ));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserPasswordChanged { .. },
+            "Got incorrect event expected UserPasswordChanged"
+        );
+        let login = |password: &str| LoginRequest {
+            email: String::from(user.email.clone()),
+            password: Some(Secret::new(password.to_string())),
+        };
+        let old_password = password_of(&user).expose_secret().clone();
+        assert_err!(ctx.user().login(login(&old_password)).await);
+        assert_ok!(ctx.user().login(login("new password")).await);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_change_the_password_given_a_wrong_current_password() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = ChangePasswordRequest {
+            user_id: user.id,
+            current_password: Secret::new("wrong".to_string()),
+            new_password: Secret::new("new password".to_string()),
+        };
+        // when
+        let res = ctx.user().change_password(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, UpdateUserError::InvalidCredentials());
+        assert!(ctx.get_user(&user.id).await.sessions_revoked_at.is_none());
+        assert_none!(ctx.last_stored_event());
+    }
+
+    fn token_of(mail: &Mail) -> Secret<String> {
+        let (_, token) = mail.body.split_once("token=").unwrap();
+        Secret::new(token.split_whitespace().next().unwrap().to_string())
+    }
+
+    /// Mails the reset link of the last request, as the worker does.
+    async fn mail_reset_link(ctx: &TestContext) -> Secret<String> {
+        let event = assert_some!(ctx.last_published_event());
+        let mailer =
+            PasswordResetMailer::new(ctx.store(), ctx.account_mailer(), Duration::minutes(60));
+        assert_ok!(mailer.handle(&event).await);
+        token_of(assert_some!(ctx.sent_mails().last()))
+    }
+
+    #[tokio::test]
+    async fn it_should_request_a_password_reset_for_the_worker_to_mail() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let req = PasswordResetRequest {
+            email: String::from(user.email.clone()),
+        };
+        // when
+        let res = ctx.user().request_password_reset(req).await;
+        // then
+        assert_ok!(res);
+        assert!(ctx.sent_mails().is_empty());
+        let event = assert_some!(ctx.last_stored_event());
+        assert_eq!(Some(event.id), ctx.last_published_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserPasswordResetRequested { .. },
+            "Got incorrect event expected UserPasswordResetRequested"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_disclose_that_an_email_is_unknown() {
+        // given
+        let ctx = TestContext::new();
+        let req = PasswordResetRequest {
+            email: "unknown@test.com".to_string(),
+        };
+        // when
+        let res = ctx.user().request_password_reset(req).await;
+        // then
+        assert_ok!(res);
+        assert!(ctx.sent_mails().is_empty());
+        assert_none!(ctx.last_stored_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_reset_the_password_only_once_and_revoke_the_sessions() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let logged_in_at = date::now();
+        let email = String::from(user.email.clone());
+        let req = PasswordResetRequest {
+            email: email.clone(),
+        };
+        assert_ok!(ctx.user().request_password_reset(req).await);
+        let req = ResetPasswordRequest {
+            token: mail_reset_link(&ctx).await,
+            password: Secret::new("new password".to_string()),
+        };
+        // when
+        let res = ctx.user().reset_password(req.clone()).await;
+        // then
+        assert_ok!(res);
+        assert!(!assert_ok!(
+            ctx.user()
+                .is_valid_session(&user.id, Some(logged_in_at))
+                .await
+        ));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserPasswordChanged { .. },
+            "Got incorrect event expected UserPasswordChanged"
+        );
+        let res = ctx
+            .user()
+            .login(LoginRequest {
+                email,
+                password: Some(Secret::new("new password".to_string())),
+            })
+            .await;
+        assert_eq!(assert_ok!(res), LoginOutcome::LoggedIn(user.id));
+        let err = assert_err!(ctx.user().reset_password(req).await);
+        assert_matches!(err, PasswordResetError::InvalidToken());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_reset_the_password_given_an_expired_token() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let (token, user_token) =
+            UserToken::generate(user.id, UserTokenKind::PasswordReset, Duration::minutes(-1));
+        let mut tx = ctx.store().tx().await.unwrap();
+        ctx.store()
+            .user_tokens()
+            .save(&mut tx, &user_token)
+            .await
+            .unwrap();
+        ctx.store().commit(tx.into_inner()).await.unwrap();
+        let req = ResetPasswordRequest {
+            token,
+            password: Secret::new("new password".to_string()),
+        };
+        // when
+        let res = ctx.user().reset_password(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, PasswordResetError::InvalidToken());
+        assert!(ctx.get_user(&user.id).await.sessions_revoked_at.is_none());
+        assert_none!(ctx.last_stored_event());
+    }
+
+    fn registration(email: &str) -> RegistrationRequest {
+        RegistrationRequest {
+            name: "Bob".to_string(),
+            email: email.to_string(),
+            password: Some(Secret::new("password".to_string())),
+        }
+    }
+
+    fn login_of(email: &str) -> LoginRequest {
+        LoginRequest {
+            email: email.to_string(),
+            password: Some(Secret::new("password".to_string())),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_register_an_unverified_user_and_mail_them_a_verification_link() {
+        // given
+        let ctx = TestContext::new();
+        let req = registration("bob@test.com");
+        // when
+        let res = ctx.user_with_verification().register(req).await;
+        // then
+        let user_id = assert_ok!(res);
+        assert!(!ctx.get_user(&user_id).await.email_verified);
+        let mails = ctx.sent_mails();
+        assert_eq!(mails.len(), 1);
+        assert_eq!(String::from(mails[0].to.clone()), "bob@test.com");
+        assert!(mails[0].body.contains("/verify-email?token="));
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserEmailVerificationRequested { .. },
+            "Got incorrect event expected UserEmailVerificationRequested"
+        );
+        let err = assert_err!(ctx.user().login(login_of("bob@test.com")).await);
+        assert_matches!(err, LoginError::Unverified());
+        assert!(!assert_ok!(ctx.user().is_valid_user(&user_id).await));
+    }
+
+    #[tokio::test]
+    async fn it_should_verify_the_email_only_once_and_activate_the_user() {
+        // given
+        let ctx = TestContext::new();
+        let user_id = assert_ok!(
+            ctx.user_with_verification()
+                .register(registration("bob@test.com"))
+                .await
+        );
+        let req = VerifyEmailRequest {
+            token: token_of(&ctx.sent_mails()[0]),
+        };
+        // when
+        let res = ctx.user_with_verification().verify_email(req.clone()).await;
+        // then
+        assert_ok!(res);
+        assert!(ctx.get_user(&user_id).await.email_verified);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserEmailVerified { .. },
+            "Got incorrect event expected UserEmailVerified"
+        );
+        assert_eq!(
+            assert_ok!(ctx.user().login(login_of("bob@test.com")).await),
+            LoginOutcome::LoggedIn(user_id)
+        );
+        assert!(assert_ok!(ctx.user().is_valid_user(&user_id).await));
+        let err = assert_err!(ctx.user_with_verification().verify_email(req).await);
+        assert_matches!(err, EmailVerificationError::InvalidToken());
+    }
+
+    #[tokio::test]
+    async fn it_should_resend_a_verification_link_replacing_the_previous_one() {
+        // given
+        let ctx = TestContext::new();
+        let user_id = assert_ok!(
+            ctx.user_with_verification()
+                .register(registration("bob@test.com"))
+                .await
+        );
+        let req = ResendVerificationRequest {
+            email: "bob@test.com".to_string(),
+        };
+        // when
+        let res = ctx.user_with_verification().resend_verification(req).await;
+        // then
+        assert_ok!(res);
+        let mails = ctx.sent_mails();
+        assert_eq!(mails.len(), 2);
+        let previous = VerifyEmailRequest {
+            token: token_of(&mails[0]),
+        };
+        let err = assert_err!(ctx.user_with_verification().verify_email(previous).await);
+        assert_matches!(err, EmailVerificationError::InvalidToken());
+        let last = VerifyEmailRequest {
+            token: token_of(&mails[1]),
+        };
+        assert_ok!(ctx.user_with_verification().verify_email(last).await);
+        assert!(ctx.get_user(&user_id).await.email_verified);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_resend_a_verification_link_to_a_verified_or_unknown_email() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let resend = |email: String| ResendVerificationRequest { email };
+        // when
+        let verified = ctx
+            .user_with_verification()
+            .resend_verification(resend(String::from(user.email)))
+            .await;
+        let unknown = ctx
+            .user_with_verification()
+            .resend_verification(resend("unknown@test.com".to_string()))
+            .await;
+        // then
+        assert_ok!(verified);
+        assert_ok!(unknown);
+        assert!(ctx.sent_mails().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_when_verification_is_disabled() {
+        // given
+        let ctx = TestContext::new();
+        let user_id = assert_ok!(ctx.user().register(registration("bob@test.com")).await);
+        let req = ResendVerificationRequest {
+            email: "bob@test.com".to_string(),
+        };
+        // when
+        let res = ctx.user().resend_verification(req).await;
+        // then
+        let err = assert_err!(res);
+        assert_matches!(err, EmailVerificationError::Validation(_));
+        assert!(ctx.get_user(&user_id).await.email_verified);
+        assert!(ctx.sent_mails().is_empty());
+    }
+
+    fn identity(email: &str, name: Option<&str>) -> Identity {
+        Identity {
+            email: email.to_string(),
+            name: name.map(String::from),
+        }
+    }
+
+    async fn identity_login(ctx: &TestContext) -> Result<Uuid, LoginError> {
+        let (_, flow) = assert_ok!(
+            ctx.user_with_identity_provider()
+                .start_identity_login()
+                .await
+        );
+        let req = IdentityLoginRequest {
+            code: Some("code".to_string()),
+            state: Some(flow.state.clone()),
+            flow: Some(flow),
+        };
+        ctx.user_with_identity_provider()
+            .complete_identity_login(req)
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_create_the_user_on_their_first_identity_login() {
+        // given
+        let ctx = TestContext::new();
+        ctx.assert_identity(Some(identity("alice@corp.com", Some("Alice"))));
+        // when
+        let res = identity_login(&ctx).await;
+        // then
+        let user_id = assert_ok!(res);
+        let user = ctx.get_user(&user_id).await;
+        assert_eq!(String::from(user.name), "Alice");
+        assert_eq!(String::from(user.email), "alice@corp.com");
+        assert!(user.email_verified);
+        let store = ctx.store();
+        let events = assert_ok!(store.events().get_user_events(&user_id).await);
+        assert_eq!(events.len(), 2);
+        assert_matches!(&events[0].event, UserEventKind::Created { .. });
+        assert_matches!(&events[1].event, UserEventKind::Login);
+        assert_eq!(assert_ok!(identity_login(&ctx).await), user_id);
+    }
+
+    #[tokio::test]
+    async fn it_should_link_the_identity_to_the_user_with_the_same_email() {
+        // given
+        let ctx = TestContext::new();
+        let user_id = assert_ok!(
+            ctx.user_with_verification()
+                .register(registration("bob@test.com"))
+                .await
+        );
+        ctx.assert_identity(Some(identity("bob@test.com", None)));
+        // when
+        let res = identity_login(&ctx).await;
+        // then
+        assert_eq!(assert_ok!(res), user_id);
+        // the provider vouches for the email
+        assert!(ctx.get_user(&user_id).await.email_verified);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserLogin { .. });
+    }
+
+    #[tokio::test]
+    async fn it_should_return_invalid_credentials_when_the_flow_does_not_match() {
+        // given
+        let ctx = TestContext::new();
+        ctx.assert_identity(Some(identity("alice@corp.com", None)));
+        let (_, flow) = assert_ok!(
+            ctx.user_with_identity_provider()
+                .start_identity_login()
+                .await
+        );
+        let forged = IdentityLoginRequest {
+            code: Some("code".to_string()),
+            state: Some("forged".to_string()),
+            flow: Some(flow),
+        };
+        let not_started = IdentityLoginRequest {
+            code: Some("code".to_string()),
+            state: Some("state".to_string()),
+            flow: None,
+        };
+        // when
+        let forged = ctx
+            .user_with_identity_provider()
+            .complete_identity_login(forged)
+            .await;
+        let not_started = ctx
+            .user_with_identity_provider()
+            .complete_identity_login(not_started)
+            .await;
+        // then
+        assert_matches!(assert_err!(forged), LoginError::InvalidCredentials());
+        assert_matches!(assert_err!(not_started), LoginError::InvalidCredentials());
+        ctx.assert_identity(None);
+        let rejected = identity_login(&ctx).await;
+        assert_matches!(assert_err!(rejected), LoginError::InvalidCredentials());
+        assert_none!(ctx.last_stored_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_passwords_when_users_log_in_through_a_provider() {
+        // given
+        let ctx = TestContext::new();
+        ctx.assert_identity(Some(identity("bob@test.com", None)));
+        assert_ok!(identity_login(&ctx).await);
+        // when
+        let login = ctx
+            .user_with_identity_provider()
+            .login(login_of("bob@test.com"))
+            .await;
+        let register = ctx
+            .user_with_identity_provider()
+            .register(registration("eve@test.com"))
+            .await;
+        // then
+        assert_matches!(assert_err!(login), LoginError::Validation(_));
+        assert_matches!(assert_err!(register), CreateUserError::Validation(_));
+        assert_matches!(
+            assert_err!(ctx.user().start_identity_login().await),
+            LoginError::Validation(_)
+        );
+    }
+
+    fn api_token_request(user_id: Uuid, scope: &str) -> CreateApiTokenRequest {
+        CreateApiTokenRequest {
+            user_id,
+            name: "backup script".to_string(),
+            scope: scope.to_string(),
+            validity_days: 30,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_token_and_only_store_its_hash() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        // when
+        let res = ctx
+            .user()
+            .create_api_token(api_token_request(user.id, "read"))
+            .await;
+        // then
+        let (token, secret) = assert_ok!(res);
+        assert!(ApiToken::is_token(secret.expose_secret()));
+        assert_eq!(token.hash, ApiToken::hash(&secret));
+        assert_ne!(&token.hash, secret.expose_secret());
+        let tokens = assert_ok!(ctx.user().get_api_tokens(&user.id).await);
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].name, "backup script");
+        assert_eq!(tokens[0].scope, ApiTokenScope::Read);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserApiTokenCreated { .. },
+            "Got incorrect event expected UserApiTokenCreated"
+        );
+        let authenticated = ctx.user().authenticate_api_token(&secret).await;
+        assert_eq!(
+            assert_ok!(authenticated),
+            Some((user.id, ApiTokenScope::Read))
+        );
+        let tokens = assert_ok!(ctx.user().get_api_tokens(&user.id).await);
+        assert_some!(tokens[0].last_used_at);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_validation_error_given_an_invalid_token_request() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let no_name = CreateApiTokenRequest {
+            name: " ".to_string(),
+            ..api_token_request(user.id, "read")
+        };
+        let too_long = CreateApiTokenRequest {
+            validity_days: 366,
+            ..api_token_request(user.id, "read")
+        };
+        // when
+        let no_name = ctx.user().create_api_token(no_name).await;
+        let too_long = ctx.user().create_api_token(too_long).await;
+        let bad_scope = ctx
+            .user()
+            .create_api_token(api_token_request(user.id, "admin"))
+            .await;
+        // then
+        assert_matches!(assert_err!(no_name), ApiTokenError::Validation(_));
+        assert_matches!(assert_err!(too_long), ApiTokenError::Validation(_));
+        assert_matches!(assert_err!(bad_scope), ApiTokenError::Validation(_));
+        assert_none!(ctx.last_stored_event());
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_a_revoked_or_expired_token() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let (token, secret) = assert_ok!(
+            ctx.user()
+                .create_api_token(api_token_request(user.id, "write"))
+                .await
+        );
+        let (expired_secret, mut expired) = assert_ok!(ApiToken::generate(
+            user.id,
+            "expired".to_string(),
+            ApiTokenScope::Write,
+            1
+        ));
+        expired.expires_at = date::now() - Duration::minutes(1);
+        let mut tx = ctx.store().tx().await.unwrap();
+        ctx.store()
+            .api_tokens()
+            .save(&mut tx, &expired)
+            .await
+            .unwrap();
+        ctx.store().commit(tx.into_inner()).await.unwrap();
+        // when
+        let res = ctx
+            .user()
+            .revoke_api_token(RevokeApiTokenRequest {
+                user_id: user.id,
+                token_id: token.id,
+            })
+            .await;
+        // then
+        assert_ok!(res);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(
+            event.event,
+            InnerEventKind::UserApiTokenRevoked { .. },
+            "Got incorrect event expected UserApiTokenRevoked"
+        );
+        assert_none!(assert_ok!(ctx.user().authenticate_api_token(&secret).await));
+        assert_none!(assert_ok!(
+            ctx.user().authenticate_api_token(&expired_secret).await
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_revoke_the_token_of_another_user() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let other = ctx.with_user().await;
+        let (token, secret) = assert_ok!(
+            ctx.user()
+                .create_api_token(api_token_request(user.id, "read"))
+                .await
+        );
+        // when
+        let res = ctx
+            .user()
+            .revoke_api_token(RevokeApiTokenRequest {
+                user_id: other.id,
+                token_id: token.id,
+            })
+            .await;
+        // then
+        assert_matches!(assert_err!(res), ApiTokenError::NotFound(_));
+        assert_some!(assert_ok!(ctx.user().authenticate_api_token(&secret).await));
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_the_tokens_of_a_deleted_user() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let (_, secret) = assert_ok!(
+            ctx.user()
+                .create_api_token(api_token_request(user.id, "write"))
+                .await
+        );
+        // when
+        let res = ctx
+            .user()
+            .delete(DeleteUserRequest { user_id: user.id })
+            .await;
+        // then
+        assert_ok!(res);
+        assert_none!(assert_ok!(ctx.user().authenticate_api_token(&secret).await));
+        assert!(assert_ok!(ctx.store().api_tokens().get_user_tokens(&user.id).await).is_empty());
+    }
+
+    fn login_with_password(user: &User) -> LoginRequest {
+        LoginRequest {
+            email: String::from(user.email.clone()),
+            password: Some(password_of(user)),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_require_the_second_factor_once_confirmed() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let enrollment = assert_ok!(ctx.user_with_two_factor().enroll_two_factor(&user.id).await);
+        assert!(enrollment
+            .provisioning_uri
+            .starts_with("otpauth://totp/koru:"));
+        assert_eq!(enrollment.recovery_codes.len(), 10);
+        // the enrollment is pending until confirmed
+        assert_eq!(
+            assert_ok!(
+                ctx.user_with_two_factor()
+                    .login(login_with_password(&user))
+                    .await
+            ),
+            LoginOutcome::LoggedIn(user.id)
+        );
+        let code = assert_some!(current_code(&enrollment.secret));
+        // when
+        let res = ctx
+            .user_with_two_factor()
+            .confirm_two_factor(ConfirmTwoFactorRequest {
+                user_id: user.id,
+                code: code.clone(),
+            })
+            .await;
+        // then
+        assert_ok!(res);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserTwoFactorEnabled { .. });
+        assert_eq!(
+            assert_ok!(
+                ctx.user_with_two_factor()
+                    .login(login_with_password(&user))
+                    .await
+            ),
+            LoginOutcome::SecondFactorRequired(user.id)
+        );
+        // the code used for the confirmation cannot be replayed
+        let replayed = ctx
+            .user_with_two_factor()
+            .complete_second_factor(SecondFactorRequest {
+                user_id: user.id,
+                code,
+            })
+            .await;
+        assert_matches!(assert_err!(replayed), LoginError::InvalidCredentials());
+        let err = assert_err!(ctx.user_with_two_factor().enroll_two_factor(&user.id).await);
+        assert_matches!(err, TwoFactorError::Conflict(_));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_confirm_the_second_factor_given_an_invalid_code() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        assert_ok!(ctx.user_with_two_factor().enroll_two_factor(&user.id).await);
+        // when
+        let res = ctx
+            .user_with_two_factor()
+            .confirm_two_factor(ConfirmTwoFactorRequest {
+                user_id: user.id,
+                code: "abcdef".to_string(),
+            })
+            .await;
+        // then
+        assert_matches!(assert_err!(res), TwoFactorError::InvalidCode());
+        let err = assert_err!(ctx.user().enroll_two_factor(&user.id).await);
+        assert_matches!(err, TwoFactorError::Validation(_));
+    }
+
+    #[tokio::test]
+    async fn it_should_complete_the_login_with_a_recovery_code_only_once() {
+        // given
+        let ctx = TestContext::new();
+        let user = ctx.with_user().await;
+        let enrollment = assert_ok!(ctx.user_with_two_factor().enroll_two_factor(&user.id).await);
+        assert_ok!(
+            ctx.user_with_two_factor()
+                .confirm_two_factor(ConfirmTwoFactorRequest {
+                    user_id: user.id,
+                    code: assert_some!(current_code(&enrollment.secret)),
+                })
+                .await
+        );
+        let request = SecondFactorRequest {
+            user_id: user.id,
+            code: enrollment.recovery_codes[0].expose_secret().clone(),
+        };
+        // when
+        let res = ctx
+            .user_with_two_factor()
+            .complete_second_factor(request.clone())
+            .await;
+        // then
+        assert_eq!(assert_ok!(res), user.id);
+        let event = assert_some!(ctx.last_stored_event());
+        assert_matches!(event.event, InnerEventKind::UserLogin { .. });
+        let err = assert_err!(
+            ctx.user_with_two_factor()
+                .complete_second_factor(request)
+                .await
+        );
+        assert_matches!(err, LoginError::InvalidCredentials());
+    }
 }
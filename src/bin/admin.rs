@@ -0,0 +1,15 @@
+use std::env;
+
+/// This executable runs maintenance commands against the store of a Koru server: creating the
+/// first administrator, migrating the database, managing the events, exporting and importing
+/// groups.
+///
+/// It can be executed with
+/// ```
+/// cargo run --bin koru-admin -- create-admin --name Admin --email admin@koru.com
+/// ```
+///
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    koru::cli::admin(env::args().skip(1)).await
+}
@@ -0,0 +1,14 @@
+use std::env;
+
+/// This executable imports expenses exported by another application (Splitwise, Tricount or a
+/// generic CSV) into a group.
+///
+/// It can be executed with
+/// ```
+/// cargo run --bin koru-import -- --group GROUP_ID --user ADMIN_ID --format splitwise --member Alice=USER_ID --dry-run export.csv
+/// ```
+///
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    koru::cli::import(env::args().skip(1)).await
+}
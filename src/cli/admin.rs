@@ -0,0 +1,381 @@
+use crate::application::app::Application;
+use crate::configuration::get_configuration;
+use crate::domain::usecases::dto::dtos::StoredEventDto;
+use crate::domain::usecases::user::RegistrationRequest;
+use crate::infrastructure::event_bus::EventBusImpl;
+use crate::infrastructure::store::StoreImpl;
+use anyhow::{anyhow, bail, Context};
+use chrono::{DateTime, NaiveDate, Utc};
+use secrecy::Secret;
+use serde_json::json;
+use std::io::BufRead;
+use std::sync::Arc;
+use std::{fs, io};
+use uuid::Uuid;
+
+const USAGE: &str = "Usage: koru-admin [--json] COMMAND
+
+Commands:
+  create-admin --name NAME --email EMAIL [--password PASSWORD]
+  promote-user --email EMAIL
+  run-migrations
+  list-events [--unprocessed] [--limit COUNT]
+  purge-events --before DATE
+  republish-events
+  export-group --group GROUP_ID [--output FILE]
+  import-group FILE";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    /// The password is read from the standard input when not given.
+    CreateAdmin {
+        name: String,
+        email: String,
+        password: Option<String>,
+    },
+    PromoteUser {
+        email: String,
+    },
+    RunMigrations,
+    ListEvents {
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    },
+    PurgeEvents {
+        before: DateTime<Utc>,
+    },
+    RepublishEvents,
+    ExportGroup {
+        group_id: Uuid,
+        output: Option<String>,
+    },
+    ImportGroup {
+        file: String,
+    },
+}
+
+/// What a command prints, either for a human or as JSON with `--json`.
+struct Outcome {
+    message: String,
+    data: serde_json::Value,
+}
+
+/// Runs a maintenance command against the store of a Koru server, using the configuration of the
+/// server to reach it.
+///
+/// The changes are made on behalf of the operator, without an administrator of the application
+/// requesting them, which allows to bootstrap the first administrator.
+pub async fn admin(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let (command, as_json) = parse_args(args).map_err(|e| anyhow!("{}\n\n{}", e, USAGE))?;
+    let configuration = get_configuration().expect("Failed to read configuration.");
+    let store = Arc::new(StoreImpl::build(&configuration.database).await?);
+    if command == Command::RunMigrations {
+        store.migrate().await?;
+        return print(
+            Outcome {
+                message: "Database migrated.".to_string(),
+                data: json!({ "migrated": true }),
+            },
+            as_json,
+        );
+    }
+    let (event_bus, _) = EventBusImpl::build(&configuration.event_bus).await?;
+    let app = Application::build(&configuration.application, store, event_bus, None)?;
+    let outcome = run(command, &app).await?;
+    print(outcome, as_json)
+}
+
+async fn run(command: Command, app: &Application<StoreImpl>) -> anyhow::Result<Outcome> {
+    let admin = app.admin();
+    let outcome = match command {
+        Command::CreateAdmin {
+            name,
+            email,
+            password,
+        } => {
+            let password = match password {
+                Some(password) => password,
+                None => read_password()?,
+            };
+            let id = admin
+                .create_administrator(RegistrationRequest {
+                    name,
+                    email: email.clone(),
+                    password: Some(Secret::new(password)),
+                })
+                .await?;
+            Outcome {
+                message: format!("Created administrator {} ({}).", email, id),
+                data: json!({ "id": id }),
+            }
+        }
+        Command::PromoteUser { email } => {
+            let id = admin.promote(&email).await?;
+            Outcome {
+                message: format!("{} ({}) is administrator.", email, id),
+                data: json!({ "id": id }),
+            }
+        }
+        Command::RunMigrations => unreachable!("Migrations run without the application"),
+        Command::ListEvents {
+            unprocessed_only,
+            limit,
+        } => {
+            let events = admin.list_events(unprocessed_only, limit).await?;
+            Outcome {
+                message: events.iter().map(describe).collect::<Vec<_>>().join("\n"),
+                data: serde_json::to_value(&events)?,
+            }
+        }
+        Command::PurgeEvents { before } => {
+            let count = admin.purge_events(&before).await?;
+            Outcome {
+                message: format!("Purged {} events processed before {}.", count, before),
+                data: json!({ "purged": count }),
+            }
+        }
+        Command::RepublishEvents => {
+            let ids = admin.republish_events().await?;
+            Outcome {
+                message: format!("Republished {} unprocessed events.", ids.len()),
+                data: json!({ "republished": ids }),
+            }
+        }
+        Command::ExportGroup { group_id, output } => {
+            let backup = serde_json::to_string_pretty(&admin.export_group(&group_id).await?)?;
+            match output {
+                // the backup is the output, whatever the format asked for
+                None => Outcome {
+                    message: backup.clone(),
+                    data: serde_json::from_str(&backup)?,
+                },
+                Some(file) => {
+                    fs::write(&file, backup)
+                        .with_context(|| format!("Failed to write {}", file))?;
+                    Outcome {
+                        message: format!("Exported group {} to {}.", group_id, file),
+                        data: json!({ "file": file }),
+                    }
+                }
+            }
+        }
+        Command::ImportGroup { file } => {
+            let content =
+                fs::read_to_string(&file).with_context(|| format!("Failed to read {}", file))?;
+            let document = serde_json::from_str(&content).context("Invalid backup document")?;
+            let id = admin.import_group(document).await?;
+            Outcome {
+                message: format!("Imported group {}.", id),
+                data: json!({ "id": id }),
+            }
+        }
+    };
+    Ok(outcome)
+}
+
+fn print(outcome: Outcome, as_json: bool) -> anyhow::Result<()> {
+    if as_json {
+        println!("{}", serde_json::to_string_pretty(&outcome.data)?);
+    } else if !outcome.message.is_empty() {
+        println!("{}", outcome.message);
+    }
+    Ok(())
+}
+
+fn read_password() -> anyhow::Result<String> {
+    let mut password = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut password)
+        .context("Failed to read the password")?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// One line per event: its date, kind, subject and processing status.
+fn describe(event: &StoredEventDto) -> String {
+    let subject = match (event.user_id, event.group_id, event.member_id) {
+        (Some(user_id), _, _) => format!("user {}", user_id),
+        (_, Some(group_id), Some(member_id)) => format!("group {} by {}", group_id, member_id),
+        _ => String::new(),
+    };
+    let status = match event.processed_at {
+        Some(date) => format!("processed {}", date.to_rfc3339()),
+        None => "unprocessed".to_string(),
+    };
+    format!(
+        "{}  {}  {:<32} {}  {}",
+        event.date.to_rfc3339(),
+        event.id,
+        event.kind,
+        subject,
+        status
+    )
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> anyhow::Result<(Command, bool)> {
+    let mut as_json = false;
+    let mut args = args.filter(|arg| {
+        let json = arg == "--json";
+        as_json |= json;
+        !json
+    });
+    let command = args.next().context("Missing command")?;
+    let (mut name, mut email, mut password, mut group_id, mut output, mut file) =
+        (None, None, None, None, None, None);
+    let (mut unprocessed_only, mut limit, mut before) = (false, None, None);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .with_context(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--name" => name = Some(value()?),
+            "--email" => email = Some(value()?),
+            "--password" => password = Some(value()?),
+            "--group" => group_id = Some(Uuid::parse_str(&value()?).context("Invalid group id")?),
+            "--output" => output = Some(value()?),
+            "--unprocessed" => unprocessed_only = true,
+            "--limit" => limit = Some(value()?.parse().context("Invalid limit")?),
+            "--before" => before = Some(parse_date(&value()?)?),
+            _ if arg.starts_with("--") => bail!("Unknown option {}", arg),
+            _ => file = Some(arg),
+        }
+    }
+    let command = match command.as_str() {
+        "create-admin" => Command::CreateAdmin {
+            name: name.context("Missing name")?,
+            email: email.context("Missing email")?,
+            password,
+        },
+        "promote-user" => Command::PromoteUser {
+            email: email.context("Missing email")?,
+        },
+        "run-migrations" => Command::RunMigrations,
+        "list-events" => Command::ListEvents {
+            unprocessed_only,
+            limit,
+        },
+        "purge-events" => Command::PurgeEvents {
+            before: before.context("Missing date")?,
+        },
+        "republish-events" => Command::RepublishEvents,
+        "export-group" => Command::ExportGroup {
+            group_id: group_id.context("Missing group id")?,
+            output,
+        },
+        "import-group" => Command::ImportGroup {
+            file: file.context("Missing file")?,
+        },
+        _ => bail!("Unknown command {}", command),
+    };
+    Ok((command, as_json))
+}
+
+/// Either a RFC 3339 date, or a day starting at midnight UTC.
+fn parse_date(value: &str) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    let day = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .with_context(|| format!("Invalid date {}", value))?;
+    Ok(DateTime::from_utc(day.and_hms_opt(0, 0, 0).unwrap(), Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use claim::{assert_err, assert_ok};
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn it_should_parse_the_commands() {
+        let group = Uuid::new_v4();
+        assert_eq!(
+            assert_ok!(parse_args(args(&[
+                "create-admin",
+                "--name",
+                "Jean",
+                "--email",
+                "jean@koru.com",
+            ]))),
+            (
+                Command::CreateAdmin {
+                    name: "Jean".to_string(),
+                    email: "jean@koru.com".to_string(),
+                    password: None,
+                },
+                false
+            )
+        );
+        assert_eq!(
+            assert_ok!(parse_args(args(&[
+                "list-events",
+                "--json",
+                "--unprocessed",
+                "--limit",
+                "20"
+            ]))),
+            (
+                Command::ListEvents {
+                    unprocessed_only: true,
+                    limit: Some(20),
+                },
+                true
+            )
+        );
+        assert_eq!(
+            assert_ok!(parse_args(args(&[
+                "purge-events",
+                "--before",
+                "2026-01-31"
+            ]))),
+            (
+                Command::PurgeEvents {
+                    before: Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap(),
+                },
+                false
+            )
+        );
+        assert_eq!(
+            assert_ok!(parse_args(args(&[
+                "--json",
+                "export-group",
+                "--group",
+                &group.to_string(),
+            ]))),
+            (
+                Command::ExportGroup {
+                    group_id: group,
+                    output: None,
+                },
+                true
+            )
+        );
+        assert_eq!(
+            assert_ok!(parse_args(args(&["import-group", "backup.json"]))),
+            (
+                Command::ImportGroup {
+                    file: "backup.json".to_string(),
+                },
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_reject_missing_or_unknown_arguments() {
+        assert_err!(parse_args(args(&[])));
+        assert_err!(parse_args(args(&["drop-database"])));
+        assert_err!(parse_args(args(&["promote-user"])));
+        assert_err!(parse_args(args(&["purge-events", "--before", "yesterday"])));
+        assert_err!(parse_args(args(&["export-group", "--group", "not-a-uuid"])));
+        assert_err!(parse_args(args(&["list-events", "--verbose"])));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::application::app::Application;
+use crate::configuration::get_configuration;
+use crate::domain::usecases::group::{GroupUseCase, ImportExpensesRequest};
+use crate::infrastructure::event_bus::EventBusImpl;
+use crate::infrastructure::store::StoreImpl;
+use anyhow::{anyhow, bail, Context};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::{fs, process};
+use uuid::Uuid;
+
+const USAGE: &str =
+    "Usage: koru-import --group GROUP_ID --user ADMIN_ID --format splitwise|tricount|generic \
+[--member NAME=USER_ID]... [--dry-run] FILE";
+
+/// Imports the expenses of a file exported by another application into a group, using the
+/// configuration of the server to reach its store.
+///
+/// Each person paying an expense in the file should be mapped to a group member with `--member`.
+/// The report of the import is printed as JSON, and the process exits with an error when any
+/// line of the file is invalid.
+pub async fn import(args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let request = parse_args(args).map_err(|e| anyhow!("{}\n{}", e, USAGE))?;
+    let configuration = get_configuration().expect("Failed to read configuration.");
+    let store = Arc::new(StoreImpl::build(&configuration.database).await?);
+    let (event_bus, _) = EventBusImpl::build(&configuration.event_bus).await?;
+    let app = Application::build(&configuration.application, store, event_bus, None)?;
+
+    let report = app.groups().import_expenses(request).await?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    if !report.errors.is_empty() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<ImportExpensesRequest> {
+    let (mut group_id, mut user_id, mut format, mut file) = (None, None, None, None);
+    let mut members = HashMap::new();
+    let mut dry_run = false;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .with_context(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--group" => group_id = Some(Uuid::parse_str(&value()?).context("Invalid group id")?),
+            "--user" => user_id = Some(Uuid::parse_str(&value()?).context("Invalid user id")?),
+            "--format" => format = Some(value()?),
+            "--member" => {
+                let member = value()?;
+                let (name, id) = member
+                    .rsplit_once('=')
+                    .with_context(|| format!("Invalid member mapping {}", member))?;
+                let id = Uuid::parse_str(id).context("Invalid member id")?;
+                members.insert(name.to_string(), id);
+            }
+            "--dry-run" => dry_run = true,
+            _ if arg.starts_with("--") => bail!("Unknown option {}", arg),
+            _ => file = Some(arg),
+        }
+    }
+    let file = file.context("Missing file")?;
+    Ok(ImportExpensesRequest {
+        group_id: group_id.context("Missing group id")?,
+        user_id: user_id.context("Missing user id")?,
+        format: format.context("Missing format")?,
+        content: fs::read_to_string(&file).with_context(|| format!("Failed to read {}", file))?,
+        members,
+        dry_run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::assert_ok;
+    use std::env;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn it_should_parse_the_import_arguments() {
+        // given
+        let file = env::temp_dir().join(format!("{}.csv", Uuid::new_v4()));
+        fs::write(&file, "date,title,amount,paid_by\n").unwrap();
+        let (group, admin, member) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        // when
+        let request = parse_args(args(&[
+            "--group",
+            &group.to_string(),
+            "--user",
+            &admin.to_string(),
+            "--format",
+            "generic",
+            "--member",
+            &format!("Jean=Luc={}", member),
+            "--dry-run",
+            file.to_str().unwrap(),
+        ]));
+        // then
+        let request = assert_ok!(request);
+        assert_eq!(request.group_id, group);
+        assert_eq!(request.user_id, admin);
+        assert_eq!(request.members.get("Jean=Luc"), Some(&member));
+        assert!(request.dry_run);
+        assert_eq!(request.content, "date,title,amount,paid_by\n");
+        fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn it_should_reject_missing_or_unknown_arguments() {
+        assert!(parse_args(args(&["--group", "not-a-uuid", "file.csv"])).is_err());
+        assert!(parse_args(args(&["--verbose"])).is_err());
+        assert!(parse_args(args(&["--format"])).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! Command line tools operating on the store of a Koru server.
+mod admin;
+mod import;
+
+pub use admin::admin;
+pub use import::import;
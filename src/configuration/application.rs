@@ -1,6 +1,11 @@
+use crate::configuration::mail::MailSettings;
 #[cfg(feature = "notification")]
 use crate::configuration::notification::NotificationSettings;
+#[cfg(feature = "oidc")]
+use crate::domain::IdentityProvider;
 use crate::domain::TokenGenerator;
+#[cfg(feature = "oidc")]
+use crate::infrastructure::identity_provider::OidcIdentityProvider;
 #[cfg(feature = "jwt")]
 use crate::infrastructure::token_generator::JwtTokenGenerator;
 use secrecy::Secret;
@@ -10,12 +15,21 @@ pub struct ApiSettings {
     pub port: u16,
     pub host: String,
     pub session: SessionSettings,
+    pub rate_limit: RateLimitSettings,
 }
 
 #[derive(serde::Deserialize, Debug)]
 pub struct ApplicationSettings {
     pub auth: AuthSettings,
     pub token: TokenSettings,
+    pub settlement: SettlementSettings,
+    pub takeout: TakeoutSettings,
+    pub password_reset: PasswordResetSettings,
+    /// New users have to verify their email when set, which requires the `internal` auth.
+    pub email_verification: Option<EmailVerificationSettings>,
+    /// Users can enroll a TOTP second factor when set, which requires the `internal` auth.
+    pub two_factor: Option<TwoFactorSettings>,
+    pub mail: MailSettings,
     #[cfg(feature = "notification")]
     pub notification: Option<NotificationSettings>,
 }
@@ -26,6 +40,54 @@ pub enum AuthSettings {
     None,
     #[serde(rename = "internal")]
     Internal,
+    #[cfg(feature = "oidc")]
+    #[serde(rename = "oidc")]
+    Oidc(OidcSettings),
+}
+
+#[cfg(feature = "oidc")]
+#[derive(serde::Deserialize, Debug)]
+pub struct OidcSettings {
+    /// Issuer of the provider, its metadata being discovered at
+    /// `{issuer_url}/.well-known/openid-configuration`.
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Secret<String>,
+    /// Callback registered with the provider, served by `/oidc/callback`.
+    pub redirect_url: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SettlementSettings {
+    pub revert_window: u16,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct TakeoutSettings {
+    /// Hours during which a generated archive is kept for download.
+    pub retention: u16,
+    /// Archives of users belonging to more groups are generated by the worker.
+    pub sync_group_limit: u16,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct PasswordResetSettings {
+    /// Minutes during which a password reset link can be used.
+    pub validity: u16,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct EmailVerificationSettings {
+    /// Hours during which a verification link can be used.
+    pub validity: u16,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct TwoFactorSettings {
+    /// Name under which authenticator applications list the accounts.
+    pub issuer: String,
+    /// Key the TOTP secrets are encrypted with in the store.
+    pub encryption_key: Secret<String>,
 }
 
 #[derive(serde::Deserialize, Debug)]
@@ -51,6 +113,40 @@ pub struct RedisSessionSettings {
     pub port: u16,
 }
 
+/// Throttling of the `/register` and `/login` endpoints.
+#[derive(serde::Deserialize, Debug)]
+pub struct RateLimitSettings {
+    /// Requests allowed per client IP within `window`.
+    pub ip_attempts: u32,
+    /// Seconds after which the requests of a client IP are counted anew.
+    pub window: u32,
+    /// Consecutive failed logins after which an account is locked.
+    pub account_failures: u32,
+    /// Seconds of the first lockout of an account, doubled on each following one.
+    pub lockout: u32,
+    /// Uses the client IP reported by the `Forwarded` or `X-Forwarded-For` headers, which
+    /// should only be set behind a reverse proxy.
+    #[serde(default)]
+    pub trust_forwarded_headers: bool,
+    pub store: RateLimitStoreSettings,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub enum RateLimitStoreSettings {
+    #[cfg(feature = "redis-rate-limit")]
+    #[serde(rename = "redis")]
+    Redis(RedisRateLimitSettings),
+    #[serde(rename = "memory")]
+    Memory,
+}
+
+#[cfg(feature = "redis-rate-limit")]
+#[derive(serde::Deserialize, Debug)]
+pub struct RedisRateLimitSettings {
+    pub host: String,
+    pub port: u16,
+}
+
 #[derive(serde::Deserialize, Debug)]
 pub enum TokenSettings {
     #[cfg(feature = "jwt")]
@@ -79,9 +175,28 @@ impl TokenSettings {
     }
 }
 
+#[cfg(feature = "oidc")]
+impl OidcSettings {
+    pub fn setup_identity_provider(&self) -> impl IdentityProvider {
+        OidcIdentityProvider::new(
+            self.issuer_url.clone(),
+            self.client_id.clone(),
+            self.client_secret.clone(),
+            self.redirect_url.clone(),
+        )
+    }
+}
+
 #[cfg(feature = "redis-session")]
 impl RedisSessionSettings {
     pub fn connection_string(&self) -> Secret<String> {
         Secret::new(format!("redis://{}:{}", self.host, self.port))
     }
 }
+
+#[cfg(feature = "redis-rate-limit")]
+impl RedisRateLimitSettings {
+    pub fn connection_string(&self) -> Secret<String> {
+        Secret::new(format!("redis://{}:{}", self.host, self.port))
+    }
+}
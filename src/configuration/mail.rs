@@ -0,0 +1,64 @@
+use crate::domain::mail::Mailer;
+use crate::infrastructure::mailer::FileMailer;
+#[cfg(feature = "smtp")]
+use crate::infrastructure::mailer::SmtpMailer;
+#[cfg(feature = "smtp")]
+use secrecy::Secret;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MailSettings {
+    /// Address the mails are sent from, e.g. `Koru <noreply@koru.app>`.
+    pub sender: String,
+    /// Base URL of the client application, the links sent by mail point to its pages.
+    pub public_url: String,
+    pub transport: MailTransportSettings,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub enum MailTransportSettings {
+    #[serde(rename = "file")]
+    File(FileMailSettings),
+    #[cfg(feature = "smtp")]
+    #[serde(rename = "smtp")]
+    Smtp(SmtpSettings),
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct FileMailSettings {
+    /// Directory the mails are written to, they are only logged when unset.
+    pub directory: Option<String>,
+}
+
+#[cfg(feature = "smtp")]
+#[derive(serde::Deserialize, Debug)]
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<Secret<String>>,
+    /// Whether to require STARTTLS, only disable it for local mail catchers.
+    #[serde(default = "default_tls")]
+    pub tls: bool,
+}
+
+#[cfg(feature = "smtp")]
+fn default_tls() -> bool {
+    true
+}
+
+impl MailSettings {
+    pub fn setup_mailer(&self) -> anyhow::Result<Arc<dyn Mailer>> {
+        match &self.transport {
+            MailTransportSettings::File(conf) => Ok(Arc::new(FileMailer::new(
+                self.sender.clone(),
+                conf.directory.as_ref().map(PathBuf::from),
+            ))),
+            #[cfg(feature = "smtp")]
+            MailTransportSettings::Smtp(conf) => {
+                Ok(Arc::new(SmtpMailer::try_new(&self.sender, conf)?))
+            }
+        }
+    }
+}
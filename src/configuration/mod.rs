@@ -4,6 +4,7 @@ use crate::configuration::store::DatabaseSettings;
 
 pub mod application;
 pub mod event_bus;
+pub mod mail;
 #[cfg(feature = "notification")]
 pub mod notification;
 pub mod store;
@@ -0,0 +1,272 @@
+use crate::domain::backup::Ids;
+use crate::domain::{Amount, BudgetPeriod, GroupEvent, GroupEventKind, MemberColor, Transaction};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupEvent {
+    pub date: DateTime<Utc>,
+    /// Email of the member at the origin of the event.
+    pub member: String,
+    pub event: BackupEventKind,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BackupEventKind {
+    GroupCreated {
+        name: String,
+        color: String,
+    },
+    MemberJoined {
+        color: String,
+    },
+    MemberColorChanged {
+        previous_color: String,
+        new_color: String,
+    },
+    ExpenseCreated {
+        id: Uuid,
+        description: String,
+        amount: f32,
+        date: DateTime<Utc>,
+    },
+    ExpenseModified {
+        id: Uuid,
+        previous_description: String,
+        new_description: String,
+        previous_amount: f32,
+        new_amount: f32,
+    },
+    ExpenseDeleted {
+        id: Uuid,
+    },
+    Settled {
+        id: Uuid,
+        start_date: Option<DateTime<Utc>>,
+        end_date: DateTime<Utc>,
+        transactions: Vec<BackupTransaction>,
+    },
+    SettlementReverted {
+        id: Uuid,
+    },
+    BudgetChanged {
+        amount: Option<f32>,
+        period: Option<String>,
+    },
+    OwnershipTransferred {
+        /// Email of the member made administrator.
+        new_admin: String,
+    },
+    GroupDeleted,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupTransaction {
+    /// Email of the member paying.
+    pub from: String,
+    /// Email of the member paid.
+    pub to: String,
+    pub amount: f32,
+    pub paid: bool,
+}
+
+impl BackupTransaction {
+    pub(in crate::domain) fn from_all(
+        transactions: &[Transaction],
+        email: &impl Fn(&Uuid) -> Result<String, &'static str>,
+    ) -> Result<Vec<Self>, &'static str> {
+        transactions
+            .iter()
+            .map(|t| {
+                Ok(Self {
+                    from: email(&t.from)?,
+                    to: email(&t.to)?,
+                    amount: f32::from(t.amount),
+                    paid: t.paid,
+                })
+            })
+            .collect()
+    }
+
+    pub(super) fn restore_all(
+        transactions: Vec<Self>,
+        member: &impl Fn(&str) -> Result<Uuid, &'static str>,
+    ) -> Result<Vec<Transaction>, &'static str> {
+        transactions
+            .into_iter()
+            .map(|t| {
+                Ok(Transaction {
+                    from: member(&t.from)?,
+                    to: member(&t.to)?,
+                    amount: Amount::try_from(t.amount)?,
+                    paid: t.paid,
+                })
+            })
+            .collect()
+    }
+}
+
+impl BackupEvent {
+    pub(in crate::domain) fn from(
+        event: &GroupEvent,
+        email: &impl Fn(&Uuid) -> Result<String, &'static str>,
+    ) -> Result<Self, &'static str> {
+        let kind = match event.event.clone() {
+            GroupEventKind::GroupCreated { name, color } => BackupEventKind::GroupCreated {
+                name,
+                color: String::from(color),
+            },
+            GroupEventKind::MemberJoined { color } => BackupEventKind::MemberJoined {
+                color: String::from(color),
+            },
+            GroupEventKind::MemberColorChanged {
+                previous_color,
+                new_color,
+            } => BackupEventKind::MemberColorChanged {
+                previous_color: String::from(previous_color),
+                new_color: String::from(new_color),
+            },
+            GroupEventKind::ExpenseCreated {
+                id,
+                description,
+                amount,
+                date,
+            } => BackupEventKind::ExpenseCreated {
+                id,
+                description,
+                amount,
+                date,
+            },
+            GroupEventKind::ExpenseModified {
+                id,
+                previous_description,
+                new_description,
+                previous_amount,
+                new_amount,
+            } => BackupEventKind::ExpenseModified {
+                id,
+                previous_description,
+                new_description,
+                previous_amount,
+                new_amount,
+            },
+            GroupEventKind::ExpenseDeleted { id } => BackupEventKind::ExpenseDeleted { id },
+            GroupEventKind::Settled {
+                id,
+                start_date,
+                end_date,
+                transactions,
+            } => BackupEventKind::Settled {
+                id,
+                start_date,
+                end_date,
+                transactions: BackupTransaction::from_all(&transactions, email)?,
+            },
+            GroupEventKind::SettlementReverted { id } => BackupEventKind::SettlementReverted { id },
+            GroupEventKind::BudgetChanged { amount, period } => BackupEventKind::BudgetChanged {
+                amount,
+                period: period.map(String::from),
+            },
+            GroupEventKind::OwnershipTransferred { new_admin_id } => {
+                BackupEventKind::OwnershipTransferred {
+                    new_admin: email(&new_admin_id)?,
+                }
+            }
+            GroupEventKind::GroupDeleted => BackupEventKind::GroupDeleted,
+            GroupEventKind::GroupArchived
+            | GroupEventKind::GroupUnarchived
+            | GroupEventKind::MemberRemoved { .. } => {
+                return Err("Moderation events are not backed up.")
+            }
+        };
+        Ok(Self {
+            date: event.event_date,
+            member: email(&event.member_id)?,
+            event: kind,
+        })
+    }
+
+    pub(super) fn restore(
+        self,
+        group_id: Uuid,
+        member: &impl Fn(&str) -> Result<Uuid, &'static str>,
+        ids: &mut Ids,
+    ) -> Result<GroupEvent, &'static str> {
+        let event = match self.event {
+            BackupEventKind::GroupCreated { name, color } => GroupEventKind::GroupCreated {
+                name,
+                color: MemberColor::try_from(color)?,
+            },
+            BackupEventKind::MemberJoined { color } => GroupEventKind::MemberJoined {
+                color: MemberColor::try_from(color)?,
+            },
+            BackupEventKind::MemberColorChanged {
+                previous_color,
+                new_color,
+            } => GroupEventKind::MemberColorChanged {
+                previous_color: MemberColor::try_from(previous_color)?,
+                new_color: MemberColor::try_from(new_color)?,
+            },
+            BackupEventKind::ExpenseCreated {
+                id,
+                description,
+                amount,
+                date,
+            } => GroupEventKind::ExpenseCreated {
+                id: ids.remap(id),
+                description,
+                amount,
+                date,
+            },
+            BackupEventKind::ExpenseModified {
+                id,
+                previous_description,
+                new_description,
+                previous_amount,
+                new_amount,
+            } => GroupEventKind::ExpenseModified {
+                id: ids.remap(id),
+                previous_description,
+                new_description,
+                previous_amount,
+                new_amount,
+            },
+            BackupEventKind::ExpenseDeleted { id } => {
+                GroupEventKind::ExpenseDeleted { id: ids.remap(id) }
+            }
+            BackupEventKind::Settled {
+                id,
+                start_date,
+                end_date,
+                transactions,
+            } => GroupEventKind::Settled {
+                id: ids.remap(id),
+                start_date,
+                end_date,
+                transactions: BackupTransaction::restore_all(transactions, member)?,
+            },
+            BackupEventKind::SettlementReverted { id } => {
+                GroupEventKind::SettlementReverted { id: ids.remap(id) }
+            }
+            BackupEventKind::BudgetChanged { amount, period } => GroupEventKind::BudgetChanged {
+                amount,
+                period: period.map(BudgetPeriod::try_from).transpose()?,
+            },
+            BackupEventKind::OwnershipTransferred { new_admin } => {
+                GroupEventKind::OwnershipTransferred {
+                    new_admin_id: member(&new_admin)?,
+                }
+            }
+            BackupEventKind::GroupDeleted => GroupEventKind::GroupDeleted,
+        };
+        Ok(GroupEvent {
+            id: Uuid::new_v4(),
+            event_date: self.date,
+            group_id,
+            member_id: member(&self.member)?,
+            event,
+        })
+    }
+}
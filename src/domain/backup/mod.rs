@@ -0,0 +1,430 @@
+//! Versioned JSON document holding a whole group, used to move it to another instance.
+//!
+//! Members are referenced by email rather than by id, so that a backup can be restored on an
+//! instance where the same people have different accounts. Restoring a backup gives new ids
+//! to the group, its expenses, settlements and events.
+//!
+//! The `version` field is increased on each change of the document schema. Documents of an
+//! older version are read with their own schema, then upgraded to the current one.
+mod backup_event;
+
+pub use backup_event::{BackupEvent, BackupTransaction};
+
+use crate::domain::{
+    Amount, Budget, BudgetPeriod, Expense, ExpenseTitle, Group, GroupEvent, GroupMember, GroupName,
+    MemberBalance, MemberColor, Settlement, SettlementLabel, User,
+};
+use crate::utils::date;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Version of the documents written by this instance.
+pub const BACKUP_VERSION: u32 = 1;
+
+const UNKNOWN_MEMBER: &str = "The backup references a member that is not part of the group.";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupBackup {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub members: Vec<BackupMember>,
+    pub expenses: Vec<BackupExpense>,
+    pub settlements: Vec<BackupSettlement>,
+    #[serde(default)]
+    pub budget: Option<BackupBudget>,
+    pub events: Vec<BackupEvent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupMember {
+    pub email: String,
+    pub name: String,
+    pub color: String,
+    pub is_admin: bool,
+    pub joined_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupExpense {
+    pub id: Uuid,
+    /// Email of the member who paid the expense.
+    pub member: String,
+    pub title: String,
+    pub amount: f32,
+    pub created_at: DateTime<Utc>,
+    pub modified_at: Option<DateTime<Utc>>,
+    pub settled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupSettlement {
+    pub id: Uuid,
+    pub label: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: DateTime<Utc>,
+    pub total: f32,
+    pub expense_ids: Vec<Uuid>,
+    pub transactions: Vec<BackupTransaction>,
+    pub balances: Vec<BackupBalance>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupBalance {
+    pub member: String,
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupBudget {
+    pub amount: f32,
+    pub period: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Group rebuilt from a backup, ready to be saved.
+#[derive(Debug)]
+pub struct RestoredGroup {
+    pub group: Group,
+    pub expenses: Vec<Expense>,
+    pub settlements: Vec<Settlement>,
+    pub budget: Option<Budget>,
+}
+
+impl GroupBackup {
+    pub fn create(
+        group: &Group,
+        expenses: &[Expense],
+        settlements: &[Settlement],
+        budget: Option<&Budget>,
+        events: &[GroupEvent],
+    ) -> Result<Self, &'static str> {
+        let emails = group
+            .members
+            .iter()
+            .map(|m| (m.id, String::from(m.email.clone())))
+            .collect::<HashMap<Uuid, String>>();
+        let email = |id: &Uuid| emails.get(id).cloned().ok_or(UNKNOWN_MEMBER);
+        Ok(Self {
+            version: BACKUP_VERSION,
+            exported_at: date::now(),
+            name: String::from(group.name.clone()),
+            created_at: group.created_at,
+            members: group.members.iter().map(BackupMember::from).collect(),
+            expenses: expenses
+                .iter()
+                .map(|e| {
+                    Ok(BackupExpense {
+                        id: e.id,
+                        member: email(&e.member_id)?,
+                        title: String::from(e.title.clone()),
+                        amount: f32::from(e.amount),
+                        created_at: e.created_at,
+                        modified_at: e.modified_at,
+                        settled: e.settled,
+                    })
+                })
+                .collect::<Result<_, &'static str>>()?,
+            settlements: settlements
+                .iter()
+                .map(|s| {
+                    Ok(BackupSettlement {
+                        id: s.id,
+                        label: s.label.clone().map(String::from),
+                        start_date: s.start_date,
+                        end_date: s.end_date,
+                        total: s.total,
+                        expense_ids: s.expense_ids.clone(),
+                        transactions: BackupTransaction::from_all(&s.transactions, &email)?,
+                        balances: s
+                            .balances
+                            .iter()
+                            .map(|b| {
+                                Ok(BackupBalance {
+                                    member: email(&b.member_id)?,
+                                    paid: b.paid,
+                                    share: b.share,
+                                    net: b.net,
+                                })
+                            })
+                            .collect::<Result<_, &'static str>>()?,
+                    })
+                })
+                .collect::<Result<_, &'static str>>()?,
+            budget: budget.map(|b| BackupBudget {
+                amount: f32::from(b.amount),
+                period: String::from(b.period),
+                updated_at: b.updated_at,
+            }),
+            // the moderation of the group, and the history of the members it removed, are left out
+            events: events
+                .iter()
+                .filter(|e| emails.contains_key(&e.member_id) && !e.is_moderation())
+                .map(|e| BackupEvent::from(e, &email))
+                .collect::<Result<_, &'static str>>()?,
+        })
+    }
+
+    /// Reads a backup document of any supported version.
+    pub fn read(document: serde_json::Value) -> Result<Self, &'static str> {
+        let version = document
+            .get("version")
+            .and_then(|v| v.as_u64())
+            .ok_or("The backup document has no version.")?;
+        match version {
+            1 => serde_json::from_value(document).map_err(|_| "The backup document is invalid."),
+            _ => Err("The backup version is not supported."),
+        }
+    }
+
+    /// Rebuilds the group with new ids, members being matched to `users` by email.
+    pub fn restore(self, users: &[User]) -> Result<RestoredGroup, &'static str> {
+        let users = users
+            .iter()
+            .map(|u| (String::from(u.email.clone()).to_lowercase(), u))
+            .collect::<HashMap<String, &User>>();
+        let user = |email: &str| {
+            users
+                .get(&email.to_lowercase())
+                .copied()
+                .ok_or("Every member of the backup should have an account with the same email.")
+        };
+        let group_id = Uuid::new_v4();
+
+        let mut members = Vec::new();
+        for member in &self.members {
+            let user = user(&member.email)?;
+            if members.iter().any(|m: &GroupMember| m.id == user.id) {
+                return Err("Members of the backup should have distinct emails.");
+            }
+            members.push(GroupMember {
+                id: user.id,
+                name: user.name.clone(),
+                email: user.email.clone(),
+                group_id,
+                is_admin: member.is_admin,
+                color: MemberColor::try_from(member.color.clone())?,
+                joined_at: member.joined_at,
+            });
+        }
+        let admin_id = match members.iter().filter(|m| m.is_admin).collect::<Vec<_>>()[..] {
+            [admin] => admin.id,
+            _ => return Err("The backup should have exactly one administrator."),
+        };
+        let emails = self
+            .members
+            .iter()
+            .zip(members.iter())
+            .map(|(backup, member)| (backup.email.to_lowercase(), member.id))
+            .collect::<HashMap<String, Uuid>>();
+        let member = |email: &str| {
+            emails
+                .get(&email.to_lowercase())
+                .copied()
+                .ok_or(UNKNOWN_MEMBER)
+        };
+
+        let mut ids = Ids::default();
+        let expenses = self
+            .expenses
+            .into_iter()
+            .map(|e| {
+                Ok(Expense {
+                    id: ids.remap(e.id),
+                    group_id,
+                    member_id: member(&e.member)?,
+                    title: ExpenseTitle::try_from(e.title)?,
+                    amount: Amount::try_from(e.amount)?,
+                    created_at: e.created_at,
+                    modified_at: e.modified_at,
+                    settled: e.settled,
+                })
+            })
+            .collect::<Result<Vec<_>, &'static str>>()?;
+        let mut settlements = Vec::new();
+        for s in self.settlements {
+            if !s.expense_ids.iter().all(|id| ids.contains(id)) {
+                return Err("Settlements should only reference expenses of the backup.");
+            }
+            settlements.push(Settlement {
+                id: ids.remap(s.id),
+                group_id,
+                start_date: s.start_date,
+                end_date: s.end_date,
+                transactions: BackupTransaction::restore_all(s.transactions, &member)?,
+                expense_count: s.expense_ids.len() as u32,
+                expense_ids: s.expense_ids.into_iter().map(|id| ids.remap(id)).collect(),
+                label: s.label.map(SettlementLabel::try_from).transpose()?,
+                total: s.total,
+                balances: s
+                    .balances
+                    .into_iter()
+                    .map(|b| {
+                        Ok(MemberBalance {
+                            member_id: member(&b.member)?,
+                            paid: b.paid,
+                            share: b.share,
+                            net: b.net,
+                        })
+                    })
+                    .collect::<Result<_, &'static str>>()?,
+            });
+        }
+        let budget = self
+            .budget
+            .map(|b| {
+                Ok::<_, &'static str>(Budget {
+                    group_id,
+                    amount: Amount::try_from(b.amount)?,
+                    period: BudgetPeriod::try_from(b.period)?,
+                    updated_at: b.updated_at,
+                })
+            })
+            .transpose()?;
+        let events = self
+            .events
+            .into_iter()
+            .map(|e| e.restore(group_id, &member, &mut ids))
+            .collect::<Result<Vec<_>, &'static str>>()?;
+
+        Ok(RestoredGroup {
+            group: Group {
+                id: group_id,
+                name: GroupName::try_from(self.name)?,
+                admin_id,
+                created_at: self.created_at,
+                archived_at: None,
+                members,
+                expense_ids: expenses
+                    .iter()
+                    .filter(|e| !e.settled)
+                    .map(|e| e.id)
+                    .collect(),
+                settlement_ids: settlements.iter().map(|s| s.id).collect(),
+                events,
+            },
+            expenses,
+            settlements,
+            budget,
+        })
+    }
+}
+
+impl From<&GroupMember> for BackupMember {
+    fn from(m: &GroupMember) -> Self {
+        Self {
+            email: String::from(m.email.clone()),
+            name: String::from(m.name.clone()),
+            color: String::from(m.color.clone()),
+            is_admin: m.is_admin,
+            joined_at: m.joined_at,
+        }
+    }
+}
+
+/// New ids of the restored entities, by id in the backup.
+///
+/// Events may reference expenses or settlements deleted since, which get a new id as well.
+#[derive(Default)]
+struct Ids(HashMap<Uuid, Uuid>);
+
+impl Ids {
+    fn remap(&mut self, id: Uuid) -> Uuid {
+        *self.0.entry(id).or_insert_with(Uuid::new_v4)
+    }
+
+    fn contains(&self, id: &Uuid) -> bool {
+        self.0.contains_key(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Email, GroupEventKind, UserName};
+    use claim::{assert_err, assert_ok};
+
+    fn user(name: &str) -> User {
+        User::create(name.to_string(), format!("{}@test.com", name)).unwrap()
+    }
+
+    fn group(admin: &User, member: &User) -> (Group, Vec<Expense>, Settlement) {
+        let color = MemberColor::try_from("0,0,0".to_string()).unwrap();
+        let mut group = Group::create(
+            "trip".to_string(),
+            admin.id,
+            admin.name.clone(),
+            admin.email.clone(),
+            color.clone(),
+        )
+        .unwrap();
+        group
+            .add_member(member.id, member.name.clone(), member.email.clone(), color)
+            .unwrap();
+        let mut expenses = vec![group.add_expense("Taxi".into(), 20.0, member.id).unwrap()];
+        let settlement = group.settle(&mut expenses, None, admin.id, None).unwrap();
+        expenses.push(group.add_expense("Bread".into(), 3.0, admin.id).unwrap());
+        (group, expenses, settlement)
+    }
+
+    #[test]
+    fn it_should_restore_a_group_with_consistent_new_ids() {
+        // given
+        let (admin, member) = (user("alice"), user("bob"));
+        let (group, expenses, settlement) = group(&admin, &member);
+        let backup = GroupBackup::create(&group, &expenses, &[settlement], None, &group.events);
+        let document = assert_ok!(serde_json::to_value(assert_ok!(backup)));
+        // the same people, with other accounts
+        let users = vec![
+            User {
+                email: Email::try_from("ALICE@test.com".to_string()).unwrap(),
+                ..user("alice")
+            },
+            User {
+                name: UserName::try_from("Robert".to_string()).unwrap(),
+                ..user("bob")
+            },
+        ];
+        // when
+        let restored = assert_ok!(assert_ok!(GroupBackup::read(document)).restore(&users));
+        // then
+        let restored_group = &restored.group;
+        assert_ne!(restored_group.id, group.id);
+        assert_eq!(restored_group.admin_id, users[0].id);
+        let bob = restored_group.members.iter().find(|m| m.id == users[1].id);
+        assert_eq!(String::from(bob.unwrap().name.clone()), "Robert");
+        assert_eq!(restored_group.expense_ids, vec![restored.expenses[1].id]);
+        let settlement = &restored.settlements[0];
+        assert_eq!(settlement.expense_ids, vec![restored.expenses[0].id]);
+        assert_eq!(settlement.transactions[0].from, users[0].id);
+        assert_eq!(settlement.transactions[0].to, users[1].id);
+        let created = restored_group
+            .events
+            .iter()
+            .filter_map(|e| match e.event {
+                GroupEventKind::ExpenseCreated { id, .. } => Some(id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            created,
+            restored.expenses.iter().map(|e| e.id).collect::<Vec<_>>()
+        );
+        assert!(restored_group
+            .events
+            .iter()
+            .any(|e| matches!(e.event, GroupEventKind::Settled { id, .. } if id == settlement.id)));
+    }
+
+    #[test]
+    fn it_should_only_read_supported_versions() {
+        assert_err!(GroupBackup::read(serde_json::json!({ "name": "trip" })));
+        assert_err!(GroupBackup::read(serde_json::json!({ "version": 2 })));
+        assert_err!(GroupBackup::read(serde_json::json!({ "version": 1 })));
+    }
+}
@@ -0,0 +1,26 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BudgetPeriod {
+    Monthly,
+    Settlement,
+}
+
+impl TryFrom<String> for BudgetPeriod {
+    type Error = &'static str;
+
+    fn try_from(n: String) -> Result<Self, Self::Error> {
+        match n.as_str() {
+            "monthly" => Ok(Self::Monthly),
+            "settlement" => Ok(Self::Settlement),
+            _ => Err("Budget period should be either monthly or settlement"),
+        }
+    }
+}
+
+impl From<BudgetPeriod> for String {
+    fn from(n: BudgetPeriod) -> Self {
+        match n {
+            BudgetPeriod::Monthly => "monthly".to_string(),
+            BudgetPeriod::Settlement => "settlement".to_string(),
+        }
+    }
+}
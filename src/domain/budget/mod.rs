@@ -0,0 +1,119 @@
+mod budget_period;
+
+pub use budget_period::BudgetPeriod;
+
+use crate::domain::Amount;
+use crate::utils::date;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use uuid::Uuid;
+
+/// Consumption percentages at which the group members are alerted.
+pub const BUDGET_THRESHOLDS: [u8; 2] = [80, 100];
+
+/// Spending of the group during a budget period.
+#[derive(Debug, Clone)]
+pub struct BudgetConsumption {
+    pub start: Option<DateTime<Utc>>,
+    pub spent: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Budget {
+    pub group_id: Uuid,
+    pub amount: Amount,
+    pub period: BudgetPeriod,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Budget {
+    pub fn create(group_id: Uuid, amount: f32, period: String) -> Result<Self, &'static str> {
+        Ok(Self {
+            group_id,
+            amount: Amount::try_from(amount)?,
+            period: BudgetPeriod::try_from(period)?,
+            updated_at: date::now(),
+        })
+    }
+
+    /// Start of the budget period containing `date`.
+    ///
+    /// `last_settlement` is the end date of the last settlement before `date`,
+    /// `None` meaning that the period covers every expense of the group.
+    pub fn period_start(
+        &self,
+        date: &DateTime<Utc>,
+        last_settlement: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        match self.period {
+            BudgetPeriod::Monthly => Utc
+                .with_ymd_and_hms(date.year(), date.month(), 1, 0, 0, 0)
+                .single(),
+            BudgetPeriod::Settlement => last_settlement,
+        }
+    }
+
+    pub fn remaining(&self, spent: f32) -> f32 {
+        f32::from(self.amount) - spent
+    }
+
+    pub fn percentage(&self, spent: f32) -> f32 {
+        spent * 100.0 / f32::from(self.amount)
+    }
+
+    /// Highest alert threshold crossed when the spending went from `previous` to `current`.
+    pub fn crossed_threshold(&self, previous: f32, current: f32) -> Option<u8> {
+        BUDGET_THRESHOLDS
+            .iter()
+            .rev()
+            .find(|&&threshold| {
+                let limit = f32::from(self.amount) * threshold as f32 / 100.0;
+                previous < limit && current >= limit
+            })
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::{assert_err, assert_none};
+
+    fn budget(amount: f32, period: &str) -> Budget {
+        Budget::create(Uuid::new_v4(), amount, period.to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_budget() {
+        assert_err!(Budget::create(Uuid::new_v4(), 0.0, "monthly".to_string()));
+        assert_err!(Budget::create(Uuid::new_v4(), 10.0, "weekly".to_string()));
+    }
+
+    #[test]
+    fn it_should_start_the_period_at_the_beginning_of_the_month_or_the_last_settlement() {
+        // given
+        let date = Utc.with_ymd_and_hms(2023, 3, 15, 12, 0, 0).unwrap();
+        let settlement = Utc.with_ymd_and_hms(2023, 2, 20, 8, 0, 0).unwrap();
+        // when
+        let monthly = budget(100.0, "monthly").period_start(&date, Some(settlement));
+        let settled = budget(100.0, "settlement").period_start(&date, Some(settlement));
+        // then
+        assert_eq!(
+            monthly,
+            Some(Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(settled, Some(settlement));
+    }
+
+    #[test]
+    fn it_should_only_report_the_highest_crossed_threshold() {
+        // given
+        let budget = budget(100.0, "monthly");
+        // then
+        assert_none!(budget.crossed_threshold(10.0, 79.0));
+        assert_eq!(budget.crossed_threshold(79.0, 80.0), Some(80));
+        assert_none!(budget.crossed_threshold(80.0, 90.0));
+        assert_eq!(budget.crossed_threshold(90.0, 120.0), Some(100));
+        assert_eq!(budget.crossed_threshold(10.0, 120.0), Some(100));
+        assert_none!(budget.crossed_threshold(100.0, 150.0));
+    }
+}
@@ -23,6 +23,12 @@ error_chain! {
         Validation(&'static str),
         #[error("Credentials are invalid.")]
         InvalidCredentials(),
+        #[error("Email is not verified.")]
+        Unverified(),
+        #[error("Account is disabled.")]
+        Disabled(),
+        #[error("A password reset is required.")]
+        PasswordResetRequired(),
         #[error(transparent)]
         Unexpected(#[from] anyhow::Error),
     }
@@ -38,6 +44,120 @@ error_chain! {
     }
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum TakeoutError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum DeleteUserError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("{0}")]
+        Conflict(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum UpdateUserError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("Credentials are invalid.")]
+        InvalidCredentials(),
+        #[error("Email already in use.")]
+        Conflict(),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum PasswordResetError {
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("The reset link is invalid or expired.")]
+        InvalidToken(),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum EmailVerificationError {
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("The verification link is invalid or expired.")]
+        InvalidToken(),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ApiTokenError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum TwoFactorError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("User is not an administrator.")]
+        Unauthorized(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("The code is invalid.")]
+        InvalidCode(),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Conflict(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ManageUserError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("User is not an administrator.")]
+        Unauthorized(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Conflict(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum GetUsersError {
@@ -50,6 +170,38 @@ error_chain! {
     }
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ManageGroupError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("User is not an administrator.")]
+        Unauthorized(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Conflict(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetAuditLogError {
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("User is not an administrator.")]
+        Unauthorized(),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum GetAllGroupsError {
@@ -138,6 +290,22 @@ error_chain! {
     }
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum TransferOwnershipError {
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum CreateExpenseError {
@@ -157,12 +325,58 @@ error_chain! {
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum SettlementError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum RevertSettlementError {
         #[error(transparent)]
         Unexpected(#[from] anyhow::Error),
         #[error("{0}")]
         NotFound(&'static str),
         #[error("{0}")]
         Unauthorized(&'static str),
+        #[error("{0}")]
+        Conflict(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetDebtError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum SettleDebtError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
         #[error("User is not recognized.")]
         Unauthenticated(),
     }
@@ -240,6 +454,16 @@ error_chain! {
     }
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetSummaryError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum GetSettlementsError {
@@ -254,6 +478,36 @@ error_chain! {
     }
 }
 
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetActivityError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+        #[error("{0}")]
+        Validation(&'static str),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetStatsError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
 error_chain! {
     #[derive(thiserror::Error)]
     pub enum GetExpensesError {
@@ -267,3 +521,93 @@ error_chain! {
         Unauthenticated(),
     }
 }
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum SetBudgetError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum DeleteBudgetError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum GetBudgetError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ImportExpensesError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum ExportError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        NotFound(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum RestoreGroupError {
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+        #[error("{0}")]
+        Validation(&'static str),
+        #[error("{0}")]
+        Unauthorized(&'static str),
+        #[error("User is not recognized.")]
+        Unauthenticated(),
+    }
+}
@@ -0,0 +1,28 @@
+use crate::domain::Event;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Selection of events of the audit log, matching every event when left empty.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Events of the user account, or performed by the user.
+    pub user_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    /// Kind of the events as stored, e.g. `UserLogin`.
+    pub kind: Option<String>,
+    /// Events recorded after `from` (excluded) and until `to` (included).
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &Event) -> bool {
+        let date = event.date();
+        let by_user = |id: &Uuid| event.user_id() == Some(*id) || event.actor_id() == *id;
+        self.user_id.iter().all(by_user)
+            && self.group_id.iter().all(|id| event.group_id() == Some(*id))
+            && self.kind.iter().all(|kind| kind == event.name())
+            && self.from.iter().all(|from| date > *from)
+            && self.to.iter().all(|to| date <= *to)
+    }
+}
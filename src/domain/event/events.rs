@@ -1,4 +1,4 @@
-use crate::domain::{MemberColor, Transaction};
+use crate::domain::{BudgetPeriod, MemberColor, Transaction};
 use crate::utils::date;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -16,6 +16,45 @@ impl Event {
             Event::Group(g) => g.id,
         }
     }
+
+    pub fn date(&self) -> DateTime<Utc> {
+        match self {
+            Event::User(u) => u.event_date,
+            Event::Group(g) => g.event_date,
+        }
+    }
+
+    /// The kind of the event, as stored.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::User(u) => u.event.name(),
+            Event::Group(g) => g.event.name(),
+        }
+    }
+
+    /// The user account of a user event.
+    pub fn user_id(&self) -> Option<Uuid> {
+        match self {
+            Event::User(u) => Some(u.user_id),
+            Event::Group(_) => None,
+        }
+    }
+
+    pub fn group_id(&self) -> Option<Uuid> {
+        match self {
+            Event::User(_) => None,
+            Event::Group(g) => Some(g.group_id),
+        }
+    }
+
+    /// The user who performed the action: the member of a group event, the administrator acting
+    /// on the account of a user, or the user themselves.
+    pub fn actor_id(&self) -> Uuid {
+        match self {
+            Event::User(u) => u.event.by().unwrap_or(u.user_id),
+            Event::Group(g) => g.member_id,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +95,16 @@ impl GroupEvent {
             event,
         }
     }
+
+    /// Whether the event records an administrator moderating the group.
+    pub fn is_moderation(&self) -> bool {
+        matches!(
+            self.event,
+            GroupEventKind::GroupArchived
+                | GroupEventKind::GroupUnarchived
+                | GroupEventKind::MemberRemoved { .. }
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -93,13 +142,148 @@ pub enum GroupEventKind {
         end_date: DateTime<Utc>,
         transactions: Vec<Transaction>,
     },
+    SettlementReverted {
+        id: Uuid,
+    },
+    BudgetChanged {
+        amount: Option<f32>,
+        period: Option<BudgetPeriod>,
+    },
+    OwnershipTransferred {
+        new_admin_id: Uuid,
+    },
     GroupDeleted,
+    GroupArchived,
+    GroupUnarchived,
+    MemberRemoved {
+        member_id: Uuid,
+    },
+}
+
+impl GroupEventKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GroupEventKind::GroupCreated { .. } => "GroupCreated",
+            GroupEventKind::MemberJoined { .. } => "MemberJoined",
+            GroupEventKind::MemberColorChanged { .. } => "MemberColorChanged",
+            GroupEventKind::ExpenseCreated { .. } => "ExpenseCreated",
+            GroupEventKind::ExpenseModified { .. } => "ExpenseModified",
+            GroupEventKind::ExpenseDeleted { .. } => "ExpenseDeleted",
+            GroupEventKind::Settled { .. } => "Settled",
+            GroupEventKind::SettlementReverted { .. } => "SettlementReverted",
+            GroupEventKind::BudgetChanged { .. } => "BudgetChanged",
+            GroupEventKind::OwnershipTransferred { .. } => "OwnershipTransferred",
+            GroupEventKind::GroupDeleted => "GroupDeleted",
+            GroupEventKind::GroupArchived => "GroupArchived",
+            GroupEventKind::GroupUnarchived => "GroupUnarchived",
+            GroupEventKind::MemberRemoved { .. } => "MemberRemoved",
+        }
+    }
+
+    /// The expense, settlement or member the event is about, if any.
+    pub fn subject_id(&self) -> Option<Uuid> {
+        match self {
+            GroupEventKind::ExpenseCreated { id, .. }
+            | GroupEventKind::ExpenseModified { id, .. }
+            | GroupEventKind::ExpenseDeleted { id }
+            | GroupEventKind::Settled { id, .. }
+            | GroupEventKind::SettlementReverted { id } => Some(*id),
+            GroupEventKind::OwnershipTransferred { new_admin_id } => Some(*new_admin_id),
+            GroupEventKind::MemberRemoved { member_id } => Some(*member_id),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum UserEventKind {
-    Created { name: String, email: String },
+    Created {
+        name: String,
+        email: String,
+    },
     Login,
     Logout,
     Deleted,
+    TakeoutRequested,
+    NameChanged {
+        previous_name: String,
+        new_name: String,
+    },
+    EmailChanged {
+        previous_email: String,
+        new_email: String,
+    },
+    PasswordChanged,
+    PasswordResetRequested,
+    EmailVerificationRequested,
+    EmailVerified,
+    ApiTokenCreated {
+        token_id: Uuid,
+        name: String,
+    },
+    ApiTokenRevoked {
+        token_id: Uuid,
+        name: String,
+    },
+    TwoFactorEnabled,
+    TwoFactorReset {
+        by: Uuid,
+    },
+    RoleChanged {
+        role: String,
+        by: Uuid,
+    },
+    Disabled {
+        by: Uuid,
+    },
+    Enabled {
+        by: Uuid,
+    },
+    PasswordResetForced {
+        by: Uuid,
+    },
+    DeletedByAdmin {
+        by: Uuid,
+    },
+}
+
+impl UserEventKind {
+    /// The name of the kind, prefixed with `User` as stored.
+    pub fn name(&self) -> &'static str {
+        match self {
+            UserEventKind::Created { .. } => "UserCreated",
+            UserEventKind::Login => "UserLogin",
+            UserEventKind::Logout => "UserLogout",
+            UserEventKind::Deleted => "UserDeleted",
+            UserEventKind::TakeoutRequested => "UserTakeoutRequested",
+            UserEventKind::NameChanged { .. } => "UserNameChanged",
+            UserEventKind::EmailChanged { .. } => "UserEmailChanged",
+            UserEventKind::PasswordChanged => "UserPasswordChanged",
+            UserEventKind::PasswordResetRequested => "UserPasswordResetRequested",
+            UserEventKind::EmailVerificationRequested => "UserEmailVerificationRequested",
+            UserEventKind::EmailVerified => "UserEmailVerified",
+            UserEventKind::ApiTokenCreated { .. } => "UserApiTokenCreated",
+            UserEventKind::ApiTokenRevoked { .. } => "UserApiTokenRevoked",
+            UserEventKind::TwoFactorEnabled => "UserTwoFactorEnabled",
+            UserEventKind::TwoFactorReset { .. } => "UserTwoFactorReset",
+            UserEventKind::RoleChanged { .. } => "UserRoleChanged",
+            UserEventKind::Disabled { .. } => "UserDisabled",
+            UserEventKind::Enabled { .. } => "UserEnabled",
+            UserEventKind::PasswordResetForced { .. } => "UserPasswordResetForced",
+            UserEventKind::DeletedByAdmin { .. } => "UserDeletedByAdmin",
+        }
+    }
+
+    /// The administrator who acted on the account, if the user did not.
+    pub fn by(&self) -> Option<Uuid> {
+        match self {
+            UserEventKind::TwoFactorReset { by }
+            | UserEventKind::RoleChanged { by, .. }
+            | UserEventKind::Disabled { by }
+            | UserEventKind::Enabled { by }
+            | UserEventKind::PasswordResetForced { by }
+            | UserEventKind::DeletedByAdmin { by } => Some(*by),
+            _ => None,
+        }
+    }
 }
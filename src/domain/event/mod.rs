@@ -1,3 +1,7 @@
+mod event_filter;
 mod events;
+mod origin;
 
+pub use event_filter::EventFilter;
 pub use events::*;
+pub use origin::*;
@@ -0,0 +1,24 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static ORIGIN: EventOrigin;
+}
+
+/// Client at the origin of the events recorded while handling a request, when known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventOrigin {
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl EventOrigin {
+    /// Runs `f`, the events saved meanwhile being recorded as coming from this origin.
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        ORIGIN.scope(self, f).await
+    }
+
+    /// The origin of the running request, unknown outside of one (e.g. in the worker).
+    pub fn current() -> Self {
+        ORIGIN.try_with(|origin| origin.clone()).unwrap_or_default()
+    }
+}
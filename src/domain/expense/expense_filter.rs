@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Selection of group expenses, matching the filters of the expense listing.
+#[derive(Debug, Clone, Copy)]
+pub enum ExpenseFilter {
+    /// Expenses not settled yet.
+    Unsettled,
+    /// Expenses created after `from` (excluded) and until `to` (included).
+    Period {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    /// Expenses of a given settlement.
+    Settlement(Uuid),
+}
+
+impl ExpenseFilter {
+    pub fn new(
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        settlement_id: Option<Uuid>,
+    ) -> Self {
+        match (from, to, settlement_id) {
+            (_, _, Some(settlement_id)) => ExpenseFilter::Settlement(settlement_id),
+            (None, None, None) => ExpenseFilter::Unsettled,
+            (from, to, None) => ExpenseFilter::Period { from, to },
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::domain::Expense;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Spending aggregates of a group over a date range.
+#[derive(Debug)]
+pub struct ExpenseStats {
+    pub total: f32,
+    pub count: u32,
+    pub members: Vec<MemberTotal>,
+    pub months: Vec<MonthTotal>,
+    pub largest: Vec<Expense>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberTotal {
+    pub member_id: Uuid,
+    pub total: f32,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonthTotal {
+    /// First instant of the month, in UTC.
+    pub month: DateTime<Utc>,
+    pub total: f32,
+    pub count: u32,
+}
+
+impl ExpenseStats {
+    pub fn new(members: Vec<MemberTotal>, months: Vec<MonthTotal>, largest: Vec<Expense>) -> Self {
+        Self {
+            total: members.iter().map(|m| m.total).sum(),
+            count: members.iter().map(|m| m.count).sum(),
+            members,
+            months,
+            largest,
+        }
+    }
+}
@@ -1,5 +1,9 @@
+mod expense_filter;
+mod expense_stats;
 mod expense_title;
 
+pub use expense_filter::ExpenseFilter;
+pub use expense_stats::{ExpenseStats, MemberTotal, MonthTotal};
 pub use expense_title::ExpenseTitle;
 
 use crate::domain::errors::{CreateExpenseError, UpdateExpenseError};
@@ -43,6 +47,10 @@ impl Expense {
         self.settled = true;
     }
 
+    pub fn unsettle(&mut self) {
+        self.settled = false;
+    }
+
     pub fn update(&mut self, title: String, amount: f32) -> Result<(), UpdateExpenseError> {
         self.title = ExpenseTitle::try_from(title).map_err(UpdateExpenseError::Validation)?;
         self.amount = Amount::try_from(amount).map_err(UpdateExpenseError::Validation)?;
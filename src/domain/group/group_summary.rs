@@ -0,0 +1,44 @@
+use crate::domain::{GroupMember, GroupName, Transaction};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Aggregated position of a user in one of their groups, computed by the store
+/// without loading the group, its expenses or its settlements.
+#[derive(Debug, Clone)]
+pub struct GroupSummary {
+    pub group_id: Uuid,
+    pub name: GroupName,
+    /// Sum of the unsettled expenses paid by the user.
+    pub paid: f32,
+    /// Sum of all the unsettled expenses of the group.
+    pub total: f32,
+    pub member_count: u32,
+    pub last_activity: DateTime<Utc>,
+    /// Unpaid settlement transactions in which the user is involved.
+    pub transactions: Vec<Transaction>,
+    /// The members taking part in the transactions.
+    pub members: Vec<GroupMember>,
+}
+
+impl GroupSummary {
+    /// Unsettled balance of the user, positive when the group owes them money.
+    pub fn balance(&self) -> f32 {
+        if self.member_count == 0 {
+            return self.paid;
+        }
+        self.paid - self.total / self.member_count as f32
+    }
+
+    /// Net amount of the outstanding transactions, positive when the user is owed money.
+    pub fn outstanding(&self, user_id: &Uuid) -> f32 {
+        self.transactions.iter().fold(0.0, |acc, t| {
+            if t.to == *user_id {
+                acc + f32::from(t.amount)
+            } else if t.from == *user_id {
+                acc - f32::from(t.amount)
+            } else {
+                acc
+            }
+        })
+    }
+}
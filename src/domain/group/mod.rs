@@ -1,31 +1,39 @@
 mod group_member;
 mod group_name;
+mod group_summary;
 mod member_color;
 mod token_generator;
 
 pub use group_member::GroupMember;
 pub use group_name::GroupName;
+pub use group_summary::GroupSummary;
 pub use member_color::MemberColor;
 use std::sync::Arc;
 pub use token_generator::TokenGenerator;
 
 use crate::domain::errors::{
-    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteExpenseError,
-    DeleteGroupError, GenerateGroupTokenError, JoinGroupError, SettlementError, UpdateExpenseError,
+    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteBudgetError,
+    DeleteExpenseError, DeleteGroupError, GenerateGroupTokenError, JoinGroupError,
+    ManageGroupError, RevertSettlementError, SetBudgetError, SettlementError,
+    TransferOwnershipError, UpdateExpenseError,
 };
 use crate::domain::{
-    Email, Expense, GroupEvent, GroupEventKind, Settlement, SettlementDescription, UserName,
+    Budget, Email, Expense, GroupEvent, GroupEventKind, Settlement, SettlementDescription, UserName,
 };
 use crate::utils::date;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
+const ARCHIVED: &str = "Group is archived.";
+
 #[derive(Debug)]
 pub struct Group {
     pub id: Uuid,
     pub name: GroupName,
     pub admin_id: Uuid,
     pub created_at: DateTime<Utc>,
+    /// Archived groups are read-only.
+    pub archived_at: Option<DateTime<Utc>>,
     pub members: Vec<GroupMember>,
     pub expense_ids: Vec<Uuid>,
     pub settlement_ids: Vec<Uuid>,
@@ -47,6 +55,7 @@ impl Group {
             name: GroupName::try_from(name.clone()).map_err(CreateGroupError::Validation)?,
             admin_id,
             created_at: date::now(),
+            archived_at: None,
             members: vec![admin.clone()],
             expense_ids: vec![],
             settlement_ids: vec![],
@@ -66,11 +75,26 @@ impl Group {
         title: String,
         amount: f32,
         user_id: Uuid,
+    ) -> Result<Expense, CreateExpenseError> {
+        self.add_expense_at(title, amount, user_id, date::now())
+    }
+
+    /// Adds an expense created at a given date, e.g. when imported from another application.
+    pub fn add_expense_at(
+        &mut self,
+        title: String,
+        amount: f32,
+        user_id: Uuid,
+        date: DateTime<Utc>,
     ) -> Result<Expense, CreateExpenseError> {
         if !self.is_member(&user_id) {
             return Err(CreateExpenseError::Unauthorized());
         }
-        let expense = Expense::create(title, amount, user_id, self.id)?;
+        if self.is_archived() {
+            return Err(CreateExpenseError::Validation(ARCHIVED));
+        }
+        let mut expense = Expense::create(title, amount, user_id, self.id)?;
+        expense.created_at = date;
         self.expense_ids.push(expense.id);
         self.events.push(GroupEvent::new(
             self.id,
@@ -96,6 +120,9 @@ impl Group {
         if !self.is_member(&user_id) {
             return Err(UpdateExpenseError::Unauthorized("User is not a member"));
         }
+        if self.is_archived() {
+            return Err(UpdateExpenseError::Unauthorized(ARCHIVED));
+        }
         let expense = expenses.into_iter().find(|e| e.id == expense_id);
         match expense {
             Some(mut expense) => {
@@ -133,6 +160,9 @@ impl Group {
         if !self.is_member(&user_id) {
             return Err(DeleteExpenseError::Unauthorized("User is not a member"));
         }
+        if self.is_archived() {
+            return Err(DeleteExpenseError::Unauthorized(ARCHIVED));
+        }
         let expense = expenses.into_iter().find(|e| e.id == expense_id);
         match expense {
             Some(expense) => {
@@ -165,6 +195,9 @@ impl Group {
         if self.is_member(&user_id) {
             return Err(JoinGroupError::Conflict());
         }
+        if self.is_archived() {
+            return Err(JoinGroupError::Unauthorized(ARCHIVED));
+        }
         let member = GroupMember::create(user_id, name, email, self.id, false, color);
         self.members.push(member.clone());
         self.events.push(GroupEvent::new(
@@ -212,6 +245,18 @@ impl Group {
         user_id == &self.admin_id
     }
 
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Whether the actor may administer the group, as its admin or as a moderator.
+    fn is_administered_by(&self, actor: &GroupActor) -> bool {
+        match actor {
+            GroupActor::Member(user_id) => self.is_admin(user_id),
+            GroupActor::Administrator(_) => true,
+        }
+    }
+
     pub fn admin(&self) -> &GroupMember {
         self.members
             .iter()
@@ -224,16 +269,21 @@ impl Group {
         expenses: &mut [Expense],
         last_settlement: Option<SettlementDescription>,
         user: Uuid,
+        label: Option<String>,
     ) -> Result<Settlement, SettlementError> {
         if !self.is_admin(&user) {
             return Err(SettlementError::Unauthorized("User is not group admin."));
         }
+        if self.is_archived() {
+            return Err(SettlementError::Unauthorized(ARCHIVED));
+        }
         let member_ids: Vec<Uuid> = self.members.iter().map(|m| m.id).collect();
         let settlement = Settlement::create(
             self.id,
             last_settlement.map(|d| d.end_date),
             expenses,
             &member_ids,
+            label,
         )?;
         self.settlement_ids.push(settlement.id);
         self.expense_ids.clear();
@@ -250,18 +300,226 @@ impl Group {
         Ok(settlement)
     }
 
-    pub fn delete(&mut self, user: &Uuid) -> Result<(), DeleteGroupError> {
-        if !self.is_admin(user) {
+    pub fn revert_settlement(
+        &mut self,
+        settlement_id: Uuid,
+        settlements: Vec<Settlement>,
+        expenses: &mut [Expense],
+        user: Uuid,
+        revert_window: Duration,
+    ) -> Result<Settlement, RevertSettlementError> {
+        if !self.is_admin(&user) {
+            return Err(RevertSettlementError::Unauthorized(
+                "User is not group admin.",
+            ));
+        }
+        if self.is_archived() {
+            return Err(RevertSettlementError::Unauthorized(ARCHIVED));
+        }
+        let latest = settlements.into_iter().max_by_key(|s| s.end_date);
+        let settlement = match latest {
+            Some(settlement) if settlement.id == settlement_id => settlement,
+            Some(_) if self.settlement_ids.contains(&settlement_id) => {
+                return Err(RevertSettlementError::Conflict(
+                    "Only the most recent settlement can be reverted.",
+                ))
+            }
+            _ => return Err(RevertSettlementError::NotFound("Settlement not found.")),
+        };
+        if date::now() - settlement.end_date > revert_window {
+            return Err(RevertSettlementError::Conflict(
+                "Settlement can no longer be reverted.",
+            ));
+        }
+        if settlement.has_paid_transactions() {
+            return Err(RevertSettlementError::Conflict(
+                "Settlement has paid transactions.",
+            ));
+        }
+        settlement.revert(expenses);
+        self.settlement_ids.retain(|id| id != &settlement.id);
+        self.expense_ids.extend(settlement.expense_ids.iter());
+        self.events.push(GroupEvent::new(
+            self.id,
+            user,
+            GroupEventKind::SettlementReverted { id: settlement.id },
+        ));
+        Ok(settlement)
+    }
+
+    pub fn set_budget(
+        &mut self,
+        user: Uuid,
+        amount: f32,
+        period: String,
+    ) -> Result<Budget, SetBudgetError> {
+        if !self.is_admin(&user) {
+            return Err(SetBudgetError::Unauthorized("User is not group admin."));
+        }
+        if self.is_archived() {
+            return Err(SetBudgetError::Unauthorized(ARCHIVED));
+        }
+        let budget = Budget::create(self.id, amount, period).map_err(SetBudgetError::Validation)?;
+        self.events.push(GroupEvent::new(
+            self.id,
+            user,
+            GroupEventKind::BudgetChanged {
+                amount: Some(f32::from(budget.amount)),
+                period: Some(budget.period),
+            },
+        ));
+        Ok(budget)
+    }
+
+    pub fn remove_budget(
+        &mut self,
+        user: Uuid,
+        budget: Option<Budget>,
+    ) -> Result<(), DeleteBudgetError> {
+        if !self.is_admin(&user) {
+            return Err(DeleteBudgetError::Unauthorized("User is not group admin."));
+        }
+        if self.is_archived() {
+            return Err(DeleteBudgetError::Unauthorized(ARCHIVED));
+        }
+        if budget.is_none() {
+            return Err(DeleteBudgetError::NotFound("Budget not found."));
+        }
+        self.events.push(GroupEvent::new(
+            self.id,
+            user,
+            GroupEventKind::BudgetChanged {
+                amount: None,
+                period: None,
+            },
+        ));
+        Ok(())
+    }
+
+    /// Makes `new_admin` the administrator of the group in place of the current one, who stays a
+    /// member.
+    pub fn transfer_ownership(
+        &mut self,
+        actor: GroupActor,
+        new_admin: Uuid,
+    ) -> Result<(), TransferOwnershipError> {
+        if !self.is_administered_by(&actor) {
+            return Err(TransferOwnershipError::Unauthorized(
+                "User is not group admin.",
+            ));
+        }
+        if self.is_admin(&new_admin) {
+            return Err(TransferOwnershipError::Validation(
+                "User is already the group admin.",
+            ));
+        }
+        if !self.members.iter().any(|m| m.id == new_admin) {
+            return Err(TransferOwnershipError::NotFound("Member not found."));
+        }
+        self.admin_id = new_admin;
+        for member in self.members.iter_mut() {
+            member.is_admin = member.id == new_admin;
+        }
+        self.events.push(GroupEvent::new(
+            self.id,
+            actor.id(),
+            GroupEventKind::OwnershipTransferred {
+                new_admin_id: new_admin,
+            },
+        ));
+        Ok(())
+    }
+
+    pub fn delete(&mut self, actor: GroupActor) -> Result<(), DeleteGroupError> {
+        if !self.is_administered_by(&actor) {
             return Err(DeleteGroupError::Unauthorized());
         }
         self.events.push(GroupEvent::new(
             self.id,
-            *user,
+            actor.id(),
             GroupEventKind::GroupDeleted,
         ));
         Ok(())
     }
 
+    /// Makes the group read-only, until it is unarchived.
+    pub fn archive(&mut self, actor: GroupActor) -> Result<(), ManageGroupError> {
+        if !self.is_administered_by(&actor) {
+            return Err(ManageGroupError::Unauthorized());
+        }
+        if self.is_archived() {
+            return Err(ManageGroupError::Conflict(ARCHIVED));
+        }
+        self.archived_at = Some(date::now());
+        self.events.push(GroupEvent::new(
+            self.id,
+            actor.id(),
+            GroupEventKind::GroupArchived,
+        ));
+        Ok(())
+    }
+
+    pub fn unarchive(&mut self, actor: GroupActor) -> Result<(), ManageGroupError> {
+        if !self.is_administered_by(&actor) {
+            return Err(ManageGroupError::Unauthorized());
+        }
+        if !self.is_archived() {
+            return Err(ManageGroupError::Conflict("Group is not archived."));
+        }
+        self.archived_at = None;
+        self.events.push(GroupEvent::new(
+            self.id,
+            actor.id(),
+            GroupEventKind::GroupUnarchived,
+        ));
+        Ok(())
+    }
+
+    /// Removes a member who has no unsettled expense nor unpaid transaction in the group, which
+    /// would otherwise lose the balances of the other members. The ownership of the group must be
+    /// transferred before removing its admin.
+    pub fn remove_member(
+        &mut self,
+        actor: GroupActor,
+        member_id: Uuid,
+        expenses: &[Expense],
+        settlements: &[Settlement],
+    ) -> Result<GroupMember, ManageGroupError> {
+        if !self.is_administered_by(&actor) {
+            return Err(ManageGroupError::Unauthorized());
+        }
+        let index = self
+            .members
+            .iter()
+            .position(|m| m.id == member_id)
+            .ok_or(ManageGroupError::NotFound("Member not found."))?;
+        if self.is_admin(&member_id) {
+            return Err(ManageGroupError::Conflict(
+                "The ownership of the group must be transferred before removing its admin.",
+            ));
+        }
+        if expenses
+            .iter()
+            .any(|e| !e.settled && e.member_id == member_id)
+            || settlements.iter().any(|s| {
+                s.transactions
+                    .iter()
+                    .any(|t| !t.paid && (t.from == member_id || t.to == member_id))
+            })
+        {
+            return Err(ManageGroupError::Conflict(
+                "Member has unsettled expenses or unpaid debts in the group.",
+            ));
+        }
+        let member = self.members.remove(index);
+        self.events.push(GroupEvent::new(
+            self.id,
+            actor.id(),
+            GroupEventKind::MemberRemoved { member_id },
+        ));
+        Ok(member)
+    }
+
     pub async fn generate_join_token<'a>(
         &'a self,
         user_id: &'a Uuid,
@@ -274,3 +532,19 @@ impl Group {
         }
     }
 }
+
+/// Who performs a change on a group: one of its users, subject to the rules of the group, or an
+/// administrator of the application overriding them to moderate the group.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GroupActor {
+    Member(Uuid),
+    Administrator(Uuid),
+}
+
+impl GroupActor {
+    pub fn id(&self) -> Uuid {
+        match self {
+            GroupActor::Member(id) | GroupActor::Administrator(id) => *id,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportFormat {
+    Splitwise,
+    Tricount,
+    Generic,
+}
+
+impl TryFrom<String> for ImportFormat {
+    type Error = &'static str;
+
+    fn try_from(n: String) -> Result<Self, Self::Error> {
+        match n.as_str() {
+            "splitwise" => Ok(Self::Splitwise),
+            "tricount" => Ok(Self::Tricount),
+            "generic" => Ok(Self::Generic),
+            _ => Err("Import format should be either splitwise, tricount or generic"),
+        }
+    }
+}
+
+impl From<ImportFormat> for String {
+    fn from(n: ImportFormat) -> Self {
+        match n {
+            ImportFormat::Splitwise => "splitwise".to_string(),
+            ImportFormat::Tricount => "tricount".to_string(),
+            ImportFormat::Generic => "generic".to_string(),
+        }
+    }
+}
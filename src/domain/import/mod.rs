@@ -0,0 +1,334 @@
+//! Reading of expenses exported by other applications.
+//!
+//! Supported formats:
+//!     - `splitwise`: the group export of Splitwise, with `Date`, `Description`, `Category`,
+//!       `Cost` and `Currency` columns followed by one balance column per person. The payer is
+//!       the only person with a positive balance. Payments and the total balance line are skipped.
+//!     - `tricount`: the export of Tricount, with `Title`, `Amount` (or `Amount in default
+//!       currency`), `Paid by` and `Date` columns. When a `Transaction type` column is present,
+//!       only `Normal` transactions are read.
+//!     - `generic`: a CSV with `date`, `title`, `amount` and `paid_by` columns, in any order.
+//!
+//! Column names are case-insensitive. Dates are either RFC 3339, `YYYY-MM-DD HH:MM:SS`,
+//! `YYYY-MM-DD` or `DD/MM/YYYY`, dates without time being set at noon UTC.
+mod import_format;
+
+pub use import_format::ImportFormat;
+
+use crate::utils::csv;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// Expense read from a line of an export, not validated yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedExpense {
+    pub line: usize,
+    pub date: DateTime<Utc>,
+    pub title: String,
+    pub amount: f32,
+    pub paid_by: String,
+}
+
+/// Error found on a line of an export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportLineError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ImportLineError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+pub type ImportLine = Result<ImportedExpense, ImportLineError>;
+
+impl ImportFormat {
+    /// Reads the expenses of an export, skipping the lines that are not expenses.
+    ///
+    /// Fails when the document itself can't be read, errors of single lines being returned
+    /// along with the expenses.
+    pub fn read(&self, content: &str) -> Result<Vec<ImportLine>, &'static str> {
+        let mut records = csv::parse(content)?.into_iter();
+        let header = match records.next() {
+            Some((_, header)) => Columns::new(header),
+            None => return Err("The document is empty."),
+        };
+        match self {
+            ImportFormat::Splitwise => read_splitwise(&header, records),
+            ImportFormat::Tricount => read_tricount(&header, records),
+            ImportFormat::Generic => read_generic(&header, records),
+        }
+    }
+}
+
+struct Columns(Vec<String>);
+
+impl Columns {
+    fn new(header: Vec<String>) -> Self {
+        Self(header.iter().map(|c| c.trim().to_string()).collect())
+    }
+
+    /// Index of the first column named after one of `names`, ignoring case.
+    fn find(&self, names: &[&str]) -> Option<usize> {
+        names
+            .iter()
+            .find_map(|name| self.0.iter().position(|c| c.eq_ignore_ascii_case(name)))
+    }
+}
+
+fn field(record: &[String], index: usize) -> &str {
+    record.get(index).map(|f| f.trim()).unwrap_or_default()
+}
+
+fn expense(
+    line: usize,
+    date: &str,
+    title: &str,
+    amount: &str,
+    paid_by: &str,
+) -> Result<ImportedExpense, ImportLineError> {
+    let date = parse_date(date).ok_or_else(|| ImportLineError::new(line, "Invalid date."))?;
+    let amount =
+        parse_amount(amount).ok_or_else(|| ImportLineError::new(line, "Invalid amount."))?;
+    if paid_by.is_empty() {
+        return Err(ImportLineError::new(line, "Missing payer."));
+    }
+    Ok(ImportedExpense {
+        line,
+        date,
+        title: title.to_string(),
+        amount,
+        paid_by: paid_by.to_string(),
+    })
+}
+
+fn read_generic(
+    header: &Columns,
+    records: impl Iterator<Item = (usize, Vec<String>)>,
+) -> Result<Vec<ImportLine>, &'static str> {
+    let columns = (
+        header.find(&["date"]),
+        header.find(&["title"]),
+        header.find(&["amount"]),
+        header.find(&["paid_by"]),
+    );
+    let (date, title, amount, paid_by) = match columns {
+        (Some(date), Some(title), Some(amount), Some(paid_by)) => (date, title, amount, paid_by),
+        _ => return Err("Generic imports should have date, title, amount and paid_by columns."),
+    };
+    Ok(records
+        .map(|(line, r)| {
+            expense(
+                line,
+                field(&r, date),
+                field(&r, title),
+                field(&r, amount),
+                field(&r, paid_by),
+            )
+        })
+        .collect())
+}
+
+fn read_tricount(
+    header: &Columns,
+    records: impl Iterator<Item = (usize, Vec<String>)>,
+) -> Result<Vec<ImportLine>, &'static str> {
+    let columns = (
+        header.find(&["date", "date & time"]),
+        header.find(&["title", "description"]),
+        header.find(&["amount in default currency", "amount"]),
+        header.find(&["paid by"]),
+    );
+    let (date, title, amount, paid_by) = match columns {
+        (Some(date), Some(title), Some(amount), Some(paid_by)) => (date, title, amount, paid_by),
+        _ => return Err("Tricount exports should have Title, Amount, Paid by and Date columns."),
+    };
+    let kind = header.find(&["transaction type"]);
+    Ok(records
+        .filter(|(_, r)| kind.map_or(true, |k| field(r, k).eq_ignore_ascii_case("normal")))
+        .map(|(line, r)| {
+            // expenses may be exported as negative amounts
+            let amount = field(&r, amount).trim_start_matches('-');
+            expense(
+                line,
+                field(&r, date),
+                field(&r, title),
+                amount,
+                field(&r, paid_by),
+            )
+        })
+        .collect())
+}
+
+fn read_splitwise(
+    header: &Columns,
+    records: impl Iterator<Item = (usize, Vec<String>)>,
+) -> Result<Vec<ImportLine>, &'static str> {
+    let fixed = ["date", "description", "category", "cost", "currency"];
+    if header.0.len() <= fixed.len()
+        || !fixed
+            .iter()
+            .zip(header.0.iter())
+            .all(|(name, c)| c.eq_ignore_ascii_case(name))
+    {
+        return Err(
+            "Splitwise exports should start with Date, Description, Category, Cost and Currency columns.",
+        );
+    }
+    Ok(records
+        .filter(|(_, r)| {
+            !field(r, 1).eq_ignore_ascii_case("total balance")
+                && !field(r, 2).eq_ignore_ascii_case("payment")
+        })
+        .map(|(line, r)| {
+            let payers = (5..header.0.len())
+                .filter(|&i| parse_amount(field(&r, i)).map_or(false, |b| b > 0.0))
+                .collect::<Vec<_>>();
+            let paid_by = match payers[..] {
+                [payer] => &header.0[payer],
+                [] => return Err(ImportLineError::new(line, "Missing payer.")),
+                _ => {
+                    return Err(ImportLineError::new(
+                        line,
+                        "Expenses paid by several people are not supported.",
+                    ))
+                }
+            };
+            expense(line, field(&r, 0), field(&r, 1), field(&r, 3), paid_by)
+        })
+        .collect())
+}
+
+fn parse_amount(value: &str) -> Option<f32> {
+    // the last separator is the decimal point and any other one groups thousands, so that
+    // 1.234,56 and 1,234.56 both read 1234.56, a repeated separator only grouping thousands
+    let value = match value.rfind([',', '.']) {
+        Some(i) if value.matches(&value[i..=i]).count() == 1 => {
+            let (int, dec) = value.split_at(i);
+            format!("{}.{}", int.replace([',', '.'], ""), &dec[1..])
+        }
+        _ => value.replace([',', '.'], ""),
+    };
+    value.parse::<f32>().ok().filter(|a| a.is_finite())
+}
+
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(Utc.from_utc_datetime(&date));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%d/%m/%Y"))
+        .ok()
+        .and_then(|date| date.and_hms_opt(12, 0, 0))
+        .map(|date| Utc.from_utc_datetime(&date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn it_should_read_the_payer_of_splitwise_expenses() {
+        // given
+        let content = "Date,Description,Category,Cost,Currency,Alice,Bob\n\
+            2023-01-02,Groceries,General,30.00,EUR,15.00,-15.00\n\
+            2023-01-03,Settle up,Payment,15.00,EUR,-15.00,15.00\n\
+            2023-01-04,Dinner,General,40.00,EUR,-20.00,20.00\n\
+            2023-01-05,Shared,General,40.00,EUR,10.00,10.00\n\
+            \n\
+            ,Total balance,,,EUR,-5.00,5.00\n";
+        // when
+        let lines = assert_ok!(ImportFormat::Splitwise.read(content));
+        // then
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            Ok(ImportedExpense {
+                line: 2,
+                date: Utc.with_ymd_and_hms(2023, 1, 2, 12, 0, 0).unwrap(),
+                title: "Groceries".to_string(),
+                amount: 30.0,
+                paid_by: "Alice".to_string(),
+            })
+        );
+        assert_eq!(lines[1].as_ref().unwrap().paid_by, "Bob");
+        assert_eq!(lines[1].as_ref().unwrap().line, 4);
+        assert_eq!(lines[2].as_ref().unwrap_err().line, 5);
+    }
+
+    #[test]
+    fn it_should_read_normal_tricount_transactions() {
+        // given
+        let content = "Title,Amount,Currency,Exchange rate,Amount in default currency,Paid by,Date & time,Transaction type\n\
+            Hotel,\"-120,50\",EUR,1,\"-120,50\",Bob,2023-02-01 18:30:00,Normal\n\
+            Refund,20,EUR,1,20,Alice,2023-02-02 10:00:00,Money transfer\n";
+        // when
+        let lines = assert_ok!(ImportFormat::Tricount.read(content));
+        // then
+        assert_eq!(
+            lines,
+            vec![Ok(ImportedExpense {
+                line: 2,
+                date: Utc.with_ymd_and_hms(2023, 2, 1, 18, 30, 0).unwrap(),
+                title: "Hotel".to_string(),
+                amount: 120.5,
+                paid_by: "Bob".to_string(),
+            })]
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_lines_of_a_generic_import() {
+        // given
+        let content = "paid_by,amount,title,date\n\
+            Alice,12.5,Taxi,2023-03-04T10:00:00Z\n\
+            Bob,twelve,Taxi,2023-03-04\n\
+            Bob,12,Taxi,04/03/2023\n\
+            ,12,Taxi,2023-03-04\n\
+            Bob,12,Taxi,yesterday\n";
+        // when
+        let lines = assert_ok!(ImportFormat::Generic.read(content));
+        // then
+        assert_eq!(lines.len(), 5);
+        assert_ok!(&lines[0]);
+        assert_eq!(lines[1], Err(ImportLineError::new(3, "Invalid amount.")));
+        assert_eq!(
+            lines[2].as_ref().unwrap().date,
+            Utc.with_ymd_and_hms(2023, 3, 4, 12, 0, 0).unwrap()
+        );
+        assert_eq!(lines[3], Err(ImportLineError::new(5, "Missing payer.")));
+        assert_eq!(lines[4], Err(ImportLineError::new(6, "Invalid date.")));
+    }
+
+    #[test]
+    fn it_should_read_amounts_grouped_by_thousands() {
+        // european grouping
+        assert_eq!(parse_amount("1.234,56"), Some(1234.56));
+        assert_eq!(parse_amount("1.234.567,5"), Some(1234567.5));
+        assert_eq!(parse_amount("12,5"), Some(12.5));
+        // us grouping
+        assert_eq!(parse_amount("1,234.56"), Some(1234.56));
+        assert_eq!(parse_amount("1,234,567.5"), Some(1234567.5));
+        assert_eq!(parse_amount("12.5"), Some(12.5));
+        // without decimals
+        assert_eq!(parse_amount("1,234,567"), Some(1234567.0));
+        assert_eq!(parse_amount("1.234.567"), Some(1234567.0));
+        assert_eq!(parse_amount("42"), Some(42.0));
+    }
+
+    #[test]
+    fn it_should_reject_a_document_without_the_expected_columns() {
+        assert_err!(ImportFormat::Generic.read("date,title,amount\n"));
+        assert_err!(ImportFormat::Tricount.read("Title,Amount\n"));
+        assert_err!(ImportFormat::Splitwise.read("Date,Description,Cost\n"));
+        assert_err!(ImportFormat::Generic.read(""));
+    }
+}
@@ -0,0 +1,26 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LedgerFormat {
+    Beancount,
+    Ledger,
+}
+
+impl TryFrom<String> for LedgerFormat {
+    type Error = &'static str;
+
+    fn try_from(n: String) -> Result<Self, Self::Error> {
+        match n.as_str() {
+            "beancount" => Ok(Self::Beancount),
+            "ledger" => Ok(Self::Ledger),
+            _ => Err("Export format should be either beancount or ledger"),
+        }
+    }
+}
+
+impl From<LedgerFormat> for String {
+    fn from(n: LedgerFormat) -> Self {
+        match n {
+            LedgerFormat::Beancount => "beancount".to_string(),
+            LedgerFormat::Ledger => "ledger".to_string(),
+        }
+    }
+}
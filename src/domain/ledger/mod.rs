@@ -0,0 +1,365 @@
+//! Plain-text accounting view of a group, from the point of view of one of its members.
+//!
+//! Each expense is booked with the member share as an expense, the rest of the amount being
+//! owed to or by the group. Each settlement transaction involving the member is a transfer
+//! between the group account and the cash account.
+mod ledger_format;
+
+pub use ledger_format::LedgerFormat;
+
+use crate::domain::{Expense, Settlement, Transaction};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+const BEANCOUNT_ROOTS: [&str; 5] = ["Assets", "Liabilities", "Equity", "Income", "Expenses"];
+
+/// Accounts and commodity used when booking the entries.
+#[derive(Debug, Clone)]
+pub struct LedgerAccounts {
+    /// Account of the member share of the expenses.
+    pub expenses: String,
+    /// Account of what the group owes to the member (or the opposite when negative).
+    pub group: String,
+    /// Account the member pays from and gets paid to.
+    pub cash: String,
+    pub currency: String,
+}
+
+impl LedgerAccounts {
+    pub fn new(
+        format: LedgerFormat,
+        expenses: Option<String>,
+        group: Option<String>,
+        cash: Option<String>,
+        currency: Option<String>,
+    ) -> Result<Self, &'static str> {
+        let accounts = Self {
+            expenses: expenses.unwrap_or_else(|| "Expenses:Koru".to_string()),
+            group: group.unwrap_or_else(|| "Assets:Koru".to_string()),
+            cash: cash.unwrap_or_else(|| "Assets:Cash".to_string()),
+            currency: currency.unwrap_or_else(|| "EUR".to_string()),
+        };
+        for account in [&accounts.expenses, &accounts.group, &accounts.cash] {
+            if !is_valid_account(format, account) {
+                return Err("Account names should be colon separated components without spaces, starting with Assets, Liabilities, Equity, Income or Expenses for beancount.");
+            }
+        }
+        if !is_valid_currency(&accounts.currency) {
+            return Err(
+                "Currency should be 1 to 24 uppercase letters or digits, starting with a letter.",
+            );
+        }
+        Ok(accounts)
+    }
+}
+
+fn is_valid_account(format: LedgerFormat, account: &str) -> bool {
+    let components = account.split(':').collect::<Vec<_>>();
+    let valid = components.len() > 1
+        && components
+            .iter()
+            .all(|c| !c.is_empty() && !c.contains(|ch: char| ch.is_whitespace() || ch == ';'));
+    match format {
+        LedgerFormat::Ledger => valid,
+        LedgerFormat::Beancount => {
+            valid
+                && BEANCOUNT_ROOTS.contains(&components[0])
+                && components[1..].iter().all(|c| {
+                    c.starts_with(|ch: char| ch.is_ascii_uppercase() || ch.is_ascii_digit())
+                        && c.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+                })
+        }
+    }
+}
+
+fn is_valid_currency(currency: &str) -> bool {
+    (1..=24).contains(&currency.len())
+        && currency.starts_with(|ch: char| ch.is_ascii_uppercase())
+        && currency
+            .chars()
+            .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit())
+}
+
+/// Transaction of the member books, amounts being in cents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerEntry {
+    pub date: DateTime<Utc>,
+    pub payee: String,
+    pub narration: String,
+    /// Whether the entry is not certain yet, e.g. an unpaid settlement transaction.
+    pub pending: bool,
+    pub postings: Vec<(String, i64)>,
+}
+
+impl LedgerEntry {
+    /// Books an expense split between `participants` members.
+    pub fn from_expense(
+        expense: &Expense,
+        member_id: &Uuid,
+        participants: usize,
+        payer: String,
+        accounts: &LedgerAccounts,
+    ) -> Self {
+        let amount = cents(f32::from(expense.amount));
+        let share = (amount as f64 / participants.max(1) as f64).round() as i64;
+        let postings = match expense.member_id == *member_id {
+            true => vec![
+                (accounts.expenses.clone(), share),
+                (accounts.group.clone(), amount - share),
+                (accounts.cash.clone(), -amount),
+            ],
+            false => vec![
+                (accounts.expenses.clone(), share),
+                (accounts.group.clone(), -share),
+            ],
+        };
+        Self {
+            date: expense.created_at,
+            payee: payer,
+            narration: String::from(expense.title.clone()),
+            pending: false,
+            postings,
+        }
+    }
+
+    /// Books a settlement transaction, `None` when the member is not part of it.
+    pub fn from_transaction(
+        settlement: &Settlement,
+        transaction: &Transaction,
+        member_id: &Uuid,
+        other: String,
+        accounts: &LedgerAccounts,
+    ) -> Option<Self> {
+        let amount = cents(f32::from(transaction.amount));
+        let (narration, amount) = if transaction.from == *member_id {
+            (format!("Settlement payment to {}", other), amount)
+        } else if transaction.to == *member_id {
+            (format!("Settlement payment from {}", other), -amount)
+        } else {
+            return None;
+        };
+        Some(Self {
+            date: settlement.end_date,
+            payee: settlement
+                .label
+                .clone()
+                .map(String::from)
+                .unwrap_or_else(|| "Settlement".to_string()),
+            narration,
+            pending: !transaction.paid,
+            postings: vec![
+                (accounts.group.clone(), amount),
+                (accounts.cash.clone(), -amount),
+            ],
+        })
+    }
+}
+
+fn cents(amount: f32) -> i64 {
+    (amount as f64 * 100.0).round() as i64
+}
+
+fn format_cents(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    format!("{}{}.{:02}", sign, cents.abs() / 100, cents.abs() % 100)
+}
+
+fn quote(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl LedgerFormat {
+    /// Renders the entries as a journal, ordered by date.
+    pub fn render(&self, entries: &mut [LedgerEntry], accounts: &LedgerAccounts) -> String {
+        entries.sort_by_key(|e| e.date);
+        let mut journal = String::new();
+        if let (LedgerFormat::Beancount, Some(first)) = (self, entries.first()) {
+            let date = first.date.format("%Y-%m-%d");
+            for account in [&accounts.expenses, &accounts.group, &accounts.cash] {
+                journal.push_str(&format!("{} open {}\n", date, account));
+            }
+            journal.push('\n');
+        }
+        for entry in entries.iter() {
+            let date = entry.date.format("%Y-%m-%d");
+            let flag = if entry.pending { "!" } else { "*" };
+            match self {
+                LedgerFormat::Beancount => journal.push_str(&format!(
+                    "{} {} \"{}\" \"{}\"\n",
+                    date,
+                    flag,
+                    quote(&entry.payee),
+                    quote(&entry.narration)
+                )),
+                LedgerFormat::Ledger => journal.push_str(&format!(
+                    "{} {} {} | {}\n",
+                    date,
+                    flag,
+                    entry.payee.replace(['|', '\n'], " "),
+                    entry.narration.replace('\n', " ")
+                )),
+            }
+            for (account, amount) in entry.postings.iter() {
+                journal.push_str(&format!(
+                    "    {}  {} {}\n",
+                    account,
+                    format_cents(*amount),
+                    accounts.currency
+                ));
+            }
+            journal.push('\n');
+        }
+        journal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Amount;
+    use chrono::TimeZone;
+    use claim::{assert_err, assert_none, assert_ok, assert_some};
+
+    fn expense(member_id: Uuid, amount: f32, day: u32) -> Expense {
+        let mut expense =
+            Expense::create("Dinner".to_string(), amount, member_id, Uuid::new_v4()).unwrap();
+        expense.created_at = Utc.with_ymd_and_hms(2023, 1, day, 20, 0, 0).unwrap();
+        expense
+    }
+
+    fn accounts(format: LedgerFormat) -> LedgerAccounts {
+        LedgerAccounts::new(format, None, None, None, None).unwrap()
+    }
+
+    #[test]
+    fn it_should_book_the_member_share_of_an_expense() {
+        // given
+        let (me, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let accounts = accounts(LedgerFormat::Beancount);
+        // when
+        let paid = LedgerEntry::from_expense(&expense(me, 10.0, 2), &me, 3, "Me".into(), &accounts);
+        let owed =
+            LedgerEntry::from_expense(&expense(other, 10.0, 3), &me, 3, "Bob".into(), &accounts);
+        // then
+        assert_eq!(
+            paid.postings,
+            vec![
+                ("Expenses:Koru".to_string(), 333),
+                ("Assets:Koru".to_string(), 667),
+                ("Assets:Cash".to_string(), -1000),
+            ]
+        );
+        assert_eq!(
+            owed.postings,
+            vec![
+                ("Expenses:Koru".to_string(), 333),
+                ("Assets:Koru".to_string(), -333),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_render_a_beancount_journal() {
+        // given
+        let (me, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let accounts = accounts(LedgerFormat::Beancount);
+        let settlement = Settlement {
+            id: Uuid::new_v4(),
+            group_id: Uuid::new_v4(),
+            start_date: None,
+            end_date: Utc.with_ymd_and_hms(2023, 1, 5, 8, 0, 0).unwrap(),
+            transactions: vec![],
+            expense_ids: vec![],
+            label: None,
+            total: 10.0,
+            expense_count: 1,
+            balances: vec![],
+        };
+        let transaction = Transaction {
+            from: me,
+            to: other,
+            amount: Amount::try_from(5.0).unwrap(),
+            paid: false,
+        };
+        let mut entries = vec![
+            assert_some!(LedgerEntry::from_transaction(
+                &settlement,
+                &transaction,
+                &me,
+                "Bob".into(),
+                &accounts
+            )),
+            LedgerEntry::from_expense(&expense(other, 10.0, 2), &me, 2, "Bob".into(), &accounts),
+        ];
+        // when
+        let journal = LedgerFormat::Beancount.render(&mut entries, &accounts);
+        // then
+        assert_eq!(
+            journal,
+            "2023-01-02 open Expenses:Koru\n\
+             2023-01-02 open Assets:Koru\n\
+             2023-01-02 open Assets:Cash\n\
+             \n\
+             2023-01-02 * \"Bob\" \"Dinner\"\n    \
+                 Expenses:Koru  5.00 EUR\n    \
+                 Assets:Koru  -5.00 EUR\n\
+             \n\
+             2023-01-05 ! \"Settlement\" \"Settlement payment to Bob\"\n    \
+                 Assets:Koru  5.00 EUR\n    \
+                 Assets:Cash  -5.00 EUR\n\
+             \n"
+        );
+        assert_none!(LedgerEntry::from_transaction(
+            &settlement,
+            &transaction,
+            &Uuid::new_v4(),
+            "Bob".into(),
+            &accounts
+        ));
+    }
+
+    #[test]
+    fn it_should_render_a_ledger_journal() {
+        // given
+        let me = Uuid::new_v4();
+        let accounts = accounts(LedgerFormat::Ledger);
+        let mut entries = vec![LedgerEntry::from_expense(
+            &expense(me, 10.0, 2),
+            &me,
+            2,
+            "Me".into(),
+            &accounts,
+        )];
+        // when
+        let journal = LedgerFormat::Ledger.render(&mut entries, &accounts);
+        // then
+        assert_eq!(
+            journal,
+            "2023-01-02 * Me | Dinner\n    \
+                 Expenses:Koru  5.00 EUR\n    \
+                 Assets:Koru  5.00 EUR\n    \
+                 Assets:Cash  -10.00 EUR\n\
+             \n"
+        );
+    }
+
+    #[test]
+    fn it_should_validate_account_names_and_currency() {
+        let account = |format, name: &str| {
+            LedgerAccounts::new(format, Some(name.to_string()), None, None, None)
+        };
+        assert_ok!(account(LedgerFormat::Beancount, "Expenses:Food:Shared"));
+        assert_err!(account(LedgerFormat::Beancount, "Spending:Food"));
+        assert_err!(account(LedgerFormat::Beancount, "Expenses:food"));
+        assert_ok!(account(LedgerFormat::Ledger, "spending:food"));
+        assert_err!(account(LedgerFormat::Ledger, "Expenses:Shared food"));
+        assert_err!(account(LedgerFormat::Ledger, "Expenses"));
+        assert_err!(LedgerAccounts::new(
+            LedgerFormat::Ledger,
+            None,
+            None,
+            None,
+            Some("eur".to_string())
+        ));
+    }
+}
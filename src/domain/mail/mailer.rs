@@ -0,0 +1,23 @@
+use crate::domain::Email;
+use crate::error_chain;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: Email,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError>;
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum MailerError {
+        #[error("Failed to send mail.")]
+        Send(#[source] anyhow::Error),
+    }
+}
@@ -0,0 +1,3 @@
+mod mailer;
+
+pub use mailer::*;
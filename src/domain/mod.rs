@@ -1,18 +1,29 @@
+mod backup;
+mod budget;
 pub mod errors;
 mod event;
 mod expense;
 mod group;
+mod import;
+mod ledger;
+pub mod mail;
 #[cfg(feature = "notification")]
 pub mod notification;
 mod settlement;
 mod shared;
+mod takeout;
 pub mod usecases;
 mod user;
 
+pub use backup::*;
+pub use budget::*;
 pub use event::*;
 pub use expense::*;
 pub use group::*;
+pub use import::*;
+pub use ledger::*;
 pub use settlement::*;
 pub use shared::amount::Amount;
 pub use shared::email::Email;
+pub use takeout::*;
 pub use user::*;
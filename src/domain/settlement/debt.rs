@@ -0,0 +1,182 @@
+use crate::domain::errors::SettleDebtError;
+use crate::domain::{Amount, Settlement, Transaction};
+use float_cmp::{ApproxEq, F32Margin};
+use uuid::Uuid;
+
+const MARGIN: f32 = 0.001;
+
+/// Outstanding settlement transactions between two users, across all the groups they share.
+#[derive(Debug)]
+pub struct Debt {
+    pub user_id: Uuid,
+    pub other_id: Uuid,
+    pub settlements: Vec<Settlement>,
+}
+
+impl Debt {
+    pub fn new(user_id: Uuid, other_id: Uuid, settlements: Vec<Settlement>) -> Self {
+        let settlements = settlements
+            .into_iter()
+            .filter(|s| {
+                s.transactions
+                    .iter()
+                    .any(|tr| !tr.paid && Self::is_between(tr, &user_id, &other_id))
+            })
+            .collect();
+        Self {
+            user_id,
+            other_id,
+            settlements,
+        }
+    }
+
+    /// Unpaid transactions between the two users, with the settlement they belong to.
+    pub fn transactions(&self) -> Vec<(&Settlement, &Transaction)> {
+        self.settlements
+            .iter()
+            .flat_map(|s| s.transactions.iter().map(move |tr| (s, tr)))
+            .filter(|(_, tr)| !tr.paid && Self::is_between(tr, &self.user_id, &self.other_id))
+            .collect()
+    }
+
+    /// Net amount of the transactions, positive when the other user owes money to the user.
+    pub fn net(&self) -> f32 {
+        self.transactions().iter().fold(0.0, |acc, (_, tr)| {
+            if tr.to == self.user_id {
+                acc + f32::from(tr.amount)
+            } else {
+                acc - f32::from(tr.amount)
+            }
+        })
+    }
+
+    /// Single payment clearing all the transactions, if any money is still owed.
+    pub fn payment(&self) -> Option<Transaction> {
+        let net = self.net();
+        let margin = F32Margin::epsilon(F32Margin::default(), MARGIN);
+        if net.approx_eq(0.0, margin) {
+            return None;
+        }
+        let (from, to) = if net > 0.0 {
+            (self.other_id, self.user_id)
+        } else {
+            (self.user_id, self.other_id)
+        };
+        Amount::try_from(net.abs()).ok().map(|amount| Transaction {
+            from,
+            to,
+            amount,
+            paid: false,
+        })
+    }
+
+    /// Marks all the transactions between the two users as paid.
+    pub fn settle(&mut self) -> Result<(), SettleDebtError> {
+        if self.settlements.is_empty() {
+            return Err(SettleDebtError::NotFound("No outstanding transactions."));
+        }
+        for settlement in &mut self.settlements {
+            settlement.pay_between(&self.user_id, &self.other_id);
+        }
+        Ok(())
+    }
+
+    fn is_between(tr: &Transaction, user_id: &Uuid, other_id: &Uuid) -> bool {
+        (tr.from == *user_id && tr.to == *other_id) || (tr.from == *other_id && tr.to == *user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::date;
+    use claim::{assert_none, assert_ok, assert_some};
+
+    fn settlement(transactions: Vec<Transaction>) -> Settlement {
+        Settlement {
+            id: Uuid::new_v4(),
+            group_id: Uuid::new_v4(),
+            start_date: None,
+            end_date: date::now(),
+            transactions,
+            expense_ids: vec![],
+            label: None,
+            total: 0.0,
+            expense_count: 0,
+            balances: vec![],
+        }
+    }
+
+    fn transaction(from: Uuid, to: Uuid, amount: f32, paid: bool) -> Transaction {
+        Transaction {
+            from,
+            to,
+            amount: Amount::try_from(amount).unwrap(),
+            paid,
+        }
+    }
+
+    #[test]
+    fn it_should_net_transactions_across_settlements() {
+        // given
+        let (user, other, third) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let settlements = vec![
+            settlement(vec![
+                transaction(other, user, 30.0, false),
+                transaction(third, user, 5.0, false),
+            ]),
+            settlement(vec![transaction(user, other, 10.0, false)]),
+            settlement(vec![transaction(user, other, 50.0, true)]),
+        ];
+
+        // when
+        let debt = Debt::new(user, other, settlements);
+
+        // then
+        assert_eq!(debt.settlements.len(), 2);
+        assert_eq!(debt.transactions().len(), 2);
+        assert_eq!(debt.net(), 20.0);
+        let payment = assert_some!(debt.payment());
+        assert_eq!(payment.from, other);
+        assert_eq!(payment.to, user);
+        assert_eq!(f32::from(payment.amount), 20.0);
+    }
+
+    #[test]
+    fn it_should_not_suggest_a_payment_when_debts_cancel_out() {
+        // given
+        let (user, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let settlements = vec![
+            settlement(vec![transaction(other, user, 12.5, false)]),
+            settlement(vec![transaction(user, other, 12.5, false)]),
+        ];
+
+        // when
+        let debt = Debt::new(user, other, settlements);
+
+        // then
+        assert_eq!(debt.transactions().len(), 2);
+        assert_none!(debt.payment());
+    }
+
+    #[test]
+    fn it_should_only_pay_the_transactions_between_the_two_users() {
+        // given
+        let (user, other, third) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let settlements = vec![settlement(vec![
+            transaction(other, user, 30.0, false),
+            transaction(third, user, 5.0, false),
+        ])];
+        let mut debt = Debt::new(user, other, settlements);
+
+        // when
+        let res = debt.settle();
+
+        // then
+        assert_ok!(res);
+        let transactions = &debt.settlements[0].transactions;
+        assert!(transactions[0].paid);
+        assert!(!transactions[1].paid);
+        assert!(debt.transactions().is_empty());
+    }
+}
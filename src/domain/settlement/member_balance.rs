@@ -0,0 +1,10 @@
+use uuid::Uuid;
+
+/// What a member paid during a settlement period, compared to their fair share.
+#[derive(Debug, Clone)]
+pub struct MemberBalance {
+    pub member_id: Uuid,
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
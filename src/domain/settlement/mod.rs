@@ -1,7 +1,13 @@
+mod debt;
+mod member_balance;
 mod settlement_description;
+mod settlement_label;
 mod transaction;
 
+pub use debt::Debt;
+pub use member_balance::MemberBalance;
 pub use settlement_description::SettlementDescription;
+pub use settlement_label::SettlementLabel;
 pub use transaction::Transaction;
 
 use crate::domain::errors::SettlementError;
@@ -25,6 +31,10 @@ pub struct Settlement {
     pub end_date: DateTime<Utc>,
     pub transactions: Vec<Transaction>,
     pub expense_ids: Vec<Uuid>,
+    pub label: Option<SettlementLabel>,
+    pub total: f32,
+    pub expense_count: u32,
+    pub balances: Vec<MemberBalance>,
 }
 
 impl Settlement {
@@ -33,8 +43,15 @@ impl Settlement {
         start_date: Option<DateTime<Utc>>,
         expenses: &mut [Expense],
         users: &[Uuid],
+        label: Option<String>,
     ) -> Result<Self, SettlementError> {
-        let transactions = Self::compute_transactions(expenses, users)?;
+        let label = label
+            .map(SettlementLabel::try_from)
+            .transpose()
+            .map_err(SettlementError::Validation)?;
+        let balances = Self::balances(expenses, users);
+        let total = balances.iter().map(|b| b.paid).sum();
+        let transactions = Self::compute_transactions(&balances)?;
         let mut expense_ids = Vec::new();
         for expense in expenses {
             expense.settle();
@@ -47,19 +64,44 @@ impl Settlement {
             start_date,
             end_date,
             transactions,
+            expense_count: expense_ids.len() as u32,
             expense_ids,
+            label,
+            total,
+            balances,
         })
     }
 
+    pub fn revert(&self, expenses: &mut [Expense]) {
+        for expense in expenses {
+            if self.expense_ids.contains(&expense.id) {
+                expense.unsettle();
+            }
+        }
+    }
+
+    pub fn has_paid_transactions(&self) -> bool {
+        self.transactions.iter().any(|tr| tr.paid)
+    }
+
+    pub fn pay_between(&mut self, user_id: &Uuid, other_id: &Uuid) {
+        for tr in &mut self.transactions {
+            if (tr.from == *user_id && tr.to == *other_id)
+                || (tr.from == *other_id && tr.to == *user_id)
+            {
+                tr.paid = true;
+            }
+        }
+    }
+
     fn compute_transactions(
-        expenses: &[Expense],
-        users: &[Uuid],
+        balances: &[MemberBalance],
     ) -> Result<Vec<Transaction>, SettlementError> {
-        let deltas_by_user = Self::deltas_by_user(expenses, users);
+        let deltas_by_user = balances.iter().map(|b| (b.member_id, b.net)).collect();
         Self::settle(deltas_by_user).map_err(SettlementError::Unexpected)
     }
 
-    fn deltas_by_user(expenses: &[Expense], users: &[Uuid]) -> HashMap<Uuid, f32> {
+    fn balances(expenses: &[Expense], users: &[Uuid]) -> Vec<MemberBalance> {
         let mut expenses_by_user = HashMap::new();
 
         // total expenses by user
@@ -78,14 +120,26 @@ impl Settlement {
         let avg = total / (users.len() as f32);
         info!("total: {}, avg: {} ", total, avg);
 
-        // compute delta by user
+        // compute balance by user, members first then former members who still paid
+        let mut balances = Vec::new();
         for user in users {
-            expenses_by_user
-                .entry(*user)
-                .and_modify(|v| *v -= avg)
-                .or_insert(-avg);
+            let paid = expenses_by_user.remove(user).unwrap_or(0.0);
+            balances.push(MemberBalance {
+                member_id: *user,
+                paid,
+                share: avg,
+                net: paid - avg,
+            });
         }
-        expenses_by_user
+        for (user, paid) in expenses_by_user.into_iter().sorted_by_key(|e| e.0) {
+            balances.push(MemberBalance {
+                member_id: user,
+                paid,
+                share: 0.0,
+                net: paid,
+            });
+        }
+        balances
     }
 
     fn settle(deltas_by_user: HashMap<Uuid, f32>) -> Result<Vec<Transaction>, anyhow::Error> {
@@ -129,6 +183,7 @@ impl Settlement {
                 from: from.0,
                 to: to.0,
                 amount: Amount::try_from(transfer).map_err(|e| anyhow!(e))?,
+                paid: false,
             });
 
             from.1 += transfer;
@@ -245,6 +300,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_compute_paid_share_and_net_by_member() -> anyhow::Result<()> {
+        let group_id = Uuid::new_v4();
+        let users = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let mut expenses = vec![
+            Expense::create("exp".to_string(), 40.0, users[0], group_id)?,
+            Expense::create("exp".to_string(), 20.0, users[1], group_id)?,
+        ];
+        let stl = Settlement::create(
+            group_id,
+            None,
+            &mut expenses,
+            &users,
+            Some("Rome trip".to_string()),
+        )?;
+        assert_approx_eq!(f32, stl.total, 60.0);
+        assert_eq!(stl.expense_count, 2);
+        assert_eq!(String::from(stl.label.unwrap()), "Rome trip");
+        assert_eq!(stl.balances.len(), 3);
+        let expected = [(40.0, 20.0), (20.0, 0.0), (0.0, -20.0)];
+        for (balance, (user, (paid, net))) in stl.balances.iter().zip(users.iter().zip(expected)) {
+            assert_eq!(balance.member_id, *user);
+            assert_approx_eq!(f32, balance.paid, paid);
+            assert_approx_eq!(f32, balance.share, 20.0);
+            assert_approx_eq!(f32, balance.net, net);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_fail_if_label_is_empty() {
+        let res = Settlement::create(Uuid::new_v4(), None, &mut [], &[], Some("".to_string()));
+        assert_err!(res);
+    }
+
     #[test]
     fn it_should_fail_if_deltas_are_invalid() {
         let deltas = [
@@ -0,0 +1,22 @@
+#[derive(Clone, Debug)]
+pub struct SettlementLabel(String);
+
+impl TryFrom<String> for SettlementLabel {
+    type Error = &'static str;
+
+    fn try_from(n: String) -> Result<Self, Self::Error> {
+        if n.trim().is_empty() {
+            Err("Settlement label cannot be empty")
+        } else if n.chars().count() > 100 {
+            Err("Settlement label cannot be longer than 100 characters")
+        } else {
+            Ok(Self(n))
+        }
+    }
+}
+
+impl From<SettlementLabel> for String {
+    fn from(n: SettlementLabel) -> Self {
+        n.0
+    }
+}
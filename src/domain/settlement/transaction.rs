@@ -6,4 +6,5 @@ pub struct Transaction {
     pub from: Uuid,
     pub to: Uuid,
     pub amount: Amount,
+    pub paid: bool,
 }
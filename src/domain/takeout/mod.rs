@@ -0,0 +1,436 @@
+//! Archive of the personal data of a user, given to them on request.
+//!
+//! It holds what the user entered or took part in: their expenses, the settlements where
+//! they pay or are paid, and the events they triggered. Credentials are described by their
+//! login only, password hashes never leave the store. Other members are referenced by email,
+//! as in the group backups.
+//!
+//! Archives of users belonging to many groups are generated by the worker and kept for
+//! download in a [`Takeout`].
+use crate::domain::{
+    BackupEvent, BackupTransaction, Email, Expense, Group, GroupEvent, GroupEventKind, Settlement,
+    User, UserEvent, UserEventKind, UserRole,
+};
+use crate::utils::date;
+use chrono::{DateTime, Duration, Utc};
+use itertools::Itertools;
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Version of the archives written by this instance.
+pub const TAKEOUT_VERSION: u32 = 1;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UserTakeout {
+    pub version: u32,
+    pub generated_at: DateTime<Utc>,
+    pub profile: TakeoutProfile,
+    pub credentials: Option<TakeoutCredentials>,
+    pub devices: Vec<String>,
+    pub memberships: Vec<TakeoutMembership>,
+    pub expenses: Vec<TakeoutExpense>,
+    pub settlements: Vec<TakeoutSettlement>,
+    pub user_events: Vec<TakeoutUserEvent>,
+    pub group_events: Vec<TakeoutGroupEvent>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutProfile {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutCredentials {
+    pub login: String,
+    pub password: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutMembership {
+    pub group_id: Uuid,
+    pub group_name: String,
+    pub color: String,
+    pub is_admin: bool,
+    pub joined_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutExpense {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    pub title: String,
+    pub amount: f32,
+    pub created_at: DateTime<Utc>,
+    pub modified_at: Option<DateTime<Utc>>,
+    pub settled: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutSettlement {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    pub label: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: DateTime<Utc>,
+    /// Balance of the user over the settled period.
+    pub balance: Option<TakeoutBalance>,
+    /// Transactions paid or received by the user.
+    pub transactions: Vec<BackupTransaction>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutBalance {
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutUserEvent {
+    pub date: DateTime<Utc>,
+    pub event: TakeoutUserEventKind,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TakeoutUserEventKind {
+    Created {
+        name: String,
+        email: String,
+    },
+    Login,
+    Logout,
+    Deleted,
+    TakeoutRequested,
+    NameChanged {
+        previous_name: String,
+        new_name: String,
+    },
+    EmailChanged {
+        previous_email: String,
+        new_email: String,
+    },
+    PasswordChanged,
+    PasswordResetRequested,
+    EmailVerificationRequested,
+    EmailVerified,
+    ApiTokenCreated {
+        name: String,
+    },
+    ApiTokenRevoked {
+        name: String,
+    },
+    TwoFactorEnabled,
+    TwoFactorReset,
+    RoleChanged {
+        role: String,
+    },
+    Disabled,
+    Enabled,
+    PasswordResetForced,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TakeoutGroupEvent {
+    pub group_id: Uuid,
+    #[serde(flatten)]
+    pub event: BackupEvent,
+}
+
+impl UserTakeout {
+    /// Gathers the data of `user` out of the groups they belong to.
+    ///
+    /// `expenses`, `settlements` and `group_events` can hold the whole history of the groups,
+    /// only the parts involving the user are kept.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        user: &User,
+        login: Option<&Email>,
+        devices: Vec<String>,
+        groups: &[Group],
+        expenses: &[Expense],
+        settlements: &[Settlement],
+        user_events: &[UserEvent],
+        group_events: &[GroupEvent],
+    ) -> Result<Self, &'static str> {
+        let emails = groups
+            .iter()
+            .flat_map(|g| g.members.iter())
+            .map(|m| (m.id, String::from(m.email.clone())))
+            .collect::<HashMap<Uuid, String>>();
+        let email = |id: &Uuid| {
+            emails
+                .get(id)
+                .cloned()
+                .ok_or("The archive references a member that is not part of the groups.")
+        };
+        let involves = |from: &Uuid, to: &Uuid| *from == user.id || *to == user.id;
+        Ok(Self {
+            version: TAKEOUT_VERSION,
+            generated_at: date::now(),
+            profile: TakeoutProfile {
+                id: user.id,
+                name: String::from(user.name.clone()),
+                email: String::from(user.email.clone()),
+                role: match user.role {
+                    UserRole::Administrator => "Administrator".to_string(),
+                    UserRole::User => "User".to_string(),
+                },
+                created_at: user.created_at,
+            },
+            credentials: login.map(|login| TakeoutCredentials {
+                login: String::from(login.clone()),
+                password: true,
+            }),
+            devices,
+            memberships: groups
+                .iter()
+                .filter_map(|g| {
+                    g.members
+                        .iter()
+                        .find(|m| m.id == user.id)
+                        .map(|m| TakeoutMembership {
+                            group_id: g.id,
+                            group_name: String::from(g.name.clone()),
+                            color: String::from(m.color.clone()),
+                            is_admin: m.is_admin,
+                            joined_at: m.joined_at,
+                        })
+                })
+                .collect(),
+            expenses: expenses
+                .iter()
+                .filter(|e| e.member_id == user.id)
+                .sorted_by_key(|e| e.created_at)
+                .map(|e| TakeoutExpense {
+                    id: e.id,
+                    group_id: e.group_id,
+                    title: String::from(e.title.clone()),
+                    amount: f32::from(e.amount),
+                    created_at: e.created_at,
+                    modified_at: e.modified_at,
+                    settled: e.settled,
+                })
+                .collect(),
+            settlements: settlements
+                .iter()
+                .filter(|s| {
+                    s.transactions.iter().any(|t| involves(&t.from, &t.to))
+                        || s.balances.iter().any(|b| b.member_id == user.id)
+                })
+                .sorted_by_key(|s| s.end_date)
+                .map(|s| {
+                    let transactions = s
+                        .transactions
+                        .iter()
+                        .filter(|t| involves(&t.from, &t.to))
+                        .cloned()
+                        .collect_vec();
+                    Ok(TakeoutSettlement {
+                        id: s.id,
+                        group_id: s.group_id,
+                        label: s.label.clone().map(String::from),
+                        start_date: s.start_date,
+                        end_date: s.end_date,
+                        balance: s.balances.iter().find(|b| b.member_id == user.id).map(|b| {
+                            TakeoutBalance {
+                                paid: b.paid,
+                                share: b.share,
+                                net: b.net,
+                            }
+                        }),
+                        transactions: BackupTransaction::from_all(&transactions, &email)?,
+                    })
+                })
+                .collect::<Result<_, &'static str>>()?,
+            user_events: user_events
+                .iter()
+                .filter(|e| e.user_id == user.id)
+                .sorted_by_key(|e| e.event_date)
+                .map(TakeoutUserEvent::from)
+                .collect(),
+            group_events: group_events
+                .iter()
+                .filter(|e| match &e.event {
+                    GroupEventKind::Settled { transactions, .. } => {
+                        e.member_id == user.id
+                            || transactions.iter().any(|t| involves(&t.from, &t.to))
+                    }
+                    _ => e.member_id == user.id,
+                })
+                .sorted_by_key(|e| e.event_date)
+                .map(|e| {
+                    Ok(TakeoutGroupEvent {
+                        group_id: e.group_id,
+                        event: BackupEvent::from(e, &email)?,
+                    })
+                })
+                .collect::<Result<_, &'static str>>()?,
+        })
+    }
+}
+
+impl From<&UserEvent> for TakeoutUserEvent {
+    fn from(event: &UserEvent) -> Self {
+        Self {
+            date: event.event_date,
+            event: match event.event.clone() {
+                UserEventKind::Created { name, email } => {
+                    TakeoutUserEventKind::Created { name, email }
+                }
+                UserEventKind::Login => TakeoutUserEventKind::Login,
+                UserEventKind::Logout => TakeoutUserEventKind::Logout,
+                UserEventKind::Deleted => TakeoutUserEventKind::Deleted,
+                UserEventKind::TakeoutRequested => TakeoutUserEventKind::TakeoutRequested,
+                UserEventKind::NameChanged {
+                    previous_name,
+                    new_name,
+                } => TakeoutUserEventKind::NameChanged {
+                    previous_name,
+                    new_name,
+                },
+                UserEventKind::EmailChanged {
+                    previous_email,
+                    new_email,
+                } => TakeoutUserEventKind::EmailChanged {
+                    previous_email,
+                    new_email,
+                },
+                UserEventKind::PasswordChanged => TakeoutUserEventKind::PasswordChanged,
+                UserEventKind::PasswordResetRequested => {
+                    TakeoutUserEventKind::PasswordResetRequested
+                }
+                UserEventKind::EmailVerificationRequested => {
+                    TakeoutUserEventKind::EmailVerificationRequested
+                }
+                UserEventKind::EmailVerified => TakeoutUserEventKind::EmailVerified,
+                UserEventKind::ApiTokenCreated { name, .. } => {
+                    TakeoutUserEventKind::ApiTokenCreated { name }
+                }
+                UserEventKind::ApiTokenRevoked { name, .. } => {
+                    TakeoutUserEventKind::ApiTokenRevoked { name }
+                }
+                UserEventKind::TwoFactorEnabled => TakeoutUserEventKind::TwoFactorEnabled,
+                UserEventKind::TwoFactorReset { .. } => TakeoutUserEventKind::TwoFactorReset,
+                UserEventKind::RoleChanged { role, .. } => {
+                    TakeoutUserEventKind::RoleChanged { role }
+                }
+                UserEventKind::Disabled { .. } => TakeoutUserEventKind::Disabled,
+                UserEventKind::Enabled { .. } => TakeoutUserEventKind::Enabled,
+                UserEventKind::PasswordResetForced { .. } => {
+                    TakeoutUserEventKind::PasswordResetForced
+                }
+                UserEventKind::DeletedByAdmin { .. } => TakeoutUserEventKind::Deleted,
+            },
+        }
+    }
+}
+
+/// Archive generated by the worker, waiting to be downloaded by the user.
+#[derive(Debug, Clone)]
+pub struct Takeout {
+    pub user_id: Uuid,
+    pub requested_at: DateTime<Utc>,
+    pub generated_at: Option<DateTime<Utc>>,
+    pub archive: Option<serde_json::Value>,
+}
+
+impl Takeout {
+    pub fn request(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            requested_at: date::now(),
+            generated_at: None,
+            archive: None,
+        }
+    }
+
+    pub fn complete(&mut self, archive: serde_json::Value) {
+        self.generated_at = Some(date::now());
+        self.archive = Some(archive);
+    }
+
+    /// Whether the archive, or the request when it is still pending, is older than
+    /// `retention` and should be generated again.
+    pub fn is_expired(&self, retention: Duration) -> bool {
+        self.generated_at.unwrap_or(self.requested_at) + retention < date::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MemberColor;
+    use claim::assert_ok;
+
+    fn user(name: &str) -> User {
+        User::create(name.to_string(), format!("{}@test.com", name)).unwrap()
+    }
+
+    #[test]
+    fn it_should_only_keep_what_involves_the_user() {
+        // given
+        let (alice, bob, carol) = (user("alice"), user("bob"), user("carol"));
+        let color = MemberColor::try_from("0,0,0".to_string()).unwrap();
+        let mut group = Group::create(
+            "trip".to_string(),
+            alice.id,
+            alice.name.clone(),
+            alice.email.clone(),
+            color.clone(),
+        )
+        .unwrap();
+        for member in [&bob, &carol] {
+            group
+                .add_member(
+                    member.id,
+                    member.name.clone(),
+                    member.email.clone(),
+                    color.clone(),
+                )
+                .unwrap();
+        }
+        let mut expenses = vec![group.add_expense("Taxi".into(), 30.0, bob.id).unwrap()];
+        let settlement = group.settle(&mut expenses, None, alice.id, None).unwrap();
+        expenses.push(group.add_expense("Bread".into(), 3.0, alice.id).unwrap());
+        let user_events = vec![
+            UserEvent::new(alice.id, UserEventKind::Login),
+            UserEvent::new(bob.id, UserEventKind::Login),
+        ];
+        // when
+        let takeout = UserTakeout::create(
+            &alice,
+            Some(&alice.email),
+            vec!["device".to_string()],
+            std::slice::from_ref(&group),
+            &expenses,
+            &[settlement],
+            &user_events,
+            &group.events,
+        );
+        // then
+        let takeout = assert_ok!(takeout);
+        assert_eq!(takeout.memberships.len(), 1);
+        assert!(takeout.memberships[0].is_admin);
+        assert_eq!(takeout.expenses.len(), 1);
+        assert_eq!(takeout.expenses[0].id, expenses[1].id);
+        assert_eq!(takeout.settlements.len(), 1);
+        assert_eq!(takeout.settlements[0].transactions.len(), 1);
+        assert_eq!(takeout.settlements[0].transactions[0].to, "bob@test.com");
+        assert_eq!(takeout.user_events.len(), 1);
+        // created the group, settled it and paid the bread
+        assert_eq!(takeout.group_events.len(), 3);
+        let document = assert_ok!(serde_json::to_value(&takeout));
+        assert_eq!(document["credentials"]["login"], "alice@test.com");
+        assert_eq!(
+            document["group_events"][0]["event"]["type"],
+            "group_created"
+        );
+        assert_eq!(document["user_events"][0]["event"]["type"], "login");
+    }
+}
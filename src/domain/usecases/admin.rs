@@ -1,10 +1,89 @@
-use crate::domain::errors::{GetAllGroupsError, GetUsersError};
-use crate::domain::usecases::dto::dtos::{DetailedUserDto, GroupDto};
+use crate::domain::errors::{
+    GetAllGroupsError, GetAuditLogError, GetUsersError, ManageGroupError, ManageUserError,
+    TwoFactorError,
+};
+use crate::domain::usecases::dto::dtos::{
+    AuditLogDto, DetailedUserDto, GroupDto, ModeratedGroupDto,
+};
+use crate::domain::EventFilter;
 use async_trait::async_trait;
 use uuid::Uuid;
 
+#[derive(Clone)]
+pub struct AuditLogRequest {
+    pub filter: EventFilter,
+    /// The event the page starts after, the most recent events being fetched without it.
+    pub before: Option<Uuid>,
+    pub limit: Option<u32>,
+}
+
 #[async_trait(?Send)]
 pub trait AdminUseCase {
     async fn get_users(&self, requester: &Uuid) -> Result<Vec<DetailedUserDto>, GetUsersError>;
     async fn get_groups(&self, requester: &Uuid) -> Result<Vec<GroupDto>, GetAllGroupsError>;
+    /// Removes the second factor of the user, e.g. when they lost both their authenticator and
+    /// their recovery codes.
+    async fn reset_two_factor(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(), TwoFactorError>;
+    /// Grants or withdraws the administrator role of the user.
+    async fn change_role(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+        role: &str,
+    ) -> Result<(), ManageUserError>;
+    /// Blocks the login of the user and rejects their sessions and tokens until they are enabled.
+    async fn disable_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError>;
+    async fn enable_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError>;
+    /// Rejects the sessions of the user and mails them a password reset link, which they have to
+    /// follow before logging in again.
+    async fn force_password_reset(
+        &self,
+        requester: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(), ManageUserError>;
+    /// Deletes the account of the user as they could themselves.
+    async fn delete_user(&self, requester: &Uuid, user_id: &Uuid) -> Result<(), ManageUserError>;
+    /// Fetches any group with all its expenses, settlements and events.
+    async fn get_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<ModeratedGroupDto, ManageGroupError>;
+    /// Makes a member the admin of the group, in place of its current admin.
+    async fn transfer_group_ownership(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+        new_admin_id: &Uuid,
+    ) -> Result<(), ManageGroupError>;
+    /// Makes the group read-only until it is unarchived.
+    async fn archive_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(), ManageGroupError>;
+    async fn unarchive_group(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+    ) -> Result<(), ManageGroupError>;
+    async fn delete_group(&self, requester: &Uuid, group_id: &Uuid)
+        -> Result<(), ManageGroupError>;
+    /// Removes a member without expenses nor settlements from the group.
+    async fn remove_member(
+        &self,
+        requester: &Uuid,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), ManageGroupError>;
+    /// Fetches a page of the events matching the filters, newest first.
+    async fn get_audit_log(
+        &self,
+        requester: &Uuid,
+        request: AuditLogRequest,
+    ) -> Result<AuditLogDto, GetAuditLogError>;
 }
@@ -1,6 +1,11 @@
-use crate::domain::{Expense, Group, GroupMember, MemberColor, Transaction, User, UserRole};
+use crate::domain::{
+    ApiToken, Budget, BudgetConsumption, Debt, Event, EventOrigin, Expense, ExpenseStats, Group,
+    GroupEvent, GroupEventKind, GroupMember, GroupSummary, ImportLineError, MemberBalance,
+    MemberColor, Settlement, Transaction, User,
+};
 use chrono::{DateTime, Utc};
 use itertools::Itertools;
+use std::collections::HashMap;
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -11,15 +16,58 @@ pub struct SettlementDto {
     pub id: Uuid,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: DateTime<Utc>,
+    pub label: Option<String>,
+    pub total: f32,
+    pub expense_count: u32,
+    pub balances: Vec<MemberBalanceDto>,
     pub transactions: Vec<TransactionDto>,
 }
 
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MemberBalanceDto {
+    pub member: MemberDto,
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
+
 #[derive(serde::Serialize, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct TransactionDto {
     pub from: MemberDto,
     pub to: MemberDto,
     pub amount: f32,
+    pub paid: bool,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct DebtDto {
+    pub user: UserDto,
+    pub net: f32,
+    pub payment: Option<PaymentDto>,
+    pub transactions: Vec<DebtTransactionDto>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PaymentDto {
+    pub from: UserDto,
+    pub to: UserDto,
+    pub amount: f32,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct DebtTransactionDto {
+    pub group_id: Uuid,
+    pub settlement_id: Uuid,
+    pub label: Option<String>,
+    pub date: DateTime<Utc>,
+    pub from: UserDto,
+    pub to: UserDto,
+    pub amount: f32,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -30,7 +78,7 @@ pub struct ColorDto {
     pub blue: u8,
 }
 
-#[derive(serde::Serialize, Debug)]
+#[derive(serde::Serialize, Debug, Clone)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct UserDto {
     pub id: Uuid,
@@ -45,6 +93,19 @@ pub struct DetailedUserDto {
     pub email: String,
     pub role: String,
     pub created_at: DateTime<Utc>,
+    pub disabled_at: Option<DateTime<Utc>>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ApiTokenDto {
+    pub id: Uuid,
+    pub name: String,
+    /// Either `read` or `write`.
+    pub scope: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
 }
 
 #[derive(serde::Serialize, Debug)]
@@ -68,12 +129,90 @@ pub struct ExpenseDto {
     pub date: DateTime<Utc>,
 }
 
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct StatsDto {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub total: f32,
+    pub count: u32,
+    pub members: Vec<MemberTotalDto>,
+    pub months: Vec<MonthTotalDto>,
+    pub largest: Vec<ExpenseDto>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MemberTotalDto {
+    pub member: MemberDto,
+    pub total: f32,
+    pub count: u32,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MonthTotalDto {
+    /// Month formatted as `YYYY-MM`.
+    pub month: String,
+    pub total: f32,
+    pub count: u32,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BudgetDto {
+    pub amount: f32,
+    /// Either `monthly` or `settlement`.
+    pub period: String,
+    /// Start of the current period, absent when the group was never settled.
+    pub start: Option<DateTime<Utc>>,
+    pub spent: f32,
+    pub remaining: f32,
+    pub percentage: f32,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ImportReportDto {
+    pub dry_run: bool,
+    /// Number of expenses imported, or that would be imported by a dry run.
+    /// Nothing is imported when the file contains errors.
+    pub imported: u32,
+    pub errors: Vec<ImportErrorDto>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ImportErrorDto {
+    pub line: u32,
+    pub error: String,
+}
+
 #[derive(serde::Serialize, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct GroupDto {
     pub id: Uuid,
     pub name: String,
     pub members: Vec<MemberDto>,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SummaryDto {
+    pub total: f32,
+    pub groups: Vec<GroupSummaryDto>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GroupSummaryDto {
+    pub id: Uuid,
+    pub name: String,
+    pub balance: f32,
+    pub outstanding: f32,
+    pub transactions: Vec<TransactionDto>,
+    pub last_activity: DateTime<Utc>,
 }
 
 #[derive(serde::Serialize, Debug)]
@@ -83,6 +222,198 @@ pub struct DetailedGroupDto {
     pub name: String,
     pub members: Vec<MemberDto>,
     pub expenses: Vec<ExpenseDto>,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// A group as seen by the administrators moderating it.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ModeratedGroupDto {
+    /// The group, with all its expenses, settled or not.
+    pub group: DetailedGroupDto,
+    pub settlements: Vec<SettlementDto>,
+    /// The history of the group, oldest first.
+    pub events: Vec<GroupEventDto>,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GroupEventDto {
+    pub id: Uuid,
+    pub date: DateTime<Utc>,
+    /// The member, or the moderating administrator, at the origin of the event.
+    pub member_id: Uuid,
+    /// The kind of event, e.g. `ExpenseCreated`.
+    pub kind: String,
+    /// The expense, settlement or member the event is about, if any.
+    pub subject_id: Option<Uuid>,
+}
+
+/// An event of the store, as listed to the operators.
+#[derive(serde::Serialize, Debug)]
+pub struct StoredEventDto {
+    pub id: Uuid,
+    pub date: DateTime<Utc>,
+    /// The kind of event as stored, e.g. `UserCreated`.
+    pub kind: String,
+    pub user_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    /// The member at the origin of a group event.
+    pub member_id: Option<Uuid>,
+    /// When the worker processed the event, if it did.
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+/// An entry of the audit log.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct AuditEventDto {
+    pub id: Uuid,
+    pub date: DateTime<Utc>,
+    /// The kind of event as stored, e.g. `UserLogin`.
+    pub kind: String,
+    /// The user who performed the action, the nil id standing for the operators of the server.
+    pub actor_id: Uuid,
+    /// The user account of a user event.
+    pub user_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    /// The expense, settlement or member a group event is about, if any.
+    pub subject_id: Option<Uuid>,
+    /// The IP address the action was requested from, when known.
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// A page of the audit log, newest events first.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct AuditLogDto {
+    pub events: Vec<AuditEventDto>,
+    /// The cursor of the next page, if any: the id of the last event of this page.
+    pub next: Option<Uuid>,
+}
+
+/// An entry of the activity feed of a group.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ActivityDto {
+    pub id: Uuid,
+    pub date: DateTime<Utc>,
+    /// The member who did it.
+    pub member: ActivityMemberDto,
+    /// What happened in a sentence, e.g. `Alice added "Dinner" (42.00)`.
+    pub summary: String,
+    pub activity: ActivityKindDto,
+}
+
+/// A member mentioned in the activity feed, who may have left the group since.
+#[derive(serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ActivityMemberDto {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActivityKindDto {
+    GroupCreated {
+        name: String,
+    },
+    MemberJoined,
+    MemberColorChanged {
+        color: ColorDto,
+    },
+    ExpenseCreated {
+        expense_id: Uuid,
+        description: String,
+        amount: f32,
+    },
+    ExpenseModified {
+        expense_id: Uuid,
+        previous_description: String,
+        description: String,
+        previous_amount: f32,
+        amount: f32,
+    },
+    ExpenseDeleted {
+        expense_id: Uuid,
+    },
+    Settled {
+        settlement_id: Uuid,
+        transactions: Vec<ActivityTransactionDto>,
+    },
+    SettlementReverted {
+        settlement_id: Uuid,
+    },
+    BudgetChanged {
+        /// None when the budget was removed.
+        amount: Option<f32>,
+        period: Option<String>,
+    },
+    OwnershipTransferred {
+        new_admin: ActivityMemberDto,
+    },
+    GroupDeleted,
+    GroupArchived,
+    GroupUnarchived,
+    MemberRemoved {
+        removed_member: ActivityMemberDto,
+    },
+}
+
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ActivityTransactionDto {
+    pub from: ActivityMemberDto,
+    pub to: ActivityMemberDto,
+    pub amount: f32,
+}
+
+/// A page of the activity feed of a group, most recent first.
+#[derive(serde::Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ActivityFeedDto {
+    pub activities: Vec<ActivityDto>,
+    /// The cursor of the next page, if any: the id of the last activity of this page.
+    pub next: Option<Uuid>,
+}
+
+impl DebtDto {
+    pub fn from(debt: &Debt, user: User, other: User) -> Self {
+        let user = UserDto::from(user);
+        let other = UserDto::from(other);
+        let pick = |id: Uuid| {
+            if id == user.id {
+                user.clone()
+            } else {
+                other.clone()
+            }
+        };
+        DebtDto {
+            net: debt.net(),
+            payment: debt.payment().map(|p| PaymentDto {
+                from: pick(p.from),
+                to: pick(p.to),
+                amount: f32::from(p.amount),
+            }),
+            transactions: debt
+                .transactions()
+                .into_iter()
+                .map(|(s, tr)| DebtTransactionDto {
+                    group_id: s.group_id,
+                    settlement_id: s.id,
+                    label: s.label.clone().map(String::from),
+                    date: s.end_date,
+                    from: pick(tr.from),
+                    to: pick(tr.to),
+                    amount: f32::from(tr.amount),
+                })
+                .collect(),
+            user: other.clone(),
+        }
+    }
 }
 
 impl From<MemberColor> for ColorDto {
@@ -120,11 +451,22 @@ impl From<User> for DetailedUserDto {
             id: value.id,
             name: String::from(value.name),
             email: String::from(value.email),
-            role: match value.role {
-                UserRole::Administrator => "Administrator".to_string(),
-                UserRole::User => "User".to_string(),
-            },
+            role: value.role.to_string(),
             created_at: value.created_at,
+            disabled_at: value.disabled_at,
+        }
+    }
+}
+
+impl From<ApiToken> for ApiTokenDto {
+    fn from(value: ApiToken) -> Self {
+        ApiTokenDto {
+            id: value.id,
+            name: value.name,
+            scope: value.scope.as_str().to_string(),
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            last_used_at: value.last_used_at,
         }
     }
 }
@@ -148,6 +490,240 @@ impl GroupDto {
             id: grp.id,
             name: String::from(grp.name),
             members: grp.members.into_iter().map(MemberDto::from).collect(),
+            archived_at: grp.archived_at,
+        }
+    }
+}
+
+impl From<GroupEvent> for GroupEventDto {
+    fn from(value: GroupEvent) -> Self {
+        GroupEventDto {
+            id: value.id,
+            date: value.event_date,
+            member_id: value.member_id,
+            kind: value.event.name().to_string(),
+            subject_id: value.event.subject_id(),
+        }
+    }
+}
+
+impl StoredEventDto {
+    pub fn from(event: &Event, processed_at: Option<DateTime<Utc>>) -> Self {
+        let (user_id, group_id, member_id) = match event {
+            Event::User(e) => (Some(e.user_id), None, None),
+            Event::Group(e) => (None, Some(e.group_id), Some(e.member_id)),
+        };
+        StoredEventDto {
+            id: event.id(),
+            date: event.date(),
+            kind: event.name().to_string(),
+            user_id,
+            group_id,
+            member_id,
+            processed_at,
+        }
+    }
+}
+
+impl AuditEventDto {
+    pub fn from(event: Event, origin: EventOrigin) -> Self {
+        let subject_id = match &event {
+            Event::User(_) => None,
+            Event::Group(e) => e.event.subject_id(),
+        };
+        AuditEventDto {
+            id: event.id(),
+            date: event.date(),
+            kind: event.name().to_string(),
+            actor_id: event.actor_id(),
+            user_id: event.user_id(),
+            group_id: event.group_id(),
+            subject_id,
+            ip: origin.ip,
+            user_agent: origin.user_agent,
+        }
+    }
+}
+
+impl ActivityDto {
+    /// Tells the event with the names of the members, those missing from `names` having left.
+    pub fn from(event: GroupEvent, names: &HashMap<Uuid, String>) -> Self {
+        let member = |id: Uuid| ActivityMemberDto {
+            id,
+            name: names
+                .get(&id)
+                .cloned()
+                .unwrap_or_else(|| "Former member".to_string()),
+        };
+        let activity = match event.event {
+            GroupEventKind::GroupCreated { name, .. } => ActivityKindDto::GroupCreated { name },
+            GroupEventKind::MemberJoined { .. } => ActivityKindDto::MemberJoined,
+            GroupEventKind::MemberColorChanged { new_color, .. } => {
+                ActivityKindDto::MemberColorChanged {
+                    color: ColorDto::from(new_color),
+                }
+            }
+            GroupEventKind::ExpenseCreated {
+                id,
+                description,
+                amount,
+                ..
+            } => ActivityKindDto::ExpenseCreated {
+                expense_id: id,
+                description,
+                amount,
+            },
+            GroupEventKind::ExpenseModified {
+                id,
+                previous_description,
+                new_description,
+                previous_amount,
+                new_amount,
+            } => ActivityKindDto::ExpenseModified {
+                expense_id: id,
+                previous_description,
+                description: new_description,
+                previous_amount,
+                amount: new_amount,
+            },
+            GroupEventKind::ExpenseDeleted { id } => {
+                ActivityKindDto::ExpenseDeleted { expense_id: id }
+            }
+            GroupEventKind::Settled {
+                id, transactions, ..
+            } => ActivityKindDto::Settled {
+                settlement_id: id,
+                transactions: transactions
+                    .into_iter()
+                    .map(|t| ActivityTransactionDto {
+                        from: member(t.from),
+                        to: member(t.to),
+                        amount: f32::from(t.amount),
+                    })
+                    .collect(),
+            },
+            GroupEventKind::SettlementReverted { id } => {
+                ActivityKindDto::SettlementReverted { settlement_id: id }
+            }
+            GroupEventKind::BudgetChanged { amount, period } => ActivityKindDto::BudgetChanged {
+                amount,
+                period: period.map(String::from),
+            },
+            GroupEventKind::OwnershipTransferred { new_admin_id } => {
+                ActivityKindDto::OwnershipTransferred {
+                    new_admin: member(new_admin_id),
+                }
+            }
+            GroupEventKind::GroupDeleted => ActivityKindDto::GroupDeleted,
+            GroupEventKind::GroupArchived => ActivityKindDto::GroupArchived,
+            GroupEventKind::GroupUnarchived => ActivityKindDto::GroupUnarchived,
+            GroupEventKind::MemberRemoved { member_id } => ActivityKindDto::MemberRemoved {
+                removed_member: member(member_id),
+            },
+        };
+        let member = member(event.member_id);
+        ActivityDto {
+            id: event.id,
+            date: event.event_date,
+            summary: activity.summary(&member.name),
+            member,
+            activity,
+        }
+    }
+}
+
+impl ActivityKindDto {
+    fn summary(&self, member: &str) -> String {
+        match self {
+            ActivityKindDto::GroupCreated { name } => {
+                format!("{} created the group \"{}\"", member, name)
+            }
+            ActivityKindDto::MemberJoined => format!("{} joined the group", member),
+            ActivityKindDto::MemberColorChanged { .. } => format!("{} changed color", member),
+            ActivityKindDto::ExpenseCreated {
+                description,
+                amount,
+                ..
+            } => format!("{} added \"{}\" ({:.2})", member, description, amount),
+            ActivityKindDto::ExpenseModified {
+                previous_description,
+                description,
+                previous_amount,
+                amount,
+                ..
+            } => {
+                if previous_description == description {
+                    format!(
+                        "{} changed the amount of \"{}\" from {:.2} to {:.2}",
+                        member, description, previous_amount, amount
+                    )
+                } else if previous_amount == amount {
+                    format!(
+                        "{} renamed \"{}\" to \"{}\"",
+                        member, previous_description, description
+                    )
+                } else {
+                    format!(
+                        "{} changed \"{}\" ({:.2}) to \"{}\" ({:.2})",
+                        member, previous_description, previous_amount, description, amount
+                    )
+                }
+            }
+            ActivityKindDto::ExpenseDeleted { .. } => format!("{} deleted an expense", member),
+            ActivityKindDto::Settled { transactions, .. } => format!(
+                "{} settled the expenses in {} transaction(s)",
+                member,
+                transactions.len()
+            ),
+            ActivityKindDto::SettlementReverted { .. } => {
+                format!("{} reverted a settlement", member)
+            }
+            ActivityKindDto::BudgetChanged {
+                amount: Some(amount),
+                period,
+            } => format!(
+                "{} set the {} budget to {:.2}",
+                member,
+                period.as_deref().unwrap_or("monthly"),
+                amount
+            ),
+            ActivityKindDto::BudgetChanged { amount: None, .. } => {
+                format!("{} removed the budget", member)
+            }
+            ActivityKindDto::OwnershipTransferred { new_admin } => {
+                format!("{} made {} the group administrator", member, new_admin.name)
+            }
+            ActivityKindDto::GroupDeleted => format!("{} deleted the group", member),
+            ActivityKindDto::GroupArchived => format!("{} archived the group", member),
+            ActivityKindDto::GroupUnarchived => format!("{} unarchived the group", member),
+            ActivityKindDto::MemberRemoved { removed_member } => {
+                format!("{} removed {} from the group", member, removed_member.name)
+            }
+        }
+    }
+}
+
+impl SummaryDto {
+    pub fn from(groups: Vec<GroupSummaryDto>) -> Self {
+        SummaryDto {
+            total: groups.iter().map(|g| g.balance + g.outstanding).sum(),
+            groups: groups
+                .into_iter()
+                .sorted_by(|a, b| b.last_activity.cmp(&a.last_activity))
+                .collect(),
+        }
+    }
+}
+
+impl GroupSummaryDto {
+    pub fn from(summary: GroupSummary, user_id: &Uuid) -> Self {
+        GroupSummaryDto {
+            id: summary.group_id,
+            name: String::from(summary.name.clone()),
+            balance: summary.balance(),
+            outstanding: summary.outstanding(user_id),
+            last_activity: summary.last_activity,
+            transactions: TransactionDto::from_vec(summary.transactions, &summary.members),
         }
     }
 }
@@ -179,6 +755,7 @@ impl DetailedGroupDto {
                         .expect("expenses dates to be comparable")
                 })
                 .collect(),
+            archived_at: grp.archived_at,
         }
     }
 }
@@ -195,6 +772,129 @@ impl ExpenseDto {
     }
 }
 
+impl BudgetDto {
+    pub fn from(budget: &Budget, consumption: BudgetConsumption) -> Self {
+        BudgetDto {
+            amount: budget.amount.into(),
+            period: budget.period.into(),
+            start: consumption.start,
+            spent: consumption.spent,
+            remaining: budget.remaining(consumption.spent),
+            percentage: budget.percentage(consumption.spent),
+        }
+    }
+}
+
+impl ImportReportDto {
+    pub fn from(dry_run: bool, imported: usize, errors: &[ImportLineError]) -> Self {
+        ImportReportDto {
+            dry_run,
+            imported: match errors.is_empty() {
+                true => imported as u32,
+                false => 0,
+            },
+            errors: errors
+                .iter()
+                .map(|e| ImportErrorDto {
+                    line: e.line as u32,
+                    error: e.message.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl StatsDto {
+    pub fn from(
+        stats: ExpenseStats,
+        members: &[GroupMember],
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Self {
+        let member = |id: Uuid| {
+            members
+                .iter()
+                .find(|m| m.id == id)
+                .cloned()
+                .unwrap_or_default()
+        };
+        StatsDto {
+            from,
+            to,
+            total: stats.total,
+            count: stats.count,
+            members: stats
+                .members
+                .into_iter()
+                .map(|m| MemberTotalDto {
+                    member: MemberDto::from(member(m.member_id)),
+                    total: m.total,
+                    count: m.count,
+                })
+                .collect(),
+            months: stats
+                .months
+                .into_iter()
+                .map(|m| MonthTotalDto {
+                    month: m.month.format("%Y-%m").to_string(),
+                    total: m.total,
+                    count: m.count,
+                })
+                .collect(),
+            largest: stats
+                .largest
+                .into_iter()
+                .map(|e| {
+                    let m = member(e.member_id);
+                    ExpenseDto::from(e, m)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SettlementDto {
+    pub fn from(settlement: Settlement, members: &[GroupMember]) -> Self {
+        SettlementDto {
+            id: settlement.id,
+            start_date: settlement.start_date,
+            end_date: settlement.end_date,
+            label: settlement.label.map(String::from),
+            total: settlement.total,
+            expense_count: settlement.expense_count,
+            balances: settlement
+                .balances
+                .into_iter()
+                .map(|b| MemberBalanceDto::from(b, members))
+                .sorted_by(|a, b| {
+                    b.net
+                        .partial_cmp(&a.net)
+                        .expect("balances to be comparable f32")
+                        .then(a.member.id.cmp(&b.member.id))
+                })
+                .collect(),
+            transactions: TransactionDto::from_vec(settlement.transactions, members),
+        }
+    }
+}
+
+impl MemberBalanceDto {
+    pub fn from(balance: MemberBalance, members: &[GroupMember]) -> Self {
+        MemberBalanceDto {
+            member: MemberDto::from(
+                members
+                    .iter()
+                    .find(|m| m.id == balance.member_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            paid: balance.paid,
+            share: balance.share,
+            net: balance.net,
+        }
+    }
+}
+
 impl TransactionDto {
     pub fn from(transaction: Transaction, members: &[GroupMember]) -> Self {
         TransactionDto {
@@ -213,6 +913,7 @@ impl TransactionDto {
                     .unwrap_or_default(),
             ),
             amount: f32::from(transaction.amount),
+            paid: transaction.paid,
         }
     }
     pub fn from_vec(transactions: Vec<Transaction>, members: &[GroupMember]) -> Vec<Self> {
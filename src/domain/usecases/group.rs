@@ -1,13 +1,20 @@
 use crate::domain::errors::{
-    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteExpenseError,
-    DeleteGroupError, GenerateGroupTokenError, GetExpensesError, GetGroupError, GetGroupsError,
-    GetSettlementsError, JoinGroupError, SettlementError, UpdateExpenseError,
+    ChangeMemberColorError, CreateExpenseError, CreateGroupError, DeleteBudgetError,
+    DeleteExpenseError, DeleteGroupError, ExportError, GenerateGroupTokenError, GetActivityError,
+    GetBudgetError, GetDebtError, GetExpensesError, GetGroupError, GetGroupsError,
+    GetSettlementsError, GetStatsError, GetSummaryError, ImportExpensesError, JoinGroupError,
+    RestoreGroupError, RevertSettlementError, SetBudgetError, SettleDebtError, SettlementError,
+    TransferOwnershipError, UpdateExpenseError,
 };
 use crate::domain::usecases::dto::dtos::{
-    ColorDto, DetailedGroupDto, ExpenseDto, GroupDto, SettlementDto,
+    ActivityFeedDto, BudgetDto, ColorDto, DebtDto, DetailedGroupDto, ExpenseDto, GroupDto,
+    ImportReportDto, SettlementDto, StatsDto, SummaryDto,
 };
+use crate::domain::GroupBackup;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::stream::LocalBoxStream;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[async_trait(?Send)]
@@ -23,8 +30,13 @@ pub trait GroupUseCase {
         request: GenerateGroupTokenRequest,
     ) -> Result<String, GenerateGroupTokenError>;
     async fn delete_group(&self, request: DeleteGroupRequest) -> Result<(), DeleteGroupError>;
+    async fn transfer_ownership(
+        &self,
+        request: TransferOwnershipRequest,
+    ) -> Result<(), TransferOwnershipError>;
     async fn get_group(&self, request: GetGroupRequest) -> Result<DetailedGroupDto, GetGroupError>;
     async fn get_groups(&self, request: GetGroupsRequest) -> Result<Vec<GroupDto>, GetGroupsError>;
+    async fn get_summary(&self, request: GetSummaryRequest) -> Result<SummaryDto, GetSummaryError>;
     async fn get_expenses(
         &self,
         request: GetExpensesRequest,
@@ -33,6 +45,11 @@ pub trait GroupUseCase {
         &self,
         request: GetSettlementsRequest,
     ) -> Result<Vec<SettlementDto>, GetSettlementsError>;
+    async fn get_activity(
+        &self,
+        request: GetActivityRequest,
+    ) -> Result<ActivityFeedDto, GetActivityError>;
+    async fn get_stats(&self, request: GetStatsRequest) -> Result<StatsDto, GetStatsError>;
     async fn create_expense(
         &self,
         request: CreateExpenseRequest,
@@ -42,8 +59,29 @@ pub trait GroupUseCase {
     async fn update_expense(&self, request: UpdateExpenseRequest)
         -> Result<(), UpdateExpenseError>;
     async fn settle(&self, request: SettleRequest) -> Result<SettlementDto, SettlementError>;
+    async fn revert_settlement(
+        &self,
+        request: RevertSettlementRequest,
+    ) -> Result<(), RevertSettlementError>;
+    async fn get_debt(&self, request: GetDebtRequest) -> Result<DebtDto, GetDebtError>;
+    async fn settle_debt(&self, request: SettleDebtRequest) -> Result<(), SettleDebtError>;
+    async fn set_budget(&self, request: SetBudgetRequest) -> Result<BudgetDto, SetBudgetError>;
+    async fn delete_budget(&self, request: DeleteBudgetRequest) -> Result<(), DeleteBudgetError>;
+    async fn get_budget(&self, request: GetBudgetRequest) -> Result<BudgetDto, GetBudgetError>;
+    async fn export_expenses(&self, request: ExportRequest) -> Result<CsvStream, ExportError>;
+    async fn export_settlements(&self, request: ExportRequest) -> Result<CsvStream, ExportError>;
+    async fn export_ledger(&self, request: ExportLedgerRequest) -> Result<String, ExportError>;
+    async fn import_expenses(
+        &self,
+        request: ImportExpensesRequest,
+    ) -> Result<ImportReportDto, ImportExpensesError>;
+    async fn backup_group(&self, request: BackupGroupRequest) -> Result<GroupBackup, ExportError>;
+    async fn restore_group(&self, request: RestoreGroupRequest) -> Result<Uuid, RestoreGroupError>;
 }
 
+/// CSV document produced chunk by chunk, starting with its header.
+pub type CsvStream = LocalBoxStream<'static, Result<String, anyhow::Error>>;
+
 #[derive(Clone)]
 pub struct UpdateExpenseRequest {
     pub group_id: Uuid,
@@ -57,6 +95,26 @@ pub struct UpdateExpenseRequest {
 pub struct SettleRequest {
     pub group_id: Uuid,
     pub user_id: Uuid,
+    pub label: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct RevertSettlementRequest {
+    pub group_id: Uuid,
+    pub settlement_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct GetDebtRequest {
+    pub user_id: Uuid,
+    pub other_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct SettleDebtRequest {
+    pub user_id: Uuid,
+    pub other_id: Uuid,
 }
 
 #[derive(Clone)]
@@ -73,11 +131,25 @@ pub struct GetSettlementsRequest {
     pub user_id: Uuid,
 }
 
+#[derive(Clone)]
+pub struct GetActivityRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    /// The id of the last event of the previous page, if any.
+    pub before: Option<Uuid>,
+    pub limit: Option<u32>,
+}
+
 #[derive(Clone)]
 pub struct GetGroupsRequest {
     pub user_id: Uuid,
 }
 
+#[derive(Clone)]
+pub struct GetSummaryRequest {
+    pub user_id: Uuid,
+}
+
 #[derive(Clone)]
 pub struct GetGroupRequest {
     pub group_id: Uuid,
@@ -93,6 +165,80 @@ pub struct GetExpensesRequest {
     pub to: Option<DateTime<Utc>>,
 }
 
+#[derive(Clone)]
+pub struct ExportRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    pub settlement_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+pub struct ExportLedgerRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    /// Either `beancount` or `ledger`.
+    pub format: String,
+    pub expense_account: Option<String>,
+    pub group_account: Option<String>,
+    pub cash_account: Option<String>,
+    pub currency: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ImportExpensesRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    /// Either `splitwise`, `tricount` or `generic`.
+    pub format: String,
+    pub content: String,
+    /// Group member matching each person named in the file.
+    pub members: HashMap<String, Uuid>,
+    pub dry_run: bool,
+}
+
+#[derive(Clone)]
+pub struct BackupGroupRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct RestoreGroupRequest {
+    pub user_id: Uuid,
+    /// Backup document, of any supported version.
+    pub document: serde_json::Value,
+}
+
+#[derive(Clone)]
+pub struct SetBudgetRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    pub amount: f32,
+    pub period: String,
+}
+
+#[derive(Clone)]
+pub struct DeleteBudgetRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct GetBudgetRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct GetStatsRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
 #[derive(Clone)]
 pub struct GenerateGroupTokenRequest {
     pub group_id: Uuid,
@@ -105,6 +251,13 @@ pub struct DeleteGroupRequest {
     pub user_id: Uuid,
 }
 
+#[derive(Clone)]
+pub struct TransferOwnershipRequest {
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+    pub new_admin_id: Uuid,
+}
+
 #[derive(Clone)]
 pub struct CreateGroupRequest {
     pub name: String,
@@ -1,14 +1,117 @@
-use crate::domain::errors::{CreateUserError, LoginError, LogoutError};
+use crate::domain::errors::{
+    ApiTokenError, CreateUserError, DeleteUserError, EmailVerificationError, LoginError,
+    LogoutError, PasswordResetError, TakeoutError, TwoFactorError, UpdateUserError,
+};
+use crate::domain::{ApiToken, ApiTokenScope, AuthorizationFlow};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use secrecy::Secret;
 use uuid::Uuid;
 
 #[async_trait(?Send)]
 pub trait UserUseCase {
     async fn register(&self, request: RegistrationRequest) -> Result<Uuid, CreateUserError>;
-    async fn login(&self, request: LoginRequest) -> Result<Uuid, LoginError>;
+    /// Checks the credentials of the user, the login being completed by
+    /// [`complete_second_factor`](Self::complete_second_factor) when they enrolled a second
+    /// factor.
+    async fn login(&self, request: LoginRequest) -> Result<LoginOutcome, LoginError>;
+    /// Completes the login of a user whose credentials were checked, given a TOTP or recovery
+    /// code of their second factor.
+    async fn complete_second_factor(
+        &self,
+        request: SecondFactorRequest,
+    ) -> Result<Uuid, LoginError>;
+    /// Starts a login through the identity provider, returning the URL to redirect the user to
+    /// and the flow to keep in their session until they come back.
+    async fn start_identity_login(&self) -> Result<(String, AuthorizationFlow), LoginError>;
+    /// Completes the login the identity provider redirected the user back from. The user with
+    /// the email asserted by the provider is created on their first login.
+    async fn complete_identity_login(
+        &self,
+        request: IdentityLoginRequest,
+    ) -> Result<Uuid, LoginError>;
     async fn logout(&self, request: LogoutRequest) -> Result<(), LogoutError>;
     async fn is_valid_user(&self, user_id: &Uuid) -> Result<bool, anyhow::Error>;
+    /// Whether a session of the user opened at `logged_in_at` is still accepted.
+    async fn is_valid_session(
+        &self,
+        user_id: &Uuid,
+        logged_in_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, anyhow::Error>;
+    async fn update_name(&self, request: UpdateNameRequest) -> Result<(), UpdateUserError>;
+    /// Changes the email of the user, which is also their login. The current password is
+    /// required when the credentials are managed by the application.
+    async fn change_email(&self, request: ChangeEmailRequest) -> Result<(), UpdateUserError>;
+    /// Changes the password of the user and revokes all their sessions.
+    async fn change_password(&self, request: ChangePasswordRequest) -> Result<(), UpdateUserError>;
+    /// Mails a password reset link to the user registered with the email. Succeeds as well
+    /// when there is none, not to disclose which emails are registered.
+    async fn request_password_reset(
+        &self,
+        request: PasswordResetRequest,
+    ) -> Result<(), PasswordResetError>;
+    /// Sets the password of the user the reset token was mailed to and revokes all their
+    /// sessions. The token can only be used once.
+    async fn reset_password(&self, request: ResetPasswordRequest)
+        -> Result<(), PasswordResetError>;
+    /// Verifies the email of the user the verification token was mailed to, which activates
+    /// their account.
+    async fn verify_email(&self, request: VerifyEmailRequest)
+        -> Result<(), EmailVerificationError>;
+    /// Mails a new verification link to the unverified user registered with the email. Succeeds
+    /// as well when there is none, not to disclose which emails are registered.
+    async fn resend_verification(
+        &self,
+        request: ResendVerificationRequest,
+    ) -> Result<(), EmailVerificationError>;
+    /// Returns the archive of the user personal data, or `None` while it is being generated
+    /// by the worker.
+    async fn takeout(
+        &self,
+        request: TakeoutRequest,
+    ) -> Result<Option<serde_json::Value>, TakeoutError>;
+    /// Anonymizes the user in the groups they belonged to and removes their credentials.
+    async fn delete(&self, request: DeleteUserRequest) -> Result<(), DeleteUserError>;
+    /// Creates a personal access token, returned in clear only this once.
+    async fn create_api_token(
+        &self,
+        request: CreateApiTokenRequest,
+    ) -> Result<(ApiToken, Secret<String>), ApiTokenError>;
+    async fn get_api_tokens(&self, user_id: &Uuid) -> Result<Vec<ApiToken>, ApiTokenError>;
+    async fn revoke_api_token(&self, request: RevokeApiTokenRequest) -> Result<(), ApiTokenError>;
+    /// Returns the user the personal access token belongs to and its scope, or `None` when the
+    /// token is unknown, expired or its user is no longer active.
+    async fn authenticate_api_token(
+        &self,
+        token: &Secret<String>,
+    ) -> Result<Option<(Uuid, ApiTokenScope)>, anyhow::Error>;
+    /// Starts the enrollment of a TOTP second factor, returning its secret and recovery codes
+    /// in clear only this once.
+    async fn enroll_two_factor(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<TwoFactorEnrollment, TwoFactorError>;
+    /// Confirms the enrollment given a valid code, after which the second factor is required
+    /// at login.
+    async fn confirm_two_factor(
+        &self,
+        request: ConfirmTwoFactorRequest,
+    ) -> Result<(), TwoFactorError>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LoginOutcome {
+    LoggedIn(Uuid),
+    /// The credentials are valid, but the user still has to provide their second factor.
+    SecondFactorRequired(Uuid),
+}
+
+#[derive(Debug)]
+pub struct TwoFactorEnrollment {
+    pub secret: Secret<String>,
+    /// `otpauth://` URI of the secret, to show as a QR code to authenticator applications.
+    pub provisioning_uri: String,
+    pub recovery_codes: Vec<Secret<String>>,
 }
 
 #[derive(Clone)]
@@ -24,7 +127,95 @@ pub struct LoginRequest {
     pub password: Option<Secret<String>>,
 }
 
+#[derive(Clone)]
+pub struct SecondFactorRequest {
+    pub user_id: Uuid,
+    /// Either a TOTP code or one of the recovery codes.
+    pub code: String,
+}
+
+#[derive(Clone)]
+pub struct ConfirmTwoFactorRequest {
+    pub user_id: Uuid,
+    pub code: String,
+}
+
+#[derive(Clone)]
+pub struct IdentityLoginRequest {
+    /// Authorization code the provider redirected the user with.
+    pub code: Option<String>,
+    pub state: Option<String>,
+    /// Flow kept in the session of the user when the login was started.
+    pub flow: Option<AuthorizationFlow>,
+}
+
 #[derive(Clone)]
 pub struct LogoutRequest {
     pub user_id: Uuid,
 }
+
+#[derive(Clone)]
+pub struct TakeoutRequest {
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct DeleteUserRequest {
+    pub user_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct UpdateNameRequest {
+    pub user_id: Uuid,
+    pub name: String,
+}
+
+#[derive(Clone)]
+pub struct ChangeEmailRequest {
+    pub user_id: Uuid,
+    pub email: String,
+    pub password: Option<Secret<String>>,
+}
+
+#[derive(Clone)]
+pub struct ChangePasswordRequest {
+    pub user_id: Uuid,
+    pub current_password: Secret<String>,
+    pub new_password: Secret<String>,
+}
+
+#[derive(Clone)]
+pub struct PasswordResetRequest {
+    pub email: String,
+}
+
+#[derive(Clone)]
+pub struct ResetPasswordRequest {
+    pub token: Secret<String>,
+    pub password: Secret<String>,
+}
+
+#[derive(Clone)]
+pub struct VerifyEmailRequest {
+    pub token: Secret<String>,
+}
+
+#[derive(Clone)]
+pub struct ResendVerificationRequest {
+    pub email: String,
+}
+
+#[derive(Clone)]
+pub struct CreateApiTokenRequest {
+    pub user_id: Uuid,
+    pub name: String,
+    /// Either `read` or `write`.
+    pub scope: String,
+    pub validity_days: u16,
+}
+
+#[derive(Clone)]
+pub struct RevokeApiTokenRequest {
+    pub user_id: Uuid,
+    pub token_id: Uuid,
+}
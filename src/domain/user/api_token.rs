@@ -0,0 +1,122 @@
+use crate::utils::date;
+use chrono::{DateTime, Duration, Utc};
+use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Prefix of the personal access tokens, which makes them easy to spot when leaked.
+const TOKEN_PREFIX: &str = "koru_";
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_VALIDITY_DAYS: i64 = 365;
+
+/// Personal access token with which scripts and integrations act on behalf of a user, through
+/// the `Authorization: Bearer` header.
+///
+/// Only the SHA-256 hash of the token is stored, the token itself is shown once on creation.
+#[derive(Debug, Clone)]
+pub struct ApiToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub hash: String,
+    pub scope: ApiTokenScope,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ApiTokenScope {
+    /// Only allows reading, i.e. `GET` requests.
+    Read,
+    Write,
+}
+
+impl ApiToken {
+    /// Generates a token valid for `validity_days`, returned in clear along with what is stored.
+    pub fn generate(
+        user_id: Uuid,
+        name: String,
+        scope: ApiTokenScope,
+        validity_days: u16,
+    ) -> Result<(Secret<String>, Self), &'static str> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("Token name cannot be empty");
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err("Token name cannot exceed 50 characters");
+        }
+        if validity_days == 0 || i64::from(validity_days) > MAX_VALIDITY_DAYS {
+            return Err("Tokens must expire within 1 to 365 days");
+        }
+        let token = Secret::new(format!(
+            "{}{}",
+            TOKEN_PREFIX,
+            hex::encode(rand::random::<[u8; 32]>())
+        ));
+        let created_at = date::now();
+        let api_token = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            hash: Self::hash(&token),
+            scope,
+            created_at,
+            expires_at: created_at + Duration::days(validity_days.into()),
+            last_used_at: None,
+        };
+        Ok((token, api_token))
+    }
+
+    pub fn hash(token: &Secret<String>) -> String {
+        hex::encode(Sha256::digest(token.expose_secret().as_bytes()))
+    }
+
+    /// Whether the value looks like a personal access token, rather than e.g. a session id.
+    pub fn is_token(value: &str) -> bool {
+        value.starts_with(TOKEN_PREFIX)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at < date::now()
+    }
+
+    /// Records the use of the token, returning whether it has to be saved. The date is only
+    /// refreshed once a minute, not to write on every request.
+    pub fn mark_used(&mut self) -> bool {
+        let now = date::now();
+        match self.last_used_at {
+            Some(last_used_at) if now - last_used_at < Duration::minutes(1) => false,
+            _ => {
+                self.last_used_at = Some(now);
+                true
+            }
+        }
+    }
+}
+
+impl ApiTokenScope {
+    pub fn allows_writes(&self) -> bool {
+        matches!(self, ApiTokenScope::Write)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::Read => "read",
+            ApiTokenScope::Write => "write",
+        }
+    }
+}
+
+impl TryFrom<&str> for ApiTokenScope {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "read" => Ok(ApiTokenScope::Read),
+            "write" => Ok(ApiTokenScope::Write),
+            _ => Err("Token scope must be either `read` or `write`"),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::error_chain;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Authorization-code flow started with the identity provider, kept in the session of the
+/// user until the provider redirects them back.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuthorizationFlow {
+    pub state: String,
+    pub nonce: String,
+    pub pkce_verifier: String,
+}
+
+/// Identity asserted by the provider once the user authenticated.
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+#[async_trait]
+pub trait IdentityProvider: Send + Sync {
+    /// Starts an authorization-code flow, returning the URL of the provider to redirect the
+    /// user to.
+    async fn authorize(&self) -> Result<(String, AuthorizationFlow), IdentityProviderError>;
+
+    /// Exchanges the code the provider redirected the user with for an ID token, and returns
+    /// the identity it asserts once validated.
+    async fn authenticate(
+        &self,
+        code: &str,
+        flow: &AuthorizationFlow,
+    ) -> Result<Identity, IdentityProviderError>;
+}
+
+error_chain! {
+    #[derive(thiserror::Error)]
+    pub enum IdentityProviderError {
+        #[error("Identity rejected: {0}")]
+        Rejected(&'static str),
+        #[error(transparent)]
+        Unexpected(#[from] anyhow::Error),
+    }
+}
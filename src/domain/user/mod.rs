@@ -1,9 +1,14 @@
+mod api_token;
+mod identity_provider;
 mod user_name;
 
+pub use api_token::*;
+pub use identity_provider::*;
 pub use user_name::UserName;
 
 use crate::domain::errors::CreateUserError;
 use crate::domain::shared::email::Email;
+use crate::domain::UserEventKind;
 use crate::utils::date;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -15,6 +20,15 @@ pub struct User {
     pub email: Email,
     pub role: UserRole,
     pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Sessions opened before this date are no longer accepted.
+    pub sessions_revoked_at: Option<DateTime<Utc>>,
+    /// Unverified users can neither log in nor act in groups.
+    pub email_verified: bool,
+    /// Disabled users can neither log in nor act in groups, until an administrator enables them.
+    pub disabled_at: Option<DateTime<Utc>>,
+    /// The user has to reset their password before logging in again.
+    pub password_reset_required: bool,
 }
 
 impl User {
@@ -28,9 +42,115 @@ impl User {
             email,
             role: UserRole::User,
             created_at: date::now(),
+            deleted_at: None,
+            sessions_revoked_at: None,
+            email_verified: true,
+            disabled_at: None,
+            password_reset_required: false,
         })
     }
 
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Replaces the name and email of the user with a tombstone.
+    ///
+    /// The account itself is kept so that the expenses and transactions of the user still
+    /// add up in the groups they belonged to.
+    pub fn anonymize(&mut self) {
+        self.name = UserName::try_from("Deleted user".to_string()).unwrap_or_default();
+        self.email =
+            Email::try_from(format!("deleted-{}@koru.invalid", self.id)).unwrap_or_default();
+        self.deleted_at = Some(date::now());
+    }
+
+    pub fn rename(&mut self, name: String) -> Result<UserEventKind, &'static str> {
+        let name = UserName::try_from(name)?;
+        let previous = std::mem::replace(&mut self.name, name);
+        Ok(UserEventKind::NameChanged {
+            previous_name: String::from(previous),
+            new_name: String::from(self.name.clone()),
+        })
+    }
+
+    pub fn change_email(&mut self, email: Email) -> UserEventKind {
+        let previous = std::mem::replace(&mut self.email, email);
+        UserEventKind::EmailChanged {
+            previous_email: String::from(previous),
+            new_email: String::from(self.email.clone()),
+        }
+    }
+
+    /// Blocks the user until they verify their email.
+    pub fn require_email_verification(&mut self) {
+        self.email_verified = false;
+    }
+
+    /// Marks the email as verified, `None` if it already was.
+    pub fn verify_email(&mut self) -> Option<UserEventKind> {
+        if self.email_verified {
+            return None;
+        }
+        self.email_verified = true;
+        Some(UserEventKind::EmailVerified)
+    }
+
+    /// Rejects every session opened until now.
+    pub fn revoke_sessions(&mut self) {
+        self.sessions_revoked_at = Some(date::now());
+    }
+
+    /// Whether a session opened at `logged_in_at` is still valid. Sessions with no known
+    /// opening date predate the revocation mechanism and are only accepted as long as no
+    /// revocation happened.
+    pub fn accepts_session(&self, logged_in_at: Option<DateTime<Utc>>) -> bool {
+        match (self.sessions_revoked_at, logged_in_at) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(revoked_at), Some(logged_in_at)) => logged_in_at >= revoked_at,
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+
+    /// Blocks the user and rejects their sessions, `None` if they already were disabled.
+    pub fn disable(&mut self, by: Uuid) -> Option<UserEventKind> {
+        if self.is_disabled() {
+            return None;
+        }
+        self.disabled_at = Some(date::now());
+        self.revoke_sessions();
+        Some(UserEventKind::Disabled { by })
+    }
+
+    /// Unblocks the user, `None` if they were not disabled.
+    pub fn enable(&mut self, by: Uuid) -> Option<UserEventKind> {
+        self.disabled_at.take()?;
+        Some(UserEventKind::Enabled { by })
+    }
+
+    /// Changes the role of the user, `None` if they already had it.
+    pub fn change_role(&mut self, role: UserRole, by: Uuid) -> Option<UserEventKind> {
+        if self.role == role {
+            return None;
+        }
+        self.role = role;
+        Some(UserEventKind::RoleChanged {
+            role: role.to_string(),
+            by,
+        })
+    }
+
+    /// Rejects the sessions of the user, who cannot log in until they reset their password.
+    pub fn require_password_reset(&mut self, by: Uuid) -> UserEventKind {
+        self.password_reset_required = true;
+        self.revoke_sessions();
+        UserEventKind::PasswordResetForced { by }
+    }
+
     pub fn is_admin(&self) -> bool {
         match self.role {
             UserRole::Administrator => true,
@@ -39,8 +159,29 @@ impl User {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UserRole {
     Administrator,
     User,
 }
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserRole::Administrator => write!(f, "Administrator"),
+            UserRole::User => write!(f, "User"),
+        }
+    }
+}
+
+impl TryFrom<&str> for UserRole {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Administrator" => Ok(UserRole::Administrator),
+            "User" => Ok(UserRole::User),
+            _ => Err("Role must be either `Administrator` or `User`"),
+        }
+    }
+}
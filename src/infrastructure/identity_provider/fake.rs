@@ -0,0 +1,43 @@
+use crate::domain::{AuthorizationFlow, Identity, IdentityProvider, IdentityProviderError};
+use async_trait::async_trait;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Provider asserting the identity it is given, whatever the authorization code.
+#[derive(Default)]
+pub struct FakeIdentityProvider {
+    pub identity: Mutex<Option<Identity>>,
+}
+
+impl FakeIdentityProvider {
+    pub fn new() -> Self {
+        Self {
+            identity: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for FakeIdentityProvider {
+    async fn authorize(&self) -> Result<(String, AuthorizationFlow), IdentityProviderError> {
+        let flow = AuthorizationFlow {
+            state: Uuid::new_v4().to_string(),
+            nonce: Uuid::new_v4().to_string(),
+            pkce_verifier: Uuid::new_v4().to_string(),
+        };
+        let url = format!("http://idp.test/authorize?state={}", flow.state);
+        Ok((url, flow))
+    }
+
+    async fn authenticate(
+        &self,
+        _code: &str,
+        _flow: &AuthorizationFlow,
+    ) -> Result<Identity, IdentityProviderError> {
+        self.identity
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or(IdentityProviderError::Rejected("unknown user"))
+    }
+}
@@ -0,0 +1,7 @@
+mod fake;
+#[cfg(feature = "oidc")]
+mod oidc_provider;
+
+pub use fake::FakeIdentityProvider;
+#[cfg(feature = "oidc")]
+pub use oidc_provider::OidcIdentityProvider;
@@ -0,0 +1,220 @@
+use crate::domain::{AuthorizationFlow, Identity, IdentityProvider, IdentityProviderError};
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use reqwest::Url;
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::sync::RwLock;
+
+/// OpenID Connect provider, authenticating users with the authorization-code flow and PKCE.
+///
+/// The provider metadata is discovered on first use and its signing keys are fetched again
+/// whenever an ID token is signed with an unknown one, as providers rotate them.
+pub struct OidcIdentityProvider {
+    client: reqwest::Client,
+    issuer_url: String,
+    client_id: String,
+    client_secret: Secret<String>,
+    redirect_url: String,
+    metadata: RwLock<Option<ProviderMetadata>>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+#[derive(Deserialize, Clone)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Deserialize)]
+struct IdTokenClaims {
+    nonce: Option<String>,
+    email: Option<String>,
+    email_verified: Option<bool>,
+    name: Option<String>,
+}
+
+impl OidcIdentityProvider {
+    pub fn new(
+        issuer_url: String,
+        client_id: String,
+        client_secret: Secret<String>,
+        redirect_url: String,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            issuer_url,
+            client_id,
+            client_secret,
+            redirect_url,
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    async fn metadata(&self) -> Result<ProviderMetadata, IdentityProviderError> {
+        let cached = self.metadata.read().unwrap().clone();
+        if let Some(metadata) = cached {
+            return Ok(metadata);
+        }
+        let issuer_url = self.issuer_url.trim_end_matches('/');
+        let metadata: ProviderMetadata = self
+            .client
+            .get(format!("{}/.well-known/openid-configuration", issuer_url))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .context("Failed to fetch provider metadata.")?
+            .json()
+            .await
+            .context("Invalid provider metadata.")?;
+        if metadata.issuer.trim_end_matches('/') != issuer_url {
+            return Err(anyhow!("Provider metadata issued for {}.", metadata.issuer).into());
+        }
+        *self.metadata.write().unwrap() = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    async fn signing_key(&self, kid: &str, jwks_uri: &str) -> Result<Jwk, IdentityProviderError> {
+        let cached = self
+            .jwks
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|jwks| jwks.find(kid).cloned());
+        if let Some(jwk) = cached {
+            return Ok(jwk);
+        }
+        let jwks: JwkSet = self
+            .client
+            .get(jwks_uri)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .context("Failed to fetch provider keys.")?
+            .json()
+            .await
+            .context("Invalid provider keys.")?;
+        let jwk = jwks.find(kid).cloned();
+        *self.jwks.write().unwrap() = Some(jwks);
+        jwk.ok_or(IdentityProviderError::Rejected("unknown signing key"))
+    }
+}
+
+fn random_token() -> String {
+    URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>())
+}
+
+#[async_trait]
+impl IdentityProvider for OidcIdentityProvider {
+    #[tracing::instrument(name = "Starting OIDC authorization", skip(self))]
+    async fn authorize(&self) -> Result<(String, AuthorizationFlow), IdentityProviderError> {
+        let metadata = self.metadata().await?;
+        let flow = AuthorizationFlow {
+            state: random_token(),
+            nonce: random_token(),
+            pkce_verifier: random_token(),
+        };
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(flow.pkce_verifier.as_bytes()));
+        let url = Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", &self.client_id),
+                ("redirect_uri", &self.redirect_url),
+                ("scope", "openid email profile"),
+                ("state", &flow.state),
+                ("nonce", &flow.nonce),
+                ("code_challenge", &challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .context("Invalid authorization endpoint.")?;
+        Ok((url.to_string(), flow))
+    }
+
+    #[tracing::instrument(name = "Completing OIDC authorization", skip(self, code, flow))]
+    async fn authenticate(
+        &self,
+        code: &str,
+        flow: &AuthorizationFlow,
+    ) -> Result<Identity, IdentityProviderError> {
+        let metadata = self.metadata().await?;
+        let response = self
+            .client
+            .post(&metadata.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &self.redirect_url),
+                ("client_id", &self.client_id),
+                ("client_secret", self.client_secret.expose_secret()),
+                ("code_verifier", &flow.pkce_verifier),
+            ])
+            .send()
+            .await
+            .context("Failed to reach the token endpoint.")?;
+        if response.status().is_client_error() {
+            return Err(IdentityProviderError::Rejected(
+                "the authorization code was refused",
+            ));
+        }
+        let response: TokenResponse = response
+            .error_for_status()
+            .context("Failed to exchange the authorization code.")?
+            .json()
+            .await
+            .context("Invalid token response.")?;
+
+        let header = decode_header(&response.id_token)
+            .map_err(|_| IdentityProviderError::Rejected("malformed ID token"))?;
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(IdentityProviderError::Rejected(
+                "ID token signed with a shared secret",
+            ));
+        }
+        let kid = header
+            .kid
+            .ok_or(IdentityProviderError::Rejected("ID token without key id"))?;
+        let jwk = self.signing_key(&kid, &metadata.jwks_uri).await?;
+        let key = DecodingKey::from_jwk(&jwk).context("Invalid provider key.")?;
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&self.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        let claims = decode::<IdTokenClaims>(&response.id_token, &key, &validation)
+            .map_err(|_| IdentityProviderError::Rejected("invalid ID token"))?
+            .claims;
+
+        if claims.nonce.as_deref() != Some(flow.nonce.as_str()) {
+            return Err(IdentityProviderError::Rejected("nonce mismatch"));
+        }
+        // the email links the identity to a local account, so it must be vouched for
+        if claims.email_verified != Some(true) {
+            return Err(IdentityProviderError::Rejected(
+                "email not verified by the provider",
+            ));
+        }
+        Ok(Identity {
+            email: claims
+                .email
+                .ok_or(IdentityProviderError::Rejected("no email in ID token"))?,
+            name: claims.name,
+        })
+    }
+}
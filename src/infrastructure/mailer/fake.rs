@@ -0,0 +1,26 @@
+use crate::domain::mail::{Mail, Mailer, MailerError};
+use async_trait::async_trait;
+use log::info;
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct FakeMailer {
+    pub mails: Mutex<Vec<Mail>>,
+}
+
+impl FakeMailer {
+    pub fn new() -> Self {
+        Self {
+            mails: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FakeMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        info!("Sending {:?}", mail);
+        self.mails.lock().unwrap().push(mail);
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::domain::mail::{Mail, Mailer, MailerError};
+use crate::utils::date;
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use log::info;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Logs the mails instead of sending them, and writes them to `directory` when one is set.
+///
+/// Meant for local development and tests, where the links of the mails can be followed
+/// without a mail server.
+pub struct FileMailer {
+    sender: String,
+    directory: Option<PathBuf>,
+}
+
+impl FileMailer {
+    pub fn new(sender: String, directory: Option<PathBuf>) -> Self {
+        Self { sender, directory }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    #[tracing::instrument(name = "Writing mail", skip(self, mail))]
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let content = format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n",
+            self.sender,
+            String::from(mail.to),
+            mail.subject,
+            mail.body
+        );
+        info!("Sending mail:\n{}", content);
+        if let Some(directory) = &self.directory {
+            let path = directory.join(format!(
+                "{}-{}.eml",
+                date::now().format("%Y%m%dT%H%M%S%.6f"),
+                Uuid::new_v4()
+            ));
+            std::fs::create_dir_all(directory)
+                .and_then(|_| std::fs::write(&path, content))
+                .context(format!("Failed to write mail to {:?}", path))
+                .map_err(|e| MailerError::Send(anyhow!(e)))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+mod fake;
+mod file;
+#[cfg(feature = "smtp")]
+mod smtp;
+
+pub use fake::FakeMailer;
+pub use file::FileMailer;
+#[cfg(feature = "smtp")]
+pub use smtp::SmtpMailer;
@@ -0,0 +1,58 @@
+use crate::configuration::mail::SmtpSettings;
+use crate::domain::mail::{Mail, Mailer, MailerError};
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use secrecy::ExposeSecret;
+
+pub struct SmtpMailer {
+    sender: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn try_new(sender: &str, conf: &SmtpSettings) -> anyhow::Result<Self> {
+        let sender = sender.parse().context("Invalid mail sender.")?;
+        let builder = if conf.tls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&conf.host)
+                .context("Failed to setup SMTP transport.")?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&conf.host)
+        };
+        let builder = builder.port(conf.port);
+        let builder = match (&conf.username, &conf.password) {
+            (Some(username), Some(password)) => builder.credentials(Credentials::new(
+                username.clone(),
+                password.expose_secret().clone(),
+            )),
+            _ => builder,
+        };
+        Ok(Self {
+            sender,
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    #[tracing::instrument(name = "Sending mail through SMTP", skip(self, mail))]
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let to = String::from(mail.to)
+            .parse::<Mailbox>()
+            .map_err(|e| MailerError::Send(anyhow!(e)))?;
+        let message = Message::builder()
+            .from(self.sender.clone())
+            .to(to)
+            .subject(mail.subject)
+            .body(mail.body)
+            .map_err(|e| MailerError::Send(anyhow!(e)))?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| MailerError::Send(anyhow!(e)))?;
+        Ok(())
+    }
+}
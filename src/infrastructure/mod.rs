@@ -1,6 +1,9 @@
 pub mod event_bus;
+pub mod identity_provider;
+pub mod mailer;
 #[cfg(feature = "notification")]
 pub mod notification_service;
+pub mod rate_limit_store;
 pub mod services;
 pub mod session_store;
 pub mod store;
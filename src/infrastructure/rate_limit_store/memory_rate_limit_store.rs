@@ -0,0 +1,53 @@
+use crate::infrastructure::rate_limit_store::RateLimitStore;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+pub struct MemoryRateLimitStore {
+    counters: Mutex<HashMap<String, (u64, Instant)>>,
+}
+
+impl MemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self {
+            counters: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl RateLimitStore for MemoryRateLimitStore {
+    async fn increment(&self, key: &str, ttl: Duration) -> Result<u64, anyhow::Error> {
+        let now = Instant::now();
+        let mut counters = self.counters.lock().unwrap();
+        counters.retain(|_, (_, expiry)| *expiry > now);
+        let (count, _) = counters.entry(key.to_string()).or_insert((0, now + ttl));
+        *count += 1;
+        Ok(*count)
+    }
+
+    async fn expires_in(&self, key: &str) -> Result<Option<Duration>, anyhow::Error> {
+        let now = Instant::now();
+        Ok(self
+            .counters
+            .lock()
+            .unwrap()
+            .get(key)
+            .filter(|(_, expiry)| *expiry > now)
+            .map(|(_, expiry)| *expiry - now))
+    }
+
+    async fn set(&self, key: &str, ttl: Duration) -> Result<(), anyhow::Error> {
+        self.counters
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (1, Instant::now() + ttl));
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+        self.counters.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::configuration::application::RateLimitStoreSettings;
+use crate::infrastructure::rate_limit_store::memory_rate_limit_store::MemoryRateLimitStore;
+#[cfg(feature = "redis-rate-limit")]
+use crate::infrastructure::rate_limit_store::redis_rate_limit_store::RedisRateLimitStore;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod memory_rate_limit_store;
+#[cfg(feature = "redis-rate-limit")]
+mod redis_rate_limit_store;
+
+/// Expiring counters backing the rate limiting of the API.
+#[async_trait::async_trait(?Send)]
+pub trait RateLimitStore {
+    /// Increments the counter at `key`, which expires `ttl` after its first increment,
+    /// and returns its new value.
+    async fn increment(&self, key: &str, ttl: Duration) -> Result<u64, anyhow::Error>;
+    /// Returns the time left before `key` expires, if it exists.
+    async fn expires_in(&self, key: &str) -> Result<Option<Duration>, anyhow::Error>;
+    /// Sets `key`, which expires after `ttl`.
+    async fn set(&self, key: &str, ttl: Duration) -> Result<(), anyhow::Error>;
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error>;
+}
+
+#[derive(Clone)]
+pub enum RateLimitStoreImpl {
+    #[cfg(feature = "redis-rate-limit")]
+    Redis(RedisRateLimitStore),
+    Memory(Arc<MemoryRateLimitStore>),
+}
+
+impl RateLimitStoreImpl {
+    pub async fn build(configuration: &RateLimitStoreSettings) -> Result<Self, anyhow::Error> {
+        match configuration {
+            #[cfg(feature = "redis-rate-limit")]
+            RateLimitStoreSettings::Redis(redis) => Ok(RateLimitStoreImpl::Redis(
+                RedisRateLimitStore::try_new(redis).await?,
+            )),
+            RateLimitStoreSettings::Memory => Ok(RateLimitStoreImpl::Memory(Arc::new(
+                MemoryRateLimitStore::new(),
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl RateLimitStore for RateLimitStoreImpl {
+    async fn increment(&self, key: &str, ttl: Duration) -> Result<u64, anyhow::Error> {
+        match self {
+            #[cfg(feature = "redis-rate-limit")]
+            RateLimitStoreImpl::Redis(store) => store.increment(key, ttl).await,
+            RateLimitStoreImpl::Memory(store) => store.increment(key, ttl).await,
+        }
+    }
+
+    async fn expires_in(&self, key: &str) -> Result<Option<Duration>, anyhow::Error> {
+        match self {
+            #[cfg(feature = "redis-rate-limit")]
+            RateLimitStoreImpl::Redis(store) => store.expires_in(key).await,
+            RateLimitStoreImpl::Memory(store) => store.expires_in(key).await,
+        }
+    }
+
+    async fn set(&self, key: &str, ttl: Duration) -> Result<(), anyhow::Error> {
+        match self {
+            #[cfg(feature = "redis-rate-limit")]
+            RateLimitStoreImpl::Redis(store) => store.set(key, ttl).await,
+            RateLimitStoreImpl::Memory(store) => store.set(key, ttl).await,
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+        match self {
+            #[cfg(feature = "redis-rate-limit")]
+            RateLimitStoreImpl::Redis(store) => store.delete(key).await,
+            RateLimitStoreImpl::Memory(store) => store.delete(key).await,
+        }
+    }
+}
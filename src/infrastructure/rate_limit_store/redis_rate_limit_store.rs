@@ -0,0 +1,57 @@
+use crate::configuration::application::RedisRateLimitSettings;
+use crate::infrastructure::rate_limit_store::RateLimitStore;
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use secrecy::ExposeSecret;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct RedisRateLimitStore {
+    connection: MultiplexedConnection,
+}
+
+impl RedisRateLimitStore {
+    pub async fn try_new(configuration: &RedisRateLimitSettings) -> Result<Self, anyhow::Error> {
+        let client = Client::open(configuration.connection_string().expose_secret().clone())?;
+        Ok(Self {
+            connection: client.get_multiplexed_tokio_connection().await?,
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl RateLimitStore for RedisRateLimitStore {
+    async fn increment(&self, key: &str, ttl: Duration) -> Result<u64, anyhow::Error> {
+        let mut connection = self.connection.clone();
+        let (count, _): (u64, bool) = redis::pipe()
+            .atomic()
+            .incr(key, 1)
+            .cmd("EXPIRE")
+            .arg(key)
+            .arg(ttl.as_secs().max(1))
+            .arg("NX")
+            .query_async(&mut connection)
+            .await?;
+        Ok(count)
+    }
+
+    async fn expires_in(&self, key: &str) -> Result<Option<Duration>, anyhow::Error> {
+        let mut connection = self.connection.clone();
+        let ttl: i64 = connection.pttl(key).await?;
+        Ok(u64::try_from(ttl).ok().map(Duration::from_millis))
+    }
+
+    async fn set(&self, key: &str, ttl: Duration) -> Result<(), anyhow::Error> {
+        let mut connection = self.connection.clone();
+        connection
+            .set_ex::<_, _, ()>(key, 1, ttl.as_secs().max(1) as usize)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+        let mut connection = self.connection.clone();
+        connection.del::<_, ()>(key).await?;
+        Ok(())
+    }
+}
@@ -1,21 +1,54 @@
-use crate::infrastructure::session_store::SessionState;
+use crate::infrastructure::session_store::{user_of, SessionState};
 use actix_session::storage::{LoadError, SaveError, SessionKey, SessionStore, UpdateError};
 use actix_web::cookie::time::Duration;
 use anyhow::{anyhow, Error};
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 use uuid::Uuid;
 
 #[derive(Default)]
 pub struct MemorySessionStore {
     sessions: Mutex<HashMap<String, (SessionState, DateTime<Utc>)>>,
+    user_sessions: Mutex<HashMap<Uuid, HashSet<String>>>,
 }
 
 impl MemorySessionStore {
     pub fn new() -> Self {
         Self {
             sessions: Mutex::new(HashMap::new()),
+            user_sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the keys of the sessions indexed for the user, some of which may have expired.
+    pub fn session_keys(&self, user_id: &Uuid) -> Vec<String> {
+        self.user_sessions
+            .lock()
+            .unwrap()
+            .get(user_id)
+            .map(|keys| keys.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn forget(&self, user_id: &Uuid, session_key: &str) {
+        let mut user_sessions = self.user_sessions.lock().unwrap();
+        if let Some(keys) = user_sessions.get_mut(user_id) {
+            keys.remove(session_key);
+            if keys.is_empty() {
+                user_sessions.remove(user_id);
+            }
+        }
+    }
+
+    fn index(&self, session_key: &str, session_state: &SessionState) {
+        if let Some(user_id) = user_of(session_state) {
+            self.user_sessions
+                .lock()
+                .unwrap()
+                .entry(user_id)
+                .or_default()
+                .insert(session_key.to_string());
         }
     }
 }
@@ -45,6 +78,7 @@ impl SessionStore for MemorySessionStore {
     ) -> Result<SessionKey, SaveError> {
         let key = Uuid::new_v4().to_string();
         let expiry = Utc::now() + chrono::Duration::seconds(ttl.whole_seconds());
+        self.index(&key, &session_state);
         self.sessions
             .lock()
             .unwrap()
@@ -64,12 +98,14 @@ impl SessionStore for MemorySessionStore {
             None => {
                 let key = Uuid::new_v4().to_string();
                 let expiry = Utc::now() + chrono::Duration::seconds(ttl.whole_seconds());
+                self.index(&key, &session_state);
                 sessions.insert(key.clone(), (session_state, expiry));
                 Ok(SessionKey::try_from(key).map_err(|e| UpdateError::Other(anyhow!(e)))?)
             }
             Some(_) => {
                 let expiry = Utc::now() + chrono::Duration::seconds(ttl.whole_seconds());
                 let key = session_key.as_ref().to_string();
+                self.index(&key, &session_state);
                 sessions.insert(key, (session_state, expiry));
                 Ok(session_key)
             }
@@ -91,7 +127,10 @@ impl SessionStore for MemorySessionStore {
     }
 
     async fn delete(&self, session_key: &SessionKey) -> Result<(), Error> {
-        self.sessions.lock().unwrap().remove(session_key.as_ref());
+        let session = self.sessions.lock().unwrap().remove(session_key.as_ref());
+        if let Some(user_id) = session.and_then(|(state, _)| user_of(&state)) {
+            self.forget(&user_id, session_key.as_ref());
+        }
         Ok(())
     }
 }
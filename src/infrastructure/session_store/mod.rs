@@ -1,23 +1,38 @@
 use crate::configuration::application::SessionStoreSettings;
 use crate::infrastructure::session_store::memory_session_store::MemorySessionStore;
 #[cfg(feature = "redis-session")]
+use crate::infrastructure::session_store::redis_session_index::RedisSessionIndex;
+#[cfg(feature = "redis-session")]
 use actix_session::storage::RedisSessionStore;
 use actix_session::storage::{LoadError, SaveError, SessionKey, SessionStore, UpdateError};
 
 use actix_web::cookie::time::Duration;
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 #[cfg(feature = "redis-session")]
 use secrecy::ExposeSecret;
 use std::collections::HashMap;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub type SessionState = HashMap<String, String>;
 mod memory_session_store;
+#[cfg(feature = "redis-session")]
+mod redis_session_index;
+
+/// Key of the logged in user in the session state, by which the sessions are indexed.
+pub const USER_ID_KEY: &str = "user_id";
+
+/// Returns the user logged in the session, if any.
+fn user_of(session_state: &SessionState) -> Option<Uuid> {
+    session_state
+        .get(USER_ID_KEY)
+        .and_then(|user_id| serde_json::from_str(user_id).ok())
+}
 
 #[derive(Clone)]
 pub enum SessionStoreImpl {
     #[cfg(feature = "redis-session")]
-    Redis(RedisSessionStore),
+    Redis(RedisSessionStore, RedisSessionIndex),
     Memory(Arc<MemorySessionStore>),
 }
 
@@ -26,15 +41,66 @@ impl SessionStoreImpl {
         match configuration {
             #[cfg(feature = "redis-session")]
             SessionStoreSettings::Redis(redis) => {
-                let store =
-                    RedisSessionStore::new(redis.connection_string().expose_secret()).await?;
-                Ok(SessionStoreImpl::Redis(store))
+                let connection_string = redis.connection_string();
+                let store = RedisSessionStore::new(connection_string.expose_secret()).await?;
+                let index = RedisSessionIndex::try_new(connection_string.expose_secret()).await?;
+                Ok(SessionStoreImpl::Redis(store, index))
             }
             SessionStoreSettings::Memory => Ok(SessionStoreImpl::Memory(Arc::new(
                 MemorySessionStore::new(),
             ))),
         }
     }
+
+    /// Returns the live sessions of the user, along with their keys.
+    pub async fn user_sessions(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<(SessionKey, SessionState)>, anyhow::Error> {
+        let keys = match self {
+            #[cfg(feature = "redis-session")]
+            SessionStoreImpl::Redis(_, index) => index.session_keys(user_id).await?,
+            SessionStoreImpl::Memory(store) => store.session_keys(user_id),
+        };
+        let mut sessions = Vec::new();
+        for key in keys {
+            let session_key = SessionKey::try_from(key.clone()).map_err(|e| anyhow!(e))?;
+            match self.load(&session_key).await? {
+                Some(state) if user_of(&state) == Some(*user_id) => {
+                    sessions.push((session_key, state))
+                }
+                _ => self.forget(user_id, &key).await?,
+            }
+        }
+        Ok(sessions)
+    }
+
+    async fn forget(&self, user_id: &Uuid, session_key: &str) -> Result<(), anyhow::Error> {
+        match self {
+            #[cfg(feature = "redis-session")]
+            SessionStoreImpl::Redis(_, index) => index.forget(user_id, session_key).await,
+            SessionStoreImpl::Memory(store) => {
+                store.forget(user_id, session_key);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "redis-session")]
+async fn index_redis_session(
+    index: &RedisSessionIndex,
+    session_key: &SessionKey,
+    session_state: &SessionState,
+    ttl: &Duration,
+) -> Result<(), anyhow::Error> {
+    match user_of(session_state) {
+        Some(user_id) => {
+            let ttl = std::time::Duration::from_secs(ttl.whole_seconds().max(0) as u64);
+            index.index(&user_id, session_key.as_ref(), ttl).await
+        }
+        None => Ok(()),
+    }
 }
 
 #[async_trait::async_trait(?Send)]
@@ -42,7 +108,7 @@ impl SessionStore for SessionStoreImpl {
     async fn load(&self, session_key: &SessionKey) -> Result<Option<SessionState>, LoadError> {
         match self {
             #[cfg(feature = "redis-session")]
-            SessionStoreImpl::Redis(store) => store.load(session_key).await,
+            SessionStoreImpl::Redis(store, _) => store.load(session_key).await,
             SessionStoreImpl::Memory(store) => store.load(session_key).await,
         }
     }
@@ -54,7 +120,13 @@ impl SessionStore for SessionStoreImpl {
     ) -> Result<SessionKey, SaveError> {
         match self {
             #[cfg(feature = "redis-session")]
-            SessionStoreImpl::Redis(store) => store.save(session_state, ttl).await,
+            SessionStoreImpl::Redis(store, index) => {
+                let key = store.save(session_state.clone(), ttl).await?;
+                index_redis_session(index, &key, &session_state, ttl)
+                    .await
+                    .map_err(SaveError::Other)?;
+                Ok(key)
+            }
             SessionStoreImpl::Memory(store) => store.save(session_state, ttl).await,
         }
     }
@@ -67,7 +139,15 @@ impl SessionStore for SessionStoreImpl {
     ) -> Result<SessionKey, UpdateError> {
         match self {
             #[cfg(feature = "redis-session")]
-            SessionStoreImpl::Redis(store) => store.update(session_key, session_state, ttl).await,
+            SessionStoreImpl::Redis(store, index) => {
+                let key = store
+                    .update(session_key, session_state.clone(), ttl)
+                    .await?;
+                index_redis_session(index, &key, &session_state, ttl)
+                    .await
+                    .map_err(UpdateError::Other)?;
+                Ok(key)
+            }
             SessionStoreImpl::Memory(store) => store.update(session_key, session_state, ttl).await,
         }
     }
@@ -75,7 +155,7 @@ impl SessionStore for SessionStoreImpl {
     async fn update_ttl(&self, session_key: &SessionKey, ttl: &Duration) -> Result<(), Error> {
         match self {
             #[cfg(feature = "redis-session")]
-            SessionStoreImpl::Redis(store) => store.update_ttl(session_key, ttl).await,
+            SessionStoreImpl::Redis(store, _) => store.update_ttl(session_key, ttl).await,
             SessionStoreImpl::Memory(store) => store.update_ttl(session_key, ttl).await,
         }
     }
@@ -83,7 +163,7 @@ impl SessionStore for SessionStoreImpl {
     async fn delete(&self, session_key: &SessionKey) -> Result<(), Error> {
         match self {
             #[cfg(feature = "redis-session")]
-            SessionStoreImpl::Redis(store) => store.delete(session_key).await,
+            SessionStoreImpl::Redis(store, _) => store.delete(session_key).await,
             SessionStoreImpl::Memory(store) => store.delete(session_key).await,
         }
     }
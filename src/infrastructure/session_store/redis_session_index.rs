@@ -0,0 +1,57 @@
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Sets of the session keys of each user, next to the sessions in Redis.
+#[derive(Clone)]
+pub struct RedisSessionIndex {
+    connection: MultiplexedConnection,
+}
+
+impl RedisSessionIndex {
+    pub async fn try_new(connection_string: &str) -> Result<Self, anyhow::Error> {
+        let client = Client::open(connection_string)?;
+        Ok(Self {
+            connection: client.get_multiplexed_tokio_connection().await?,
+        })
+    }
+
+    /// Indexes the session, the index of the user expiring with their latest session.
+    pub async fn index(
+        &self,
+        user_id: &Uuid,
+        session_key: &str,
+        ttl: Duration,
+    ) -> Result<(), anyhow::Error> {
+        let mut connection = self.connection.clone();
+        let key = Self::key(user_id);
+        redis::pipe()
+            .atomic()
+            .sadd(&key, session_key)
+            .ignore()
+            .expire(&key, ttl.as_secs().max(1) as usize)
+            .ignore()
+            .query_async::<_, ()>(&mut connection)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the keys of the sessions indexed for the user, some of which may have expired.
+    pub async fn session_keys(&self, user_id: &Uuid) -> Result<Vec<String>, anyhow::Error> {
+        let mut connection = self.connection.clone();
+        Ok(connection.smembers(Self::key(user_id)).await?)
+    }
+
+    pub async fn forget(&self, user_id: &Uuid, session_key: &str) -> Result<(), anyhow::Error> {
+        let mut connection = self.connection.clone();
+        connection
+            .srem::<_, _, ()>(Self::key(user_id), session_key)
+            .await?;
+        Ok(())
+    }
+
+    fn key(user_id: &Uuid) -> String {
+        format!("user_sessions:{}", user_id)
+    }
+}
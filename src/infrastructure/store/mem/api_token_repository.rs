@@ -0,0 +1,90 @@
+use crate::application::store::{ApiTokenRepository, ApiTokenRepositoryError};
+use crate::domain::ApiToken;
+use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerApiToken};
+use async_trait::async_trait;
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering::Relaxed;
+use uuid::Uuid;
+
+#[async_trait]
+impl ApiTokenRepository for InMemoryStore {
+    type Tr = InMemTx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        token: &ApiToken,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        if self.crash_api_tokens.load(Relaxed) {
+            return Err(ApiTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .api_tokens
+            .lock()
+            .unwrap()
+            .insert(token.id, InnerApiToken::from(token));
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiToken>, ApiTokenRepositoryError> {
+        if self.crash_api_tokens.load(Relaxed) {
+            return Err(ApiTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        Ok(self
+            .api_tokens
+            .lock()
+            .unwrap()
+            .values()
+            .find(|t| t.hash == hash)
+            .cloned()
+            .map(ApiToken::from))
+    }
+
+    async fn get_user_tokens(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError> {
+        if self.crash_api_tokens.load(Relaxed) {
+            return Err(ApiTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        Ok(self
+            .api_tokens
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|t| t.user_id == *user_id)
+            .sorted_by_key(|t| t.created_at)
+            .cloned()
+            .map(ApiToken::from)
+            .collect())
+    }
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        if self.crash_api_tokens.load(Relaxed) {
+            return Err(ApiTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut().deleted_api_tokens.lock().unwrap().insert(*id);
+        Ok(())
+    }
+
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        user_id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        if self.crash_api_tokens.load(Relaxed) {
+            return Err(ApiTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_user_api_tokens
+            .lock()
+            .unwrap()
+            .insert(*user_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::application::store::{BudgetRepository, BudgetRepositoryError};
+use crate::domain::Budget;
+use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerBudget};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering::Relaxed;
+use uuid::Uuid;
+
+#[async_trait]
+impl BudgetRepository for InMemoryStore {
+    type Tr = InMemTx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        budget: &Budget,
+    ) -> Result<(), BudgetRepositoryError> {
+        if self.crash_budgets.load(Relaxed) {
+            return Err(BudgetRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .budgets
+            .lock()
+            .unwrap()
+            .insert(budget.group_id, InnerBudget::from(budget));
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        group_id: &Uuid,
+    ) -> Result<(), BudgetRepositoryError> {
+        if self.crash_budgets.load(Relaxed) {
+            return Err(BudgetRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_budgets
+            .lock()
+            .unwrap()
+            .insert(*group_id);
+        Ok(())
+    }
+
+    async fn find(&self, group_id: &Uuid) -> Result<Option<Budget>, BudgetRepositoryError> {
+        if self.crash_budgets.load(Relaxed) {
+            return Err(BudgetRepositoryError::CorruptedData("Crashed store"));
+        }
+        self.budgets
+            .lock()
+            .unwrap()
+            .get(group_id)
+            .cloned()
+            .map(Budget::try_from)
+            .transpose()
+            .map_err(BudgetRepositoryError::CorruptedData)
+    }
+}
@@ -53,4 +53,20 @@ impl CredentialRepository for InMemoryStore {
             None => Ok(None),
         }
     }
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), CredentialRepositoryError> {
+        if self.crash_user_credentials.load(Relaxed) {
+            return Err(CredentialRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_user_credentials
+            .lock()
+            .unwrap()
+            .insert(String::from(email.clone()));
+        Ok(())
+    }
 }
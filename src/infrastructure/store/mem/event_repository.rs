@@ -1,9 +1,10 @@
-use crate::application::store::{EventRepository, EventRepositoryError};
-use crate::domain::Event;
+use crate::application::store::{EventRepository, EventRepositoryError, StoredEvent};
+use crate::domain::{Event, EventFilter, EventOrigin, GroupEvent, UserEvent};
 use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerEvent};
 use crate::utils::date;
 use anyhow::anyhow;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::cell::RefCell;
 use std::sync::atomic::Ordering::Relaxed;
 use uuid::Uuid;
@@ -20,9 +21,13 @@ impl EventRepository for InMemoryStore {
         if self.crash_events.load(Relaxed) {
             return Err(EventRepositoryError::CorruptedData("Crashed store"));
         }
+        let origin = EventOrigin::current();
         let mut evts = tx.get_mut().events.lock().unwrap();
         for evt in events {
-            evts.push(InnerEvent::from(evt.clone()))
+            evts.push(InnerEvent {
+                origin: origin.clone(),
+                ..InnerEvent::from(evt.clone())
+            })
         }
         Ok(())
     }
@@ -41,6 +46,44 @@ impl EventRepository for InMemoryStore {
             .map_err(EventRepositoryError::CorruptedData)
     }
 
+    async fn get_group_events(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        if self.crash_events.load(Relaxed) {
+            return Err(EventRepositoryError::CorruptedData("Crashed store"));
+        }
+        let mut events = Vec::new();
+        for event in self.events.lock().unwrap().iter() {
+            match event.clone().event() {
+                Ok(Event::Group(event)) if event.group_id == *group_id => events.push(event),
+                Ok(_) => {}
+                Err(e) => return Err(EventRepositoryError::CorruptedData(e)),
+            }
+        }
+        events.sort_by_key(|e| e.event_date);
+        Ok(events)
+    }
+
+    async fn get_user_events(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<UserEvent>, EventRepositoryError> {
+        if self.crash_events.load(Relaxed) {
+            return Err(EventRepositoryError::CorruptedData("Crashed store"));
+        }
+        let mut events = Vec::new();
+        for event in self.events.lock().unwrap().iter() {
+            match event.clone().event() {
+                Ok(Event::User(event)) if event.user_id == *user_id => events.push(event),
+                Ok(_) => {}
+                Err(e) => return Err(EventRepositoryError::CorruptedData(e)),
+            }
+        }
+        events.sort_by_key(|e| e.event_date);
+        Ok(events)
+    }
+
     async fn mark_processed(&self, id: &Uuid) -> Result<(), EventRepositoryError> {
         if self.crash_events.load(Relaxed) {
             return Err(EventRepositoryError::CorruptedData("Crashed store"));
@@ -55,4 +98,87 @@ impl EventRepository for InMemoryStore {
             }
         }
     }
+
+    async fn get_events(
+        &self,
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        if self.crash_events.load(Relaxed) {
+            return Err(EventRepositoryError::CorruptedData("Crashed store"));
+        }
+        let mut events = Vec::new();
+        // the most recently saved first, among events of the same date
+        for event in self.events.lock().unwrap().iter().rev() {
+            if unprocessed_only && event.processed_date.is_some() {
+                continue;
+            }
+            events.push(StoredEvent {
+                processed_at: event.processed_date,
+                origin: event.origin.clone(),
+                event: event
+                    .clone()
+                    .event()
+                    .map_err(EventRepositoryError::CorruptedData)?,
+            });
+        }
+        events.sort_by_key(|e| std::cmp::Reverse(e.event.date()));
+        events.truncate(limit.map_or(events.len(), |l| l as usize));
+        Ok(events)
+    }
+
+    async fn purge(&self, processed_before: &DateTime<Utc>) -> Result<u64, EventRepositoryError> {
+        if self.crash_events.load(Relaxed) {
+            return Err(EventRepositoryError::CorruptedData("Crashed store"));
+        }
+        let mut events = self.events.lock().unwrap();
+        let count = events.len();
+        events.retain(|e| !matches!(e.processed_date, Some(date) if date < *processed_before));
+        Ok((count - events.len()) as u64)
+    }
+
+    async fn get_event_page(
+        &self,
+        filter: &EventFilter,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        if self.crash_events.load(Relaxed) {
+            return Err(EventRepositoryError::CorruptedData("Crashed store"));
+        }
+        let mut events = Vec::new();
+        for event in self.events.lock().unwrap().iter() {
+            let stored = StoredEvent {
+                processed_at: event.processed_date,
+                origin: event.origin.clone(),
+                event: event
+                    .clone()
+                    .event()
+                    .map_err(EventRepositoryError::CorruptedData)?,
+            };
+            if filter.matches(&stored.event)
+                && before
+                    .iter()
+                    .all(|cursor| (stored.event.date(), stored.event.id()) < *cursor)
+            {
+                events.push(stored);
+            }
+        }
+        events.sort_by_key(|e| std::cmp::Reverse((e.event.date(), e.event.id())));
+        events.truncate(limit as usize);
+        Ok(events)
+    }
+
+    async fn get_group_event_page(
+        &self,
+        group_id: &Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        let mut events = self.get_group_events(group_id).await?;
+        events.retain(|e| before.iter().all(|cursor| (e.event_date, e.id) < *cursor));
+        events.sort_by_key(|e| std::cmp::Reverse((e.event_date, e.id)));
+        events.truncate(limit as usize);
+        Ok(events)
+    }
 }
@@ -1,10 +1,11 @@
 use crate::application::store::{ExpenseRepository, ExpenseRepositoryError};
-use crate::domain::Expense;
+use crate::domain::{Expense, ExpenseFilter, ExpenseStats, MemberTotal, MonthTotal};
 use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerExpense};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, TimeZone, Utc};
 use itertools::Itertools;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::atomic::Ordering::Relaxed;
 use uuid::Uuid;
 
@@ -145,4 +146,103 @@ impl ExpenseRepository for InMemoryStore {
         }
         Ok(expenses)
     }
+
+    async fn get_stats(
+        &self,
+        group_id: &Uuid,
+        start_date: Option<&DateTime<Utc>>,
+        end_date: Option<&DateTime<Utc>>,
+        largest: u32,
+    ) -> Result<ExpenseStats, ExpenseRepositoryError> {
+        let expenses = self.get_expenses(group_id, start_date, end_date).await?;
+        let members = expenses
+            .iter()
+            .into_group_map_by(|e| e.member_id)
+            .into_iter()
+            .map(|(member_id, exps)| MemberTotal {
+                member_id,
+                total: exps.iter().map(|e| f32::from(e.amount)).sum(),
+                count: exps.len() as u32,
+            })
+            .sorted_by(|a, b| {
+                b.total
+                    .partial_cmp(&a.total)
+                    .expect("totals to be comparable f32")
+                    .then(a.member_id.cmp(&b.member_id))
+            })
+            .collect_vec();
+        let months = expenses
+            .iter()
+            .into_group_map_by(|e| {
+                Utc.with_ymd_and_hms(e.created_at.year(), e.created_at.month(), 1, 0, 0, 0)
+                    .unwrap()
+            })
+            .into_iter()
+            .map(|(month, exps)| MonthTotal {
+                month,
+                total: exps.iter().map(|e| f32::from(e.amount)).sum(),
+                count: exps.len() as u32,
+            })
+            .sorted_by_key(|m| m.month)
+            .collect_vec();
+        let largest = expenses
+            .into_iter()
+            .sorted_by(|a, b| {
+                f32::from(b.amount)
+                    .partial_cmp(&f32::from(a.amount))
+                    .expect("amounts to be comparable f32")
+                    .then(b.created_at.cmp(&a.created_at))
+            })
+            .take(largest as usize)
+            .collect_vec();
+        Ok(ExpenseStats::new(members, months, largest))
+    }
+
+    async fn get_expense_page(
+        &self,
+        group_id: &Uuid,
+        filter: &ExpenseFilter,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<(Expense, Option<Uuid>)>, ExpenseRepositoryError> {
+        if self.crash_expenses.load(Relaxed) {
+            return Err(ExpenseRepositoryError::CorruptedData("Crashed store"));
+        }
+        let settlements = self
+            .settlements
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| s.group_id == *group_id)
+            .flat_map(|s| s.expenses.iter().map(|e| (*e, s.id)).collect_vec())
+            .collect::<HashMap<Uuid, Uuid>>();
+        let r = self
+            .expenses
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|e| e.group_id == *group_id)
+            .filter(|e| match filter {
+                ExpenseFilter::Unsettled => !e.settled,
+                ExpenseFilter::Period { from, to } => {
+                    from.map_or(true, |from| e.created_at > from)
+                        && to.map_or(true, |to| e.created_at <= to)
+                }
+                ExpenseFilter::Settlement(id) => settlements.get(&e.id) == Some(id),
+            })
+            .filter(|e| after.map_or(true, |after| (e.created_at, e.id) > after))
+            .sorted_by_key(|e| (e.created_at, e.id))
+            .take(limit as usize)
+            .cloned()
+            .collect_vec();
+        let mut expenses = Vec::new();
+        for expense in r {
+            let settlement_id = settlements.get(&expense.id).cloned();
+            expenses.push((
+                Expense::try_from(expense).map_err(ExpenseRepositoryError::CorruptedData)?,
+                settlement_id,
+            ));
+        }
+        Ok(expenses)
+    }
 }
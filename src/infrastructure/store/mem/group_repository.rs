@@ -1,5 +1,5 @@
 use crate::application::store::{GroupRepository, GroupRepositoryError, MemberRepository};
-use crate::domain::Group;
+use crate::domain::{Group, GroupMember, GroupName, GroupSummary, Transaction};
 use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerGroup};
 use async_trait::async_trait;
 use std::cell::RefCell;
@@ -23,6 +23,7 @@ impl GroupRepository for InMemoryStore {
             name: group.name.clone().into(),
             admin_id: group.admin_id,
             created_at: group.created_at,
+            archived_at: group.archived_at,
             member_ids: group.members.iter().map(|m| m.id).collect(),
             expenses: group.expense_ids.clone(),
             settlements: group.settlement_ids.clone(),
@@ -93,6 +94,79 @@ impl GroupRepository for InMemoryStore {
         Ok(res)
     }
 
+    async fn get_user_summaries(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<GroupSummary>, GroupRepositoryError> {
+        if self.crash_groups.load(Relaxed) {
+            return Err(GroupRepositoryError::CorruptedData("Crashed store"));
+        }
+        let groups: Vec<InnerGroup> = self
+            .groups
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|g| g.admin_id == *user_id || g.member_ids.contains(user_id))
+            .cloned()
+            .collect();
+        let expenses = self.expenses.lock().unwrap();
+        let members = self.members.lock().unwrap();
+        let settlements = self.settlements.lock().unwrap();
+        let users = self.users.lock().unwrap();
+        let mut res = Vec::new();
+        for group in groups {
+            let group_expenses = expenses.values().filter(|e| e.group_id == group.id);
+            let unsettled = group_expenses.clone().filter(|e| !e.settled);
+            let group_members = members.values().filter(|m| m.id.1 == group.id);
+            let group_settlements = settlements.values().filter(|s| s.group_id == group.id);
+            let last_activity = group_expenses
+                .map(|e| e.modified_at.unwrap_or(e.created_at))
+                .chain(group_members.clone().map(|m| m.joined_at))
+                .chain(group_settlements.clone().map(|s| s.end_date))
+                .fold(group.created_at, |acc, date| acc.max(date));
+            let transactions = group_settlements
+                .flat_map(|s| s.transactions.iter())
+                .filter(|t| !t.paid && (t.from == *user_id || t.to == *user_id))
+                .map(|t| Transaction::try_from(t.clone()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(GroupRepositoryError::CorruptedData)?;
+            let mut transaction_members = Vec::new();
+            for member in group_members.clone() {
+                let user_id = member.id.0;
+                if !transactions
+                    .iter()
+                    .any(|t| t.from == user_id || t.to == user_id)
+                {
+                    continue;
+                }
+                let user = users
+                    .get(&user_id)
+                    .cloned()
+                    .ok_or(GroupRepositoryError::CorruptedData("No user"))?;
+                transaction_members.push(
+                    GroupMember::try_from(member.clone(), user)
+                        .map_err(GroupRepositoryError::CorruptedData)?,
+                );
+            }
+            res.push(GroupSummary {
+                group_id: group.id,
+                name: GroupName::try_from(group.name.clone())
+                    .map_err(GroupRepositoryError::CorruptedData)?,
+                paid: unsettled
+                    .clone()
+                    .filter(|e| e.member_id == *user_id)
+                    .map(|e| e.amount)
+                    .sum(),
+                total: unsettled.map(|e| e.amount).sum(),
+                member_count: group_members.count() as u32,
+                last_activity,
+                transactions,
+                members: transaction_members,
+            });
+        }
+        Ok(res)
+    }
+
     async fn fetch_all_groups(&self) -> Result<Vec<Group>, GroupRepositoryError> {
         if self.crash_groups.load(Relaxed) {
             return Err(GroupRepositoryError::CorruptedData("Crashed store"));
@@ -1,12 +1,17 @@
-use crate::application::auth::CredentialRepository;
+use crate::application::auth::{
+    CredentialRepository, TwoFactor, TwoFactorRepository, UserToken, UserTokenKind,
+    UserTokenRepository,
+};
 use crate::application::store::{
-    DeviceRepository, EventRepository, ExpenseRepository, GroupRepository, MemberRepository,
-    MultiRepository, SettlementRepository, Tx, UserRepository,
+    ApiTokenRepository, BudgetRepository, DeviceRepository, EventRepository, ExpenseRepository,
+    GroupRepository, MemberRepository, MultiRepository, SettlementRepository, TakeoutRepository,
+    Tx, UserRepository,
 };
 use crate::domain::{
-    Amount, Email, Event, Expense, ExpenseTitle, Group, GroupEvent, GroupEventKind, GroupMember,
-    GroupName, MemberColor, Settlement, SettlementDescription, Transaction, User, UserEvent,
-    UserEventKind, UserName, UserRole,
+    Amount, ApiToken, ApiTokenScope, Budget, BudgetPeriod, Email, Event, EventOrigin, Expense,
+    ExpenseTitle, Group, GroupEvent, GroupEventKind, GroupMember, GroupName, MemberBalance,
+    MemberColor, Settlement, SettlementDescription, SettlementLabel, Takeout, Transaction, User,
+    UserEvent, UserEventKind, UserName, UserRole,
 };
 use anyhow::Error;
 use async_trait::async_trait;
@@ -29,6 +34,11 @@ pub struct InMemoryStore {
     pub crash_expenses: AtomicBool,
     pub crash_settlements: AtomicBool,
     pub crash_events: AtomicBool,
+    pub crash_budgets: AtomicBool,
+    pub crash_takeouts: AtomicBool,
+    pub crash_user_tokens: AtomicBool,
+    pub crash_api_tokens: AtomicBool,
+    pub crash_two_factors: AtomicBool,
     pub users: Mutex<HashMap<Uuid, InnerUser>>,
     pub user_devices: Mutex<HashMap<Uuid, String>>,
     pub user_credentials: Mutex<HashMap<String, String>>,
@@ -37,6 +47,11 @@ pub struct InMemoryStore {
     pub expenses: Mutex<HashMap<Uuid, InnerExpense>>,
     pub settlements: Mutex<HashMap<Uuid, InnerSettlement>>,
     pub events: Mutex<Vec<InnerEvent>>,
+    pub budgets: Mutex<HashMap<Uuid, InnerBudget>>,
+    pub takeouts: Mutex<HashMap<Uuid, InnerTakeout>>,
+    pub user_tokens: Mutex<HashMap<String, InnerUserToken>>,
+    pub api_tokens: Mutex<HashMap<Uuid, InnerApiToken>>,
+    pub two_factors: Mutex<HashMap<String, InnerTwoFactor>>,
 }
 
 impl InMemoryStore {
@@ -50,6 +65,11 @@ impl InMemoryStore {
             crash_expenses: AtomicBool::from(false),
             crash_settlements: AtomicBool::from(false),
             crash_events: AtomicBool::from(false),
+            crash_budgets: AtomicBool::from(false),
+            crash_takeouts: AtomicBool::from(false),
+            crash_user_tokens: AtomicBool::from(false),
+            crash_api_tokens: AtomicBool::from(false),
+            crash_two_factors: AtomicBool::from(false),
             users: Mutex::new(HashMap::new()),
             user_devices: Mutex::new(HashMap::new()),
             user_credentials: Mutex::new(HashMap::new()),
@@ -58,6 +78,11 @@ impl InMemoryStore {
             expenses: Mutex::new(HashMap::new()),
             settlements: Mutex::new(HashMap::new()),
             events: Mutex::new(Vec::new()),
+            budgets: Mutex::new(HashMap::new()),
+            takeouts: Mutex::new(HashMap::new()),
+            user_tokens: Mutex::new(HashMap::new()),
+            api_tokens: Mutex::new(HashMap::new()),
+            two_factors: Mutex::new(HashMap::new()),
         }
     }
 
@@ -90,13 +115,28 @@ pub struct InMemTx {
     pub users: Mutex<HashMap<Uuid, InnerUser>>,
     pub user_devices: Mutex<HashMap<Uuid, String>>,
     pub user_credentials: Mutex<HashMap<String, String>>,
+    pub deleted_user_credentials: Mutex<HashSet<String>>,
     pub groups: Mutex<HashMap<Uuid, InnerGroup>>,
     pub deleted_groups: Mutex<HashSet<Uuid>>,
     pub members: Mutex<HashMap<(Uuid, Uuid), InnerMember>>,
+    pub deleted_members: Mutex<HashSet<(Uuid, Uuid)>>,
     pub expenses: Mutex<HashMap<Uuid, InnerExpense>>,
     pub deleted_expenses: Mutex<HashSet<Uuid>>,
     pub settlements: Mutex<HashMap<Uuid, InnerSettlement>>,
+    pub deleted_settlements: Mutex<HashSet<Uuid>>,
     pub events: Mutex<Vec<InnerEvent>>,
+    pub budgets: Mutex<HashMap<Uuid, InnerBudget>>,
+    pub deleted_budgets: Mutex<HashSet<Uuid>>,
+    pub takeouts: Mutex<HashMap<Uuid, InnerTakeout>>,
+    pub deleted_takeouts: Mutex<HashSet<Uuid>>,
+    pub user_tokens: Mutex<HashMap<String, InnerUserToken>>,
+    pub deleted_user_tokens: Mutex<HashSet<(Uuid, UserTokenKind)>>,
+    pub deleted_all_user_tokens: Mutex<HashSet<Uuid>>,
+    pub api_tokens: Mutex<HashMap<Uuid, InnerApiToken>>,
+    pub deleted_api_tokens: Mutex<HashSet<Uuid>>,
+    pub deleted_user_api_tokens: Mutex<HashSet<Uuid>>,
+    pub two_factors: Mutex<HashMap<String, InnerTwoFactor>>,
+    pub deleted_two_factors: Mutex<HashSet<String>>,
 }
 
 impl InMemTx {
@@ -105,13 +145,28 @@ impl InMemTx {
             users: Mutex::new(HashMap::new()),
             user_devices: Mutex::new(HashMap::new()),
             user_credentials: Mutex::new(HashMap::new()),
+            deleted_user_credentials: Mutex::new(HashSet::new()),
             groups: Mutex::new(HashMap::new()),
             deleted_groups: Mutex::new(HashSet::new()),
+            deleted_members: Mutex::new(HashSet::new()),
             members: Mutex::new(HashMap::new()),
             expenses: Mutex::new(HashMap::new()),
             deleted_expenses: Mutex::new(HashSet::new()),
             settlements: Mutex::new(HashMap::new()),
+            deleted_settlements: Mutex::new(HashSet::new()),
             events: Mutex::new(Vec::new()),
+            budgets: Mutex::new(HashMap::new()),
+            deleted_budgets: Mutex::new(HashSet::new()),
+            takeouts: Mutex::new(HashMap::new()),
+            deleted_takeouts: Mutex::new(HashSet::new()),
+            user_tokens: Mutex::new(HashMap::new()),
+            deleted_user_tokens: Mutex::new(HashSet::new()),
+            deleted_all_user_tokens: Mutex::new(HashSet::new()),
+            api_tokens: Mutex::new(HashMap::new()),
+            deleted_api_tokens: Mutex::new(HashSet::new()),
+            deleted_user_api_tokens: Mutex::new(HashSet::new()),
+            two_factors: Mutex::new(HashMap::new()),
+            deleted_two_factors: Mutex::new(HashSet::new()),
         }
     }
 }
@@ -192,6 +247,76 @@ impl MultiRepository for InMemoryStore {
                 self.events.lock().unwrap().push(event.clone());
             }
         }
+        {
+            let guard = tx.budgets.lock().unwrap();
+            let budgets = guard.iter();
+            for (group_id, budget) in budgets {
+                self.budgets
+                    .lock()
+                    .unwrap()
+                    .insert(*group_id, budget.clone());
+            }
+        }
+        {
+            let guard = tx.takeouts.lock().unwrap();
+            let takeouts = guard.iter();
+            for (user_id, takeout) in takeouts {
+                self.takeouts
+                    .lock()
+                    .unwrap()
+                    .insert(*user_id, takeout.clone());
+            }
+        }
+        {
+            // tokens are removed before the new ones are added, to allow replacing them
+            let guard = tx.deleted_user_tokens.lock().unwrap();
+            let del_tokens = guard.iter();
+            for (user_id, kind) in del_tokens {
+                self.user_tokens
+                    .lock()
+                    .unwrap()
+                    .retain(|_, t| !(t.user_id == *user_id && t.kind == *kind));
+            }
+            let guard = tx.deleted_all_user_tokens.lock().unwrap();
+            let del_user_tokens = guard.iter();
+            for user_id in del_user_tokens {
+                self.user_tokens
+                    .lock()
+                    .unwrap()
+                    .retain(|_, t| t.user_id != *user_id);
+            }
+            let guard = tx.user_tokens.lock().unwrap();
+            let tokens = guard.iter();
+            for (hash, token) in tokens {
+                self.user_tokens
+                    .lock()
+                    .unwrap()
+                    .insert(hash.clone(), token.clone());
+            }
+        }
+        {
+            // second factors are removed before the new ones are added, to allow moving them
+            let guard = tx.deleted_two_factors.lock().unwrap();
+            let del_two_factors = guard.iter();
+            for email in del_two_factors {
+                self.two_factors.lock().unwrap().remove(email);
+            }
+            let guard = tx.two_factors.lock().unwrap();
+            let two_factors = guard.iter();
+            for (email, two_factor) in two_factors {
+                self.two_factors
+                    .lock()
+                    .unwrap()
+                    .insert(email.clone(), two_factor.clone());
+            }
+        }
+        {
+            let guard = tx.api_tokens.lock().unwrap();
+            let tokens = guard.iter();
+            for (id, token) in tokens {
+                self.api_tokens.lock().unwrap().insert(*id, token.clone());
+            }
+        }
         {
             let guard = tx.deleted_expenses.lock().unwrap();
             let del_expenses = guard.iter();
@@ -199,11 +324,62 @@ impl MultiRepository for InMemoryStore {
                 self.expenses.lock().unwrap().remove(id);
             }
         }
+        {
+            let guard = tx.deleted_settlements.lock().unwrap();
+            let del_settlements = guard.iter();
+            for id in del_settlements {
+                self.settlements.lock().unwrap().remove(id);
+            }
+        }
         {
             let guard = tx.deleted_groups.lock().unwrap();
             let del_groups = guard.iter();
             for id in del_groups {
                 self.groups.lock().unwrap().remove(id);
+                self.budgets.lock().unwrap().remove(id);
+            }
+        }
+        {
+            let guard = tx.deleted_members.lock().unwrap();
+            let del_members = guard.iter();
+            for id in del_members {
+                self.members.lock().unwrap().remove(id);
+            }
+        }
+        {
+            let guard = tx.deleted_budgets.lock().unwrap();
+            let del_budgets = guard.iter();
+            for id in del_budgets {
+                self.budgets.lock().unwrap().remove(id);
+            }
+        }
+        {
+            let guard = tx.deleted_user_credentials.lock().unwrap();
+            let del_credentials = guard.iter();
+            for email in del_credentials {
+                self.user_credentials.lock().unwrap().remove(email);
+            }
+        }
+        {
+            let guard = tx.deleted_takeouts.lock().unwrap();
+            let del_takeouts = guard.iter();
+            for user_id in del_takeouts {
+                self.takeouts.lock().unwrap().remove(user_id);
+            }
+        }
+        {
+            let guard = tx.deleted_api_tokens.lock().unwrap();
+            let del_tokens = guard.iter();
+            for id in del_tokens {
+                self.api_tokens.lock().unwrap().remove(id);
+            }
+            let guard = tx.deleted_user_api_tokens.lock().unwrap();
+            let del_user_tokens = guard.iter();
+            for user_id in del_user_tokens {
+                self.api_tokens
+                    .lock()
+                    .unwrap()
+                    .retain(|_, t| t.user_id != *user_id);
             }
         }
         Ok(())
@@ -213,6 +389,10 @@ impl MultiRepository for InMemoryStore {
         self
     }
 
+    fn budgets(&self) -> &dyn BudgetRepository<Tr = Self::KTransaction> {
+        self
+    }
+
     fn credentials(&self) -> &dyn CredentialRepository<Tr = Self::KTransaction> {
         self
     }
@@ -240,6 +420,22 @@ impl MultiRepository for InMemoryStore {
     fn events(&self) -> &dyn EventRepository<Tr = Self::KTransaction> {
         self
     }
+
+    fn takeouts(&self) -> &dyn TakeoutRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn user_tokens(&self) -> &dyn UserTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn api_tokens(&self) -> &dyn ApiTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn two_factors(&self) -> &dyn TwoFactorRepository<Tr = Self::KTransaction> {
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -249,6 +445,11 @@ pub struct InnerUser {
     pub email: String,
     pub role: InnerRole,
     pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub sessions_revoked_at: Option<DateTime<Utc>>,
+    pub email_verified: bool,
+    pub disabled_at: Option<DateTime<Utc>>,
+    pub password_reset_required: bool,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -272,6 +473,168 @@ impl TryFrom<InnerUser> for User {
             email,
             role,
             created_at: value.created_at,
+            deleted_at: value.deleted_at,
+            sessions_revoked_at: value.sessions_revoked_at,
+            email_verified: value.email_verified,
+            disabled_at: value.disabled_at,
+            password_reset_required: value.password_reset_required,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerTakeout {
+    pub user_id: Uuid,
+    pub requested_at: DateTime<Utc>,
+    pub generated_at: Option<DateTime<Utc>>,
+    pub archive: Option<serde_json::Value>,
+}
+
+impl From<&Takeout> for InnerTakeout {
+    fn from(value: &Takeout) -> Self {
+        Self {
+            user_id: value.user_id,
+            requested_at: value.requested_at,
+            generated_at: value.generated_at,
+            archive: value.archive.clone(),
+        }
+    }
+}
+
+impl From<InnerTakeout> for Takeout {
+    fn from(value: InnerTakeout) -> Self {
+        Self {
+            user_id: value.user_id,
+            requested_at: value.requested_at,
+            generated_at: value.generated_at,
+            archive: value.archive,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerUserToken {
+    pub hash: String,
+    pub user_id: Uuid,
+    pub kind: UserTokenKind,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<&UserToken> for InnerUserToken {
+    fn from(value: &UserToken) -> Self {
+        Self {
+            hash: value.hash.clone(),
+            user_id: value.user_id,
+            kind: value.kind,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+impl From<InnerUserToken> for UserToken {
+    fn from(value: InnerUserToken) -> Self {
+        Self {
+            hash: value.hash,
+            user_id: value.user_id,
+            kind: value.kind,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerTwoFactor {
+    pub email: String,
+    pub encrypted_secret: String,
+    pub recovery_codes: Vec<String>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+}
+
+impl From<&TwoFactor> for InnerTwoFactor {
+    fn from(value: &TwoFactor) -> Self {
+        Self {
+            email: String::from(value.email.clone()),
+            encrypted_secret: value.encrypted_secret.clone(),
+            recovery_codes: value.recovery_codes.clone(),
+            confirmed_at: value.confirmed_at,
+            last_used_step: value.last_used_step,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerApiToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub hash: String,
+    pub scope: ApiTokenScope,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ApiToken> for InnerApiToken {
+    fn from(value: &ApiToken) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id,
+            name: value.name.clone(),
+            hash: value.hash.clone(),
+            scope: value.scope,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+impl From<InnerApiToken> for ApiToken {
+    fn from(value: InnerApiToken) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id,
+            name: value.name,
+            hash: value.hash,
+            scope: value.scope,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerBudget {
+    pub group_id: Uuid,
+    pub amount: f32,
+    pub period: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Budget> for InnerBudget {
+    fn from(value: &Budget) -> Self {
+        Self {
+            group_id: value.group_id,
+            amount: value.amount.into(),
+            period: value.period.into(),
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl TryFrom<InnerBudget> for Budget {
+    type Error = &'static str;
+    fn try_from(value: InnerBudget) -> Result<Self, Self::Error> {
+        Ok(Self {
+            group_id: value.group_id,
+            amount: Amount::try_from(value.amount)?,
+            period: BudgetPeriod::try_from(value.period)?,
+            updated_at: value.updated_at,
         })
     }
 }
@@ -330,6 +693,7 @@ pub struct InnerGroup {
     pub name: String,
     pub admin_id: Uuid,
     pub created_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
     pub member_ids: Vec<Uuid>,
     pub expenses: Vec<Uuid>,
     pub settlements: Vec<Uuid>,
@@ -343,6 +707,7 @@ impl InnerGroup {
             name,
             admin_id: self.admin_id,
             created_at: self.created_at,
+            archived_at: self.archived_at,
             members,
             expense_ids: self.expenses,
             settlement_ids: self.settlements,
@@ -389,6 +754,10 @@ pub struct InnerSettlement {
     pub end_date: DateTime<Utc>,
     pub transactions: Vec<InnerTransaction>,
     pub expenses: Vec<Uuid>,
+    pub label: Option<String>,
+    pub total: f32,
+    pub expense_count: u32,
+    pub balances: Vec<InnerMemberBalance>,
 }
 
 impl TryFrom<InnerSettlement> for Settlement {
@@ -405,6 +774,14 @@ impl TryFrom<InnerSettlement> for Settlement {
             end_date: value.end_date,
             transactions,
             expense_ids: value.expenses,
+            label: value.label.map(SettlementLabel::try_from).transpose()?,
+            total: value.total,
+            expense_count: value.expense_count,
+            balances: value
+                .balances
+                .into_iter()
+                .map(MemberBalance::from)
+                .collect(),
         })
     }
 }
@@ -425,11 +802,31 @@ impl TryFrom<InnerSettlement> for SettlementDescription {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct InnerMemberBalance {
+    pub member_id: Uuid,
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
+
+impl From<InnerMemberBalance> for MemberBalance {
+    fn from(value: InnerMemberBalance) -> Self {
+        Self {
+            member_id: value.member_id,
+            paid: value.paid,
+            share: value.share,
+            net: value.net,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct InnerTransaction {
     pub from: Uuid,
     pub to: Uuid,
     pub amount: f32,
+    pub paid: bool,
 }
 
 impl TryFrom<InnerTransaction> for Transaction {
@@ -440,6 +837,7 @@ impl TryFrom<InnerTransaction> for Transaction {
             from: value.from,
             to: value.to,
             amount,
+            paid: value.paid,
         })
     }
 }
@@ -450,6 +848,7 @@ pub struct InnerEvent {
     pub event: InnerEventKind,
     pub date: DateTime<Utc>,
     pub processed_date: Option<DateTime<Utc>>,
+    pub origin: EventOrigin,
 }
 
 #[derive(Debug, Clone)]
@@ -501,10 +900,39 @@ pub enum InnerEventKind {
         end_date: DateTime<Utc>,
         transactions: Vec<InnerTransaction>,
     },
+    SettlementReverted {
+        id: Uuid,
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    BudgetChanged {
+        group_id: Uuid,
+        member_id: Uuid,
+        amount: Option<f32>,
+        period: Option<String>,
+    },
+    OwnershipTransferred {
+        group_id: Uuid,
+        member_id: Uuid,
+        new_admin_id: Uuid,
+    },
     GroupDeleted {
         id: Uuid,
         admin_id: Uuid,
     },
+    GroupArchived {
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    GroupUnarchived {
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    MemberRemoved {
+        group_id: Uuid,
+        member_id: Uuid,
+        removed_member_id: Uuid,
+    },
     UserCreated {
         user_id: Uuid,
         name: String,
@@ -519,6 +947,69 @@ pub enum InnerEventKind {
     UserLogout {
         user_id: Uuid,
     },
+    UserTakeoutRequested {
+        user_id: Uuid,
+    },
+    UserNameChanged {
+        user_id: Uuid,
+        previous_name: String,
+        new_name: String,
+    },
+    UserEmailChanged {
+        user_id: Uuid,
+        previous_email: String,
+        new_email: String,
+    },
+    UserPasswordChanged {
+        user_id: Uuid,
+    },
+    UserPasswordResetRequested {
+        user_id: Uuid,
+    },
+    UserEmailVerificationRequested {
+        user_id: Uuid,
+    },
+    UserEmailVerified {
+        user_id: Uuid,
+    },
+    UserApiTokenCreated {
+        user_id: Uuid,
+        token_id: Uuid,
+        name: String,
+    },
+    UserApiTokenRevoked {
+        user_id: Uuid,
+        token_id: Uuid,
+        name: String,
+    },
+    UserTwoFactorEnabled {
+        user_id: Uuid,
+    },
+    UserTwoFactorReset {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserRoleChanged {
+        user_id: Uuid,
+        role: String,
+        by: Uuid,
+    },
+    UserDisabled {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserEnabled {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserPasswordResetForced {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserDeletedByAdmin {
+        user_id: Uuid,
+        by: Uuid,
+    },
 }
 
 impl From<Event> for InnerEvent {
@@ -537,6 +1028,7 @@ impl From<GroupEvent> for InnerEvent {
             event: InnerEventKind::from_group(e.event, e.group_id, e.member_id),
             date: e.event_date,
             processed_date: None,
+            origin: EventOrigin::default(),
         }
     }
 }
@@ -548,6 +1040,7 @@ impl From<UserEvent> for InnerEvent {
             event: InnerEventKind::from_user(e.event, e.user_id),
             date: e.event_date,
             processed_date: None,
+            origin: EventOrigin::default(),
         }
     }
 }
@@ -562,11 +1055,35 @@ impl InnerEventKind {
             InnerEventKind::ExpenseModified { .. } => "ExpenseModified",
             InnerEventKind::ExpenseDeleted { .. } => "ExpenseDeleted",
             InnerEventKind::Settled { .. } => "Settled",
+            InnerEventKind::SettlementReverted { .. } => "SettlementReverted",
+            InnerEventKind::BudgetChanged { .. } => "BudgetChanged",
+            InnerEventKind::OwnershipTransferred { .. } => "OwnershipTransferred",
             InnerEventKind::GroupDeleted { .. } => "GroupDeleted",
+            InnerEventKind::GroupArchived { .. } => "GroupArchived",
+            InnerEventKind::GroupUnarchived { .. } => "GroupUnarchived",
+            InnerEventKind::MemberRemoved { .. } => "MemberRemoved",
             InnerEventKind::UserCreated { .. } => "UserCreated",
             InnerEventKind::UserDeleted { .. } => "UserDeleted",
             InnerEventKind::UserLogin { .. } => "UserLogin",
             InnerEventKind::UserLogout { .. } => "UserLogout",
+            InnerEventKind::UserTakeoutRequested { .. } => "UserTakeoutRequested",
+            InnerEventKind::UserNameChanged { .. } => "UserNameChanged",
+            InnerEventKind::UserEmailChanged { .. } => "UserEmailChanged",
+            InnerEventKind::UserPasswordChanged { .. } => "UserPasswordChanged",
+            InnerEventKind::UserPasswordResetRequested { .. } => "UserPasswordResetRequested",
+            InnerEventKind::UserEmailVerificationRequested { .. } => {
+                "UserEmailVerificationRequested"
+            }
+            InnerEventKind::UserEmailVerified { .. } => "UserEmailVerified",
+            InnerEventKind::UserApiTokenCreated { .. } => "UserApiTokenCreated",
+            InnerEventKind::UserApiTokenRevoked { .. } => "UserApiTokenRevoked",
+            InnerEventKind::UserTwoFactorEnabled { .. } => "UserTwoFactorEnabled",
+            InnerEventKind::UserTwoFactorReset { .. } => "UserTwoFactorReset",
+            InnerEventKind::UserRoleChanged { .. } => "UserRoleChanged",
+            InnerEventKind::UserDisabled { .. } => "UserDisabled",
+            InnerEventKind::UserEnabled { .. } => "UserEnabled",
+            InnerEventKind::UserPasswordResetForced { .. } => "UserPasswordResetForced",
+            InnerEventKind::UserDeletedByAdmin { .. } => "UserDeletedByAdmin",
         }
     }
 
@@ -580,6 +1097,60 @@ impl InnerEventKind {
             UserEventKind::Login => InnerEventKind::UserLogin { user_id },
             UserEventKind::Logout => InnerEventKind::UserLogout { user_id },
             UserEventKind::Deleted => InnerEventKind::UserDeleted { user_id },
+            UserEventKind::TakeoutRequested => InnerEventKind::UserTakeoutRequested { user_id },
+            UserEventKind::NameChanged {
+                previous_name,
+                new_name,
+            } => InnerEventKind::UserNameChanged {
+                user_id,
+                previous_name,
+                new_name,
+            },
+            UserEventKind::EmailChanged {
+                previous_email,
+                new_email,
+            } => InnerEventKind::UserEmailChanged {
+                user_id,
+                previous_email,
+                new_email,
+            },
+            UserEventKind::PasswordChanged => InnerEventKind::UserPasswordChanged { user_id },
+            UserEventKind::PasswordResetRequested => {
+                InnerEventKind::UserPasswordResetRequested { user_id }
+            }
+            UserEventKind::EmailVerificationRequested => {
+                InnerEventKind::UserEmailVerificationRequested { user_id }
+            }
+            UserEventKind::EmailVerified => InnerEventKind::UserEmailVerified { user_id },
+            UserEventKind::ApiTokenCreated { token_id, name } => {
+                InnerEventKind::UserApiTokenCreated {
+                    user_id,
+                    token_id,
+                    name,
+                }
+            }
+            UserEventKind::ApiTokenRevoked { token_id, name } => {
+                InnerEventKind::UserApiTokenRevoked {
+                    user_id,
+                    token_id,
+                    name,
+                }
+            }
+            UserEventKind::TwoFactorEnabled => InnerEventKind::UserTwoFactorEnabled { user_id },
+            UserEventKind::TwoFactorReset { by } => {
+                InnerEventKind::UserTwoFactorReset { user_id, by }
+            }
+            UserEventKind::RoleChanged { role, by } => {
+                InnerEventKind::UserRoleChanged { user_id, role, by }
+            }
+            UserEventKind::Disabled { by } => InnerEventKind::UserDisabled { user_id, by },
+            UserEventKind::Enabled { by } => InnerEventKind::UserEnabled { user_id, by },
+            UserEventKind::PasswordResetForced { by } => {
+                InnerEventKind::UserPasswordResetForced { user_id, by }
+            }
+            UserEventKind::DeletedByAdmin { by } => {
+                InnerEventKind::UserDeletedByAdmin { user_id, by }
+            }
         }
     }
 
@@ -671,13 +1242,47 @@ impl InnerEventKind {
                         from: t.from,
                         to: t.to,
                         amount: f32::from(t.amount),
+                        paid: t.paid,
                     })
                     .collect_vec(),
             },
+            GroupEventKind::SettlementReverted { id } => InnerEventKind::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            },
+            GroupEventKind::BudgetChanged { amount, period } => InnerEventKind::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period: period.map(String::from),
+            },
+            GroupEventKind::OwnershipTransferred { new_admin_id } => {
+                InnerEventKind::OwnershipTransferred {
+                    group_id,
+                    member_id,
+                    new_admin_id,
+                }
+            }
             GroupEventKind::GroupDeleted => InnerEventKind::GroupDeleted {
                 id: group_id,
                 admin_id: member_id,
             },
+            GroupEventKind::GroupArchived => InnerEventKind::GroupArchived {
+                group_id,
+                member_id,
+            },
+            GroupEventKind::GroupUnarchived => InnerEventKind::GroupUnarchived {
+                group_id,
+                member_id,
+            },
+            GroupEventKind::MemberRemoved {
+                member_id: removed_member_id,
+            } => InnerEventKind::MemberRemoved {
+                group_id,
+                member_id,
+                removed_member_id,
+            },
         }
     }
 }
@@ -804,6 +1409,43 @@ impl InnerEvent {
                     },
                 })
             }
+            InnerEventKind::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::SettlementReverted { id },
+            }),
+            InnerEventKind::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::BudgetChanged {
+                    amount,
+                    period: period.map(BudgetPeriod::try_from).transpose()?,
+                },
+            }),
+            InnerEventKind::OwnershipTransferred {
+                group_id,
+                member_id,
+                new_admin_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::OwnershipTransferred { new_admin_id },
+            }),
             InnerEventKind::GroupDeleted { id, admin_id } => Event::Group(GroupEvent {
                 id: self.id,
                 event_date: self.date,
@@ -811,6 +1453,39 @@ impl InnerEvent {
                 member_id: admin_id,
                 event: GroupEventKind::GroupDeleted,
             }),
+            InnerEventKind::GroupArchived {
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::GroupArchived,
+            }),
+            InnerEventKind::GroupUnarchived {
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::GroupUnarchived,
+            }),
+            InnerEventKind::MemberRemoved {
+                group_id,
+                member_id,
+                removed_member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::MemberRemoved {
+                    member_id: removed_member_id,
+                },
+            }),
 
             InnerEventKind::UserCreated {
                 user_id,
@@ -840,6 +1515,124 @@ impl InnerEvent {
                 user_id,
                 event: UserEventKind::Logout,
             }),
+            InnerEventKind::UserTakeoutRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TakeoutRequested,
+            }),
+            InnerEventKind::UserNameChanged {
+                user_id,
+                previous_name,
+                new_name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::NameChanged {
+                    previous_name,
+                    new_name,
+                },
+            }),
+            InnerEventKind::UserEmailChanged {
+                user_id,
+                previous_email,
+                new_email,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailChanged {
+                    previous_email,
+                    new_email,
+                },
+            }),
+            InnerEventKind::UserPasswordChanged { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordChanged,
+            }),
+            InnerEventKind::UserPasswordResetRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordResetRequested,
+            }),
+            InnerEventKind::UserEmailVerificationRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailVerificationRequested,
+            }),
+            InnerEventKind::UserEmailVerified { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailVerified,
+            }),
+            InnerEventKind::UserApiTokenCreated {
+                user_id,
+                token_id,
+                name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::ApiTokenCreated { token_id, name },
+            }),
+            InnerEventKind::UserApiTokenRevoked {
+                user_id,
+                token_id,
+                name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::ApiTokenRevoked { token_id, name },
+            }),
+            InnerEventKind::UserTwoFactorEnabled { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TwoFactorEnabled,
+            }),
+            InnerEventKind::UserTwoFactorReset { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TwoFactorReset { by },
+            }),
+            InnerEventKind::UserRoleChanged { user_id, role, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::RoleChanged { role, by },
+            }),
+            InnerEventKind::UserDisabled { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::Disabled { by },
+            }),
+            InnerEventKind::UserEnabled { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::Enabled { by },
+            }),
+            InnerEventKind::UserPasswordResetForced { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordResetForced { by },
+            }),
+            InnerEventKind::UserDeletedByAdmin { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::DeletedByAdmin { by },
+            }),
         };
         Ok(e)
     }
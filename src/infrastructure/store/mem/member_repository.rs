@@ -39,6 +39,23 @@ impl MemberRepository for InMemoryStore {
         Ok(())
     }
 
+    async fn delete(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), MemberRepositoryError> {
+        if self.crash_members.load(Relaxed) {
+            return Err(MemberRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_members
+            .lock()
+            .unwrap()
+            .insert((*member_id, *group_id));
+        Ok(())
+    }
+
     async fn fetch_members(
         &self,
         group_id: &Uuid,
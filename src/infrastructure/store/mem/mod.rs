@@ -1,3 +1,5 @@
+pub mod api_token_repository;
+pub mod budget_repository;
 pub mod credential_repository;
 pub mod device_repository;
 pub mod event_repository;
@@ -6,4 +8,7 @@ pub mod group_repository;
 pub mod mem_store;
 pub mod member_repository;
 pub mod settlement_repository;
+pub mod takeout_repository;
+pub mod two_factor_repository;
 pub mod user_repository;
+pub mod user_token_repository;
@@ -1,9 +1,10 @@
 use crate::application::store::{SettlementRepository, SettlementRepositoryError};
 use crate::domain::{Settlement, SettlementDescription};
 use crate::infrastructure::store::mem::mem_store::{
-    InMemTx, InMemoryStore, InnerSettlement, InnerTransaction,
+    InMemTx, InMemoryStore, InnerMemberBalance, InnerSettlement, InnerTransaction,
 };
 use async_trait::async_trait;
+use itertools::Itertools;
 use std::cell::RefCell;
 use std::sync::atomic::Ordering::Relaxed;
 use uuid::Uuid;
@@ -27,6 +28,7 @@ impl SettlementRepository for InMemoryStore {
                 from: tr.from,
                 to: tr.to,
                 amount: f32::from(tr.amount),
+                paid: tr.paid,
             })
             .collect();
         let settlement = InnerSettlement {
@@ -36,6 +38,19 @@ impl SettlementRepository for InMemoryStore {
             end_date: settlement.end_date,
             transactions,
             expenses: settlement.expense_ids.clone(),
+            label: settlement.label.clone().map(String::from),
+            total: settlement.total,
+            expense_count: settlement.expense_count,
+            balances: settlement
+                .balances
+                .iter()
+                .map(|b| InnerMemberBalance {
+                    member_id: b.member_id,
+                    paid: b.paid,
+                    share: b.share,
+                    net: b.net,
+                })
+                .collect(),
         };
         tx.get_mut()
             .settlements
@@ -45,6 +60,30 @@ impl SettlementRepository for InMemoryStore {
         Ok(())
     }
 
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement_id: &Uuid,
+    ) -> Result<(), SettlementRepositoryError> {
+        if self.crash_settlements.load(Relaxed) {
+            return Err(SettlementRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_settlements
+            .lock()
+            .unwrap()
+            .insert(*settlement_id);
+        Ok(())
+    }
+
+    async fn update_transactions(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement: &Settlement,
+    ) -> Result<(), SettlementRepositoryError> {
+        self.save(tx, settlement).await
+    }
+
     async fn get_settlements(
         &self,
         group_id: &Uuid,
@@ -84,6 +123,31 @@ impl SettlementRepository for InMemoryStore {
             .map_err(SettlementRepositoryError::CorruptedData)
     }
 
+    async fn get_unpaid_settlements(
+        &self,
+        user_id: &Uuid,
+        other_id: &Uuid,
+    ) -> Result<Vec<Settlement>, SettlementRepositoryError> {
+        if self.crash_settlements.load(Relaxed) {
+            return Err(SettlementRepositoryError::CorruptedData("Crashed store"));
+        }
+        self.settlements
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|s| {
+                s.transactions.iter().any(|tr| {
+                    !tr.paid
+                        && ((tr.from == *user_id && tr.to == *other_id)
+                            || (tr.from == *other_id && tr.to == *user_id))
+                })
+            })
+            .sorted_by_key(|s| s.end_date)
+            .map(|s| Settlement::try_from(s.clone()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(SettlementRepositoryError::CorruptedData)
+    }
+
     async fn find(
         &self,
         settlement_id: &Uuid,
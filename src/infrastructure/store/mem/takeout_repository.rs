@@ -0,0 +1,57 @@
+use crate::application::store::{TakeoutRepository, TakeoutRepositoryError};
+use crate::domain::Takeout;
+use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerTakeout};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering::Relaxed;
+use uuid::Uuid;
+
+#[async_trait]
+impl TakeoutRepository for InMemoryStore {
+    type Tr = InMemTx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        takeout: &Takeout,
+    ) -> Result<(), TakeoutRepositoryError> {
+        if self.crash_takeouts.load(Relaxed) {
+            return Err(TakeoutRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .takeouts
+            .lock()
+            .unwrap()
+            .insert(takeout.user_id, InnerTakeout::from(takeout));
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &Uuid) -> Result<Option<Takeout>, TakeoutRepositoryError> {
+        if self.crash_takeouts.load(Relaxed) {
+            return Err(TakeoutRepositoryError::CorruptedData("Crashed store"));
+        }
+        Ok(self
+            .takeouts
+            .lock()
+            .unwrap()
+            .get(user_id)
+            .cloned()
+            .map(Takeout::from))
+    }
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        user_id: &Uuid,
+    ) -> Result<(), TakeoutRepositoryError> {
+        if self.crash_takeouts.load(Relaxed) {
+            return Err(TakeoutRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_takeouts
+            .lock()
+            .unwrap()
+            .insert(*user_id);
+        Ok(())
+    }
+}
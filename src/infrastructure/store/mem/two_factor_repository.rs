@@ -0,0 +1,74 @@
+use crate::application::auth::{TwoFactor, TwoFactorRepository, TwoFactorRepositoryError};
+use crate::domain::Email;
+use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerTwoFactor};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering::Relaxed;
+
+#[async_trait]
+impl TwoFactorRepository for InMemoryStore {
+    type Tr = InMemTx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        two_factor: &TwoFactor,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        if self.crash_two_factors.load(Relaxed) {
+            return Err(TwoFactorRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut().two_factors.lock().unwrap().insert(
+            String::from(two_factor.email.clone()),
+            InnerTwoFactor::from(two_factor),
+        );
+        Ok(())
+    }
+
+    async fn find(&self, email: &Email) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        if self.crash_two_factors.load(Relaxed) {
+            return Err(TwoFactorRepositoryError::CorruptedData("Crashed store"));
+        }
+        let two_factor = self
+            .two_factors
+            .lock()
+            .unwrap()
+            .get(&String::from(email.clone()))
+            .cloned();
+        match two_factor {
+            Some(two_factor) => Ok(Some(TwoFactor {
+                email: Email::try_from(two_factor.email)
+                    .map_err(TwoFactorRepositoryError::CorruptedData)?,
+                encrypted_secret: two_factor.encrypted_secret,
+                recovery_codes: two_factor.recovery_codes,
+                confirmed_at: two_factor.confirmed_at,
+                last_used_step: two_factor.last_used_step,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_for_update(
+        &self,
+        _tx: &mut RefCell<InMemTx>,
+        email: &Email,
+    ) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        // the memory store does not lock entries, its transactions being applied on commit
+        self.find(email).await
+    }
+
+    async fn delete(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        email: &Email,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        if self.crash_two_factors.load(Relaxed) {
+            return Err(TwoFactorRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_two_factors
+            .lock()
+            .unwrap()
+            .insert(String::from(email.clone()));
+        Ok(())
+    }
+}
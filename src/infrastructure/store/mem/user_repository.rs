@@ -29,6 +29,11 @@ impl UserRepository for InMemoryStore {
             email: String::from(user.email.clone()),
             role,
             created_at: user.created_at,
+            deleted_at: user.deleted_at,
+            sessions_revoked_at: user.sessions_revoked_at,
+            email_verified: user.email_verified,
+            disabled_at: user.disabled_at,
+            password_reset_required: user.password_reset_required,
         };
         self.users.lock().unwrap().insert(user.id, user);
         Ok(())
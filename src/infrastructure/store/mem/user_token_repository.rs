@@ -0,0 +1,102 @@
+use crate::application::auth::{
+    UserToken, UserTokenKind, UserTokenRepository, UserTokenRepositoryError,
+};
+use crate::infrastructure::store::mem::mem_store::{InMemTx, InMemoryStore, InnerUserToken};
+use async_trait::async_trait;
+use std::cell::RefCell;
+use std::sync::atomic::Ordering::Relaxed;
+use uuid::Uuid;
+
+#[async_trait]
+impl UserTokenRepository for InMemoryStore {
+    type Tr = InMemTx;
+
+    async fn save(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        token: &UserToken,
+    ) -> Result<(), UserTokenRepositoryError> {
+        if self.crash_user_tokens.load(Relaxed) {
+            return Err(UserTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .user_tokens
+            .lock()
+            .unwrap()
+            .insert(token.hash.clone(), InnerUserToken::from(token));
+        Ok(())
+    }
+
+    async fn find(&self, hash: &str) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        if self.crash_user_tokens.load(Relaxed) {
+            return Err(UserTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        Ok(self
+            .user_tokens
+            .lock()
+            .unwrap()
+            .get(hash)
+            .cloned()
+            .map(UserToken::from))
+    }
+
+    async fn take(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        hash: &str,
+        kind: UserTokenKind,
+    ) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        if self.crash_user_tokens.load(Relaxed) {
+            return Err(UserTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        let token = self
+            .user_tokens
+            .lock()
+            .unwrap()
+            .get(hash)
+            .filter(|t| t.kind == kind)
+            .cloned()
+            .map(UserToken::from);
+        if let Some(token) = &token {
+            tx.get_mut()
+                .deleted_user_tokens
+                .lock()
+                .unwrap()
+                .insert((token.user_id, kind));
+        }
+        Ok(token)
+    }
+
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        user_id: &Uuid,
+        kind: UserTokenKind,
+    ) -> Result<(), UserTokenRepositoryError> {
+        if self.crash_user_tokens.load(Relaxed) {
+            return Err(UserTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_user_tokens
+            .lock()
+            .unwrap()
+            .insert((*user_id, kind));
+        Ok(())
+    }
+
+    async fn delete_user_tokens(
+        &self,
+        tx: &mut RefCell<InMemTx>,
+        user_id: &Uuid,
+    ) -> Result<(), UserTokenRepositoryError> {
+        if self.crash_user_tokens.load(Relaxed) {
+            return Err(UserTokenRepositoryError::CorruptedData("Crashed store"));
+        }
+        tx.get_mut()
+            .deleted_all_user_tokens
+            .lock()
+            .unwrap()
+            .insert(*user_id);
+        Ok(())
+    }
+}
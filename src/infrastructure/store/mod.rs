@@ -2,16 +2,24 @@ pub mod mem;
 #[cfg(feature = "postgres")]
 mod postgres;
 
-use crate::application::auth::{CredentialRepository, CredentialRepositoryError, UserCredentials};
+use crate::application::auth::{
+    CredentialRepository, CredentialRepositoryError, TwoFactor, TwoFactorRepository,
+    TwoFactorRepositoryError, UserCredentials, UserToken, UserTokenKind, UserTokenRepository,
+    UserTokenRepositoryError,
+};
 use crate::application::store::{
+    ApiTokenRepository, ApiTokenRepositoryError, BudgetRepository, BudgetRepositoryError,
     DeviceRepository, DeviceRepositoryError, EventRepository, EventRepositoryError,
     ExpenseRepository, ExpenseRepositoryError, GroupRepository, GroupRepositoryError,
     MemberRepository, MemberRepositoryError, MultiRepository, SettlementRepository,
-    SettlementRepositoryError, Tx, UserRepository, UserRepositoryError,
+    SettlementRepositoryError, StoredEvent, TakeoutRepository, TakeoutRepositoryError, Tx,
+    UserRepository, UserRepositoryError,
 };
 use crate::configuration::store::DatabaseSettings;
 use crate::domain::{
-    Email, Event, Expense, Group, GroupMember, Settlement, SettlementDescription, User,
+    ApiToken, Budget, Email, Event, EventFilter, Expense, ExpenseFilter, ExpenseStats, Group,
+    GroupEvent, GroupMember, GroupSummary, Settlement, SettlementDescription, Takeout, User,
+    UserEvent,
 };
 use crate::infrastructure::store::mem::mem_store::InMemTx;
 use anyhow::Error;
@@ -55,6 +63,15 @@ impl StoreImpl {
             DatabaseSettings::Memory => Ok(StoreImpl::Memory(Arc::new(InMemoryStore::new()))),
         }
     }
+
+    /// Applies the migrations not yet applied to the database, the in memory store having none.
+    pub async fn migrate(&self) -> Result<(), anyhow::Error> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.migrate().await,
+            StoreImpl::Memory(_) => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
@@ -91,6 +108,10 @@ impl MultiRepository for StoreImpl {
         self
     }
 
+    fn budgets(&self) -> &dyn BudgetRepository<Tr = Self::KTransaction> {
+        self
+    }
+
     fn credentials(&self) -> &dyn CredentialRepository<Tr = Self::KTransaction> {
         self
     }
@@ -118,6 +139,71 @@ impl MultiRepository for StoreImpl {
     fn events(&self) -> &dyn EventRepository<Tr = Self::KTransaction> {
         self
     }
+
+    fn takeouts(&self) -> &dyn TakeoutRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn user_tokens(&self) -> &dyn UserTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn api_tokens(&self) -> &dyn ApiTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn two_factors(&self) -> &dyn TwoFactorRepository<Tr = Self::KTransaction> {
+        self
+    }
+}
+
+#[async_trait]
+impl BudgetRepository for StoreImpl {
+    type Tr = TransactionImpl;
+
+    #[allow(unreachable_patterns)]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        budget: &Budget,
+    ) -> Result<(), BudgetRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.budgets().save(tx, budget).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.budgets().save(tx, budget).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+    ) -> Result<(), BudgetRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.budgets().delete(tx, group_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.budgets().delete(tx, group_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    async fn find(&self, group_id: &Uuid) -> Result<Option<Budget>, BudgetRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.budgets().find(group_id).await,
+            StoreImpl::Memory(m) => m.budgets().find(group_id).await,
+        }
+    }
 }
 
 #[async_trait]
@@ -150,6 +236,28 @@ impl EventRepository for StoreImpl {
         }
     }
 
+    async fn get_group_events(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.events().get_group_events(group_id).await,
+            StoreImpl::Memory(m) => m.events().get_group_events(group_id).await,
+        }
+    }
+
+    async fn get_user_events(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<UserEvent>, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.events().get_user_events(user_id).await,
+            StoreImpl::Memory(m) => m.events().get_user_events(user_id).await,
+        }
+    }
+
     async fn mark_processed(&self, id: &Uuid) -> Result<(), EventRepositoryError> {
         match self {
             #[cfg(feature = "postgres")]
@@ -157,6 +265,109 @@ impl EventRepository for StoreImpl {
             StoreImpl::Memory(m) => m.events().mark_processed(id).await,
         }
     }
+
+    async fn get_events(
+        &self,
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.events().get_events(unprocessed_only, limit).await,
+            StoreImpl::Memory(m) => m.events().get_events(unprocessed_only, limit).await,
+        }
+    }
+
+    async fn purge(&self, processed_before: &DateTime<Utc>) -> Result<u64, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.events().purge(processed_before).await,
+            StoreImpl::Memory(m) => m.events().purge(processed_before).await,
+        }
+    }
+
+    async fn get_event_page(
+        &self,
+        filter: &EventFilter,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.events().get_event_page(filter, before, limit).await,
+            StoreImpl::Memory(m) => m.events().get_event_page(filter, before, limit).await,
+        }
+    }
+
+    async fn get_group_event_page(
+        &self,
+        group_id: &Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => {
+                p.events()
+                    .get_group_event_page(group_id, before, limit)
+                    .await
+            }
+            StoreImpl::Memory(m) => {
+                m.events()
+                    .get_group_event_page(group_id, before, limit)
+                    .await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl TakeoutRepository for StoreImpl {
+    type Tr = TransactionImpl;
+
+    #[allow(unreachable_patterns)]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        takeout: &Takeout,
+    ) -> Result<(), TakeoutRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.takeouts().save(tx, takeout).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.takeouts().save(tx, takeout).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    async fn find(&self, user_id: &Uuid) -> Result<Option<Takeout>, TakeoutRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.takeouts().find(user_id).await,
+            StoreImpl::Memory(m) => m.takeouts().find(user_id).await,
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), TakeoutRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.takeouts().delete(tx, user_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.takeouts().delete(tx, user_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
 }
 
 #[async_trait]
@@ -252,6 +463,256 @@ impl CredentialRepository for StoreImpl {
             StoreImpl::Memory(m) => m.credentials().fetch_by_email(email).await,
         }
     }
+
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), CredentialRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.credentials().delete(tx, email).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.credentials().delete(tx, email).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+}
+
+#[async_trait]
+impl UserTokenRepository for StoreImpl {
+    type Tr = TransactionImpl;
+
+    #[allow(unreachable_patterns)]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &UserToken,
+    ) -> Result<(), UserTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.user_tokens().save(tx, token).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.user_tokens().save(tx, token).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    async fn find(&self, hash: &str) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.user_tokens().find(hash).await,
+            StoreImpl::Memory(m) => m.user_tokens().find(hash).await,
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn take(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        hash: &str,
+        kind: UserTokenKind,
+    ) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.user_tokens().take(tx, hash, kind).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.user_tokens().take(tx, hash, kind).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+        kind: UserTokenKind,
+    ) -> Result<(), UserTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.user_tokens().delete_all(tx, user_id, kind).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.user_tokens().delete_all(tx, user_id, kind).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete_user_tokens(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), UserTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.user_tokens().delete_user_tokens(tx, user_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.user_tokens().delete_user_tokens(tx, user_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepository for StoreImpl {
+    type Tr = TransactionImpl;
+
+    #[allow(unreachable_patterns)]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        two_factor: &TwoFactor,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.two_factors().save(tx, two_factor).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.two_factors().save(tx, two_factor).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    async fn find(&self, email: &Email) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.two_factors().find(email).await,
+            StoreImpl::Memory(m) => m.two_factors().find(email).await,
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn find_for_update(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.two_factors().find_for_update(tx, email).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.two_factors().find_for_update(tx, email).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.two_factors().delete(tx, email).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.two_factors().delete(tx, email).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+}
+
+#[async_trait]
+impl ApiTokenRepository for StoreImpl {
+    type Tr = TransactionImpl;
+
+    #[allow(unreachable_patterns)]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &ApiToken,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.api_tokens().save(tx, token).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.api_tokens().save(tx, token).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiToken>, ApiTokenRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.api_tokens().find_by_hash(hash).await,
+            StoreImpl::Memory(m) => m.api_tokens().find_by_hash(hash).await,
+        }
+    }
+
+    async fn get_user_tokens(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.api_tokens().get_user_tokens(user_id).await,
+            StoreImpl::Memory(m) => m.api_tokens().get_user_tokens(user_id).await,
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.api_tokens().delete(tx, id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.api_tokens().delete(tx, id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.api_tokens().delete_all(tx, user_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.api_tokens().delete_all(tx, user_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
 }
 
 #[async_trait]
@@ -341,6 +802,17 @@ impl GroupRepository for StoreImpl {
         }
     }
 
+    async fn get_user_summaries(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<GroupSummary>, GroupRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => p.groups().get_user_summaries(user_id).await,
+            StoreImpl::Memory(m) => m.groups().get_user_summaries(user_id).await,
+        }
+    }
+
     async fn fetch_all_groups(&self) -> Result<Vec<Group>, GroupRepositoryError> {
         match self {
             #[cfg(feature = "postgres")]
@@ -372,6 +844,25 @@ impl MemberRepository for StoreImpl {
         }
     }
 
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), MemberRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.members().delete(tx, group_id, member_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.members().delete(tx, group_id, member_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
     async fn fetch_members(
         &self,
         group_id: &Uuid,
@@ -474,6 +965,50 @@ impl ExpenseRepository for StoreImpl {
             StoreImpl::Memory(m) => m.expenses().get_unsettled_expenses(group_id).await,
         }
     }
+
+    async fn get_stats(
+        &self,
+        group_id: &Uuid,
+        start_date: Option<&DateTime<Utc>>,
+        end_date: Option<&DateTime<Utc>>,
+        largest: u32,
+    ) -> Result<ExpenseStats, ExpenseRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => {
+                p.expenses()
+                    .get_stats(group_id, start_date, end_date, largest)
+                    .await
+            }
+            StoreImpl::Memory(m) => {
+                m.expenses()
+                    .get_stats(group_id, start_date, end_date, largest)
+                    .await
+            }
+        }
+    }
+
+    async fn get_expense_page(
+        &self,
+        group_id: &Uuid,
+        filter: &ExpenseFilter,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<(Expense, Option<Uuid>)>, ExpenseRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => {
+                p.expenses()
+                    .get_expense_page(group_id, filter, after, limit)
+                    .await
+            }
+            StoreImpl::Memory(m) => {
+                m.expenses()
+                    .get_expense_page(group_id, filter, after, limit)
+                    .await
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -498,6 +1033,42 @@ impl SettlementRepository for StoreImpl {
         }
     }
 
+    #[allow(unreachable_patterns)]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement_id: &Uuid,
+    ) -> Result<(), SettlementRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.settlements().delete(tx, settlement_id).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.settlements().delete(tx, settlement_id).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
+    #[allow(unreachable_patterns)]
+    async fn update_transactions(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement: &Settlement,
+    ) -> Result<(), SettlementRepositoryError> {
+        match (self, tx.get_mut()) {
+            #[cfg(feature = "postgres")]
+            (StoreImpl::Postgres(p), TransactionImpl::Postgres(tx)) => {
+                p.settlements().update_transactions(tx, settlement).await
+            }
+            (StoreImpl::Memory(m), TransactionImpl::Memory(tx)) => {
+                m.settlements().update_transactions(tx, settlement).await
+            }
+            (_, _) => panic!("Tried to pass non-matching store & transaction !!"),
+        }
+    }
+
     async fn get_settlements(
         &self,
         group_id: &Uuid,
@@ -528,6 +1099,26 @@ impl SettlementRepository for StoreImpl {
         }
     }
 
+    async fn get_unpaid_settlements(
+        &self,
+        user_id: &Uuid,
+        other_id: &Uuid,
+    ) -> Result<Vec<Settlement>, SettlementRepositoryError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            StoreImpl::Postgres(p) => {
+                p.settlements()
+                    .get_unpaid_settlements(user_id, other_id)
+                    .await
+            }
+            StoreImpl::Memory(m) => {
+                m.settlements()
+                    .get_unpaid_settlements(user_id, other_id)
+                    .await
+            }
+        }
+    }
+
     async fn find(
         &self,
         settlement_id: &Uuid,
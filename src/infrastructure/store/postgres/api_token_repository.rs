@@ -0,0 +1,139 @@
+use crate::application::store::{ApiTokenRepository, ApiTokenRepositoryError};
+use crate::domain::{ApiToken, ApiTokenScope};
+use crate::infrastructure::store::postgres::pg_store::PgStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use std::cell::RefCell;
+use uuid::Uuid;
+
+#[async_trait]
+impl ApiTokenRepository for PgStore {
+    type Tr = Transaction<'static, Postgres>;
+
+    #[tracing::instrument(name = "Save API token in DB", skip(self, tx, token), fields(token_id = %token.id))]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &ApiToken,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        INSERT INTO koru_api_token (id, user_id, name, token_hash, scope, created_at, expires_at, last_used_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (id) DO UPDATE SET last_used_at = EXCLUDED.last_used_at
+        "#,
+            token.id,
+            token.user_id,
+            token.name,
+            token.hash,
+            token.scope.as_str(),
+            token.created_at,
+            token.expires_at,
+            token.last_used_at,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| ApiTokenRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get API token from DB", skip(self, hash))]
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<ApiToken>, ApiTokenRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT id, user_id, name, token_hash, scope, created_at, expires_at, last_used_at
+        FROM koru_api_token
+        WHERE token_hash = $1
+        "#,
+            hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ApiTokenRepositoryError::Fetch(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(ApiToken {
+                id: row.id,
+                user_id: row.user_id,
+                name: row.name,
+                hash: row.token_hash,
+                scope: ApiTokenScope::try_from(row.scope.as_str())
+                    .map_err(ApiTokenRepositoryError::CorruptedData)?,
+                created_at: row.created_at,
+                expires_at: row.expires_at,
+                last_used_at: row.last_used_at,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "Get user API tokens from DB", skip(self))]
+    async fn get_user_tokens(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<ApiToken>, ApiTokenRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+        SELECT id, user_id, name, token_hash, scope, created_at, expires_at, last_used_at
+        FROM koru_api_token
+        WHERE user_id = $1
+        ORDER BY created_at
+        "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ApiTokenRepositoryError::Fetch(anyhow!(e)))?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(ApiToken {
+                    id: row.id,
+                    user_id: row.user_id,
+                    name: row.name,
+                    hash: row.token_hash,
+                    scope: ApiTokenScope::try_from(row.scope.as_str())
+                        .map_err(ApiTokenRepositoryError::CorruptedData)?,
+                    created_at: row.created_at,
+                    expires_at: row.expires_at,
+                    last_used_at: row.last_used_at,
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "Delete API token from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_api_token WHERE id = $1
+        "#,
+            id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| ApiTokenRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Delete user API tokens from DB", skip(self, tx))]
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), ApiTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_api_token WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| ApiTokenRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+}
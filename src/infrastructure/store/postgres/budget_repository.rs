@@ -0,0 +1,80 @@
+use crate::application::store::{BudgetRepository, BudgetRepositoryError};
+use crate::domain::{Amount, Budget, BudgetPeriod};
+use crate::infrastructure::store::postgres::pg_store::PgStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use std::cell::RefCell;
+use uuid::Uuid;
+
+#[async_trait]
+impl BudgetRepository for PgStore {
+    type Tr = Transaction<'static, Postgres>;
+
+    #[tracing::instrument(name = "Save budget in DB", skip(self, tx))]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        budget: &Budget,
+    ) -> Result<(), BudgetRepositoryError> {
+        sqlx::query!(
+            r#"
+        INSERT INTO koru_budget (group_id, amount, period, updated_at) VALUES ($1, $2, $3, $4)
+        ON CONFLICT (group_id) DO UPDATE SET
+            amount = EXCLUDED.amount,
+            period = EXCLUDED.period,
+            updated_at = EXCLUDED.updated_at
+        "#,
+            budget.group_id,
+            f32::from(budget.amount),
+            String::from(budget.period),
+            budget.updated_at
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| BudgetRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Delete budget in DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+    ) -> Result<(), BudgetRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_budget WHERE group_id = $1
+        "#,
+            group_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| BudgetRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get budget from DB", skip(self))]
+    async fn find(&self, group_id: &Uuid) -> Result<Option<Budget>, BudgetRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT group_id, amount, period, updated_at FROM koru_budget WHERE group_id = $1
+        "#,
+            group_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BudgetRepositoryError::Fetch(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(Budget {
+                group_id: row.group_id,
+                amount: Amount::try_from(row.amount)
+                    .map_err(BudgetRepositoryError::CorruptedData)?,
+                period: BudgetPeriod::try_from(row.period)
+                    .map_err(BudgetRepositoryError::CorruptedData)?,
+                updated_at: row.updated_at,
+            })),
+            None => Ok(None),
+        }
+    }
+}
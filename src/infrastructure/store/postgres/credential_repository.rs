@@ -22,6 +22,7 @@ impl CredentialRepository for PgStore {
         sqlx::query!(
             r#"
         INSERT INTO koru_user_credentials (email, password) VALUES ($1, $2)
+        ON CONFLICT (email) DO UPDATE SET password = EXCLUDED.password
         "#,
             String::from(user_credentials.email.clone()),
             user_credentials
@@ -60,4 +61,22 @@ impl CredentialRepository for PgStore {
             None => Ok(None),
         }
     }
+
+    #[tracing::instrument(name = "Delete user credentials from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), CredentialRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_user_credentials WHERE email = $1
+        "#,
+            String::from(email.clone()),
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| CredentialRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
-use crate::application::store::{EventRepository, EventRepositoryError};
+use crate::application::store::{EventRepository, EventRepositoryError, StoredEvent};
 use crate::domain::{
-    Amount, Event, GroupEvent, GroupEventKind, MemberColor, UserEvent, UserEventKind,
+    Amount, BudgetPeriod, Event, EventFilter, EventOrigin, GroupEvent, GroupEventKind, MemberColor,
+    UserEvent, UserEventKind,
 };
 use crate::infrastructure::store::postgres::pg_store::PgStore;
 use crate::utils::date;
@@ -24,13 +25,21 @@ impl EventRepository for PgStore {
         tx: &mut RefCell<Self::Tr>,
         events: &[Event],
     ) -> Result<(), EventRepositoryError> {
-        let mut query: QueryBuilder<Postgres> =
-            QueryBuilder::new("INSERT INTO koru_event (id, event_date, event_data) ");
+        let origin = EventOrigin::current();
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO koru_event (id, event_date, event_data, event_kind, user_id, group_id, actor_id, ip, user_agent) ",
+        );
         query.push_values(events, |mut b, event| {
-            let event = EventDto::from(event.clone());
-            b.push_bind(event.id)
-                .push_bind(event.date)
-                .push_bind(event.data);
+            let dto = EventDto::from(event.clone());
+            b.push_bind(dto.id)
+                .push_bind(dto.date)
+                .push_bind(dto.data)
+                .push_bind(event.name())
+                .push_bind(event.user_id())
+                .push_bind(event.group_id())
+                .push_bind(event.actor_id())
+                .push_bind(origin.ip.clone())
+                .push_bind(origin.user_agent.clone());
         });
         query
             .build()
@@ -68,6 +77,74 @@ impl EventRepository for PgStore {
         }
     }
 
+    #[tracing::instrument(name = "Get group events from DB", skip(self))]
+    async fn get_group_events(
+        &self,
+        group_id: &Uuid,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        // the group id is stored as `id` in the group creation and deletion events,
+        // as `group_id` in the other group events
+        let rows = sqlx::query!(
+            r#"
+            SELECT e.id, e.event_date, e.event_data
+                FROM koru_event e, json_each(e.event_data) kind
+                WHERE COALESCE(kind.value ->> 'group_id', kind.value ->> 'id') = $1
+                ORDER BY e.event_date
+            "#,
+            group_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?;
+        let mut events = Vec::new();
+        for row in rows {
+            let dto = EventDto {
+                id: row.id,
+                date: row.event_date,
+                data: serde_json::from_value(row.event_data)
+                    .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?,
+            };
+            match dto.event().map_err(EventRepositoryError::CorruptedData)? {
+                Event::Group(event) => events.push(event),
+                Event::User(_) => {}
+            }
+        }
+        Ok(events)
+    }
+
+    #[tracing::instrument(name = "Get user events from DB", skip(self))]
+    async fn get_user_events(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<UserEvent>, EventRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT e.id, e.event_date, e.event_data
+                FROM koru_event e, json_each(e.event_data) kind
+                WHERE kind.value ->> 'user_id' = $1
+                ORDER BY e.event_date
+            "#,
+            user_id.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?;
+        let mut events = Vec::new();
+        for row in rows {
+            let dto = EventDto {
+                id: row.id,
+                date: row.event_date,
+                data: serde_json::from_value(row.event_data)
+                    .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?,
+            };
+            match dto.event().map_err(EventRepositoryError::CorruptedData)? {
+                Event::User(event) => events.push(event),
+                Event::Group(_) => {}
+            }
+        }
+        Ok(events)
+    }
+
     #[tracing::instrument(name = "Mark event processed in DB", skip(self))]
     async fn mark_processed(&self, id: &Uuid) -> Result<(), EventRepositoryError> {
         sqlx::query!(
@@ -80,6 +157,150 @@ impl EventRepository for PgStore {
         .map_err(|e| EventRepositoryError::Update(anyhow!(e)))?;
         Ok(())
     }
+
+    #[tracing::instrument(name = "Get events from DB", skip(self))]
+    async fn get_events(
+        &self,
+        unprocessed_only: bool,
+        limit: Option<u32>,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_date, event_data, process_date, ip, user_agent
+                FROM koru_event
+                WHERE process_date IS NULL OR NOT $1
+                ORDER BY event_date DESC
+                LIMIT $2
+            "#,
+            unprocessed_only,
+            limit.map(i64::from)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?;
+        let mut events = Vec::new();
+        for row in rows {
+            let dto = EventDto {
+                id: row.id,
+                date: row.event_date,
+                data: serde_json::from_value(row.event_data)
+                    .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?,
+            };
+            events.push(StoredEvent {
+                event: dto.event().map_err(EventRepositoryError::CorruptedData)?,
+                origin: EventOrigin {
+                    ip: row.ip,
+                    user_agent: row.user_agent,
+                },
+                processed_at: row.process_date,
+            });
+        }
+        Ok(events)
+    }
+
+    #[tracing::instrument(name = "Purge events from DB", skip(self))]
+    async fn purge(&self, processed_before: &DateTime<Utc>) -> Result<u64, EventRepositoryError> {
+        let result = sqlx::query!(
+            "DELETE FROM koru_event WHERE process_date < $1",
+            processed_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Delete(anyhow!(e)))?;
+        Ok(result.rows_affected())
+    }
+
+    #[tracing::instrument(name = "Get event page from DB", skip(self))]
+    async fn get_event_page(
+        &self,
+        filter: &EventFilter,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, EventRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_date, event_data, process_date, ip, user_agent
+                FROM koru_event
+                WHERE ($1::uuid IS NULL OR user_id = $1 OR actor_id = $1)
+                    AND ($2::uuid IS NULL OR group_id = $2)
+                    AND ($3::text IS NULL OR event_kind = $3)
+                    AND ($4::timestamptz IS NULL OR event_date > $4)
+                    AND ($5::timestamptz IS NULL OR event_date <= $5)
+                    AND ($6::timestamptz IS NULL OR (event_date, id) < ($6, $7))
+                ORDER BY event_date DESC, id DESC
+                LIMIT $8
+            "#,
+            filter.user_id,
+            filter.group_id,
+            filter.kind,
+            filter.from,
+            filter.to,
+            before.map(|(date, _)| date),
+            before.map(|(_, id)| id),
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?;
+        let mut events = Vec::new();
+        for row in rows {
+            let dto = EventDto {
+                id: row.id,
+                date: row.event_date,
+                data: serde_json::from_value(row.event_data)
+                    .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?,
+            };
+            events.push(StoredEvent {
+                event: dto.event().map_err(EventRepositoryError::CorruptedData)?,
+                origin: EventOrigin {
+                    ip: row.ip,
+                    user_agent: row.user_agent,
+                },
+                processed_at: row.process_date,
+            });
+        }
+        Ok(events)
+    }
+
+    #[tracing::instrument(name = "Get group event page from DB", skip(self))]
+    async fn get_group_event_page(
+        &self,
+        group_id: &Uuid,
+        before: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<GroupEvent>, EventRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, event_date, event_data
+                FROM koru_event
+                WHERE group_id = $1
+                    AND ($2::timestamptz IS NULL OR (event_date, id) < ($2, $3))
+                ORDER BY event_date DESC, id DESC
+                LIMIT $4
+            "#,
+            group_id,
+            before.map(|(date, _)| date),
+            before.map(|(_, id)| id),
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?;
+        let mut events = Vec::new();
+        for row in rows {
+            let dto = EventDto {
+                id: row.id,
+                date: row.event_date,
+                data: serde_json::from_value(row.event_data)
+                    .map_err(|e| EventRepositoryError::Fetch(anyhow!(e)))?,
+            };
+            match dto.event().map_err(EventRepositoryError::CorruptedData)? {
+                Event::Group(event) => events.push(event),
+                Event::User(_) => {}
+            }
+        }
+        Ok(events)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -211,6 +432,43 @@ impl EventDto {
                     },
                 })
             }
+            EventKindDto::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::SettlementReverted { id },
+            }),
+            EventKindDto::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::BudgetChanged {
+                    amount,
+                    period: period.map(BudgetPeriod::try_from).transpose()?,
+                },
+            }),
+            EventKindDto::OwnershipTransferred {
+                group_id,
+                member_id,
+                new_admin_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::OwnershipTransferred { new_admin_id },
+            }),
             EventKindDto::GroupDeleted { id, admin_id } => Event::Group(GroupEvent {
                 id: self.id,
                 event_date: self.date,
@@ -218,6 +476,39 @@ impl EventDto {
                 member_id: admin_id,
                 event: GroupEventKind::GroupDeleted,
             }),
+            EventKindDto::GroupArchived {
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::GroupArchived,
+            }),
+            EventKindDto::GroupUnarchived {
+                group_id,
+                member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::GroupUnarchived,
+            }),
+            EventKindDto::MemberRemoved {
+                group_id,
+                member_id,
+                removed_member_id,
+            } => Event::Group(GroupEvent {
+                id: self.id,
+                event_date: self.date,
+                group_id,
+                member_id,
+                event: GroupEventKind::MemberRemoved {
+                    member_id: removed_member_id,
+                },
+            }),
 
             EventKindDto::UserCreated {
                 user_id,
@@ -247,6 +538,124 @@ impl EventDto {
                 user_id,
                 event: UserEventKind::Logout,
             }),
+            EventKindDto::UserTakeoutRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TakeoutRequested,
+            }),
+            EventKindDto::UserNameChanged {
+                user_id,
+                previous_name,
+                new_name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::NameChanged {
+                    previous_name,
+                    new_name,
+                },
+            }),
+            EventKindDto::UserEmailChanged {
+                user_id,
+                previous_email,
+                new_email,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailChanged {
+                    previous_email,
+                    new_email,
+                },
+            }),
+            EventKindDto::UserPasswordChanged { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordChanged,
+            }),
+            EventKindDto::UserPasswordResetRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordResetRequested,
+            }),
+            EventKindDto::UserEmailVerificationRequested { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailVerificationRequested,
+            }),
+            EventKindDto::UserEmailVerified { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::EmailVerified,
+            }),
+            EventKindDto::UserApiTokenCreated {
+                user_id,
+                token_id,
+                name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::ApiTokenCreated { token_id, name },
+            }),
+            EventKindDto::UserApiTokenRevoked {
+                user_id,
+                token_id,
+                name,
+            } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::ApiTokenRevoked { token_id, name },
+            }),
+            EventKindDto::UserTwoFactorEnabled { user_id } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TwoFactorEnabled,
+            }),
+            EventKindDto::UserTwoFactorReset { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::TwoFactorReset { by },
+            }),
+            EventKindDto::UserRoleChanged { user_id, role, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::RoleChanged { role, by },
+            }),
+            EventKindDto::UserDisabled { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::Disabled { by },
+            }),
+            EventKindDto::UserEnabled { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::Enabled { by },
+            }),
+            EventKindDto::UserPasswordResetForced { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::PasswordResetForced { by },
+            }),
+            EventKindDto::UserDeletedByAdmin { user_id, by } => Event::User(UserEvent {
+                id: self.id,
+                event_date: self.date,
+                user_id,
+                event: UserEventKind::DeletedByAdmin { by },
+            }),
         };
         Ok(e)
     }
@@ -330,10 +739,39 @@ enum EventKindDto {
         end_date: DateTime<Utc>,
         transactions: Vec<TransactionDto>,
     },
+    SettlementReverted {
+        id: Uuid,
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    BudgetChanged {
+        group_id: Uuid,
+        member_id: Uuid,
+        amount: Option<f32>,
+        period: Option<String>,
+    },
+    OwnershipTransferred {
+        group_id: Uuid,
+        member_id: Uuid,
+        new_admin_id: Uuid,
+    },
     GroupDeleted {
         id: Uuid,
         admin_id: Uuid,
     },
+    GroupArchived {
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    GroupUnarchived {
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    MemberRemoved {
+        group_id: Uuid,
+        member_id: Uuid,
+        removed_member_id: Uuid,
+    },
     UserCreated {
         user_id: Uuid,
         name: String,
@@ -348,6 +786,69 @@ enum EventKindDto {
     UserLogout {
         user_id: Uuid,
     },
+    UserTakeoutRequested {
+        user_id: Uuid,
+    },
+    UserNameChanged {
+        user_id: Uuid,
+        previous_name: String,
+        new_name: String,
+    },
+    UserEmailChanged {
+        user_id: Uuid,
+        previous_email: String,
+        new_email: String,
+    },
+    UserPasswordChanged {
+        user_id: Uuid,
+    },
+    UserPasswordResetRequested {
+        user_id: Uuid,
+    },
+    UserEmailVerificationRequested {
+        user_id: Uuid,
+    },
+    UserEmailVerified {
+        user_id: Uuid,
+    },
+    UserApiTokenCreated {
+        user_id: Uuid,
+        token_id: Uuid,
+        name: String,
+    },
+    UserApiTokenRevoked {
+        user_id: Uuid,
+        token_id: Uuid,
+        name: String,
+    },
+    UserTwoFactorEnabled {
+        user_id: Uuid,
+    },
+    UserTwoFactorReset {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserRoleChanged {
+        user_id: Uuid,
+        role: String,
+        by: Uuid,
+    },
+    UserDisabled {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserEnabled {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserPasswordResetForced {
+        user_id: Uuid,
+        by: Uuid,
+    },
+    UserDeletedByAdmin {
+        user_id: Uuid,
+        by: Uuid,
+    },
 }
 
 impl EventKindDto {
@@ -422,10 +923,43 @@ impl EventKindDto {
                     .map(|t| TransactionDto::from(t.clone()))
                     .collect_vec(),
             },
+            GroupEventKind::SettlementReverted { id } => EventKindDto::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            },
+            GroupEventKind::BudgetChanged { amount, period } => EventKindDto::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period: period.map(String::from),
+            },
+            GroupEventKind::OwnershipTransferred { new_admin_id } => {
+                EventKindDto::OwnershipTransferred {
+                    group_id,
+                    member_id,
+                    new_admin_id,
+                }
+            }
             GroupEventKind::GroupDeleted => EventKindDto::GroupDeleted {
                 id: group_id,
                 admin_id: member_id,
             },
+            GroupEventKind::GroupArchived => EventKindDto::GroupArchived {
+                group_id,
+                member_id,
+            },
+            GroupEventKind::GroupUnarchived => EventKindDto::GroupUnarchived {
+                group_id,
+                member_id,
+            },
+            GroupEventKind::MemberRemoved {
+                member_id: removed_member_id,
+            } => EventKindDto::MemberRemoved {
+                group_id,
+                member_id,
+                removed_member_id,
+            },
         }
     }
     fn from_user(e: UserEventKind, user_id: Uuid) -> Self {
@@ -438,6 +972,60 @@ impl EventKindDto {
             UserEventKind::Login => EventKindDto::UserLogin { user_id },
             UserEventKind::Logout => EventKindDto::UserLogout { user_id },
             UserEventKind::Deleted => EventKindDto::UserDeleted { user_id },
+            UserEventKind::TakeoutRequested => EventKindDto::UserTakeoutRequested { user_id },
+            UserEventKind::NameChanged {
+                previous_name,
+                new_name,
+            } => EventKindDto::UserNameChanged {
+                user_id,
+                previous_name,
+                new_name,
+            },
+            UserEventKind::EmailChanged {
+                previous_email,
+                new_email,
+            } => EventKindDto::UserEmailChanged {
+                user_id,
+                previous_email,
+                new_email,
+            },
+            UserEventKind::PasswordChanged => EventKindDto::UserPasswordChanged { user_id },
+            UserEventKind::PasswordResetRequested => {
+                EventKindDto::UserPasswordResetRequested { user_id }
+            }
+            UserEventKind::EmailVerificationRequested => {
+                EventKindDto::UserEmailVerificationRequested { user_id }
+            }
+            UserEventKind::EmailVerified => EventKindDto::UserEmailVerified { user_id },
+            UserEventKind::ApiTokenCreated { token_id, name } => {
+                EventKindDto::UserApiTokenCreated {
+                    user_id,
+                    token_id,
+                    name,
+                }
+            }
+            UserEventKind::ApiTokenRevoked { token_id, name } => {
+                EventKindDto::UserApiTokenRevoked {
+                    user_id,
+                    token_id,
+                    name,
+                }
+            }
+            UserEventKind::TwoFactorEnabled => EventKindDto::UserTwoFactorEnabled { user_id },
+            UserEventKind::TwoFactorReset { by } => {
+                EventKindDto::UserTwoFactorReset { user_id, by }
+            }
+            UserEventKind::RoleChanged { role, by } => {
+                EventKindDto::UserRoleChanged { user_id, role, by }
+            }
+            UserEventKind::Disabled { by } => EventKindDto::UserDisabled { user_id, by },
+            UserEventKind::Enabled { by } => EventKindDto::UserEnabled { user_id, by },
+            UserEventKind::PasswordResetForced { by } => {
+                EventKindDto::UserPasswordResetForced { user_id, by }
+            }
+            UserEventKind::DeletedByAdmin { by } => {
+                EventKindDto::UserDeletedByAdmin { user_id, by }
+            }
         }
     }
 }
@@ -466,6 +1054,7 @@ impl TryFrom<TransactionDto> for crate::domain::Transaction {
             from: t.from,
             to: t.to,
             amount: Amount::try_from(t.amount)?,
+            paid: false,
         })
     }
 }
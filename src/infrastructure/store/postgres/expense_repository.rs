@@ -1,5 +1,7 @@
 use crate::application::store::{ExpenseRepository, ExpenseRepositoryError};
-use crate::domain::{Amount, Expense, ExpenseTitle};
+use crate::domain::{
+    Amount, Expense, ExpenseFilter, ExpenseStats, ExpenseTitle, MemberTotal, MonthTotal,
+};
 use crate::infrastructure::store::postgres::pg_store::PgStore;
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -224,4 +226,160 @@ impl ExpenseRepository for PgStore {
         }
         Ok(expenses)
     }
+
+    #[tracing::instrument(name = "Get expense stats from DB", skip(self))]
+    async fn get_stats(
+        &self,
+        group_id: &Uuid,
+        start_date: Option<&DateTime<Utc>>,
+        end_date: Option<&DateTime<Utc>>,
+        largest: u32,
+    ) -> Result<ExpenseStats, ExpenseRepositoryError> {
+        let start_date = start_date.cloned();
+        let end_date = end_date.cloned();
+        let members = sqlx::query!(
+            r#"
+            SELECT member_id, SUM(amount) as "total!", COUNT(*) as "count!"
+                FROM koru_expense
+                WHERE group_id = $1
+                AND ($2::timestamptz IS NULL OR created_at > $2)
+                AND ($3::timestamptz IS NULL OR created_at <= $3)
+                GROUP BY member_id
+                ORDER BY 2 DESC, member_id
+            "#,
+            group_id,
+            start_date,
+            end_date,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ExpenseRepositoryError::Fetch(anyhow!(e)))?
+        .into_iter()
+        .map(|row| MemberTotal {
+            member_id: row.member_id,
+            total: row.total,
+            count: row.count as u32,
+        })
+        .collect();
+        let months = sqlx::query!(
+            r#"
+            SELECT date_trunc('month', created_at AT TIME ZONE 'UTC') AT TIME ZONE 'UTC' as "month!",
+                SUM(amount) as "total!", COUNT(*) as "count!"
+                FROM koru_expense
+                WHERE group_id = $1
+                AND ($2::timestamptz IS NULL OR created_at > $2)
+                AND ($3::timestamptz IS NULL OR created_at <= $3)
+                GROUP BY 1
+                ORDER BY 1
+            "#,
+            group_id,
+            start_date,
+            end_date,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ExpenseRepositoryError::Fetch(anyhow!(e)))?
+        .into_iter()
+        .map(|row| MonthTotal {
+            month: row.month,
+            total: row.total,
+            count: row.count as u32,
+        })
+        .collect();
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, group_id, member_id, description, amount, created_at, modified_at, settled
+                FROM koru_expense
+                WHERE group_id = $1
+                AND ($2::timestamptz IS NULL OR created_at > $2)
+                AND ($3::timestamptz IS NULL OR created_at <= $3)
+                ORDER BY amount DESC, created_at DESC
+                LIMIT $4
+            "#,
+            group_id,
+            start_date,
+            end_date,
+            largest as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ExpenseRepositoryError::Fetch(anyhow!(e)))?;
+        let mut expenses = Vec::new();
+        for row in rows {
+            expenses.push(Expense {
+                id: row.id,
+                group_id: row.group_id,
+                member_id: row.member_id,
+                title: ExpenseTitle::try_from(row.description)
+                    .map_err(ExpenseRepositoryError::CorruptedData)?,
+                amount: Amount::try_from(row.amount)
+                    .map_err(ExpenseRepositoryError::CorruptedData)?,
+                created_at: row.created_at,
+                modified_at: row.modified_at,
+                settled: row.settled,
+            });
+        }
+        Ok(ExpenseStats::new(members, months, expenses))
+    }
+
+    #[tracing::instrument(name = "Get expense page from DB", skip(self))]
+    async fn get_expense_page(
+        &self,
+        group_id: &Uuid,
+        filter: &ExpenseFilter,
+        after: Option<(DateTime<Utc>, Uuid)>,
+        limit: u32,
+    ) -> Result<Vec<(Expense, Option<Uuid>)>, ExpenseRepositoryError> {
+        let (unsettled, from, to, settlement_id) = match *filter {
+            ExpenseFilter::Unsettled => (true, None, None, None),
+            ExpenseFilter::Period { from, to } => (false, from, to, None),
+            ExpenseFilter::Settlement(id) => (false, None, None, Some(id)),
+        };
+        let rows = sqlx::query!(
+            r#"
+        SELECT e.id, e.group_id, e.member_id, e.description, e.amount, e.created_at,
+            e.modified_at, e.settled, se.settlement_id as "settlement_id?"
+        FROM koru_expense e
+        LEFT JOIN koru_settlement_expenses se ON se.expense_id = e.id
+        WHERE e.group_id = $1
+            AND ($2 = false OR e.settled = false)
+            AND ($3::timestamptz IS NULL OR e.created_at > $3)
+            AND ($4::timestamptz IS NULL OR e.created_at <= $4)
+            AND ($5::uuid IS NULL OR se.settlement_id = $5)
+            AND ($6::timestamptz IS NULL OR (e.created_at, e.id) > ($6, $7))
+        ORDER BY e.created_at, e.id
+        LIMIT $8
+        "#,
+            group_id,
+            unsettled,
+            from,
+            to,
+            settlement_id,
+            after.map(|(date, _)| date),
+            after.map(|(_, id)| id),
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ExpenseRepositoryError::Fetch(anyhow!(e)))?;
+        let mut expenses = Vec::new();
+        for row in rows {
+            expenses.push((
+                Expense {
+                    id: row.id,
+                    group_id: row.group_id,
+                    member_id: row.member_id,
+                    title: ExpenseTitle::try_from(row.description)
+                        .map_err(ExpenseRepositoryError::CorruptedData)?,
+                    amount: Amount::try_from(row.amount)
+                        .map_err(ExpenseRepositoryError::CorruptedData)?,
+                    created_at: row.created_at,
+                    modified_at: row.modified_at,
+                    settled: row.settled,
+                },
+                row.settlement_id,
+            ));
+        }
+        Ok(expenses)
+    }
 }
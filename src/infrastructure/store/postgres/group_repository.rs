@@ -1,5 +1,7 @@
 use crate::application::store::{GroupRepository, GroupRepositoryError, MemberRepository};
-use crate::domain::{Group, GroupName};
+use crate::domain::{
+    Amount, Email, Group, GroupMember, GroupName, GroupSummary, MemberColor, UserName,
+};
 use crate::infrastructure::store::postgres::pg_store::PgStore;
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -35,6 +37,79 @@ impl PgStore {
         .map(|rows| rows.into_iter().map(|r| r.id).collect())
         .map_err(|e| GroupRepositoryError::Fetch(anyhow!(e)))
     }
+
+    #[tracing::instrument(name = "Get user unpaid transactions from DB", skip(self))]
+    async fn get_unpaid_transactions(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<(Uuid, crate::domain::Transaction)>, GroupRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+        SELECT s.group_id, t.from_user_id, t.to_user_id, t.amount
+        FROM koru_transaction t
+        JOIN koru_settlement s ON s.id = t.settlement_id
+        WHERE t.paid = false AND (t.from_user_id = $1 OR t.to_user_id = $1)
+        "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GroupRepositoryError::Fetch(anyhow!(e)))?;
+        let mut res = Vec::new();
+        for row in rows {
+            res.push((
+                row.group_id,
+                crate::domain::Transaction {
+                    from: row.from_user_id,
+                    to: row.to_user_id,
+                    amount: Amount::try_from(row.amount)
+                        .map_err(GroupRepositoryError::CorruptedData)?,
+                    paid: false,
+                },
+            ))
+        }
+        Ok(res)
+    }
+
+    /// Fetches the members taking part in the unpaid transactions of the user, in all their groups.
+    async fn get_transaction_members(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<GroupMember>, GroupRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+        SELECT m.user_id, m.group_id, m.joined_at, m.color, u.name, u.email,
+            g.admin_id = m.user_id as "is_admin!"
+        FROM koru_group_members m
+        JOIN koru_user u ON u.id = m.user_id
+        JOIN koru_group g ON g.id = m.group_id
+        WHERE (m.group_id, m.user_id) IN (
+            SELECT s.group_id, UNNEST(ARRAY[t.from_user_id, t.to_user_id])
+            FROM koru_transaction t
+            JOIN koru_settlement s ON s.id = t.settlement_id
+            WHERE t.paid = false AND (t.from_user_id = $1 OR t.to_user_id = $1)
+        )
+        "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GroupRepositoryError::Fetch(anyhow!(e)))?;
+        let mut members = Vec::new();
+        for row in rows {
+            members.push(GroupMember {
+                id: row.user_id,
+                name: UserName::try_from(row.name).map_err(GroupRepositoryError::CorruptedData)?,
+                email: Email::try_from(row.email).map_err(GroupRepositoryError::CorruptedData)?,
+                group_id: row.group_id,
+                is_admin: row.is_admin,
+                color: MemberColor::try_from(row.color)
+                    .map_err(GroupRepositoryError::CorruptedData)?,
+                joined_at: row.joined_at,
+            })
+        }
+        Ok(members)
+    }
 }
 
 #[async_trait]
@@ -49,13 +124,14 @@ impl GroupRepository for PgStore {
     ) -> Result<(), GroupRepositoryError> {
         sqlx::query!(
             r#"
-        INSERT INTO koru_group (id, name, admin_id, created_at) VALUES ($1, $2, $3, $4)
-        ON CONFLICT DO NOTHING
+        INSERT INTO koru_group (id, name, admin_id, created_at, archived_at) VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (id) DO UPDATE SET admin_id = EXCLUDED.admin_id, archived_at = EXCLUDED.archived_at
         "#,
             group.id,
             String::from(group.name.clone()),
             group.admin_id,
-            group.created_at
+            group.created_at,
+            group.archived_at
         )
         .execute(tx.get_mut())
         .await
@@ -85,7 +161,7 @@ impl GroupRepository for PgStore {
     async fn find(&self, group_id: &Uuid) -> Result<Option<Group>, GroupRepositoryError> {
         let row = sqlx::query!(
             r#"
-        SELECT id, name, admin_id, created_at FROM koru_group WHERE id = $1
+        SELECT id, name, admin_id, created_at, archived_at FROM koru_group WHERE id = $1
         "#,
             group_id,
         )
@@ -106,6 +182,7 @@ impl GroupRepository for PgStore {
                         .map_err(GroupRepositoryError::CorruptedData)?,
                     admin_id: r.admin_id,
                     created_at: r.created_at,
+                    archived_at: r.archived_at,
                     members,
                     expense_ids: expenses,
                     settlement_ids: settlements,
@@ -139,6 +216,61 @@ impl GroupRepository for PgStore {
         Ok(groups)
     }
 
+    #[tracing::instrument(name = "Get user group summaries from DB", skip(self))]
+    async fn get_user_summaries(
+        &self,
+        user_id: &Uuid,
+    ) -> Result<Vec<GroupSummary>, GroupRepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+        SELECT g.id, g.name,
+            (SELECT COALESCE(SUM(e.amount), 0) FROM koru_expense e
+                WHERE e.group_id = g.id AND e.settled = false AND e.member_id = $1) as "paid!",
+            (SELECT COALESCE(SUM(e.amount), 0) FROM koru_expense e
+                WHERE e.group_id = g.id AND e.settled = false) as "total!",
+            (SELECT COUNT(*) FROM koru_group_members m WHERE m.group_id = g.id) as "member_count!",
+            GREATEST(
+                g.created_at,
+                (SELECT MAX(m.joined_at) FROM koru_group_members m WHERE m.group_id = g.id),
+                (SELECT MAX(COALESCE(e.modified_at, e.created_at)) FROM koru_expense e
+                    WHERE e.group_id = g.id),
+                (SELECT MAX(s.end_date) FROM koru_settlement s WHERE s.group_id = g.id)
+            ) as "last_activity!"
+        FROM koru_group g
+        WHERE g.admin_id = $1
+            OR g.id IN (SELECT group_id FROM koru_group_members WHERE user_id = $1)
+        "#,
+            user_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GroupRepositoryError::Fetch(anyhow!(e)))?;
+        let transactions = self.get_unpaid_transactions(user_id).await?;
+        let members = self.get_transaction_members(user_id).await?;
+        let mut summaries = Vec::new();
+        for r in rows {
+            summaries.push(GroupSummary {
+                group_id: r.id,
+                name: GroupName::try_from(r.name).map_err(GroupRepositoryError::CorruptedData)?,
+                paid: r.paid,
+                total: r.total,
+                member_count: r.member_count as u32,
+                last_activity: r.last_activity,
+                transactions: transactions
+                    .iter()
+                    .filter(|(group_id, _)| *group_id == r.id)
+                    .map(|(_, t)| t.clone())
+                    .collect(),
+                members: members
+                    .iter()
+                    .filter(|m| m.group_id == r.id)
+                    .cloned()
+                    .collect(),
+            })
+        }
+        Ok(summaries)
+    }
+
     async fn fetch_all_groups(&self) -> Result<Vec<Group>, GroupRepositoryError> {
         let ids: Vec<Uuid> = sqlx::query!(
             r#"
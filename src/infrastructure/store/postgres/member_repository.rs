@@ -34,6 +34,26 @@ impl MemberRepository for PgStore {
         Ok(())
     }
 
+    #[tracing::instrument(name = "Delete member from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        group_id: &Uuid,
+        member_id: &Uuid,
+    ) -> Result<(), MemberRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_group_members WHERE group_id = $1 AND user_id = $2
+        "#,
+            group_id,
+            member_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| MemberRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+
     #[tracing::instrument(name = "Get group members from DB", skip(self))]
     async fn fetch_members(
         &self,
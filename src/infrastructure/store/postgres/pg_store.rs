@@ -1,7 +1,8 @@
-use crate::application::auth::CredentialRepository;
+use crate::application::auth::{CredentialRepository, TwoFactorRepository, UserTokenRepository};
 use crate::application::store::{
-    DeviceRepository, EventRepository, ExpenseRepository, GroupRepository, MemberRepository,
-    MultiRepository, SettlementRepository, Tx, UserRepository,
+    ApiTokenRepository, BudgetRepository, DeviceRepository, EventRepository, ExpenseRepository,
+    GroupRepository, MemberRepository, MultiRepository, SettlementRepository, TakeoutRepository,
+    Tx, UserRepository,
 };
 use anyhow::{Context, Error};
 use async_trait::async_trait;
@@ -16,6 +17,13 @@ impl PgStore {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    pub async fn migrate(&self) -> Result<(), anyhow::Error> {
+        sqlx::migrate!("./migrations")
+            .run(&self.pool)
+            .await
+            .context("Failed to migrate the database.")
+    }
 }
 
 impl Tx for Transaction<'static, Postgres> {}
@@ -41,6 +49,10 @@ impl MultiRepository for PgStore {
         self
     }
 
+    fn budgets(&self) -> &dyn BudgetRepository<Tr = Self::KTransaction> {
+        self
+    }
+
     fn credentials(&self) -> &dyn CredentialRepository<Tr = Self::KTransaction> {
         self
     }
@@ -68,4 +80,20 @@ impl MultiRepository for PgStore {
     fn events(&self) -> &dyn EventRepository<Tr = Self::KTransaction> {
         self
     }
+
+    fn takeouts(&self) -> &dyn TakeoutRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn user_tokens(&self) -> &dyn UserTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn api_tokens(&self) -> &dyn ApiTokenRepository<Tr = Self::KTransaction> {
+        self
+    }
+
+    fn two_factors(&self) -> &dyn TwoFactorRepository<Tr = Self::KTransaction> {
+        self
+    }
 }
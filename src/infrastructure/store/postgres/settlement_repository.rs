@@ -1,5 +1,7 @@
 use crate::application::store::{SettlementRepository, SettlementRepositoryError};
-use crate::domain::{Amount, Settlement, SettlementDescription, Transaction};
+use crate::domain::{
+    Amount, MemberBalance, Settlement, SettlementDescription, SettlementLabel, Transaction,
+};
 use crate::infrastructure::store::postgres::pg_store::PgStore;
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -16,13 +18,14 @@ impl PgStore {
         transactions: &'a [Transaction],
     ) -> Result<(), SettlementRepositoryError> {
         let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
-            "INSERT INTO koru_transaction (settlement_id, from_user_id, to_user_id, amount) ",
+            "INSERT INTO koru_transaction (settlement_id, from_user_id, to_user_id, amount, paid) ",
         );
         query.push_values(transactions, |mut b, transaction| {
             b.push_bind(settlement_id)
                 .push_bind(transaction.from)
                 .push_bind(transaction.to)
-                .push_bind(f32::from(transaction.amount));
+                .push_bind(f32::from(transaction.amount))
+                .push_bind(transaction.paid);
         });
         query
             .build()
@@ -52,6 +55,73 @@ impl PgStore {
         Ok(())
     }
 
+    #[tracing::instrument(name = "Save member balances in DB", skip(self, tx, balances))]
+    async fn save_balances<'a>(
+        &self,
+        tx: &'a mut sqlx::Transaction<'_, Postgres>,
+        settlement_id: &'a Uuid,
+        balances: &'a [MemberBalance],
+    ) -> Result<(), SettlementRepositoryError> {
+        if balances.is_empty() {
+            return Ok(());
+        }
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO koru_settlement_member (settlement_id, member_id, paid, share, net) ",
+        );
+        query.push_values(balances, |mut b, balance| {
+            b.push_bind(settlement_id)
+                .push_bind(balance.member_id)
+                .push_bind(balance.paid)
+                .push_bind(balance.share)
+                .push_bind(balance.net);
+        });
+        query
+            .build()
+            .execute(tx)
+            .await
+            .map_err(|e| SettlementRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get member balances from DB", skip(self))]
+    async fn get_balances(
+        &self,
+        settlement_id: &Uuid,
+    ) -> Result<Vec<MemberBalance>, SettlementRepositoryError> {
+        sqlx::query!(
+            r#"
+        SELECT member_id, paid, share, net
+        FROM koru_settlement_member
+        WHERE settlement_id = $1
+        ORDER BY net DESC, member_id;
+        "#,
+            settlement_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| MemberBalance {
+                    member_id: row.member_id,
+                    paid: row.paid,
+                    share: row.share,
+                    net: row.net,
+                })
+                .collect()
+        })
+        .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))
+    }
+
+    async fn load_details(
+        &self,
+        settlement: &mut Settlement,
+    ) -> Result<(), SettlementRepositoryError> {
+        settlement.transactions = self.get_transactions(&settlement.id).await?;
+        settlement.expense_ids = self.get_expenses_by_stl(&settlement.id).await?;
+        settlement.balances = self.get_balances(&settlement.id).await?;
+        Ok(())
+    }
+
     #[tracing::instrument(name = "Get transactions from DB", skip(self))]
     async fn get_transactions(
         &self,
@@ -59,7 +129,7 @@ impl PgStore {
     ) -> Result<Vec<Transaction>, SettlementRepositoryError> {
         let rows = sqlx::query!(
             r#"
-        SELECT settlement_id, from_user_id, to_user_id, amount
+        SELECT settlement_id, from_user_id, to_user_id, amount, paid
         FROM koru_transaction
         WHERE settlement_id = $1
         ORDER BY amount DESC;
@@ -76,6 +146,7 @@ impl PgStore {
                 to: row.to_user_id,
                 amount: Amount::try_from(row.amount)
                     .map_err(SettlementRepositoryError::CorruptedData)?,
+                paid: row.paid,
             })
         }
         Ok(res)
@@ -113,12 +184,16 @@ impl SettlementRepository for PgStore {
     ) -> Result<(), SettlementRepositoryError> {
         sqlx::query!(
             r#"
-        INSERT INTO koru_settlement (id, group_id, start_date, end_date) VALUES ($1, $2, $3, $4)
+        INSERT INTO koru_settlement (id, group_id, start_date, end_date, label, total, expense_count)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
         "#,
             settlement.id,
             settlement.group_id,
             settlement.start_date,
-            settlement.end_date
+            settlement.end_date,
+            settlement.label.clone().map(String::from),
+            settlement.total,
+            settlement.expense_count as i32,
         )
         .execute(tx.get_mut())
         .await
@@ -127,6 +202,50 @@ impl SettlementRepository for PgStore {
             .await?;
         self.link_expenses(tx.get_mut(), &settlement.id, &settlement.expense_ids)
             .await?;
+        self.save_balances(tx.get_mut(), &settlement.id, &settlement.balances)
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Delete settlement from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement_id: &Uuid,
+    ) -> Result<(), SettlementRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_settlement WHERE id = $1
+        "#,
+            settlement_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| SettlementRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Update settlement transactions in DB", skip(self, tx))]
+    async fn update_transactions(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        settlement: &Settlement,
+    ) -> Result<(), SettlementRepositoryError> {
+        for transaction in &settlement.transactions {
+            sqlx::query!(
+                r#"
+        UPDATE koru_transaction SET paid = $4
+        WHERE settlement_id = $1 AND from_user_id = $2 AND to_user_id = $3
+        "#,
+                settlement.id,
+                transaction.from,
+                transaction.to,
+                transaction.paid,
+            )
+            .execute(tx.get_mut())
+            .await
+            .map_err(|e| SettlementRepositoryError::Update(anyhow!(e)))?;
+        }
         Ok(())
     }
 
@@ -135,35 +254,36 @@ impl SettlementRepository for PgStore {
         &self,
         group_id: &Uuid,
     ) -> Result<Vec<Settlement>, SettlementRepositoryError> {
-        let descriptions: Vec<SettlementDescription> = sqlx::query!(
+        let rows = sqlx::query!(
             r#"
-        SELECT id, group_id, start_date, end_date FROM koru_settlement WHERE group_id = $1 ORDER BY end_date ASC;
+        SELECT id, group_id, start_date, end_date, label, total, expense_count
+        FROM koru_settlement WHERE group_id = $1 ORDER BY end_date ASC;
         "#,
             group_id
         )
-            .fetch_all(&self.pool)
-            .await
-            .map(|rows| {
-                rows.into_iter().map(|row| SettlementDescription {
-                    id: row.id,
-                    group_id: row.group_id,
-                    start_date: row.start_date,
-                    end_date: row.end_date,
-                }).collect()
-            })
-            .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))?;
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))?;
         let mut res = Vec::new();
-        for description in descriptions {
-            let transactions = self.get_transactions(&description.id).await?;
-            let expenses = self.get_expenses_by_stl(&description.id).await?;
-            res.push(Settlement {
-                id: description.id,
-                group_id: description.group_id,
-                start_date: description.start_date,
-                end_date: description.end_date,
-                transactions,
-                expense_ids: expenses,
-            })
+        for row in rows {
+            let mut settlement = Settlement {
+                id: row.id,
+                group_id: row.group_id,
+                start_date: row.start_date,
+                end_date: row.end_date,
+                transactions: Vec::new(),
+                expense_ids: Vec::new(),
+                label: row
+                    .label
+                    .map(SettlementLabel::try_from)
+                    .transpose()
+                    .map_err(SettlementRepositoryError::CorruptedData)?,
+                total: row.total,
+                expense_count: row.expense_count as u32,
+                balances: Vec::new(),
+            };
+            self.load_details(&mut settlement).await?;
+            res.push(settlement)
         }
         Ok(res)
     }
@@ -192,40 +312,74 @@ impl SettlementRepository for PgStore {
         .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))
     }
 
+    #[tracing::instrument(name = "Get unpaid settlements between users from DB", skip(self))]
+    async fn get_unpaid_settlements(
+        &self,
+        user_id: &Uuid,
+        other_id: &Uuid,
+    ) -> Result<Vec<Settlement>, SettlementRepositoryError> {
+        let ids: Vec<Uuid> = sqlx::query!(
+            r#"
+        SELECT s.id FROM koru_settlement s
+        WHERE EXISTS (
+            SELECT 1 FROM koru_transaction t
+            WHERE t.settlement_id = s.id AND t.paid = false
+            AND ((t.from_user_id = $1 AND t.to_user_id = $2)
+                OR (t.from_user_id = $2 AND t.to_user_id = $1))
+        )
+        ORDER BY s.end_date ASC
+        "#,
+            user_id,
+            other_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|r| r.id).collect())
+        .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))?;
+        let mut res = Vec::new();
+        for id in ids {
+            if let Some(settlement) = self.find(&id).await? {
+                res.push(settlement);
+            }
+        }
+        Ok(res)
+    }
+
     #[tracing::instrument(name = "Find settlement from DB", skip(self))]
     async fn find(
         &self,
         settlement_id: &Uuid,
     ) -> Result<Option<Settlement>, SettlementRepositoryError> {
-        let description = sqlx::query!(
+        let row = sqlx::query!(
             r#"
-        SELECT id, group_id, start_date, end_date FROM koru_settlement WHERE id = $1;
+        SELECT id, group_id, start_date, end_date, label, total, expense_count
+        FROM koru_settlement WHERE id = $1;
         "#,
             settlement_id
         )
         .fetch_optional(&self.pool)
         .await
-        .map(|row| {
-            row.map(|row| SettlementDescription {
-                id: row.id,
-                group_id: row.group_id,
-                start_date: row.start_date,
-                end_date: row.end_date,
-            })
-        })
         .map_err(|e| SettlementRepositoryError::Fetch(anyhow!(e)))?;
-        match description {
-            Some(description) => {
-                let transactions = self.get_transactions(&description.id).await?;
-                let expenses = self.get_expenses_by_stl(&description.id).await?;
-                Ok(Some(Settlement {
-                    id: description.id,
-                    group_id: description.group_id,
-                    start_date: description.start_date,
-                    end_date: description.end_date,
-                    transactions,
-                    expense_ids: expenses,
-                }))
+        match row {
+            Some(row) => {
+                let mut settlement = Settlement {
+                    id: row.id,
+                    group_id: row.group_id,
+                    start_date: row.start_date,
+                    end_date: row.end_date,
+                    transactions: Vec::new(),
+                    expense_ids: Vec::new(),
+                    label: row
+                        .label
+                        .map(SettlementLabel::try_from)
+                        .transpose()
+                        .map_err(SettlementRepositoryError::CorruptedData)?,
+                    total: row.total,
+                    expense_count: row.expense_count as u32,
+                    balances: Vec::new(),
+                };
+                self.load_details(&mut settlement).await?;
+                Ok(Some(settlement))
             }
             None => Ok(None),
         }
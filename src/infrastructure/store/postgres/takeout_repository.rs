@@ -0,0 +1,77 @@
+use crate::application::store::{TakeoutRepository, TakeoutRepositoryError};
+use crate::domain::Takeout;
+use crate::infrastructure::store::postgres::pg_store::PgStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use std::cell::RefCell;
+use uuid::Uuid;
+
+#[async_trait]
+impl TakeoutRepository for PgStore {
+    type Tr = Transaction<'static, Postgres>;
+
+    #[tracing::instrument(name = "Save takeout in DB", skip(self, tx, takeout), fields(user_id = %takeout.user_id))]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        takeout: &Takeout,
+    ) -> Result<(), TakeoutRepositoryError> {
+        sqlx::query!(
+            r#"
+        INSERT INTO koru_user_takeout (user_id, requested_at, generated_at, archive)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id) DO UPDATE SET
+            requested_at = EXCLUDED.requested_at,
+            generated_at = EXCLUDED.generated_at,
+            archive = EXCLUDED.archive
+        "#,
+            takeout.user_id,
+            takeout.requested_at,
+            takeout.generated_at,
+            takeout.archive
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| TakeoutRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get takeout from DB", skip(self))]
+    async fn find(&self, user_id: &Uuid) -> Result<Option<Takeout>, TakeoutRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT user_id, requested_at, generated_at, archive FROM koru_user_takeout
+        WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| TakeoutRepositoryError::Fetch(anyhow!(e)))?;
+        Ok(row.map(|row| Takeout {
+            user_id: row.user_id,
+            requested_at: row.requested_at,
+            generated_at: row.generated_at,
+            archive: row.archive,
+        }))
+    }
+
+    #[tracing::instrument(name = "Delete takeout from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), TakeoutRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_user_takeout WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| TakeoutRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::application::auth::{TwoFactor, TwoFactorRepository, TwoFactorRepositoryError};
+use crate::domain::Email;
+use crate::infrastructure::store::postgres::pg_store::PgStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use std::cell::RefCell;
+
+#[async_trait]
+impl TwoFactorRepository for PgStore {
+    type Tr = Transaction<'static, Postgres>;
+
+    #[tracing::instrument(name = "Save second factor in DB", skip(self, tx, two_factor))]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        two_factor: &TwoFactor,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        sqlx::query!(
+            r#"
+        INSERT INTO koru_user_two_factor (email, encrypted_secret, recovery_codes, confirmed_at, last_used_step)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (email) DO UPDATE SET
+            encrypted_secret = EXCLUDED.encrypted_secret,
+            recovery_codes = EXCLUDED.recovery_codes,
+            confirmed_at = EXCLUDED.confirmed_at,
+            last_used_step = EXCLUDED.last_used_step
+        "#,
+            String::from(two_factor.email.clone()),
+            two_factor.encrypted_secret,
+            &two_factor.recovery_codes,
+            two_factor.confirmed_at,
+            two_factor.last_used_step,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| TwoFactorRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get second factor from DB", skip(self))]
+    async fn find(&self, email: &Email) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT email, encrypted_secret, recovery_codes, confirmed_at, last_used_step
+        FROM koru_user_two_factor
+        WHERE email = $1
+        "#,
+            String::from(email.clone()),
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| TwoFactorRepositoryError::Fetch(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(TwoFactor {
+                email: Email::try_from(row.email)
+                    .map_err(TwoFactorRepositoryError::CorruptedData)?,
+                encrypted_secret: row.encrypted_secret,
+                recovery_codes: row.recovery_codes,
+                confirmed_at: row.confirmed_at,
+                last_used_step: row.last_used_step,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "Lock second factor in DB", skip(self, tx))]
+    async fn find_for_update(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<Option<TwoFactor>, TwoFactorRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT email, encrypted_secret, recovery_codes, confirmed_at, last_used_step
+        FROM koru_user_two_factor
+        WHERE email = $1
+        FOR UPDATE
+        "#,
+            String::from(email.clone()),
+        )
+        .fetch_optional(tx.get_mut())
+        .await
+        .map_err(|e| TwoFactorRepositoryError::Fetch(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(TwoFactor {
+                email: Email::try_from(row.email)
+                    .map_err(TwoFactorRepositoryError::CorruptedData)?,
+                encrypted_secret: row.encrypted_secret,
+                recovery_codes: row.recovery_codes,
+                confirmed_at: row.confirmed_at,
+                last_used_step: row.last_used_step,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "Delete second factor from DB", skip(self, tx))]
+    async fn delete(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        email: &Email,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_user_two_factor WHERE email = $1
+        "#,
+            String::from(email.clone()),
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| TwoFactorRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+}
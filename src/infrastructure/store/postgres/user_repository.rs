@@ -19,12 +19,26 @@ impl UserRepository for PgStore {
     ) -> Result<(), UserRepositoryError> {
         sqlx::query!(
             r#"
-        INSERT INTO koru_user (id, email, name, created_at) VALUES ($1, $2, $3, $4)
+        INSERT INTO koru_user (id, email, name, created_at, deleted_at, sessions_revoked_at, email_verified, disabled_at, password_reset_required)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (id) DO UPDATE SET
+            email = EXCLUDED.email,
+            name = EXCLUDED.name,
+            deleted_at = EXCLUDED.deleted_at,
+            sessions_revoked_at = EXCLUDED.sessions_revoked_at,
+            email_verified = EXCLUDED.email_verified,
+            disabled_at = EXCLUDED.disabled_at,
+            password_reset_required = EXCLUDED.password_reset_required
         "#,
             user.id,
             String::from(user.email.clone()),
             String::from(user.name.clone()),
-            user.created_at
+            user.created_at,
+            user.deleted_at,
+            user.sessions_revoked_at,
+            user.email_verified,
+            user.disabled_at,
+            user.password_reset_required
         )
         .execute(tx.get_mut())
         .await
@@ -32,6 +46,7 @@ impl UserRepository for PgStore {
         sqlx::query!(
             r#"
         INSERT INTO koru_user_roles (user_id, role) VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE SET role = EXCLUDED.role
         "#,
             user.id,
             PgUserRole::from(user.role) as PgUserRole,
@@ -46,7 +61,7 @@ impl UserRepository for PgStore {
     async fn find(&self, user_id: &Uuid) -> Result<Option<User>, UserRepositoryError> {
         let row = sqlx::query!(
             r#"
-        SELECT id, email, name, created_at, role as "role: PgUserRole"
+        SELECT id, email, name, created_at, deleted_at, sessions_revoked_at, email_verified, disabled_at, password_reset_required, role as "role: PgUserRole"
         FROM koru_user JOIN koru_user_roles ON user_id = id WHERE id = $1
         "#,
             user_id
@@ -64,6 +79,11 @@ impl UserRepository for PgStore {
                         .map_err(UserRepositoryError::CorruptedData)?,
                     role: UserRole::from(row.role),
                     created_at: row.created_at,
+                    deleted_at: row.deleted_at,
+                    sessions_revoked_at: row.sessions_revoked_at,
+                    email_verified: row.email_verified,
+                    disabled_at: row.disabled_at,
+                    password_reset_required: row.password_reset_required,
                 };
                 Ok(Some(u))
             }
@@ -75,7 +95,7 @@ impl UserRepository for PgStore {
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, UserRepositoryError> {
         let row = sqlx::query!(
             r#"
-        SELECT id, email, name, created_at, role as "role: PgUserRole" FROM koru_user
+        SELECT id, email, name, created_at, deleted_at, sessions_revoked_at, email_verified, disabled_at, password_reset_required, role as "role: PgUserRole" FROM koru_user
         JOIN koru_user_roles ON user_id = id WHERE email = $1
         "#,
             String::from(email.clone())
@@ -93,6 +113,11 @@ impl UserRepository for PgStore {
                         .map_err(UserRepositoryError::CorruptedData)?,
                     role: UserRole::from(row.role),
                     created_at: row.created_at,
+                    deleted_at: row.deleted_at,
+                    sessions_revoked_at: row.sessions_revoked_at,
+                    email_verified: row.email_verified,
+                    disabled_at: row.disabled_at,
+                    password_reset_required: row.password_reset_required,
                 };
                 Ok(Some(u))
             }
@@ -121,7 +146,7 @@ impl UserRepository for PgStore {
     async fn fetch_users(&self, user_ids: &[Uuid]) -> Result<Vec<User>, UserRepositoryError> {
         let rows = sqlx::query!(
             r#"
-        SELECT id, email, name, created_at, role as "role: PgUserRole" FROM koru_user
+        SELECT id, email, name, created_at, deleted_at, sessions_revoked_at, email_verified, disabled_at, password_reset_required, role as "role: PgUserRole" FROM koru_user
         JOIN koru_user_roles ON user_id = id WHERE id = ANY($1)
         "#,
             user_ids
@@ -137,6 +162,11 @@ impl UserRepository for PgStore {
                 email: Email::try_from(row.email).map_err(UserRepositoryError::CorruptedData)?,
                 role: UserRole::from(row.role),
                 created_at: row.created_at,
+                deleted_at: row.deleted_at,
+                sessions_revoked_at: row.sessions_revoked_at,
+                email_verified: row.email_verified,
+                disabled_at: row.disabled_at,
+                password_reset_required: row.password_reset_required,
             };
             users.push(u);
         }
@@ -147,7 +177,7 @@ impl UserRepository for PgStore {
     async fn fetch_all_users(&self) -> Result<Vec<User>, UserRepositoryError> {
         let rows = sqlx::query!(
             r#"
-        SELECT id, email, name, created_at, role as "role: PgUserRole" FROM koru_user
+        SELECT id, email, name, created_at, deleted_at, sessions_revoked_at, email_verified, disabled_at, password_reset_required, role as "role: PgUserRole" FROM koru_user
         JOIN koru_user_roles ON user_id = id
         "#
         )
@@ -162,6 +192,11 @@ impl UserRepository for PgStore {
                 email: Email::try_from(row.email).map_err(UserRepositoryError::CorruptedData)?,
                 role: UserRole::from(row.role),
                 created_at: row.created_at,
+                deleted_at: row.deleted_at,
+                sessions_revoked_at: row.sessions_revoked_at,
+                email_verified: row.email_verified,
+                disabled_at: row.disabled_at,
+                password_reset_required: row.password_reset_required,
             };
             users.push(u);
         }
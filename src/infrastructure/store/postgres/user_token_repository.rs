@@ -0,0 +1,131 @@
+use crate::application::auth::{
+    UserToken, UserTokenKind, UserTokenRepository, UserTokenRepositoryError,
+};
+use crate::infrastructure::store::postgres::pg_store::PgStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use std::cell::RefCell;
+use uuid::Uuid;
+
+#[async_trait]
+impl UserTokenRepository for PgStore {
+    type Tr = Transaction<'static, Postgres>;
+
+    #[tracing::instrument(name = "Save user token in DB", skip(self, tx, token), fields(user_id = %token.user_id))]
+    async fn save(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        token: &UserToken,
+    ) -> Result<(), UserTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        INSERT INTO koru_user_token (token_hash, user_id, kind, created_at, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+            token.hash,
+            token.user_id,
+            token.kind.as_str(),
+            token.created_at,
+            token.expires_at
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| UserTokenRepositoryError::Insert(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Get user token from DB", skip(self, hash))]
+    async fn find(&self, hash: &str) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        SELECT token_hash, user_id, kind, created_at, expires_at FROM koru_user_token
+        WHERE token_hash = $1
+        "#,
+            hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| UserTokenRepositoryError::Fetch(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(UserToken {
+                hash: row.token_hash,
+                user_id: row.user_id,
+                kind: UserTokenKind::try_from(row.kind.as_str())
+                    .map_err(UserTokenRepositoryError::CorruptedData)?,
+                created_at: row.created_at,
+                expires_at: row.expires_at,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "Take user token from DB", skip(self, tx, hash))]
+    async fn take(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        hash: &str,
+        kind: UserTokenKind,
+    ) -> Result<Option<UserToken>, UserTokenRepositoryError> {
+        let row = sqlx::query!(
+            r#"
+        DELETE FROM koru_user_token WHERE token_hash = $1 AND kind = $2
+        RETURNING token_hash, user_id, kind, created_at, expires_at
+        "#,
+            hash,
+            kind.as_str(),
+        )
+        .fetch_optional(tx.get_mut())
+        .await
+        .map_err(|e| UserTokenRepositoryError::Delete(anyhow!(e)))?;
+        match row {
+            Some(row) => Ok(Some(UserToken {
+                hash: row.token_hash,
+                user_id: row.user_id,
+                kind: UserTokenKind::try_from(row.kind.as_str())
+                    .map_err(UserTokenRepositoryError::CorruptedData)?,
+                created_at: row.created_at,
+                expires_at: row.expires_at,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "Delete user tokens from DB", skip(self, tx))]
+    async fn delete_all(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+        kind: UserTokenKind,
+    ) -> Result<(), UserTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_user_token WHERE user_id = $1 AND kind = $2
+        "#,
+            user_id,
+            kind.as_str(),
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| UserTokenRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Delete all user tokens from DB", skip(self, tx))]
+    async fn delete_user_tokens(
+        &self,
+        tx: &mut RefCell<Self::Tr>,
+        user_id: &Uuid,
+    ) -> Result<(), UserTokenRepositoryError> {
+        sqlx::query!(
+            r#"
+        DELETE FROM koru_user_token WHERE user_id = $1
+        "#,
+            user_id,
+        )
+        .execute(tx.get_mut())
+        .await
+        .map_err(|e| UserTokenRepositoryError::Delete(anyhow!(e)))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+/// Formats a CSV record as per RFC 4180, terminated by CRLF.
+///
+/// Fields containing a comma, a double quote or a line break are enclosed in
+/// double quotes, inner double quotes being escaped by doubling them.
+pub fn record<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// Parses a CSV document as per RFC 4180, also accepting bare LF line breaks.
+///
+/// Each record is returned with the line number it starts at (starting at 1), so that
+/// callers can report errors on the original document. Empty lines are skipped.
+pub fn parse(content: &str) -> Result<Vec<(usize, Vec<String>)>, &'static str> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                if record.len() > 1 || !record[0].is_empty() {
+                    records.push((start, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                start = line;
+            }
+            (false, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("The document contains an unterminated quoted field.");
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_only_quote_fields_that_need_it() {
+        assert_eq!(record(&["a", "b c", "12.50"]), "a,b c,12.50\r\n");
+        assert_eq!(
+            record(&["a,b", "say \"hi\"", "two\nlines"]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\r\n"
+        );
+        assert_eq!(record(&["", ""]), ",\r\n");
+    }
+
+    #[test]
+    fn it_should_parse_quoted_fields_and_report_line_numbers() {
+        // given
+        let content = "\u{feff}a,b\r\n\r\n\"x, \"\"y\"\"\",\"two\nlines\"\n1,\n";
+        // when
+        let records = parse(content).unwrap();
+        // then
+        assert_eq!(
+            records,
+            vec![
+                (1, vec!["a".to_string(), "b".to_string()]),
+                (3, vec!["x, \"y\"".to_string(), "two\nlines".to_string()]),
+                (5, vec!["1".to_string(), "".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_unterminated_quoted_field() {
+        assert!(parse("a,\"b\nc").is_err());
+    }
+
+    #[test]
+    fn it_should_parse_what_it_writes() {
+        let fields = ["a,b", "say \"hi\"", "two\r\nlines", ""];
+        let records = parse(&record(&fields)).unwrap();
+        assert_eq!(records, vec![(1, fields.map(String::from).to_vec())]);
+    }
+}
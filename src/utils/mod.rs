@@ -1,3 +1,4 @@
+pub mod csv;
 pub mod date;
 pub mod errors;
 pub mod telemetry;
@@ -1,9 +1,11 @@
 use crate::application::event_bus::EventListener;
 #[cfg(feature = "notification")]
-use crate::application::notification::Notifier;
+use crate::application::notification::{BudgetWatcher, Notifier};
+use crate::application::user::{AccountMailer, PasswordResetMailer, TakeoutGenerator};
 use crate::configuration::application::ApplicationSettings;
 use crate::infrastructure::event_bus::EventListenerImpl;
 use crate::infrastructure::store::StoreImpl;
+use chrono::Duration;
 use futures_util::future::BoxFuture;
 use std::sync::Arc;
 
@@ -17,6 +19,15 @@ impl Worker {
         mut listener: EventListenerImpl,
         store: Arc<StoreImpl>,
     ) -> Result<Self, anyhow::Error> {
+        listener.register(TakeoutGenerator::new(store.clone()));
+        listener.register(PasswordResetMailer::new(
+            store.clone(),
+            AccountMailer::new(
+                configuration.mail.setup_mailer()?,
+                configuration.mail.public_url.clone(),
+            ),
+            Duration::minutes(configuration.password_reset.validity.into()),
+        ));
         #[cfg(feature = "notification")]
         {
             let notification_svc = configuration
@@ -26,8 +37,9 @@ impl Worker {
             match notification_svc {
                 None => {}
                 Some(notification_svc) => {
-                    let notification_svc = notification_svc?;
-                    listener.register(Notifier::new(store, Arc::new(notification_svc)));
+                    let notification_svc = Arc::new(notification_svc?);
+                    listener.register(BudgetWatcher::new(store.clone(), notification_svc.clone()));
+                    listener.register(Notifier::new(store, notification_svc));
                 }
             }
         }
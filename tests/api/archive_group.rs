@@ -0,0 +1,114 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn archive_group_returns_204_and_makes_the_group_read_only(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/groups/{}/archive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "GroupArchived");
+    let response = app
+        .client
+        .post(&format!("{}/groups/{}/expenses", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .json(&json!({"description": "my expense", "amount": 12.0}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 400);
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn archive_group_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/groups/{}/archive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn archive_group_returns_409_for_archived_groups(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.client
+        .post(&format!(
+            "{}/admin/groups/{}/archive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/groups/{}/archive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
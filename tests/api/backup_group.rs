@@ -0,0 +1,72 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn backup_group_return_200_and_the_versioned_document(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let _ = app
+        .create_expense(&group.id, &user.cookie, "Pizza", 30.0)
+        .await?;
+    app.settle(&group).await?;
+    let _ = app
+        .create_expense(&group.id, &group.admin.cookie, "Beer", 12.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/backup", &app.address, &group.id))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let backup = response.json::<serde_json::Value>().await?;
+    assert_eq!(backup["version"], 1);
+    assert_eq!(backup["name"], "my group");
+    assert_eq!(backup["members"].as_array().unwrap().len(), 2);
+    assert_eq!(backup["expenses"].as_array().unwrap().len(), 2);
+    let settlements = backup["settlements"].as_array().unwrap();
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0]["transactions"][0]["from"], "r@r.com");
+    assert_eq!(settlements[0]["transactions"][0]["to"], "r1@r.com");
+    let events = backup["events"].as_array().unwrap();
+    assert_eq!(events[0]["event"]["type"], "group_created");
+    assert_eq!(events.last().unwrap()["event"]["type"], "expense_created");
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn backup_group_return_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/backup", &app.address, &group.id))
+        .header(header::COOKIE, user.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+
+    Ok(())
+}
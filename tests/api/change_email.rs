@@ -0,0 +1,143 @@
+use crate::test_app::{LoginResponse, TestApp, TwoFactorTestApp};
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_email_returns_200_and_changes_the_login(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/email", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"email":"new@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(app.get_user().await.email, "new@r.com");
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .json(&json!({"email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let login = app.login("new@r.com", "201").await?;
+    assert_eq!(login.id, user.id);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_email_returns_403_given_a_wrong_password(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/email", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"email":"new@r.com","password":"wrong"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert_eq!(app.get_user().await.email, "r@r.com");
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_email_returns_409_when_the_email_is_in_use(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    app.create_user("other", "other@r.com", "123").await;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/email", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"email":"other@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_email_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/email", &app.address))
+        .bearer_auth(&token.token)
+        .json(&json!({"email":"new@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert_eq!(app.get_user().await.email, "r@r.com");
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn change_email_keeps_the_second_factor(app: &TwoFactorTestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let two_factor = app.enable_two_factor(&user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/email", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"email":"new@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let cookie = app.start_login("new@r.com", "201").await;
+    let response = app
+        .complete_login(&cookie, &two_factor.recovery_codes[0])
+        .await;
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(response.json::<LoginResponse>().await?.data.id, user.id);
+    Ok(())
+}
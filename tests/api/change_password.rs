@@ -0,0 +1,124 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_password_returns_200_and_closes_the_other_sessions(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    let other_session = app.login("r@r.com", "201").await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/password", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"current_password":"201","new_password":"202"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let cookie = format!(
+        "id={}",
+        response
+            .cookies()
+            .next()
+            .expect("Cookie should exist")
+            .value()
+    );
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &other_session.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .json(&json!({"email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let login = app.login("r@r.com", "202").await?;
+    assert_eq!(login.id, user.id);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_password_returns_403_given_a_wrong_current_password(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/password", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"current_password":"wrong","new_password":"202"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_password_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/password", &app.address))
+        .bearer_auth(&token.token)
+        .json(&json!({"current_password":"201","new_password":"202"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    app.login("r@r.com", "201").await?;
+    Ok(())
+}
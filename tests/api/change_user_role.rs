@@ -0,0 +1,92 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_user_role_returns_204_and_grants_the_admin_endpoints(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/users/{}/role", &app.address, user.id))
+        .header(header::COOKIE, &admin.cookie)
+        .json(&json!({"role":"Administrator"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserRoleChanged");
+    let response = app
+        .client
+        .get(&format!("{}/admin/users", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_user_role_returns_400_for_unknown_roles(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/users/{}/role", &app.address, user.id))
+        .header(header::COOKIE, &admin.cookie)
+        .json(&json!({"role":"Owner"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn change_user_role_returns_409_when_admin_demotes_themselves(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/users/{}/role", &app.address, admin.id))
+        .header(header::COOKIE, &admin.cookie)
+        .json(&json!({"role":"User"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn create_api_token_returns_201_and_a_token_authenticating_requests(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/tokens", &app.address))
+        .header(header::COOKIE, &group.admin.cookie)
+        .json(&json!({"name":"backup script","scope":"read","validity_days":90}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 201);
+    let body = response.json::<serde_json::Value>().await?;
+    let token = body["data"]["token"].as_str().unwrap().to_string();
+    assert!(token.starts_with("koru_"));
+    assert_eq!(body["data"]["api_token"]["name"], "backup script");
+    assert_eq!(body["data"]["api_token"]["scope"], "read");
+    assert_eq!(app.get_event_type().await.unwrap(), "UserApiTokenCreated");
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}", &app.address, group.id))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn create_api_token_returns_400_for_an_invalid_scope(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/tokens", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"name":"backup script","scope":"admin","validity_days":90}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn create_api_token_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/tokens", &app.address))
+        .bearer_auth(&token.token)
+        .json(&json!({"name":"another","scope":"write","validity_days":365}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn read_only_tokens_return_403_on_writes(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("read", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/groups", &app.address))
+        .bearer_auth(&token.token)
+        .json(&json!({ "name": "my group", "color":{"red":0,"green":255,"blue":0} }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert!(app.get_group().await.is_none());
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn unknown_tokens_return_401(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups", &app.address))
+        .bearer_auth("koru_unknown")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn create_api_token_returns_401_when_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/tokens", &app.address))
+        .json(&json!({"name":"backup script","scope":"read","validity_days":90}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_user_returns_204_and_anonymizes_the_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    let expense_id = app
+        .create_expense(&group.id, other_user.cookie.as_str(), "expense", 6.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me", &app.address))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert!(app.get_expense_by_id(expense_id).await.is_some());
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let body = response.json::<serde_json::Value>().await?;
+    let members = body["data"]["group"]["members"].as_array().unwrap();
+    let member = members
+        .iter()
+        .find(|m| m["id"] == other_user.id.to_string())
+        .unwrap();
+    assert_eq!(member["name"], "Deleted user");
+    assert_ne!(member["email"], "r3@r.com");
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .json(&json!({"email":"r3@r.com","password":"123"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_user_returns_409_if_user_administers_a_group(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me", &app.address))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    assert!(app.get_group_by_id(group.id).await.is_some());
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_user_returns_401_if_user_is_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_user_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me", &app.address))
+        .bearer_auth(&token.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    app.login("r@r.com", "201").await?;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn disable_user_returns_204_and_blocks_the_user(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/admin/users/{}/disable", &app.address, user.id))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserDisabled");
+    let response = app
+        .client
+        .get(&format!("{}/groups", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let response = app.try_login("r@r.com", "201").await;
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn disable_user_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.create_user_and_login("other", "o@r.com", "201").await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/users/{}/disable",
+            &app.address, other.id
+        ))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert_eq!(app.login("o@r.com", "201").await?.id, other.id);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn disable_user_returns_404_for_unknown_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/users/{}/disable",
+            &app.address,
+            uuid::Uuid::new_v4()
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
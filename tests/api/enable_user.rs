@@ -0,0 +1,40 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn enable_user_returns_204_and_lets_the_user_log_in_again(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let response = app
+        .client
+        .post(&format!("{}/admin/users/{}/disable", &app.address, user.id))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 204);
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/admin/users/{}/enable", &app.address, user.id))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserEnabled");
+    assert_eq!(app.login("r@r.com", "201").await?.id, user.id);
+    Ok(())
+}
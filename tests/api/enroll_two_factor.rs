@@ -0,0 +1,101 @@
+use crate::test_app::{TestApp, TwoFactorTestApp};
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn enroll_two_factor_returns_201_and_the_secrets(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let two_factor = app.enroll_two_factor(&user.cookie).await?;
+
+    // Assert
+    assert!(two_factor
+        .provisioning_uri
+        .starts_with("otpauth://totp/koru:r%40r.com?secret="));
+    assert!(two_factor.provisioning_uri.contains(&two_factor.secret));
+    assert_eq!(two_factor.recovery_codes.len(), 10);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn confirm_two_factor_returns_400_given_an_invalid_code(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    app.enroll_two_factor(&user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/two-factor/confirm", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({ "code": "000000x" }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn confirm_two_factor_returns_200_and_requires_the_code_at_login(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    app.enable_two_factor(&user.cookie).await?;
+
+    // Assert
+    assert_eq!(app.get_event_type().await.unwrap(), "UserTwoFactorEnabled");
+    app.start_login("r@r.com", "201").await;
+    let response = app
+        .client
+        .post(&format!("{}/me/two-factor", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn enroll_two_factor_returns_400_when_not_enabled(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/two-factor", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_expenses_return_200_and_a_csv_of_the_settlement_expenses(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let exp1 = app
+        .create_expense(&group.id, &user.cookie, "pizza, drinks", 12.5)
+        .await?;
+    let settlement = app.settle(&group).await?;
+    let _ = app
+        .create_expense(&group.id, &user.cookie, "expense2", 10.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/expenses.csv?settlement_id={}",
+            &app.address, &group.id, &settlement.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/csv; charset=utf-8"
+    );
+    let body = response.text().await?;
+    let lines = body.split_terminator("\r\n").collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "expense_id,date,title,amount,member_id,member,settlement_id"
+    );
+    assert!(lines[1].starts_with(&exp1.to_string()));
+    assert!(lines[1].contains(",\"pizza, drinks\",12.50,"));
+    assert!(lines[1].contains(&format!("{},r1,{}", user.id, settlement.id)));
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_expenses_return_404_when_settlement_does_not_exist(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/expenses.csv?settlement_id={}",
+            &app.address,
+            &group.id,
+            Uuid::new_v4()
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_expenses_return_403_when_user_is_not_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/expenses.csv",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, user.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+
+    Ok(())
+}
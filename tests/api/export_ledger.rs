@@ -0,0 +1,89 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_ledger_return_200_and_the_beancount_journal_of_the_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let _ = app
+        .create_expense(&group.id, &user.cookie, "Pizza", 30.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/export?format=beancount&cash_account=Assets:Bank",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    let journal = response.text().await?;
+    assert!(journal.contains(" open Assets:Bank\n"));
+    assert!(journal.contains(" * \"r1\" \"Pizza\"\n"));
+    assert!(journal.contains("    Expenses:Koru  15.00 EUR\n    Assets:Koru  -15.00 EUR\n"));
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_ledger_return_400_for_an_invalid_account(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/export?format=beancount&expense_account=Food",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_ledger_return_400_without_format(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/export", &app.address, &group.id))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_settlements_return_200_and_a_csv_of_the_transactions(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let _ = app
+        .create_expense(&group.id, &user.cookie, "expense1", 30.0)
+        .await?;
+    let settlement = app.settle(&group).await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/settlements.csv",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, user.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/csv; charset=utf-8"
+    );
+    let body = response.text().await?;
+    let lines = body.split_terminator("\r\n").collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "settlement_id,label,start_date,end_date,from_id,from,to_id,to,amount,paid"
+    );
+    assert!(lines[1].starts_with(&settlement.id.to_string()));
+    assert!(lines[1].ends_with(&format!(
+        "{},rbiland,{},r1,15.00,false",
+        group.admin.id, user.id
+    )));
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_settlements_return_401_when_user_is_not_logged_in(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/settlements.csv",
+            &app.address, &group.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+
+    Ok(())
+}
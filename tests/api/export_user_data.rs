@@ -0,0 +1,91 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_user_data_returns_200_and_the_archive_of_the_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    app.create_expense(&group.id, group.admin.cookie.as_str(), "expense", 20.0)
+        .await?;
+    app.create_expense(&group.id, other_user.cookie.as_str(), "expense", 6.0)
+        .await?;
+    app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/export", &app.address))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let archive = response.json::<serde_json::Value>().await?;
+    assert_eq!(archive["profile"]["email"], "r3@r.com");
+    assert_eq!(archive["credentials"]["login"], "r3@r.com");
+    assert_eq!(archive["memberships"][0]["group_id"], group.id.to_string());
+    assert_eq!(archive["expenses"].as_array().unwrap().len(), 1);
+    assert_eq!(archive["expenses"][0]["amount"], 6.0);
+    let settlements = archive["settlements"].as_array().unwrap();
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0]["transactions"][0]["from"], "r3@r.com");
+    assert_eq!(settlements[0]["transactions"][0]["to"], "r@r.com");
+    assert!(!archive["user_events"].as_array().unwrap().is_empty());
+    // joined the group, created an expense, paid in the settlement
+    assert_eq!(archive["group_events"].as_array().unwrap().len(), 3);
+    assert!(!archive.to_string().contains("123"));
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_user_data_returns_401_if_user_is_not_logged_in(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/export", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn export_user_data_returns_401_if_user_does_not_exist(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    app.delete_user(user.id).await;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/export", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn force_password_reset_returns_204_and_requires_a_new_password(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/users/{}/password-reset",
+            &app.address, user.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(
+        app.get_event_type().await.unwrap(),
+        "UserPasswordResetForced"
+    );
+    let response = app.try_login("r@r.com", "201").await;
+    assert_eq!(response.status().as_u16(), 403);
+    let response = app
+        .client
+        .post(&format!("{}/password/reset", &app.address))
+        .json(&json!({"token": app.last_mail_token(), "password": "new_password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(app.login("r@r.com", "new_password").await?.id, user.id);
+    Ok(())
+}
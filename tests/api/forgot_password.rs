@@ -0,0 +1,103 @@
+use crate::test_app::{RateLimitTestApp, TestApp};
+use serde_json::{json, Value};
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn forgot_password_returns_202_and_mails_a_reset_link(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"r@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 202);
+    let mail = app.wait_for_mail().await;
+    assert!(mail.contains("To: r@r.com"));
+    assert!(mail.contains("/reset-password?token="));
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn forgot_password_returns_the_same_response_for_an_unknown_email(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    let known = app
+        .client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"r@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let known_status = known.status().as_u16();
+    let known_body = known.json::<Value>().await?;
+    let mail = Some(app.wait_for_mail().await);
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"unknown@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), known_status);
+    assert_eq!(response.json::<Value>().await?, known_body);
+    assert_eq!(app.last_mail(), mail);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn forgot_password_returns_400_given_an_invalid_email(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"not an email"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(app.last_mail().is_none());
+    Ok(())
+}
+
+#[test_context(RateLimitTestApp)]
+#[tokio::test]
+async fn forgot_password_returns_429_on_too_many_requests_from_ip(app: &RateLimitTestApp) {
+    // Arrange
+    for i in 0..10 {
+        app.client
+            .post(&format!("{}/password/forgot", &app.address))
+            .json(&json!({ "email": format!("r{}@r.com", i) }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+    }
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"r@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 429);
+    assert!(response.headers().contains_key("Retry-After"));
+}
@@ -0,0 +1,169 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_activity_returns_200_and_the_timeline_of_the_group(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let member = app
+        .create_user_and_login_and_device("jean", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, member.cookie.as_str()).await?;
+    let expense_id = app
+        .create_expense(&group.id, member.cookie.as_str(), "dinner", 12.0)
+        .await?;
+    let response = app
+        .client
+        .put(&format!(
+            "{}/groups/{}/expenses/{}",
+            &app.address, &group.id, &expense_id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&json!({"description":"dinner", "amount": 15.5}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/activity", &app.address, &group.id))
+        .header(header::COOKIE, member.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let activities = body["data"]["activity"]["activities"].as_array().unwrap();
+    assert_eq!(activities.len(), 4);
+    assert_eq!(activities[0]["activity"]["type"], "expense_modified");
+    assert_eq!(
+        activities[0]["activity"]["expense_id"],
+        expense_id.to_string()
+    );
+    assert_eq!(activities[0]["member"]["name"], "rbiland");
+    assert_eq!(
+        activities[0]["summary"],
+        "rbiland changed the amount of \"dinner\" from 12.00 to 15.50"
+    );
+    assert_eq!(activities[1]["activity"]["type"], "expense_created");
+    assert_eq!(activities[1]["summary"], "jean added \"dinner\" (12.00)");
+    assert_eq!(activities[2]["activity"]["type"], "member_joined");
+    assert_eq!(activities[2]["member"]["id"], member.id.to_string());
+    assert_eq!(activities[3]["activity"]["type"], "group_created");
+    assert_eq!(activities[3]["activity"]["name"], "my group");
+    assert_eq!(body["data"]["activity"]["next"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_activity_returns_the_timeline_page_by_page(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    for i in 0..3 {
+        app.create_expense(&group.id, &group.admin.cookie, &format!("e{}", i), 1.0)
+            .await?;
+    }
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/activity?limit=2",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let body = response.json::<serde_json::Value>().await?;
+    let next = body["data"]["activity"]["next"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/activity?limit=2&before={}",
+            &app.address, &group.id, next
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let activities = body["data"]["activity"]["activities"].as_array().unwrap();
+    assert_eq!(activities.len(), 2);
+    assert_eq!(activities[0]["activity"]["description"], "e0");
+    assert_eq!(activities[1]["activity"]["type"], "group_created");
+    assert_eq!(body["data"]["activity"]["next"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_activity_returns_403_when_user_is_not_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/activity", &app.address, &group.id))
+        .header(header::COOKIE, user.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_activity_returns_400_when_cursor_is_unknown(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/activity?before={}",
+            &app.address,
+            &group.id,
+            uuid::Uuid::new_v4()
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
@@ -103,3 +103,26 @@ pub struct UserData {
     pub role: String,
     pub created_at: DateTime<Utc>,
 }
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_all_users_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &admin.cookie).await?;
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/users", &app.address))
+        .bearer_auth(&token.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
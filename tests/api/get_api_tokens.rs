@@ -0,0 +1,89 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_api_tokens_returns_200_without_the_tokens(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let read = app.create_api_token("read", &user.cookie).await?;
+    let write = app.create_api_token("write", &user.cookie).await?;
+    app.client
+        .get(&format!("{}/me/summary", &app.address))
+        .bearer_auth(&read.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/tokens", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let tokens = body["data"]["tokens"].as_array().unwrap();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0]["id"], read.api_token.id.to_string());
+    assert_eq!(tokens[1]["id"], write.api_token.id.to_string());
+    assert_eq!(tokens[1]["scope"], "write");
+    assert!(tokens[0]["last_used_at"].is_string());
+    assert!(!body.to_string().contains(&read.token));
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_api_tokens_returns_401_when_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    app.create_api_token("read", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/tokens", &app.address))
+        .header(header::AUTHORIZATION, "Basic cmJpbGFuZDoyMDE=")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_api_tokens_returns_403_when_authenticated_by_a_token(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/tokens", &app.address))
+        .bearer_auth(&token.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_audit_log_returns_200_and_the_events_with_their_origin(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .header(header::USER_AGENT, "koru-phone")
+        .json(&json!({"email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/admin/events?user_id={}&kind=UserLogin",
+            &app.address, user.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let events = body["data"]["log"]["events"].as_array().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["kind"], "UserLogin");
+    assert_eq!(events[0]["actor_id"], user.id.to_string());
+    assert_eq!(events[0]["user_agent"], "koru-phone");
+    assert_eq!(events[0]["ip"], "127.0.0.1");
+    assert_eq!(events[1]["user_agent"], serde_json::Value::Null);
+    assert_eq!(body["data"]["log"]["next"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_audit_log_returns_the_events_page_by_page(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.create_expense(&group.id, &group.admin.cookie, "first", 12.0)
+        .await?;
+    app.create_expense(&group.id, &group.admin.cookie, "second", 8.0)
+        .await?;
+    let url = format!(
+        "{}/admin/events?group_id={}&limit=2",
+        &app.address, group.id
+    );
+    let first = app
+        .client
+        .get(&url)
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<serde_json::Value>()
+        .await?;
+    let next = first["data"]["log"]["next"].as_str().unwrap().to_string();
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}&before={}", url, next))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let kinds = body["data"]["log"]["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["kind"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["GroupCreated"]);
+    assert_eq!(body["data"]["log"]["next"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_audit_log_returns_400_for_an_invalid_limit(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/events?limit=1000", &app.address))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_audit_log_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/events", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
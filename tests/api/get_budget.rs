@@ -0,0 +1,150 @@
+use crate::test_app::TestApp;
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use std::collections::HashMap;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_budget_returns_200_and_the_spending_since_the_last_settlement(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    let cookie_1 = user.cookie.as_str();
+    let cookie_adm = group.admin.cookie.as_str();
+    app.join_group(&group, cookie_1).await?;
+    app.create_expense(&group.id, cookie_1, "expense1", 100.0)
+        .await?;
+    app.settle(&group).await?;
+    app.create_expense(&group.id, cookie_1, "expense2", 30.0)
+        .await?;
+    app.create_expense(&group.id, cookie_adm, "expense3", 60.0)
+        .await?;
+    let mut body = HashMap::new();
+    body.insert("amount", serde_json::json!(120.0));
+    body.insert("period", serde_json::json!("settlement"));
+    app.client
+        .put(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_adm)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_1)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<BudgetResponse>().await?;
+    assert_eq!(body.success, true);
+    assert_eq!(body.data.amount, 120.0);
+    assert_eq!(body.data.period, "settlement");
+    assert!(body.data.start.is_some());
+    assert_eq!(body.data.spent, 90.0);
+    assert_eq!(body.data.remaining, 30.0);
+    assert_eq!(body.data.percentage, 75.0);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_budget_returns_404_when_group_has_no_budget(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_budget_returns_404_when_group_does_not_exist(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/budget",
+            &app.address,
+            Uuid::new_v4()
+        ))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_budget_returns_403_when_user_is_not_a_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+pub struct BudgetResponse {
+    pub success: bool,
+    pub data: BudgetData,
+}
+
+#[derive(serde::Deserialize)]
+pub struct BudgetData {
+    pub amount: f32,
+    pub period: String,
+    pub start: Option<DateTime<Utc>>,
+    pub spent: f32,
+    pub remaining: f32,
+    pub percentage: f32,
+}
@@ -0,0 +1,81 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_sessions_returns_200_and_the_sessions_of_the_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .header(header::USER_AGENT, "koru-phone")
+        .json(&json!({"email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    app.create_user_and_login("other", "o@r.com", "201").await?;
+
+    // Act
+    let sessions = app.get_sessions(&user.cookie).await?;
+
+    // Assert
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions.iter().filter(|s| s.current).count(), 1);
+    let phone = sessions
+        .iter()
+        .find(|s| s.user_agent.as_deref() == Some("koru-phone"))
+        .expect("Session should be listed");
+    assert!(!phone.current);
+    assert_eq!(phone.ip.as_deref(), Some("127.0.0.1"));
+    assert!(phone.last_seen_at >= phone.created_at);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_sessions_does_not_list_logged_out_sessions(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.login("r@r.com", "201").await?;
+    let response = app
+        .client
+        .post(&format!("{}/logout", &app.address))
+        .header(header::COOKIE, &other.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 204);
+
+    // Act
+    let sessions = app.get_sessions(&user.cookie).await?;
+
+    // Assert
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].current);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_sessions_returns_401_when_not_logged_in(app: &TestApp) {
+    // Arrange
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/sessions", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
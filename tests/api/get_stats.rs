@@ -0,0 +1,182 @@
+use crate::test_app::{TestApp, UserData};
+use chrono::Utc;
+use reqwest::header;
+use std::time::Duration;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_stats_returns_200_and_the_spending_aggregates(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    let cookie_1 = user.cookie.as_str();
+    let cookie_adm = group.admin.cookie.as_str();
+    app.join_group(&group, cookie_1).await?;
+    app.create_expense(&group.id, cookie_1, "expense1", 10.0)
+        .await?;
+    app.create_expense(&group.id, cookie_adm, "expense2", 25.0)
+        .await?;
+    app.settle(&group).await?;
+    app.create_expense(&group.id, cookie_1, "expense3", 20.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/stats", &app.address, &group.id))
+        .header(header::COOKIE, cookie_1)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<StatsResponse>().await?;
+    assert_eq!(body.success, true);
+    assert_eq!(body.data.total, 55.0);
+    assert_eq!(body.data.count, 3);
+    assert_eq!(body.data.members.len(), 2);
+    assert_eq!(body.data.members[0].member.id, user.id);
+    assert_eq!(body.data.members[0].total, 30.0);
+    assert_eq!(body.data.members[0].count, 2);
+    assert_eq!(body.data.members[1].member.id, group.admin.id);
+    assert_eq!(body.data.members[1].total, 25.0);
+    assert_eq!(body.data.months.len(), 1);
+    assert_eq!(
+        body.data.months[0].month,
+        Utc::now().format("%Y-%m").to_string()
+    );
+    assert_eq!(body.data.months[0].total, 55.0);
+    assert_eq!(
+        body.data
+            .largest
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect::<Vec<_>>(),
+        vec!["expense2", "expense3", "expense1"]
+    );
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_stats_returns_200_and_filters_by_date(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let cookie_adm = group.admin.cookie.as_str();
+    app.create_expense(&group.id, cookie_adm, "expense1", 10.0)
+        .await?;
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    let from = Utc::now().timestamp_millis();
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    app.create_expense(&group.id, cookie_adm, "expense2", 25.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!(
+            "{}/groups/{}/stats?from={}",
+            &app.address, &group.id, from
+        ))
+        .header(header::COOKIE, cookie_adm)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<StatsResponse>().await?;
+    assert_eq!(body.data.total, 25.0);
+    assert_eq!(body.data.count, 1);
+    assert_eq!(body.data.largest.len(), 1);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_stats_returns_403_when_user_is_not_a_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/stats", &app.address, &group.id))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_stats_returns_404_when_group_does_not_exist(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/stats", &app.address, Uuid::new_v4()))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+pub struct StatsResponse {
+    pub success: bool,
+    pub data: StatsData,
+}
+
+#[derive(serde::Deserialize)]
+pub struct StatsData {
+    pub total: f32,
+    pub count: u32,
+    pub members: Vec<MemberTotalData>,
+    pub months: Vec<MonthTotalData>,
+    pub largest: Vec<LargestExpenseData>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct MemberTotalData {
+    pub member: UserData,
+    pub total: f32,
+    pub count: u32,
+}
+
+#[derive(serde::Deserialize)]
+pub struct MonthTotalData {
+    pub month: String,
+    pub total: f32,
+}
+
+#[derive(serde::Deserialize)]
+pub struct LargestExpenseData {
+    pub description: String,
+}
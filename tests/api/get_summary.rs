@@ -0,0 +1,135 @@
+use crate::test_app::{TestApp, TransactionData};
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_summary_returns_200_and_the_balances_of_the_user_groups(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group_1 = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let group_2 = app
+        .create_user_and_group("rbiland2", "r2@r.com", "201", "my group 2")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group_1, other_user.cookie.as_str()).await?;
+    app.join_group(&group_2, other_user.cookie.as_str()).await?;
+    app.create_expense(&group_1.id, group_1.admin.cookie.as_str(), "expense", 20.0)
+        .await?;
+    app.settle(&group_1).await?;
+    app.create_expense(&group_1.id, other_user.cookie.as_str(), "expense", 6.0)
+        .await?;
+    app.create_expense(&group_2.id, other_user.cookie.as_str(), "expense", 8.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<GetSummaryResponse>().await?;
+    assert_eq!(body.success, true);
+    assert_eq!(body.data.groups.len(), 2);
+    let grp_1 = body
+        .data
+        .groups
+        .iter()
+        .find(|grp| grp.id == group_1.id)
+        .unwrap();
+    assert_eq!(grp_1.name, "my group");
+    assert_eq!(grp_1.balance, 3.0);
+    assert_eq!(grp_1.outstanding, -10.0);
+    assert_eq!(grp_1.transactions.len(), 1);
+    assert_eq!(grp_1.transactions[0].from.id, other_user.id);
+    assert_eq!(grp_1.transactions[0].to.id, group_1.admin.id);
+    let grp_2 = body
+        .data
+        .groups
+        .iter()
+        .find(|grp| grp.id == group_2.id)
+        .unwrap();
+    assert_eq!(grp_2.balance, 4.0);
+    assert_eq!(grp_2.outstanding, 0.0);
+    assert!(grp_2.transactions.is_empty());
+    assert_eq!(body.data.total, -3.0);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_summary_returns_200_and_an_empty_summary_if_user_has_no_groups(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<GetSummaryResponse>().await?;
+    assert_eq!(body.data.groups.len(), 0);
+    assert_eq!(body.data.total, 0.0);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_summary_returns_401_if_user_is_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+pub struct GetSummaryResponse {
+    pub success: bool,
+    pub data: SummaryData,
+}
+
+#[derive(serde::Deserialize)]
+pub struct SummaryData {
+    pub total: f32,
+    pub groups: Vec<GroupSummaryData>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct GroupSummaryData {
+    pub id: Uuid,
+    pub name: String,
+    pub balance: f32,
+    pub outstanding: f32,
+    pub transactions: Vec<TransactionData>,
+    #[allow(dead_code)]
+    pub last_activity: DateTime<Utc>,
+}
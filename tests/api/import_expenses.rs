@@ -0,0 +1,210 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+const CONTENT: &str = "Date,Description,Category,Cost,Currency,Alice,Bob\n\
+    2023-01-02,Groceries,General,30.00,EUR,15.00,-15.00\n\
+    2023-01-04,Dinner,General,40.00,EUR,-20.00,20.00\n\
+    ,Total balance,,,EUR,-5.00,5.00\n";
+
+#[derive(serde::Deserialize)]
+struct ImportResponse {
+    success: bool,
+    data: ImportReport,
+}
+
+#[derive(serde::Deserialize)]
+struct ImportReport {
+    dry_run: bool,
+    imported: u32,
+    errors: Vec<ImportError>,
+}
+
+#[derive(serde::Deserialize)]
+struct ImportError {
+    line: u32,
+    error: String,
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn import_expenses_return_200_and_create_the_expenses(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let body = serde_json::json!({
+        "format": "splitwise",
+        "content": CONTENT,
+        "members": { "Alice": group.admin.id, "Bob": user.id },
+    });
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/expenses/import",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<ImportResponse>().await?;
+    assert!(body.success);
+    assert!(!body.data.dry_run);
+    assert_eq!(body.data.imported, 2);
+    assert!(body.data.errors.is_empty());
+    let expense = app.get_expense().await.unwrap();
+    assert_eq!(expense.group_id, group.id);
+    assert_eq!(app.get_event_type().await.unwrap(), "ExpenseCreated");
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn import_expenses_return_422_and_the_errors_per_line(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let body = serde_json::json!({
+        "format": "splitwise",
+        "content": CONTENT,
+        "members": { "Alice": group.admin.id },
+    });
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/expenses/import",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 422);
+    let body = response.json::<ImportResponse>().await?;
+    assert!(!body.success);
+    assert_eq!(body.data.imported, 0);
+    assert_eq!(body.data.errors.len(), 1);
+    assert_eq!(body.data.errors[0].line, 3);
+    assert_eq!(
+        body.data.errors[0].error,
+        "No group member is mapped to Bob."
+    );
+    assert!(app.get_expense().await.is_none());
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn import_expenses_return_200_without_importing_on_a_dry_run(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let body = serde_json::json!({
+        "format": "generic",
+        "content": "date,title,amount,paid_by\n2023-01-02,Taxi,12.5,Alice\n",
+        "members": { "Alice": group.admin.id },
+        "dry_run": true,
+    });
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/expenses/import",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<ImportResponse>().await?;
+    assert!(body.data.dry_run);
+    assert_eq!(body.data.imported, 1);
+    assert!(app.get_expense().await.is_none());
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn import_expenses_return_400_for_an_unknown_format(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let body = serde_json::json!({
+        "format": "excel",
+        "content": CONTENT,
+        "members": {},
+    });
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/expenses/import",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn import_expenses_return_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let body = serde_json::json!({
+        "format": "splitwise",
+        "content": CONTENT,
+        "members": { "Alice": group.admin.id, "Bob": user.id },
+    });
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/expenses/import",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, user.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn inspect_group_returns_200_and_the_full_group(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.create_expense(&group.id, &group.admin.cookie, "first", 12.0)
+        .await?;
+    app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<serde_json::Value>().await?;
+    let moderated = &body["data"]["group"];
+    assert_eq!(moderated["group"]["id"], group.id.to_string());
+    assert_eq!(moderated["group"]["expenses"].as_array().unwrap().len(), 1);
+    assert_eq!(moderated["settlements"].as_array().unwrap().len(), 1);
+    let kinds = moderated["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["kind"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["GroupCreated", "ExpenseCreated", "Settled"]);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn inspect_group_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn inspect_group_returns_404_for_unknown_groups(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/admin/groups/{}", &app.address, Uuid::new_v4()))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::test_app::{LoginResponse, TestApp};
+use crate::test_app::{LoginResponse, RateLimitTestApp, TestApp};
 use claim::assert_gt;
 use serde_json::json;
 use test_context::test_context;
@@ -105,3 +105,64 @@ async fn login_on_db_error_returns_500(app: &TestApp) {
     // Assert
     assert_eq!(response.status().as_u16(), 500,);
 }
+
+#[test_context(RateLimitTestApp)]
+#[tokio::test]
+async fn login_should_return_429_once_account_is_locked_out(
+    app: &RateLimitTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    for _ in 0..3 {
+        let response = app.try_login("r@r.com", "101").await;
+        assert_eq!(response.status().as_u16(), 401);
+    }
+    // Act
+    let response = app.try_login("r@r.com", "201").await;
+    // Assert
+    assert_eq!(response.status().as_u16(), 429);
+    let retry_after = response
+        .headers()
+        .get("Retry-After")
+        .expect("Retry-After should exist")
+        .to_str()?
+        .parse::<u64>()?;
+    assert!(retry_after > 0 && retry_after <= 60);
+    let body = response.json::<serde_json::Value>().await?;
+    assert_eq!(body["success"], false);
+    assert_eq!(body["data"]["error"], "Too many attempts, retry later.");
+    Ok(())
+}
+
+#[test_context(RateLimitTestApp)]
+#[tokio::test]
+async fn login_should_not_lock_other_accounts_out(app: &RateLimitTestApp) {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    app.create_user("bob", "b@b.com", "201").await;
+    for _ in 0..3 {
+        app.try_login("r@r.com", "101").await;
+    }
+    // Act
+    let response = app.try_login("b@b.com", "201").await;
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+}
+
+#[test_context(RateLimitTestApp)]
+#[tokio::test]
+async fn login_should_forget_failures_on_success(app: &RateLimitTestApp) {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    for _ in 0..2 {
+        app.try_login("r@r.com", "101").await;
+    }
+    assert_eq!(app.try_login("r@r.com", "201").await.status().as_u16(), 200);
+    // Act
+    for _ in 0..2 {
+        app.try_login("r@r.com", "101").await;
+    }
+    let response = app.try_login("r@r.com", "201").await;
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+}
@@ -0,0 +1,123 @@
+use crate::test_app::{LoginResponse, TwoFactorTestApp};
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn login_second_factor_returns_200_and_logs_in_given_a_recovery_code(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let two_factor = app.enable_two_factor(&user.cookie).await?;
+    let cookie = app.start_login("r@r.com", "201").await;
+    let response = app
+        .client
+        .get(&format!("{}/groups", &app.address))
+        .header(header::COOKIE, &cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+
+    // Act
+    let response = app
+        .complete_login(&cookie, &two_factor.recovery_codes[0])
+        .await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let cookie = format!("id={}", response.cookies().next().unwrap().value());
+    assert_eq!(response.json::<LoginResponse>().await?.data.id, user.id);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserLogin");
+    let response = app
+        .client
+        .get(&format!("{}/groups", &app.address))
+        .header(header::COOKIE, &cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn login_second_factor_returns_401_given_an_invalid_or_used_code(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let two_factor = app.enable_two_factor(&user.cookie).await?;
+    let cookie = app.start_login("r@r.com", "201").await;
+    let response = app
+        .complete_login(&cookie, &two_factor.recovery_codes[0])
+        .await;
+    assert_eq!(response.status().as_u16(), 200);
+    let cookie = app.start_login("r@r.com", "201").await;
+
+    // Act
+    let used = app
+        .complete_login(&cookie, &two_factor.recovery_codes[0])
+        .await;
+    // a failed attempt closes the pending login
+    let closed = app
+        .complete_login(&cookie, &two_factor.recovery_codes[1])
+        .await;
+
+    // Assert
+    assert_eq!(used.status().as_u16(), 401);
+    assert_eq!(closed.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn login_second_factor_returns_401_without_a_pending_login(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/login/second-factor", &app.address))
+        .json(&serde_json::json!({ "code": "123456" }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn login_second_factor_returns_429_after_repeated_invalid_codes(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let two_factor = app.enable_two_factor(&user.cookie).await?;
+    for _ in 0..5 {
+        let cookie = app.start_login("r@r.com", "201").await;
+        let response = app.complete_login(&cookie, "000000000").await;
+        assert_eq!(response.status().as_u16(), 401);
+    }
+    let cookie = app.start_login("r@r.com", "201").await;
+
+    // Act
+    let response = app
+        .complete_login(&cookie, &two_factor.recovery_codes[0])
+        .await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 429);
+    assert!(response.headers().contains_key("Retry-After"));
+    Ok(())
+}
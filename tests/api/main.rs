@@ -1,24 +1,73 @@
+mod archive_group;
+mod backup_group;
+mod change_email;
 mod change_member_color;
+mod change_password;
+mod change_user_role;
+mod create_api_token;
 mod create_expense;
 mod create_group;
 mod delete_expense;
 mod delete_group;
+mod delete_user;
+mod disable_user;
+mod enable_user;
+mod enroll_two_factor;
+mod export_expenses;
+mod export_ledger;
+mod export_settlements;
+mod export_user_data;
+mod force_password_reset;
+mod forgot_password;
 mod generate_group_token;
+mod get_activity;
 mod get_all_groups;
 mod get_all_users;
+mod get_api_tokens;
+mod get_audit_log;
+mod get_budget;
 mod get_expenses;
 mod get_groups;
+mod get_sessions;
 mod get_settlements;
+mod get_stats;
+mod get_summary;
 mod health_check;
+mod import_expenses;
+mod inspect_group;
 mod join_group;
 mod login;
+mod login_second_factor;
 mod logout;
 mod mem_test_db;
+#[cfg(feature = "oidc")]
+mod mock_identity_provider;
+#[cfg(feature = "oidc")]
+mod oidc_callback;
+#[cfg(feature = "oidc")]
+mod oidc_login;
 #[cfg(feature = "postgres")]
 mod pg_test_db;
 mod register;
 mod register_device;
 mod remove_device;
+mod remove_group;
+mod remove_member;
+mod remove_user;
+mod resend_verification;
+mod reset_password;
+mod reset_two_factor;
+mod restore_group;
+mod revert_settlement;
+mod revoke_api_token;
+mod revoke_session;
+mod set_budget;
 mod settle;
+mod settle_debt;
 mod test_app;
+mod transfer_group_ownership;
+mod transfer_ownership;
+mod unarchive_group;
 mod update_expense;
+mod update_user_name;
+mod verify_email;
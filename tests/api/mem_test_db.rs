@@ -148,6 +148,9 @@ impl MemTestDb {
             .map(|(_, stl)| SettlementDto {
                 id: stl.id,
                 group_id: stl.group_id,
+                label: stl.label.clone(),
+                total: stl.total,
+                expense_count: stl.expense_count,
             })
     }
     pub async fn get_expenses_status(&self, ids: &[Uuid]) -> Vec<(Uuid, bool)> {
@@ -175,6 +178,7 @@ impl MemTestDb {
                         from_user_id: tr.from,
                         to_user_id: tr.to,
                         amount: tr.amount,
+                        paid: tr.paid,
                     })
                     .sorted_by(|a, b| {
                         a.amount
@@ -190,15 +194,9 @@ impl MemTestDb {
             .settlements
             .lock()
             .unwrap()
-            .iter()
-            .next()
-            .map(|(_, stl)| {
-                stl.expenses
-                    .iter()
-                    .map(|e_id| (stl.id, *e_id))
-                    .collect_vec()
-            })
-            .unwrap()
+            .values()
+            .flat_map(|stl| stl.expenses.iter().map(|e_id| (stl.id, *e_id)))
+            .collect_vec()
     }
 
     pub async fn delete_group(&self, id: Uuid) {
@@ -0,0 +1,94 @@
+use crate::test_app::OidcTestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_callback_creates_the_user_and_opens_a_session(app: &OidcTestApp) {
+    // Act
+    let user = app.login_through_provider("alice@corp.com").await;
+
+    // Assert
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    // the next login is linked to the same user
+    let again = app.login_through_provider("alice@corp.com").await;
+    assert_eq!(again.id, user.id);
+}
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_callback_returns_401_when_the_state_does_not_match(app: &OidcTestApp) {
+    // Arrange
+    let (cookie, authorization) = app.start_login().await;
+    let claims = app.idp.claims(&authorization, "alice@corp.com");
+    app.idp.issue_token("code", &authorization, claims).await;
+
+    // Act
+    let response = app.callback(&cookie, "code", "forged").await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_callback_returns_401_when_the_id_token_is_invalid(app: &OidcTestApp) {
+    // Arrange
+    let claims = [
+        ("nonce", "replayed".into()),
+        ("aud", "other-client".into()),
+        ("iss", "http://other-issuer.test".into()),
+        ("email_verified", false.into()),
+    ];
+    for (claim, value) in claims {
+        let (cookie, authorization) = app.start_login().await;
+        let mut token = app.idp.claims(&authorization, "alice@corp.com");
+        token[claim] = value;
+        let code = format!("code-{}", claim);
+        app.idp.issue_token(&code, &authorization, token).await;
+
+        // Act
+        let response = app.callback(&cookie, &code, &authorization.state).await;
+
+        // Assert
+        assert_eq!(response.status().as_u16(), 401, "with an invalid {}", claim);
+    }
+}
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_callback_returns_401_when_the_email_is_not_known_to_be_verified(app: &OidcTestApp) {
+    // Arrange
+    app.create_user("alice", "alice@corp.com", "password").await;
+    let (cookie, authorization) = app.start_login().await;
+    let mut claims = app.idp.claims(&authorization, "alice@corp.com");
+    claims.as_object_mut().unwrap().remove("email_verified");
+    app.idp.issue_token("code", &authorization, claims).await;
+
+    // Act
+    let response = app.callback(&cookie, "code", &authorization.state).await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_callback_returns_401_when_the_provider_refuses_the_code(app: &OidcTestApp) {
+    // Arrange
+    let (cookie, authorization) = app.start_login().await;
+
+    // Act
+    // no token is issued for this code
+    let response = app.callback(&cookie, "unknown", &authorization.state).await;
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+}
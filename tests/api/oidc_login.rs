@@ -0,0 +1,49 @@
+use crate::test_app::{OidcTestApp, TestApp};
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn oidc_login_redirects_to_the_provider_with_a_pkce_challenge(app: &OidcTestApp) {
+    // Act
+    let (_, authorization) = app.start_login().await;
+
+    // Assert
+    assert!(!authorization.state.is_empty());
+    assert!(!authorization.nonce.is_empty());
+    // base64url of a SHA-256 digest
+    assert_eq!(authorization.code_challenge.len(), 43);
+    let (_, other) = app.start_login().await;
+    assert_ne!(authorization.state, other.state);
+}
+
+#[test_context(OidcTestApp)]
+#[tokio::test]
+async fn login_returns_400_when_users_log_in_through_the_provider(app: &OidcTestApp) {
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .json(&json!({"email": "alice@corp.com", "password": "123"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn oidc_login_returns_400_when_no_provider_is_configured(app: &TestApp) {
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/oidc/login", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+}
@@ -174,7 +174,7 @@ impl PgTestDb {
     pub async fn get_settlement(&self) -> Option<SettlementDto> {
         let row = sqlx::query!(
             r#"
-        SELECT id, group_id, end_date FROM koru_settlement
+        SELECT id, group_id, end_date, label, total, expense_count FROM koru_settlement
         "#
         )
         .fetch_optional(&self.pg_pool)
@@ -183,6 +183,9 @@ impl PgTestDb {
         row.map(|r| SettlementDto {
             id: r.id,
             group_id: r.group_id,
+            label: r.label,
+            total: r.total,
+            expense_count: r.expense_count as u32,
         })
     }
     pub async fn get_expenses_status(&self, ids: &[Uuid]) -> Vec<(Uuid, bool)> {
@@ -203,7 +206,7 @@ impl PgTestDb {
     pub async fn get_transactions(&self) -> Vec<TransactionDto> {
         sqlx::query!(
             r#"
-        SELECT settlement_id, from_user_id, to_user_id, amount
+        SELECT settlement_id, from_user_id, to_user_id, amount, paid
         FROM koru_transaction
         ORDER BY amount ASC
         "#
@@ -217,6 +220,7 @@ impl PgTestDb {
             from_user_id: r.from_user_id,
             to_user_id: r.to_user_id,
             amount: r.amount,
+            paid: r.paid,
         })
         .collect_vec()
     }
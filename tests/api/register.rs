@@ -1,4 +1,4 @@
-use crate::test_app::TestApp;
+use crate::test_app::{RateLimitTestApp, TestApp};
 use serde_json::json;
 use test_context::test_context;
 
@@ -96,3 +96,24 @@ async fn registration_duplicate_returns_500(app: &TestApp) {
     // Assert
     assert_eq!(response.status().as_u16(), 500,);
 }
+
+#[test_context(RateLimitTestApp)]
+#[tokio::test]
+async fn registration_should_return_429_on_too_many_requests_from_ip(app: &RateLimitTestApp) {
+    // Arrange
+    for i in 0..10 {
+        app.create_user("rbiland", &format!("r{}@r.com", i), "201")
+            .await;
+    }
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/register", &app.address))
+        .json(&json!({"name":"rbiland","email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 429);
+    assert!(response.headers().contains_key("Retry-After"));
+}
@@ -0,0 +1,55 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_group_returns_204_and_deletes_the_group(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.create_expense(&group.id, &group.admin.cookie, "my expense", 12.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/admin/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert!(app.get_group_by_id(group.id).await.is_none());
+    assert_eq!(app.get_event_type().await.unwrap(), "GroupDeleted");
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_group_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/admin/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert!(app.get_group_by_id(group.id).await.is_some());
+    Ok(())
+}
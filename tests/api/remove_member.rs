@@ -0,0 +1,137 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_member_returns_204_and_removes_the_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/groups/{}/members/{}",
+            &app.address, group.id, other_user.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "MemberRemoved");
+    assert!(app.get_member_by_id(other_user.id).await.is_none());
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_member_returns_409_for_members_with_expenses(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    app.create_expense(&group.id, &other_user.cookie, "my expense", 12.0)
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/groups/{}/members/{}",
+            &app.address, group.id, other_user.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    assert!(app.get_member_by_id(other_user.id).await.is_some());
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_member_returns_409_for_the_group_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/groups/{}/members/{}",
+            &app.address, group.id, group.admin.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn remove_member_returns_403_for_non_admin_users(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/groups/{}/members/{}",
+            &app.address, group.id, other_user.id
+        ))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
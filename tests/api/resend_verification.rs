@@ -0,0 +1,58 @@
+use crate::test_app::VerificationTestApp;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(VerificationTestApp)]
+#[tokio::test]
+async fn resend_verification_returns_202_and_only_the_new_link_is_valid(
+    app: &VerificationTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    let previous = app.last_mail_token();
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/verification/resend", &app.address))
+        .json(&json!({"email":"r@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 202);
+    let token = app.last_mail_token();
+    assert_ne!(token, previous);
+    let verify = |token: String| {
+        app.client
+            .post(&format!("{}/verification", &app.address))
+            .json(&json!({ "token": token }))
+            .send()
+    };
+    let response = verify(previous).await.expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 400);
+    let response = verify(token).await.expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 200);
+    Ok(())
+}
+
+#[test_context(VerificationTestApp)]
+#[tokio::test]
+async fn resend_verification_returns_202_for_an_unknown_email(
+    app: &VerificationTestApp,
+) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/verification/resend", &app.address))
+        .json(&json!({"email":"unknown@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 202);
+    assert!(app.last_mail().is_none());
+    Ok(())
+}
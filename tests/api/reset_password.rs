@@ -0,0 +1,73 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn reset_password_returns_200_and_the_link_can_only_be_used_once(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+    app.client
+        .post(&format!("{}/password/forgot", &app.address))
+        .json(&json!({"email":"r@r.com"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    app.wait_for_mail().await;
+    let token = app.last_mail_token();
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/reset", &app.address))
+        .json(&json!({"token":token,"password":"202"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let response = app
+        .client
+        .get(&format!("{}/me/summary", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    app.login("r@r.com", "202").await?;
+    let response = app
+        .client
+        .post(&format!("{}/password/reset", &app.address))
+        .json(&json!({"token":token,"password":"203"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn reset_password_returns_400_given_an_unknown_token(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/password/reset", &app.address))
+        .json(&json!({"token":"0123456789abcdef","password":"202"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::test_app::TwoFactorTestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn reset_two_factor_returns_204_and_removes_the_second_factor(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    app.enable_two_factor(&user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/users/{}/two-factor",
+            &app.address, user.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserTwoFactorReset");
+    assert_eq!(app.login("r@r.com", "201").await?.id, user.id);
+    Ok(())
+}
+
+#[test_context(TwoFactorTestApp)]
+#[tokio::test]
+async fn reset_two_factor_returns_403_for_non_admin_users(
+    app: &TwoFactorTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.create_user_and_login("other", "o@r.com", "201").await?;
+    app.enable_two_factor(&other.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/admin/users/{}/two-factor",
+            &app.address, other.id
+        ))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::test_app::{CreateGroupResponse, TestApp};
+use claim::{assert_none, assert_some};
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+async fn backup(app: &TestApp, group_id: &Uuid, cookie: &str) -> anyhow::Result<serde_json::Value> {
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/backup", &app.address, group_id))
+        .header(header::COOKIE, cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    Ok(response.json::<serde_json::Value>().await?)
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn restore_group_return_201_and_recreate_the_group(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let _ = app
+        .create_expense(&group.id, &group.admin.cookie, "Pizza", 30.0)
+        .await?;
+    app.settle(&group).await?;
+    let _ = app
+        .create_expense(&group.id, &group.admin.cookie, "Beer", 12.0)
+        .await?;
+    let document = backup(app, &group.id, &group.admin.cookie).await?;
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/groups/restore", &app.address))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&document)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 201);
+    let body = response.json::<CreateGroupResponse>().await?;
+    assert_ne!(body.data.id, group.id);
+    let saved = assert_some!(app.get_group_by_id(body.data.id).await);
+    assert_eq!(saved.name, "my group");
+    assert_eq!(saved.admin_id, group.admin.id);
+    let restored = backup(app, &body.data.id, &group.admin.cookie).await?;
+    for key in ["members", "expenses", "settlements", "events"] {
+        assert_eq!(
+            restored[key].as_array().unwrap().len(),
+            document[key].as_array().unwrap().len()
+        );
+    }
+    assert_eq!(
+        restored["settlements"][0]["transactions"],
+        document["settlements"][0]["transactions"]
+    );
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn restore_group_return_403_when_the_backup_lists_another_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    let mut document = backup(app, &group.id, &group.admin.cookie).await?;
+    let mut forged = document["members"][0].clone();
+    forged["email"] = "r1@r.com".into();
+    forged["is_admin"] = false.into();
+    document["members"].as_array_mut().unwrap().push(forged);
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/groups/restore", &app.address))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&document)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert_none!(app.get_member_by_id(user.id).await);
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn restore_group_return_400_for_an_unsupported_version(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let mut document = backup(app, &group.id, &group.admin.cookie).await?;
+    document["version"] = 2.into();
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/groups/restore", &app.address))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&document)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn restore_group_return_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, &user.cookie).await?;
+    let document = backup(app, &group.id, &group.admin.cookie).await?;
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/groups/restore", &app.address))
+        .header(header::COOKIE, user.cookie.as_str())
+        .json(&document)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+
+    Ok(())
+}
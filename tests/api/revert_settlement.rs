@@ -0,0 +1,175 @@
+use crate::test_app::TestApp;
+use claim::{assert_none, assert_some};
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revert_settlement_returns_204_and_unsettles_expenses_when_user_is_admin(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    let exp1 = app
+        .create_expense(&group.id, other_user.cookie.as_str(), "expense", 12.0)
+        .await?;
+    let exp2 = app
+        .create_expense(&group.id, group.admin.cookie.as_str(), "expense", 4.0)
+        .await?;
+    let settlement = app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/groups/{}/settlements/{}",
+            &app.address, &group.id, &settlement.id
+        ))
+        .header(header::COOKIE, group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_none!(app.get_settlement().await);
+    assert!(app.get_transactions().await.is_empty());
+    assert!(app.settled_expenses().await.is_empty());
+    let expenses = app.get_expenses_status(&[exp1, exp2]).await;
+    assert_eq!(expenses.len(), 2);
+    assert!(expenses.iter().all(|r| !r.1)); // not settled
+    assert_eq!(
+        app.get_event_type().await,
+        Some("SettlementReverted".to_string())
+    );
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revert_settlement_returns_409_when_settlement_is_not_the_last_one(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    app.create_expense(&group.id, other_user.cookie.as_str(), "expense", 12.0)
+        .await?;
+    let first = app.settle(&group).await?;
+    app.create_expense(&group.id, other_user.cookie.as_str(), "expense", 8.0)
+        .await?;
+    app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/groups/{}/settlements/{}",
+            &app.address, &group.id, &first.id
+        ))
+        .header(header::COOKIE, group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    assert_some!(app.get_settlement().await);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revert_settlement_returns_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    app.create_expense(&group.id, other_user.cookie.as_str(), "expense", 12.0)
+        .await?;
+    let settlement = app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/groups/{}/settlements/{}",
+            &app.address, &group.id, &settlement.id
+        ))
+        .header(header::COOKIE, other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    assert_some!(app.get_settlement().await);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revert_settlement_returns_404_when_settlement_does_not_exist(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/groups/{}/settlements/{}",
+            &app.address,
+            &group.id,
+            Uuid::new_v4()
+        ))
+        .header(header::COOKIE, group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revert_settlement_returns_401_when_user_is_not_logged_in(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let settlement = app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/groups/{}/settlements/{}",
+            &app.address, &group.id, &settlement.id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
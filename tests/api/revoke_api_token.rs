@@ -0,0 +1,92 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_api_token_returns_204_and_rejects_the_token(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let token = app.create_api_token("read", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/me/tokens/{}",
+            &app.address, token.api_token.id
+        ))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "UserApiTokenRevoked");
+    let response = app
+        .client
+        .get(&format!("{}/me/tokens", &app.address))
+        .bearer_auth(&token.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_api_token_returns_404_for_the_token_of_another_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.create_user_and_login("other", "o@r.com", "201").await?;
+    let token = app.create_api_token("write", &user.cookie).await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!(
+            "{}/me/tokens/{}",
+            &app.address, token.api_token.id
+        ))
+        .header(header::COOKIE, &other.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let unknown = app
+        .client
+        .delete(&format!("{}/me/tokens/{}", &app.address, Uuid::new_v4()))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    assert_eq!(unknown.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_api_token_returns_401_when_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me/tokens/{}", &app.address, Uuid::new_v4()))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
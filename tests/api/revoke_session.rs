@@ -0,0 +1,114 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_session_returns_204_and_logs_the_session_out(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let lost = app.login("r@r.com", "201").await?;
+    let session = app
+        .get_sessions(&user.cookie)
+        .await?
+        .into_iter()
+        .find(|s| !s.current)
+        .expect("Session should be listed");
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me/sessions/{}", &app.address, session.id))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    let response = app
+        .client
+        .get(&format!("{}/groups", &app.address))
+        .header(header::COOKIE, &lost.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 401);
+    let sessions = app.get_sessions(&user.cookie).await?;
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].current);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_session_returns_404_for_the_session_of_another_user(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.create_user_and_login("other", "o@r.com", "201").await?;
+    let session = app.get_sessions(&other.cookie).await?.remove(0);
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me/sessions/{}", &app.address, session.id))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    let response = app
+        .client
+        .delete(&format!("{}/me/sessions/{}", &app.address, Uuid::new_v4()))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 404);
+    assert_eq!(app.get_sessions(&other.cookie).await?.len(), 1);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn revoke_sessions_returns_204_and_logs_every_session_out(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login("rbiland", "r@r.com", "201")
+        .await?;
+    let other = app.login("r@r.com", "201").await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/me/sessions", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    for cookie in [&user.cookie, &other.cookie] {
+        let response = app
+            .client
+            .get(&format!("{}/groups", &app.address))
+            .header(header::COOKIE, cookie)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status().as_u16(), 401);
+    }
+    Ok(())
+}
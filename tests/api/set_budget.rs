@@ -0,0 +1,176 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use std::collections::HashMap;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn set_budget_returns_200_and_the_budget_consumption(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let cookie_adm = group.admin.cookie.as_str();
+    app.create_expense(&group.id, cookie_adm, "expense1", 40.0)
+        .await?;
+    let mut body = HashMap::new();
+    body.insert("amount", serde_json::json!(200.0));
+    body.insert("period", serde_json::json!("settlement"));
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_adm)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<BudgetResponse>().await?;
+    assert_eq!(body.success, true);
+    assert_eq!(body.data.amount, 200.0);
+    assert_eq!(body.data.period, "settlement");
+    assert_eq!(body.data.spent, 40.0);
+    assert_eq!(body.data.percentage, 20.0);
+    assert_eq!(
+        app.get_event_type().await,
+        Some("BudgetChanged".to_string())
+    );
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn set_budget_returns_400_for_an_invalid_period(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let mut body = HashMap::new();
+    body.insert("amount", serde_json::json!(200.0));
+    body.insert("period", serde_json::json!("weekly"));
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn set_budget_returns_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, user.cookie.as_str()).await?;
+    let mut body = HashMap::new();
+    body.insert("amount", serde_json::json!(200.0));
+    body.insert("period", serde_json::json!("monthly"));
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, user.cookie.as_str())
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_budget_returns_200_and_removes_the_budget(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let cookie_adm = group.admin.cookie.as_str();
+    let mut body = HashMap::new();
+    body.insert("amount", serde_json::json!(200.0));
+    body.insert("period", serde_json::json!("monthly"));
+    app.client
+        .put(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_adm)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_adm)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, cookie_adm)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn delete_budget_returns_404_when_group_has_no_budget(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .delete(&format!("{}/groups/{}/budget", &app.address, &group.id))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+pub struct BudgetResponse {
+    pub success: bool,
+    pub data: BudgetData,
+}
+
+#[derive(serde::Deserialize)]
+pub struct BudgetData {
+    pub amount: f32,
+    pub period: String,
+    pub spent: f32,
+    pub percentage: f32,
+}
@@ -1,6 +1,7 @@
-use crate::test_app::{SettlementResponse, TestApp, TransactionData, UserData};
+use crate::test_app::{BalanceData, SettlementResponse, TestApp, TransactionData, UserData};
 use claim::{assert_none, assert_some};
 use reqwest::header;
+use serde_json::json;
 use test_context::test_context;
 
 #[test_context(TestApp)]
@@ -148,6 +149,127 @@ async fn settle_returns_201_when_user_is_admin(app: &TestApp) -> anyhow::Result<
     Ok(())
 }
 
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_returns_201_with_label_and_member_summary(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let user_1 = app
+        .create_user_and_login_and_device("r1", "r1@r.com", "201")
+        .await?;
+    app.join_group(&group, user_1.cookie.as_str()).await?;
+    app.create_expense(&group.id, user_1.cookie.as_str(), "expense", 30.0)
+        .await?;
+    app.create_expense(&group.id, user_1.cookie.as_str(), "expense", 10.0)
+        .await?;
+    app.create_expense(&group.id, group.admin.cookie.as_str(), "expense", 20.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/settlements",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&json!({ "label": "Rome trip" }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 201);
+    let saved = assert_some!(app.get_settlement().await);
+    assert_eq!(saved.label, Some("Rome trip".to_string()));
+    assert_eq!(saved.total, 60.0);
+    assert_eq!(saved.expense_count, 3);
+
+    let body = response.json::<SettlementResponse>().await?;
+    assert_eq!(body.data.label, Some("Rome trip".to_string()));
+    assert_eq!(body.data.total, 60.0);
+    assert_eq!(body.data.expense_count, 3);
+    assert_eq!(
+        body.data.balances,
+        vec![
+            BalanceData {
+                member: UserData {
+                    id: user_1.id,
+                    name: String::from("r1")
+                },
+                paid: 40.0,
+                share: 30.0,
+                net: 10.0
+            },
+            BalanceData {
+                member: UserData {
+                    id: group.admin.id,
+                    name: String::from("rbiland")
+                },
+                paid: 20.0,
+                share: 30.0,
+                net: -10.0
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_returns_400_when_label_is_empty(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.create_expense(&group.id, group.admin.cookie.as_str(), "expense", 20.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/settlements",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .json(&json!({ "label": " " }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_none!(app.get_settlement().await);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_returns_400_when_payload_is_malformed(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.create_expense(&group.id, group.admin.cookie.as_str(), "expense", 20.0)
+        .await?;
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/groups/{}/settlements",
+            &app.address, &group.id
+        ))
+        .header(header::COOKIE, group.admin.cookie.as_str())
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(r#"{"label": "Rome trip""#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_none!(app.get_settlement().await);
+    Ok(())
+}
+
 #[test_context(TestApp)]
 #[tokio::test]
 async fn settle_returns_403_when_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
@@ -0,0 +1,226 @@
+use crate::test_app::{TestApp, UserData};
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_debt_returns_200_and_nets_transactions_across_groups(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group_1 = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let group_2 = app
+        .create_user_and_group("r", "r1@r.com", "123", "my group 2")
+        .await?;
+    let other_user = &group_2.admin;
+    app.join_group(&group_1, other_user.cookie.as_str()).await?;
+    app.join_group(&group_2, group_1.admin.cookie.as_str())
+        .await?;
+    app.create_expense(&group_1.id, group_1.admin.cookie.as_str(), "expense", 30.0)
+        .await?;
+    app.settle(&group_1).await?;
+    app.create_expense(&group_2.id, other_user.cookie.as_str(), "expense", 10.0)
+        .await?;
+    app.settle(&group_2).await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/debts/{}", &app.address, other_user.id))
+        .header(header::COOKIE, &group_1.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let body = response.json::<GetDebtResponse>().await?;
+    assert_eq!(body.success, true);
+    assert_eq!(body.data.user.id, other_user.id);
+    assert_eq!(body.data.net, 10.0);
+    assert_eq!(body.data.transactions.len(), 2);
+    let payment = body.data.payment.unwrap();
+    assert_eq!(payment.from.id, other_user.id);
+    assert_eq!(payment.to.id, group_1.admin.id);
+    assert_eq!(payment.amount, 10.0);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn get_debt_returns_404_when_user_does_not_exist(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .get(&format!("{}/me/debts/{}", &app.address, Uuid::new_v4()))
+        .header(header::COOKIE, user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_debt_returns_200_and_marks_transactions_paid(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+    app.create_expense(&group.id, group.admin.cookie.as_str(), "expense", 30.0)
+        .await?;
+    app.settle(&group).await?;
+    app.create_expense(&group.id, other_user.cookie.as_str(), "expense", 10.0)
+        .await?;
+    app.settle(&group).await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/me/debts/{}/settle",
+            &app.address, group.admin.id
+        ))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let transactions = app.get_transactions().await;
+    assert!(!transactions.is_empty());
+    assert!(transactions.iter().all(|tr| tr.paid));
+    let response = app
+        .client
+        .get(&format!("{}/me/debts/{}", &app.address, group.admin.id))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let body = response.json::<GetDebtResponse>().await?;
+    assert!(body.data.transactions.is_empty());
+    assert!(body.data.payment.is_none());
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_debt_returns_404_when_there_is_nothing_to_settle(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r1@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/me/debts/{}/settle",
+            &app.address, group.admin.id
+        ))
+        .header(header::COOKIE, &other_user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_debt_returns_400_when_settling_with_oneself(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/me/debts/{}/settle", &app.address, user.id))
+        .header(header::COOKIE, &user.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn settle_debt_returns_401_if_user_is_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/me/debts/{}/settle",
+            &app.address,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+pub struct GetDebtResponse {
+    pub success: bool,
+    pub data: DebtData,
+}
+
+#[derive(serde::Deserialize)]
+pub struct DebtData {
+    pub user: UserData,
+    pub net: f32,
+    pub payment: Option<PaymentData>,
+    pub transactions: Vec<DebtTransactionData>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct PaymentData {
+    pub from: UserData,
+    pub to: UserData,
+    pub amount: f32,
+}
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+pub struct DebtTransactionData {
+    pub group_id: Uuid,
+    pub settlement_id: Uuid,
+    pub date: DateTime<Utc>,
+    pub from: UserData,
+    pub to: UserData,
+    pub amount: f32,
+}
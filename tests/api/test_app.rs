@@ -1,23 +1,37 @@
 use crate::mem_test_db::MemTestDb;
+#[cfg(feature = "oidc")]
+use crate::mock_identity_provider::{Authorization, MockIdentityProvider};
 #[cfg(feature = "postgres")]
 use crate::pg_test_db::PgTestDb;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use koru::api::RestApi;
 use koru::application::app::Application;
-use koru::configuration::application::SessionStoreSettings;
+use koru::application::auth::current_code;
+#[cfg(feature = "oidc")]
+use koru::configuration::application::AuthSettings;
+use koru::configuration::application::{
+    EmailVerificationSettings, RateLimitStoreSettings, SessionStoreSettings, TwoFactorSettings,
+};
 use koru::configuration::event_bus::EventBusSettings;
+use koru::configuration::mail::{FileMailSettings, MailTransportSettings};
 use koru::configuration::store::DatabaseSettings;
 use koru::configuration::{get_configuration, Settings};
 use koru::infrastructure::event_bus::EventBusImpl;
 use koru::infrastructure::store::StoreImpl;
 use koru::utils::telemetry::{get_subscriber, init_subscriber};
+use koru::worker::Worker;
 use once_cell::sync::Lazy;
 use reqwest::header;
+use secrecy::Secret;
 use serde::Deserialize;
 use serde_json::json;
+use std::ops::Deref;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use test_context::AsyncTestContext;
+use tokio::time::sleep;
 use uuid::Uuid;
 
 pub enum Infra {
@@ -45,6 +59,7 @@ pub struct TestApp {
     pub address: String,
     pub db: TestDb,
     pub client: reqwest::Client,
+    pub mail_dir: PathBuf,
 }
 
 pub enum TestDb {
@@ -66,11 +81,29 @@ static TRACING: Lazy<()> = Lazy::new(|| {
     };
 });
 
+// Do not push notifications to the devices registered by the test cases
+fn without_notifications(c: &mut Settings) {
+    #[cfg(feature = "notification")]
+    {
+        c.application.notification = None;
+    }
+}
+
+// Write the mails of each test case to its own directory
+fn use_mail_dir(c: &mut Settings) -> PathBuf {
+    let mail_dir = std::env::temp_dir().join(format!("koru-mails-{}", Uuid::new_v4()));
+    c.application.mail.transport = MailTransportSettings::File(FileMailSettings {
+        directory: Some(mail_dir.to_string_lossy().to_string()),
+    });
+    mail_dir
+}
+
 fn in_memory_config() -> Settings {
     let mut c = get_configuration().expect("Failed to read configuration.");
     c.database = DatabaseSettings::Memory;
     c.event_bus = EventBusSettings::Memory;
     c.api.session.store = SessionStoreSettings::Memory;
+    c.api.rate_limit.store = RateLimitStoreSettings::Memory;
     // Use a random OS port
     c.api.host = "127.0.0.1".to_string();
     c.api.port = 0;
@@ -96,6 +129,8 @@ fn ext_config() -> Settings {
         }
         EventBusSettings::Memory => EventBusSettings::Memory,
     };
+    // Do not share the rate limits of the local IP between test cases
+    c.api.rate_limit.store = RateLimitStoreSettings::Memory;
     // Use a random OS port
     c.api.host = "127.0.0.1".to_string();
     c.api.port = 0;
@@ -105,6 +140,256 @@ fn ext_config() -> Settings {
 #[async_trait::async_trait]
 impl AsyncTestContext for TestApp {
     async fn setup() -> TestApp {
+        TestApp::build(|_| {}).await
+    }
+
+    async fn teardown(self) {
+        let _ = std::fs::remove_dir_all(&self.mail_dir);
+        match self.db {
+            #[cfg(feature = "postgres")]
+            TestDb::Postgres(db) => db.teardown().await,
+            TestDb::Memory(_) => {}
+        }
+    }
+}
+
+/// Test application requiring new users to verify their email.
+pub struct VerificationTestApp(pub TestApp);
+
+#[async_trait::async_trait]
+impl AsyncTestContext for VerificationTestApp {
+    async fn setup() -> VerificationTestApp {
+        VerificationTestApp(
+            TestApp::build(|c| {
+                c.application.email_verification = Some(EmailVerificationSettings { validity: 24 })
+            })
+            .await,
+        )
+    }
+
+    async fn teardown(self) {
+        self.0.teardown().await
+    }
+}
+
+impl Deref for VerificationTestApp {
+    type Target = TestApp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Test application where users can enroll a second factor.
+pub struct TwoFactorTestApp(pub TestApp);
+
+#[async_trait::async_trait]
+impl AsyncTestContext for TwoFactorTestApp {
+    async fn setup() -> TwoFactorTestApp {
+        TwoFactorTestApp(
+            TestApp::build(|c| {
+                c.application.two_factor = Some(TwoFactorSettings {
+                    issuer: "koru".to_string(),
+                    encryption_key: Secret::new("test-key".to_string()),
+                })
+            })
+            .await,
+        )
+    }
+
+    async fn teardown(self) {
+        self.0.teardown().await
+    }
+}
+
+impl Deref for TwoFactorTestApp {
+    type Target = TestApp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TwoFactorTestApp {
+    pub async fn enroll_two_factor(&self, cookie: &str) -> Result<TwoFactorData> {
+        let response = self
+            .client
+            .post(&format!("{}/me/two-factor", &self.address))
+            .header(header::COOKIE, cookie)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        let body = response.json::<TwoFactorResponse>().await?;
+        Ok(body.data)
+    }
+
+    /// Enrolls a second factor and confirms it with the current code.
+    pub async fn enable_two_factor(&self, cookie: &str) -> Result<TwoFactorData> {
+        let two_factor = self.enroll_two_factor(cookie).await?;
+        let response = self
+            .client
+            .post(&format!("{}/me/two-factor/confirm", &self.address))
+            .header(header::COOKIE, cookie)
+            .json(&json!({ "code": two_factor.current_code() }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status().as_u16(), 200);
+        Ok(two_factor)
+    }
+
+    /// Logs in with the password, returning the cookie awaiting the second factor.
+    pub async fn start_login(&self, email: &str, password: &str) -> String {
+        let response = self
+            .client
+            .post(&format!("{}/login", &self.address))
+            .json(&json!({"email":email,"password":password}))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status().as_u16(), 202);
+        let cookie = response.cookies().next().expect("Cookie should exist");
+        format!("id={}", cookie.value())
+    }
+
+    pub async fn complete_login(&self, cookie: &str, code: &str) -> reqwest::Response {
+        self.client
+            .post(&format!("{}/login/second-factor", &self.address))
+            .header(header::COOKIE, cookie)
+            .json(&json!({ "code": code }))
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+}
+
+/// Test application with strict rate limits.
+pub struct RateLimitTestApp(pub TestApp);
+
+#[async_trait::async_trait]
+impl AsyncTestContext for RateLimitTestApp {
+    async fn setup() -> RateLimitTestApp {
+        RateLimitTestApp(
+            TestApp::build(|c| {
+                c.api.rate_limit.ip_attempts = 10;
+                c.api.rate_limit.account_failures = 3;
+                c.api.rate_limit.lockout = 60;
+            })
+            .await,
+        )
+    }
+
+    async fn teardown(self) {
+        self.0.teardown().await
+    }
+}
+
+impl Deref for RateLimitTestApp {
+    type Target = TestApp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Test application where users log in through a mock identity provider.
+#[cfg(feature = "oidc")]
+pub struct OidcTestApp {
+    pub app: TestApp,
+    pub idp: MockIdentityProvider,
+    /// Client reporting the redirections instead of following them.
+    pub browser: reqwest::Client,
+}
+
+#[cfg(feature = "oidc")]
+#[async_trait::async_trait]
+impl AsyncTestContext for OidcTestApp {
+    async fn setup() -> OidcTestApp {
+        let idp = MockIdentityProvider::start().await;
+        let app = TestApp::build(|c| c.application.auth = AuthSettings::Oidc(idp.settings())).await;
+        let browser = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        OidcTestApp { app, idp, browser }
+    }
+
+    async fn teardown(self) {
+        self.app.teardown().await
+    }
+}
+
+#[cfg(feature = "oidc")]
+impl Deref for OidcTestApp {
+    type Target = TestApp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+#[cfg(feature = "oidc")]
+impl OidcTestApp {
+    /// Starts a login, returning the session cookie and the authorization request the user is
+    /// redirected to the provider with.
+    pub async fn start_login(&self) -> (String, Authorization) {
+        let response = self
+            .browser
+            .get(&format!("{}/oidc/login", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status().as_u16(), 302);
+        let cookie = format!(
+            "id={}",
+            response
+                .cookies()
+                .next()
+                .expect("Cookie should exist")
+                .value()
+        );
+        let location = response.headers()[header::LOCATION].to_str().unwrap();
+        assert!(location.starts_with(&format!("{}/authorize?", self.idp.server.uri())));
+        (cookie, Authorization::from_url(location))
+    }
+
+    /// Returns from the provider with the code and state.
+    pub async fn callback(&self, cookie: &str, code: &str, state: &str) -> reqwest::Response {
+        self.browser
+            .get(&format!("{}/oidc/callback", &self.address))
+            .query(&[("code", code), ("state", state)])
+            .header("Cookie", cookie)
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    /// Logs in through the provider as the user with the email, returning their session.
+    pub async fn login_through_provider(&self, email: &str) -> LoginData {
+        let (cookie, authorization) = self.start_login().await;
+        let claims = self.idp.claims(&authorization, email);
+        self.idp.issue_token("code", &authorization, claims).await;
+        let response = self.callback(&cookie, "code", &authorization.state).await;
+        assert_eq!(response.status().as_u16(), 200);
+        let cookie = format!(
+            "id={}",
+            response
+                .cookies()
+                .next()
+                .expect("Cookie should exist")
+                .value()
+        );
+        let body = response.json::<LoginResponse>().await.unwrap();
+        LoginData {
+            id: body.data.id,
+            cookie,
+        }
+    }
+}
+
+impl TestApp {
+    /// Starts the application with the test configuration, adjusted by `configure`.
+    pub async fn build(configure: impl FnOnce(&mut Settings)) -> TestApp {
         Lazy::force(&TRACING);
 
         let infra: Infra = std::env::var("KORU_ENV")
@@ -116,20 +401,28 @@ impl AsyncTestContext for TestApp {
 
         match infra {
             Infra::Memory => {
-                let configuration = in_memory_config();
+                let mut configuration = in_memory_config();
+                let mail_dir = use_mail_dir(&mut configuration);
+                without_notifications(&mut configuration);
+                configure(&mut configuration);
                 let db = MemTestDb::build().await;
-                let (event_bus, _) = EventBusImpl::build(&configuration.event_bus)
+                let store = Arc::new(StoreImpl::Memory(db.store.clone()));
+                let (event_bus, event_listener) = EventBusImpl::build(&configuration.event_bus)
                     .await
                     .expect("Failed to setup event bus.");
 
                 let app = Application::build(
                     &configuration.application,
-                    Arc::new(StoreImpl::Memory(db.store.clone())),
+                    store.clone(),
                     event_bus,
                     // use a small one to reduce test speed
                     Some(128),
                 )
                 .expect("Failed to setup application.");
+                let worker = Worker::build(&configuration.application, event_listener, store)
+                    .await
+                    .expect("Failed to setup worker.");
+                let _ = tokio::spawn(worker.run());
 
                 // Initialize API
                 let api = RestApi::build(&configuration.api, app)
@@ -142,28 +435,37 @@ impl AsyncTestContext for TestApp {
                     address: format!("http://127.0.0.1:{}", port),
                     db: TestDb::Memory(db),
                     client,
+                    mail_dir,
                 }
             }
             #[cfg(any(feature = "production", feature = "development"))]
             Infra::External => {
-                let configuration = ext_config();
+                let mut configuration = ext_config();
+                let mail_dir = use_mail_dir(&mut configuration);
+                without_notifications(&mut configuration);
+                configure(&mut configuration);
                 let db = PgTestDb::build(&configuration).await;
-                let (event_bus, _) = EventBusImpl::build(&configuration.event_bus)
+                let store = Arc::new(
+                    StoreImpl::build(&configuration.database)
+                        .await
+                        .expect("Failed to start store."),
+                );
+                let (event_bus, event_listener) = EventBusImpl::build(&configuration.event_bus)
                     .await
                     .expect("Failed to setup event bus.");
 
                 let app = Application::build(
                     &configuration.application,
-                    Arc::new(
-                        StoreImpl::build(&configuration.database)
-                            .await
-                            .expect("Failed to start store."),
-                    ),
+                    store.clone(),
                     event_bus,
                     // use a small one to reduce test speed
                     Some(128),
                 )
                 .expect("Failed to setup application.");
+                let worker = Worker::build(&configuration.application, event_listener, store)
+                    .await
+                    .expect("Failed to setup worker.");
+                let _ = tokio::spawn(worker.run());
 
                 // Initialize API
                 let api = RestApi::build(&configuration.api, app)
@@ -176,21 +478,43 @@ impl AsyncTestContext for TestApp {
                     address: format!("http://127.0.0.1:{}", port),
                     db: TestDb::Postgres(db),
                     client,
+                    mail_dir,
                 }
             }
         }
     }
 
-    async fn teardown(self) {
-        match self.db {
-            #[cfg(feature = "postgres")]
-            TestDb::Postgres(db) => db.teardown().await,
-            TestDb::Memory(_) => {}
+    /// Returns the content of the last mail sent, if any.
+    pub fn last_mail(&self) -> Option<String> {
+        let mut mails = std::fs::read_dir(&self.mail_dir)
+            .map(|entries| entries.map(|e| e.unwrap().path()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        mails.sort();
+        mails
+            .last()
+            .map(|path| std::fs::read_to_string(path).expect("Failed to read mail."))
+    }
+
+    /// Waits for the worker to send a mail, returning the last one sent.
+    pub async fn wait_for_mail(&self) -> String {
+        for _ in 0..100 {
+            if let Some(mail) = self.last_mail() {
+                return mail;
+            }
+            sleep(Duration::from_millis(20)).await;
         }
+        panic!("A mail should be sent");
+    }
+
+    /// Returns the token of the link in the last mail sent.
+    pub fn last_mail_token(&self) -> String {
+        let mail = self.last_mail().expect("A mail should be sent");
+        let (_, token) = mail
+            .split_once("token=")
+            .expect("The mail should hold a token");
+        token.split_whitespace().next().unwrap().to_string()
     }
-}
 
-impl TestApp {
     pub async fn create_user(&self, name: &str, email: &str, password: &str) {
         let _ = self
             .client
@@ -201,6 +525,15 @@ impl TestApp {
             .expect("Failed to execute request.");
     }
 
+    pub async fn try_login(&self, email: &str, password: &str) -> reqwest::Response {
+        self.client
+            .post(&format!("{}/login", &self.address))
+            .json(&json!({"email":email,"password":password}))
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
     pub async fn login(&self, email: &str, password: &str) -> Result<LoginData> {
         let response = self
             .client
@@ -265,6 +598,31 @@ impl TestApp {
         self.login(email, password).await
     }
 
+    pub async fn create_api_token(&self, scope: &str, cookie: &str) -> Result<ApiTokenData> {
+        let response = self
+            .client
+            .post(&format!("{}/me/tokens", &self.address))
+            .header(header::COOKIE, cookie)
+            .json(&json!({ "name": "script", "scope": scope, "validity_days": 30 }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        let body = response.json::<CreateApiTokenResponse>().await?;
+        Ok(body.data)
+    }
+
+    pub async fn get_sessions(&self, cookie: &str) -> Result<Vec<SessionData>> {
+        let response = self
+            .client
+            .get(&format!("{}/me/sessions", &self.address))
+            .header(header::COOKIE, cookie)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        let body = response.json::<SessionsResponse>().await?;
+        Ok(body.data.sessions)
+    }
+
     pub async fn register_device(&self, device: &str, cookie: &str) {
         let _ = self
             .client
@@ -526,12 +884,16 @@ pub struct TransactionDto {
     pub from_user_id: Uuid,
     pub to_user_id: Uuid,
     pub amount: f32,
+    pub paid: bool,
 }
 
 #[derive(Debug)]
 pub struct SettlementDto {
     pub id: Uuid,
     pub group_id: Uuid,
+    pub label: Option<String>,
+    pub total: f32,
+    pub expense_count: u32,
 }
 
 #[derive(Debug)]
@@ -581,6 +943,63 @@ pub struct Group {
     pub id: Uuid,
 }
 
+#[derive(Deserialize)]
+pub struct CreateApiTokenResponse {
+    pub success: bool,
+    pub data: ApiTokenData,
+}
+
+#[derive(Deserialize)]
+pub struct ApiTokenData {
+    pub token: String,
+    pub api_token: ApiTokenDto,
+}
+
+#[derive(Deserialize)]
+pub struct ApiTokenDto {
+    pub id: Uuid,
+    pub name: String,
+    pub scope: String,
+}
+
+#[derive(Deserialize)]
+pub struct SessionsResponse {
+    pub data: SessionsData,
+}
+
+#[derive(Deserialize)]
+pub struct SessionsData {
+    pub sessions: Vec<SessionData>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SessionData {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub current: bool,
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorResponse {
+    pub data: TwoFactorData,
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorData {
+    pub secret: String,
+    pub provisioning_uri: String,
+    pub recovery_codes: Vec<String>,
+}
+
+impl TwoFactorData {
+    pub fn current_code(&self) -> String {
+        current_code(&Secret::new(self.secret.clone())).expect("Secret should be base32")
+    }
+}
+
 #[derive(Deserialize)]
 pub struct LoginResponse {
     pub success: bool,
@@ -647,9 +1066,21 @@ pub struct SettlementData {
     pub id: Uuid,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: DateTime<Utc>,
+    pub label: Option<String>,
+    pub total: f32,
+    pub expense_count: u32,
+    pub balances: Vec<BalanceData>,
     pub transactions: Vec<TransactionData>,
 }
 
+#[derive(serde::Deserialize, Debug, PartialEq)]
+pub struct BalanceData {
+    pub member: UserData,
+    pub paid: f32,
+    pub share: f32,
+    pub net: f32,
+}
+
 #[derive(serde::Deserialize, Debug, PartialEq)]
 pub struct TransactionData {
     pub from: UserData,
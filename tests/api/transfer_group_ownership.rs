@@ -0,0 +1,96 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_group_ownership_returns_204_and_changes_the_admin(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &admin.cookie)
+        .json(&json!({ "user_id": other_user.id }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    let saved = app.get_group_by_id(group.id).await.unwrap();
+    assert_eq!(saved.admin_id, other_user.id);
+    assert_eq!(app.get_event_type().await.unwrap(), "OwnershipTransferred");
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_group_ownership_returns_403_for_non_admin_users(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &other_user.cookie)
+        .json(&json!({ "user_id": other_user.id }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    let saved = app.get_group_by_id(group.id).await.unwrap();
+    assert_eq!(saved.admin_id, group.admin.id);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_group_ownership_returns_404_for_non_members(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/admin/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &admin.cookie)
+        .json(&json!({ "user_id": admin.id }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+use uuid::Uuid;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_ownership_returns_200_and_changes_the_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .json(&json!({ "user_id": other_user.id }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    let saved = app.get_group_by_id(group.id).await.unwrap();
+    assert_eq!(saved.admin_id, other_user.id);
+    let response = app
+        .client
+        .delete(&format!("{}/me", &app.address))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 204);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_ownership_returns_403_if_user_is_not_admin(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    let other_user = app
+        .create_user_and_login_and_device("r", "r3@r.com", "123")
+        .await?;
+    app.join_group(&group, other_user.cookie.as_str()).await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &other_user.cookie)
+        .json(&json!({ "user_id": other_user.id }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 403);
+    let saved = app.get_group_by_id(group.id).await.unwrap();
+    assert_eq!(saved.admin_id, group.admin.id);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn transfer_ownership_returns_404_if_new_admin_is_not_a_member(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/groups/{}/admin", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .json(&json!({ "user_id": Uuid::new_v4() }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 404);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn unarchive_group_returns_204_and_makes_the_group_writable(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+    app.client
+        .post(&format!(
+            "{}/admin/groups/{}/archive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/groups/{}/unarchive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 204);
+    assert_eq!(app.get_event_type().await.unwrap(), "GroupUnarchived");
+    app.create_expense(&group.id, &group.admin.cookie, "my expense", 12.0)
+        .await?;
+    assert!(app.get_expense().await.is_some());
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn unarchive_group_returns_409_for_groups_not_archived(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let admin = app
+        .create_admin_and_login_and_device("admin", "a@r.com", "201")
+        .await?;
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!(
+            "{}/admin/groups/{}/unarchive",
+            &app.address, group.id
+        ))
+        .header(header::COOKIE, &admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 409);
+    Ok(())
+}
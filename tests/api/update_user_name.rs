@@ -0,0 +1,78 @@
+use crate::test_app::TestApp;
+use reqwest::header;
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn update_user_name_returns_200_and_renames_the_member(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let group = app
+        .create_user_and_group("rbiland", "r@r.com", "201", "my group")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/name", &app.address))
+        .header(header::COOKIE, &group.admin.cookie)
+        .json(&json!({"name":"Romain"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(app.get_user().await.name, "Romain");
+    let response = app
+        .client
+        .get(&format!("{}/groups/{}", &app.address, group.id))
+        .header(header::COOKIE, &group.admin.cookie)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let body = response.json::<serde_json::Value>().await?;
+    assert_eq!(body["data"]["group"]["members"][0]["name"], "Romain");
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn update_user_name_returns_400_for_an_empty_name(app: &TestApp) -> anyhow::Result<()> {
+    // Arrange
+    let user = app
+        .create_user_and_login_and_device("rbiland", "r@r.com", "201")
+        .await?;
+
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/name", &app.address))
+        .header(header::COOKIE, &user.cookie)
+        .json(&json!({"name":""}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert_eq!(app.get_user().await.name, "rbiland");
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn update_user_name_returns_401_when_not_logged_in(app: &TestApp) -> anyhow::Result<()> {
+    // Act
+    let response = app
+        .client
+        .put(&format!("{}/me/name", &app.address))
+        .json(&json!({"name":"Romain"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 401);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::test_app::{TestApp, VerificationTestApp};
+use serde_json::json;
+use test_context::test_context;
+
+#[test_context(VerificationTestApp)]
+#[tokio::test]
+async fn verify_email_returns_200_and_the_user_can_log_in(
+    app: &VerificationTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+    let response = app
+        .client
+        .post(&format!("{}/login", &app.address))
+        .json(&json!({"email":"r@r.com","password":"201"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status().as_u16(), 403);
+    let token = app.last_mail_token();
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/verification", &app.address))
+        .json(&json!({ "token": token }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 200);
+    app.login("r@r.com", "201").await?;
+    Ok(())
+}
+
+#[test_context(VerificationTestApp)]
+#[tokio::test]
+async fn verify_email_returns_400_given_an_unknown_token(
+    app: &VerificationTestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/verification", &app.address))
+        .json(&json!({"token":"0123456789abcdef"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    Ok(())
+}
+
+#[test_context(TestApp)]
+#[tokio::test]
+async fn verify_email_returns_400_when_verification_is_disabled(
+    app: &TestApp,
+) -> anyhow::Result<()> {
+    // Arrange
+    app.create_user("rbiland", "r@r.com", "201").await;
+
+    // Act
+    let response = app
+        .client
+        .post(&format!("{}/verification", &app.address))
+        .json(&json!({"token":"0123456789abcdef"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status().as_u16(), 400);
+    assert!(app.last_mail().is_none());
+    app.login("r@r.com", "201").await?;
+    Ok(())
+}
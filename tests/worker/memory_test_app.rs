@@ -67,6 +67,11 @@ impl MemTestApp {
                 email,
                 role: InnerRole::USER,
                 created_at: Utc::now(),
+                deleted_at: None,
+                sessions_revoked_at: None,
+                email_verified: true,
+                disabled_at: None,
+                password_reset_required: false,
             },
         );
         self.store.user_devices.lock().unwrap().insert(id, device);
@@ -85,6 +90,7 @@ impl MemTestApp {
                 member_ids: vec![admin],
                 expenses: vec![],
                 settlements: vec![],
+                archived_at: None,
             },
         );
         self.store.members.lock().unwrap().insert(
@@ -144,6 +150,7 @@ impl MemTestApp {
             event: InnerEventKind::from(event),
             date: Utc::now(),
             processed_date: None,
+            origin: Default::default(),
         });
         id
     }
@@ -270,9 +277,39 @@ impl From<EventKindDto> for InnerEventKind {
                         from: t.from,
                         to: t.to,
                         amount: t.amount,
+                        paid: false,
                     })
                     .collect_vec(),
             },
+            EventKindDto::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            } => InnerEventKind::SettlementReverted {
+                id,
+                group_id,
+                member_id,
+            },
+            EventKindDto::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period,
+            } => InnerEventKind::BudgetChanged {
+                group_id,
+                member_id,
+                amount,
+                period,
+            },
+            EventKindDto::OwnershipTransferred {
+                group_id,
+                member_id,
+                new_admin_id,
+            } => InnerEventKind::OwnershipTransferred {
+                group_id,
+                member_id,
+                new_admin_id,
+            },
             EventKindDto::GroupDeleted { id, admin_id } => {
                 InnerEventKind::GroupDeleted { id, admin_id }
             }
@@ -255,6 +255,22 @@ pub enum EventKindDto {
         end_date: DateTime<Utc>,
         transactions: Vec<TransactionDto>,
     },
+    SettlementReverted {
+        id: Uuid,
+        group_id: Uuid,
+        member_id: Uuid,
+    },
+    BudgetChanged {
+        group_id: Uuid,
+        member_id: Uuid,
+        amount: Option<f32>,
+        period: Option<String>,
+    },
+    OwnershipTransferred {
+        group_id: Uuid,
+        member_id: Uuid,
+        new_admin_id: Uuid,
+    },
     GroupDeleted {
         id: Uuid,
         admin_id: Uuid,